@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
+use crate::guardrail::GuardrailSpec;
 use crate::mcp::McpServerConfig;
 use crate::AofResult;
 
@@ -382,6 +383,14 @@ impl ToolSpec {
         }
     }
 
+    /// Whether results are cached per git commit (`config: {commit_cache: true}`)
+    pub fn commit_cached(&self) -> bool {
+        self.config()
+            .and_then(|config| config.get("commit_cache"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
     /// Get type-based spec if this is a type-based tool
     pub fn type_based_spec(&self) -> Option<&TypeBasedToolSpec> {
         match self {
@@ -533,6 +542,11 @@ pub struct ExecutionMetadata {
     pub tool_calls: usize,
     /// Model used
     pub model: Option<String>,
+    /// Reasoning/thinking captured from the model, one entry per model turn.
+    /// Kept for audit and debugging; never part of the user-facing answer.
+    pub reasoning: Vec<String>,
+    /// Reasoning tokens reported by the provider
+    pub reasoning_tokens: usize,
 }
 
 impl AgentContext {
@@ -661,11 +675,48 @@ pub struct AgentConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_tokens: Option<usize>,
 
+    /// Stop sequences passed to the model
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop: Vec<String>,
+
+    /// Sampling seed passed to the model, for reproducible output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+
     /// Output schema for structured responses (JSON Schema format)
     /// When specified, agent responses will be validated against this schema
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_schema: Option<OutputSchemaSpec>,
 
+    /// Guardrails run over the final output before it is returned
+    /// (e.g. secret redaction, blocked patterns)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub guardrails: Vec<GuardrailSpec>,
+
+    /// Max tool calls executed per model turn; extra calls are rejected
+    /// back to the model instead of being executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tool_calls_per_turn: Option<usize>,
+
+    /// Max tool calls executed across the whole run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tool_calls_per_run: Option<usize>,
+
+    /// Auto-continue responses truncated by `max_tokens`, up to this many
+    /// continuation turns (disabled when unset)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_continuations: Option<usize>,
+
+    /// Split tool results longer than this many characters into pages the
+    /// model reads with the `fetch_more` tool (disabled when unset)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_result_page_size: Option<usize>,
+
+    /// Offer the `list_tools` meta-tool so the model can look up its
+    /// available tools mid-conversation (default: false)
+    #[serde(default)]
+    pub tool_discovery: bool,
+
     /// Custom configuration
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
@@ -682,6 +733,15 @@ impl AgentConfig {
         self.tools.iter().filter(|t| t.is_builtin()).collect()
     }
 
+    /// Names of built-in tools whose results are cached per git commit
+    pub fn commit_cached_tools(&self) -> Vec<String> {
+        self.tools
+            .iter()
+            .filter(|t| t.is_builtin() && t.commit_cached())
+            .map(|t| t.name().to_string())
+            .collect()
+    }
+
     /// Get MCP tools only
     pub fn mcp_tools(&self) -> Vec<&ToolSpec> {
         self.tools.iter().filter(|t| t.is_mcp()).collect()
@@ -908,7 +968,18 @@ struct AgentSpec {
     #[serde(default = "default_temperature")]
     temperature: f32,
     max_tokens: Option<usize>,
+    #[serde(default)]
+    stop: Vec<String>,
+    seed: Option<u64>,
     output_schema: Option<OutputSchemaSpec>,
+    #[serde(default)]
+    guardrails: Vec<GuardrailSpec>,
+    max_tool_calls_per_turn: Option<usize>,
+    max_tool_calls_per_run: Option<usize>,
+    max_continuations: Option<usize>,
+    tool_result_page_size: Option<usize>,
+    #[serde(default)]
+    tool_discovery: bool,
     #[serde(flatten)]
     extra: HashMap<String, serde_json::Value>,
 }
@@ -932,7 +1003,18 @@ struct FlatAgentConfig {
     #[serde(default = "default_temperature")]
     temperature: f32,
     max_tokens: Option<usize>,
+    #[serde(default)]
+    stop: Vec<String>,
+    seed: Option<u64>,
     output_schema: Option<OutputSchemaSpec>,
+    #[serde(default)]
+    guardrails: Vec<GuardrailSpec>,
+    max_tool_calls_per_turn: Option<usize>,
+    max_tool_calls_per_run: Option<usize>,
+    max_continuations: Option<usize>,
+    tool_result_page_size: Option<usize>,
+    #[serde(default)]
+    tool_discovery: bool,
     #[serde(flatten)]
     extra: HashMap<String, serde_json::Value>,
 }
@@ -952,7 +1034,15 @@ impl From<AgentConfigInput> for AgentConfig {
                 max_iterations: flat.max_iterations,
                 temperature: flat.temperature,
                 max_tokens: flat.max_tokens,
+                stop: flat.stop,
+                seed: flat.seed,
                 output_schema: flat.output_schema,
+                guardrails: flat.guardrails,
+                max_tool_calls_per_turn: flat.max_tool_calls_per_turn,
+                max_tool_calls_per_run: flat.max_tool_calls_per_run,
+                max_continuations: flat.max_continuations,
+                tool_result_page_size: flat.tool_result_page_size,
+                tool_discovery: flat.tool_discovery,
                 extra: flat.extra,
             },
             AgentConfigInput::Kubernetes(k8s) => {
@@ -968,7 +1058,15 @@ impl From<AgentConfigInput> for AgentConfig {
                     max_iterations: k8s.spec.max_iterations,
                     temperature: k8s.spec.temperature,
                     max_tokens: k8s.spec.max_tokens,
+                    stop: k8s.spec.stop,
+                    seed: k8s.spec.seed,
                     output_schema: k8s.spec.output_schema,
+                    guardrails: k8s.spec.guardrails,
+                    max_tool_calls_per_turn: k8s.spec.max_tool_calls_per_turn,
+                    max_tool_calls_per_run: k8s.spec.max_tool_calls_per_run,
+                    max_continuations: k8s.spec.max_continuations,
+                    tool_result_page_size: k8s.spec.tool_result_page_size,
+                    tool_discovery: k8s.spec.tool_discovery,
                     extra: k8s.spec.extra,
                 }
             }
@@ -1060,6 +1158,7 @@ mod tests {
         assert_eq!(config.temperature, 0.7); // default
         assert!(config.tools.is_empty());
         assert!(config.system_prompt.is_none());
+        assert!(!config.tool_discovery); // default
     }
 
     #[test]
@@ -1086,6 +1185,30 @@ mod tests {
         assert_eq!(config.max_tokens, Some(4096));
     }
 
+    #[test]
+    fn test_agent_config_stop_and_seed() {
+        let yaml = r#"
+            apiVersion: aof.dev/v1
+            kind: Agent
+            metadata:
+              name: reporter
+            spec:
+              model: openai:gpt-4o
+              seed: 42
+              stop: ["END_OF_REPORT"]
+        "#;
+        let config: AgentConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.seed, Some(42));
+        assert_eq!(config.stop, vec!["END_OF_REPORT"]);
+        assert!(!config.extra.contains_key("seed"));
+
+        // Invalid values fail at load instead of being silently dropped
+        let bad_seed = "name: reporter\nmodel: gpt-4o\nseed: -1\n";
+        assert!(serde_yaml::from_str::<AgentConfig>(bad_seed).is_err());
+        let bad_stop = "name: reporter\nmodel: gpt-4o\nstop: END\n";
+        assert!(serde_yaml::from_str::<AgentConfig>(bad_stop).is_err());
+    }
+
     #[test]
     fn test_tool_spec_simple() {
         let yaml = r#"
@@ -1124,6 +1247,26 @@ mod tests {
         assert!(config.tools[0].config().is_some());
     }
 
+    #[test]
+    fn test_tool_spec_commit_cache() {
+        let yaml = r#"
+            name: test-agent
+            model: gpt-4
+            tools:
+              - git_status
+              - name: git_log
+                config:
+                  commit_cache: true
+              - name: git_diff_summary
+                config:
+                  commit_cache: false
+        "#;
+        let config: AgentConfig = serde_yaml::from_str(yaml).unwrap();
+
+        assert!(!config.tools[0].commit_cached());
+        assert_eq!(config.commit_cached_tools(), vec!["git_log".to_string()]);
+    }
+
     #[test]
     fn test_tool_spec_qualified_mcp() {
         let yaml = r#"
@@ -485,6 +485,10 @@ pub struct FlowConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_timeout_seconds: Option<u32>,
 
+    /// Top-level timeout for the whole run; in-flight nodes are cancelled when exceeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+
     /// Retry configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retry: Option<FlowRetryConfig>,
@@ -579,6 +583,9 @@ pub enum FlowExecutionStatus {
     Failed,
     /// Flow was cancelled
     Cancelled,
+    /// Flow exceeded its top-level timeout
+    #[serde(rename = "timed_out")]
+    TimedOut,
 }
 
 /// Result of node execution
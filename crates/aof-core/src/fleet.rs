@@ -68,6 +68,25 @@ pub struct FleetSpec {
     /// Scaling configuration
     #[serde(default)]
     pub scaling: Option<ScalingConfig>,
+
+    /// Persist fleet state and task history across restarts
+    #[serde(default)]
+    pub persistence: Option<FleetPersistenceConfig>,
+}
+
+/// Where and how long fleet history is kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FleetPersistenceConfig {
+    /// JSON file holding the fleet state and completed tasks
+    pub path: String,
+
+    /// Keep at most this many completed tasks
+    #[serde(default)]
+    pub max_tasks: Option<usize>,
+
+    /// Drop tasks that finished more than this many seconds ago
+    #[serde(default)]
+    pub max_age_secs: Option<u64>,
 }
 
 /// Agent definition within a fleet
@@ -187,6 +206,10 @@ pub struct CoordinationConfig {
     /// Deep execution configuration (for deep mode)
     #[serde(default)]
     pub deep: Option<DeepConfig>,
+
+    /// Send each agent's model a tiny request at start to prime connections
+    #[serde(default)]
+    pub warmup: bool,
 }
 
 /// Configuration for tiered coordination mode
@@ -288,6 +311,7 @@ impl Default for CoordinationConfig {
             aggregation: None,
             tiered: None,
             deep: None,
+            warmup: false,
         }
     }
 }
@@ -387,6 +411,19 @@ pub struct ConsensusConfig {
     /// Below this threshold, result is flagged for human review
     #[serde(default)]
     pub min_confidence: Option<f32>,
+
+    /// How `majority_tie_break` resolves a tie between the top answers
+    #[serde(default)]
+    pub tie_breaker: TieBreaker,
+
+    /// Agent whose answer wins a tie (for `tie_breaker: agent`)
+    #[serde(default)]
+    pub tiebreaker_agent: Option<String>,
+
+    /// Similarity (0.0-1.0) at which differently worded answers count as the same
+    /// Unset: answers must match exactly (ignoring case and surrounding whitespace)
+    #[serde(default)]
+    pub similarity_threshold: Option<f32>,
 }
 
 /// Consensus algorithm type
@@ -397,6 +434,7 @@ pub struct ConsensusConfig {
 /// - **Weighted**: Per-agent weights (senior reviewers count more). Balanced expertise.
 /// - **FirstWins**: First response wins. Fastest, no consensus overhead.
 /// - **HumanReview**: Flags for human operator decision. High-stakes scenarios.
+/// - **MajorityTieBreak**: Majority voting; ties resolved by a `TieBreaker` rule.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ConsensusAlgorithm {
@@ -411,6 +449,21 @@ pub enum ConsensusAlgorithm {
     FirstWins,
     /// Flags result for human operator review
     HumanReview,
+    /// Majority voting with a configurable tie-breaker
+    MajorityTieBreak,
+}
+
+/// Rule that resolves a tie between equally voted answers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreaker {
+    /// Answer whose agents have the highest total weight
+    #[default]
+    HighestWeight,
+    /// Answer whose agents report the highest average confidence
+    HighestConfidence,
+    /// Answer given by the designated `tiebreaker_agent`
+    Agent,
 }
 
 /// Shared resources configuration
@@ -508,6 +561,10 @@ pub struct CommunicationConfig {
     /// Broadcast configuration
     #[serde(default)]
     pub broadcast: Option<BroadcastConfig>,
+
+    /// Message exchange rounds between peer agents before consensus (0: none)
+    #[serde(default)]
+    pub rounds: u32,
 }
 
 /// Message passing pattern
@@ -840,6 +897,44 @@ impl AgentFleet {
             }
         }
 
+        // Validate the designated tiebreaker agent is part of the fleet
+        if let Some(consensus) = &self.spec.coordination.consensus {
+            if consensus.algorithm == ConsensusAlgorithm::MajorityTieBreak
+                && consensus.tie_breaker == TieBreaker::Agent
+            {
+                match &consensus.tiebreaker_agent {
+                    Some(name) if self.spec.agents.iter().any(|a| &a.name == name) => {}
+                    Some(name) => {
+                        return Err(crate::AofError::config(format!(
+                            "Tiebreaker agent '{}' is not in the fleet",
+                            name
+                        )));
+                    }
+                    None => {
+                        return Err(crate::AofError::config(
+                            "tie_breaker: agent requires tiebreaker_agent".to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Validate the similarity threshold is a fraction
+        if let Some(threshold) = self
+            .spec
+            .coordination
+            .consensus
+            .as_ref()
+            .and_then(|c| c.similarity_threshold)
+        {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(crate::AofError::config(format!(
+                    "similarity_threshold must be between 0.0 and 1.0, got {}",
+                    threshold
+                )));
+            }
+        }
+
         // Validate agent configurations
         for agent in &self.spec.agents {
             if agent.config.is_none() && agent.spec.is_none() {
@@ -1095,6 +1190,7 @@ spec:
             ("weighted", ConsensusAlgorithm::Weighted),
             ("first_wins", ConsensusAlgorithm::FirstWins),
             ("human_review", ConsensusAlgorithm::HumanReview),
+            ("majority_tie_break", ConsensusAlgorithm::MajorityTieBreak),
         ];
 
         for (yaml_value, expected) in algorithms {
@@ -1188,6 +1284,65 @@ spec:
         assert_eq!(consensus.algorithm, ConsensusAlgorithm::HumanReview);
         assert_eq!(consensus.timeout_ms, Some(300000));
         assert_eq!(consensus.min_confidence, Some(0.9));
+        assert_eq!(consensus.similarity_threshold, None);
+    }
+
+    #[test]
+    fn test_similarity_threshold_validation() {
+        let yaml = r#"
+apiVersion: aof.dev/v1
+kind: AgentFleet
+metadata:
+  name: rca-team
+spec:
+  agents:
+    - name: analyzer-1
+      config: ./analyzer.yaml
+  coordination:
+    mode: peer
+    consensus:
+      algorithm: majority
+      similarity_threshold: 0.7
+"#;
+
+        let fleet = AgentFleet::from_yaml(yaml).unwrap();
+        let consensus = fleet.spec.coordination.consensus.as_ref().unwrap();
+        assert_eq!(consensus.similarity_threshold, Some(0.7));
+        assert!(fleet.validate().is_ok());
+
+        let invalid = AgentFleet::from_yaml(&yaml.replace("0.7", "1.5")).unwrap();
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_tiebreaker_agent_consensus() {
+        let yaml = r#"
+apiVersion: aof.dev/v1
+kind: AgentFleet
+metadata:
+  name: tie-team
+spec:
+  agents:
+    - name: senior
+      config: ./agent.yaml
+    - name: junior
+      config: ./agent.yaml
+  coordination:
+    mode: peer
+    consensus:
+      algorithm: majority_tie_break
+      tie_breaker: agent
+      tiebreaker_agent: senior
+"#;
+
+        let fleet = AgentFleet::from_yaml(yaml).unwrap();
+        let consensus = fleet.spec.coordination.consensus.as_ref().unwrap();
+        assert_eq!(consensus.algorithm, ConsensusAlgorithm::MajorityTieBreak);
+        assert_eq!(consensus.tie_breaker, TieBreaker::Agent);
+        assert!(fleet.validate().is_ok());
+
+        let unknown = AgentFleet::from_yaml(&yaml.replace("tiebreaker_agent: senior", "tiebreaker_agent: lead")).unwrap();
+        assert!(unknown.validate().is_err());
     }
 
     #[test]
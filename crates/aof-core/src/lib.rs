@@ -11,6 +11,7 @@ pub mod context;
 pub mod error;
 pub mod error_tracker;
 pub mod fleet;
+pub mod guardrail;
 pub mod mcp;
 pub mod memory;
 pub mod model;
@@ -29,11 +30,16 @@ pub use agent::{
 };
 pub use error::{AofError, AofResult};
 pub use error_tracker::{ErrorKnowledgeBase, ErrorRecord, ErrorStats};
+pub use guardrail::{
+    Guardrail, GuardrailAction, GuardrailChain, GuardrailDecision, GuardrailOutcome, GuardrailSpec,
+    PatternGuardrail, SecretRedactionGuardrail,
+};
 pub use mcp::{McpServerConfig, McpTransport};
 pub use memory::{Memory, MemoryBackend, MemoryEntry, MemoryQuery};
 pub use model::{
     Model, ModelConfig, ModelProvider, ModelRequest, ModelResponse, RequestMessage, StopReason,
-    StreamChunk, ToolDefinition as ModelToolDefinition, Usage,
+    StreamChunk, ToolDefinition as ModelToolDefinition, Usage, REASONING_METADATA_KEY,
+    REASONING_TOKENS_METADATA_KEY,
 };
 pub use schema::{FormatHint, InputSchema, OutputSchema};
 pub use tool::{
@@ -49,9 +55,10 @@ pub use workflow::{
 pub use fleet::{
     AgentFleet, AgentInstanceState, AgentInstanceStatus, AgentRole, CoordinationConfig,
     CoordinationMode, ConsensusConfig, ConsensusAlgorithm, DeepConfig, FinalAggregation, FleetAgent,
+    FleetPersistenceConfig,
     FleetAgentSpec, FleetMetadata, FleetMetrics, FleetSpec, FleetState, FleetStatus, FleetTask,
     FleetTaskStatus, SharedResources, SharedMemoryConfig, SharedMemoryType, CommunicationConfig,
-    MessagePattern, TaskDistribution, ScalingConfig, TieredConfig,
+    MessagePattern, TaskDistribution, ScalingConfig, TieBreaker, TieredConfig,
 };
 pub use agentflow::{
     AgentFlow, AgentFlowMetadata, AgentFlowSpec, AgentFlowState, FlowConfig, FlowConnection,
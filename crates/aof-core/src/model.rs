@@ -76,6 +76,16 @@ pub struct ModelConfig {
     #[serde(default = "default_timeout")]
     pub timeout_secs: u64,
 
+    /// Stop sequences; generation stops when any is produced
+    /// (ignored by providers that don't support them)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop: Vec<String>,
+
+    /// Sampling seed for reproducible output
+    /// (ignored by providers that don't support it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+
     /// Custom headers
     #[serde(default)]
     pub headers: HashMap<String, String>,
@@ -175,6 +185,30 @@ pub struct ModelResponse {
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+/// Metadata key for provider-native reasoning/thinking text
+pub const REASONING_METADATA_KEY: &str = "reasoning";
+
+/// Metadata key for provider-reported reasoning token usage
+pub const REASONING_TOKENS_METADATA_KEY: &str = "reasoning_tokens";
+
+impl ModelResponse {
+    /// Provider-native reasoning text, kept separate from `content`
+    pub fn reasoning(&self) -> Option<&str> {
+        self.metadata
+            .get(REASONING_METADATA_KEY)
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+    }
+
+    /// Reasoning tokens reported by the provider (0 if not reported)
+    pub fn reasoning_tokens(&self) -> usize {
+        self.metadata
+            .get(REASONING_TOKENS_METADATA_KEY)
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as usize
+    }
+}
+
 /// Stop reason
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -198,6 +232,8 @@ pub struct Usage {
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum StreamChunk {
     ContentDelta { delta: String },
+    /// Native reasoning text (e.g. extended thinking); never shown as answer text
+    ReasoningDelta { delta: String },
     ToolCall { tool_call: crate::ToolCall },
     Done { usage: Usage, stop_reason: StopReason },
 }
@@ -244,6 +280,8 @@ mod tests {
             temperature: 0.3,
             max_tokens: Some(4096),
             timeout_secs: 120,
+            stop: vec![],
+            seed: None,
             headers: {
                 let mut h = HashMap::new();
                 h.insert("X-Custom".to_string(), "value".to_string());
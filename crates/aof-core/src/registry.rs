@@ -711,7 +711,15 @@ mod tests {
             max_iterations: 10,
             temperature: 0.7,
             max_tokens: None,
+            stop: Vec::new(),
+            seed: None,
             output_schema: None,
+            guardrails: vec![],
+            max_tool_calls_per_turn: None,
+            max_tool_calls_per_run: None,
+            max_continuations: None,
+            tool_result_page_size: None,
+            tool_discovery: false,
             extra: HashMap::new(),
         };
 
@@ -140,6 +140,10 @@ pub struct ToolResult {
 
     /// Execution time (ms)
     pub execution_time_ms: u64,
+
+    /// Details about the call itself rather than its result (e.g. remaining API quota)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, serde_json::Value>,
 }
 
 impl ToolResult {
@@ -150,6 +154,7 @@ impl ToolResult {
             data,
             error: None,
             execution_time_ms: 0,
+            metadata: HashMap::new(),
         }
     }
 
@@ -160,6 +165,7 @@ impl ToolResult {
             data: serde_json::Value::Null,
             error: Some(msg.into()),
             execution_time_ms: 0,
+            metadata: HashMap::new(),
         }
     }
 
@@ -168,6 +174,12 @@ impl ToolResult {
         self.execution_time_ms = ms;
         self
     }
+
+    /// Attach a metadata entry
+    pub fn with_metadata(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.metadata.insert(key.into(), value);
+        self
+    }
 }
 
 /// Tool call (from model)
@@ -89,6 +89,10 @@ pub struct WorkflowSpec {
     /// Reference to an AgentFleet
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fleet: Option<String>,
+
+    /// Top-level execution timeout for the whole workflow (e.g., "15m")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
 }
 
 /// State schema definition (JSON Schema format)
@@ -631,6 +635,9 @@ pub enum WorkflowStatus {
     Failed,
     /// Workflow cancelled
     Cancelled,
+    /// Workflow exceeded its top-level timeout
+    #[serde(rename = "timed_out")]
+    TimedOut,
 }
 
 /// Result of a step execution
@@ -732,6 +739,10 @@ pub struct FlatWorkflowConfig {
     /// Checkpointing configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkpointing: Option<CheckpointConfig>,
+
+    /// Top-level execution timeout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
 }
 
 /// Input format that accepts both flat and Kubernetes-style configs
@@ -767,6 +778,7 @@ impl From<WorkflowConfigInput> for Workflow {
                     checkpointing: flat.checkpointing,
                     recovery: None,
                     fleet: None,
+                    timeout: flat.timeout,
                 },
             },
         }
@@ -0,0 +1,128 @@
+// Model capability registry
+//
+// Context window and output limits for well-known models, so callers don't
+// have to hardcode them. Lookups accept bare model ids ("gpt-4o"), provider
+// prefixed ids ("google:gemini-2.5-flash") and Bedrock ids
+// ("anthropic.claude-3-5-sonnet-20240620-v1:0").
+
+/// Context window used when a model isn't in the registry
+pub const DEFAULT_CONTEXT_WINDOW: u32 = 128_000;
+
+/// Limits of a known model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelCapabilities {
+    /// Maximum prompt + completion tokens
+    pub context_window: u32,
+    /// Maximum tokens in a single completion
+    pub max_output_tokens: u32,
+}
+
+/// Known model families, matched by longest prefix
+const MODEL_CAPABILITIES: &[(&str, u32, u32)] = &[
+    // Anthropic
+    ("claude-opus-4", 200_000, 32_000),
+    ("claude-sonnet-4", 200_000, 64_000),
+    ("claude-3-7-sonnet", 200_000, 64_000),
+    ("claude-3-5-sonnet", 200_000, 8_192),
+    ("claude-3-5-haiku", 200_000, 8_192),
+    ("claude-3-opus", 200_000, 4_096),
+    ("claude-3-sonnet", 200_000, 4_096),
+    ("claude-3-haiku", 200_000, 4_096),
+    ("claude", 200_000, 4_096),
+    // Google
+    ("gemini-2.5-pro", 1_048_576, 65_536),
+    ("gemini-2.5-flash", 1_048_576, 65_536),
+    ("gemini-2.0-flash", 1_048_576, 8_192),
+    ("gemini-1.5-pro", 2_097_152, 8_192),
+    ("gemini-1.5-flash", 1_048_576, 8_192),
+    ("gemini", 1_048_576, 8_192),
+    // OpenAI
+    ("gpt-5", 400_000, 128_000),
+    ("gpt-4.1", 1_047_576, 32_768),
+    ("gpt-4o", 128_000, 16_384),
+    ("gpt-4-turbo", 128_000, 4_096),
+    ("gpt-4-32k", 32_768, 4_096),
+    ("gpt-4", 8_192, 8_192),
+    ("gpt-3.5-turbo", 16_385, 4_096),
+    ("o1-mini", 128_000, 65_536),
+    ("o1", 200_000, 100_000),
+    ("o3", 200_000, 100_000),
+    ("o4-mini", 200_000, 100_000),
+    // Amazon
+    ("titan-text-premier", 32_000, 3_072),
+    ("titan-text", 8_192, 8_192),
+];
+
+/// Look up the limits of a known model
+pub fn get_model_capabilities(model: &str) -> Option<ModelCapabilities> {
+    let model = model.to_lowercase();
+
+    // Try the id itself and every segment after a provider/vendor separator
+    let starts = std::iter::once(0).chain(
+        model
+            .char_indices()
+            .filter(|(_, c)| matches!(c, ':' | '.' | '/'))
+            .map(|(i, _)| i + 1),
+    );
+
+    starts
+        .flat_map(|start| {
+            let candidate = &model[start..];
+            MODEL_CAPABILITIES
+                .iter()
+                .filter(move |(prefix, _, _)| candidate.starts_with(prefix))
+        })
+        .max_by_key(|(prefix, _, _)| prefix.len())
+        .map(|&(_, context_window, max_output_tokens)| ModelCapabilities {
+            context_window,
+            max_output_tokens,
+        })
+}
+
+/// Context window for a model, falling back to [`DEFAULT_CONTEXT_WINDOW`]
+pub fn context_window(model: &str) -> u32 {
+    get_model_capabilities(model)
+        .map(|caps| caps.context_window)
+        .unwrap_or(DEFAULT_CONTEXT_WINDOW)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_models() {
+        assert_eq!(context_window("google:gemini-2.5-flash"), 1_048_576);
+        assert_eq!(context_window("gemini-1.5-pro-002"), 2_097_152);
+        assert_eq!(context_window("claude-3-5-sonnet-20241022"), 200_000);
+        assert_eq!(context_window("anthropic:claude-sonnet-4-20250514"), 200_000);
+        assert_eq!(context_window("openai:gpt-4o-mini"), 128_000);
+        assert_eq!(context_window("gpt-4"), 8_192);
+        assert_eq!(context_window("gpt-4-turbo-2024-04-09"), 128_000);
+        assert_eq!(context_window("o3-mini"), 200_000);
+    }
+
+    #[test]
+    fn test_bedrock_ids() {
+        let caps = get_model_capabilities("anthropic.claude-3-5-sonnet-20240620-v1:0").unwrap();
+        assert_eq!(caps.context_window, 200_000);
+        assert_eq!(caps.max_output_tokens, 8_192);
+
+        assert_eq!(context_window("us.anthropic.claude-3-7-sonnet-20250219-v1:0"), 200_000);
+        assert_eq!(context_window("amazon.titan-text-express-v1"), 8_192);
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        assert_eq!(get_model_capabilities("o1-mini").unwrap().max_output_tokens, 65_536);
+        assert_eq!(get_model_capabilities("o1-preview").unwrap().max_output_tokens, 100_000);
+        assert_eq!(get_model_capabilities("claude-3-opus-20240229").unwrap().max_output_tokens, 4_096);
+    }
+
+    #[test]
+    fn test_unknown_model_falls_back() {
+        assert!(get_model_capabilities("llama3.1:70b").is_none());
+        assert_eq!(context_window("llama3.1:70b"), DEFAULT_CONTEXT_WINDOW);
+        assert_eq!(context_window(""), DEFAULT_CONTEXT_WINDOW);
+    }
+}
@@ -3,9 +3,11 @@
 // Supports: Anthropic, OpenAI, Bedrock, Azure, Ollama
 // Optimized for minimal allocations and fast streaming
 
+pub mod capabilities;
 pub mod provider;
 pub mod stream;
 
+pub use capabilities::{context_window, get_model_capabilities, ModelCapabilities};
 pub use provider::{LlmProvider, ProviderFactory};
 
 // Re-export from aof-core
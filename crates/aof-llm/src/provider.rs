@@ -51,7 +51,7 @@ impl ProviderFactory {
                 }
                 openai::OpenAIProvider::create(ollama_config)
             }
-            ModelProvider::Azure => Err(AofError::config("Azure provider not yet implemented")),
+            ModelProvider::Azure => openai::AzureOpenAIProvider::create(config),
             ModelProvider::Custom => Err(AofError::config("Custom provider requires manual implementation")),
         }
     }
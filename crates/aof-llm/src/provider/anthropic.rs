@@ -1,7 +1,7 @@
 use aof_core::model::{StopReason, Usage};
 use aof_core::{
     AofError, AofResult, Model, ModelConfig, ModelProvider, ModelRequest, ModelResponse,
-    StreamChunk, ToolCall,
+    StreamChunk, ToolCall, REASONING_METADATA_KEY,
 };
 use async_trait::async_trait;
 use futures::{Stream, StreamExt};
@@ -125,20 +125,27 @@ impl AnthropicModel {
             } else {
                 Some(tools)
             },
+            // Anthropic has no sampling seed; only stop sequences are passed through
+            stop_sequences: self.config.stop.clone(),
         }
     }
 
     /// Convert Anthropic response to AOF format
     fn convert_anthropic_response(&self, response: AnthropicResponse) -> ModelResponse {
         let mut content = String::new();
+        let mut thinking = String::new();
         let mut tool_calls = Vec::new();
 
-        // Extract content and tool calls
+        // Extract content, extended thinking and tool calls
         for block in response.content {
             match block {
                 AnthropicContentBlock::Text { text } => {
                     content.push_str(&text);
                 }
+                AnthropicContentBlock::Thinking { thinking: text } => {
+                    thinking.push_str(&text);
+                }
+                AnthropicContentBlock::RedactedThinking { .. } => {}
                 AnthropicContentBlock::ToolUse {
                     id,
                     name,
@@ -162,6 +169,14 @@ impl AnthropicModel {
             _ => StopReason::EndTurn,
         };
 
+        let mut metadata = HashMap::new();
+        if !thinking.is_empty() {
+            metadata.insert(
+                REASONING_METADATA_KEY.to_string(),
+                serde_json::Value::String(thinking),
+            );
+        }
+
         ModelResponse {
             content,
             tool_calls,
@@ -170,7 +185,7 @@ impl AnthropicModel {
                 input_tokens: response.usage.input_tokens,
                 output_tokens: response.usage.output_tokens,
             },
-            metadata: HashMap::new(),
+            metadata,
         }
     }
 
@@ -211,6 +226,9 @@ impl AnthropicModel {
             AnthropicStreamEvent::ContentBlockDelta { delta: AnthropicDelta::TextDelta { text }, .. } => {
                 Some(Ok(StreamChunk::ContentDelta { delta: text }))
             }
+            AnthropicStreamEvent::ContentBlockDelta { delta: AnthropicDelta::ThinkingDelta { thinking }, .. } => {
+                Some(Ok(StreamChunk::ReasoningDelta { delta: thinking }))
+            }
             AnthropicStreamEvent::ContentBlockStart { .. } => None,
             AnthropicStreamEvent::ContentBlockDelta { .. } => None,
             AnthropicStreamEvent::MessageDelta {
@@ -385,6 +403,8 @@ struct AnthropicRequest {
     stream: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<AnthropicTool>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop_sequences: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -431,6 +451,15 @@ enum AnthropicContentBlock {
         name: String,
         input: serde_json::Value,
     },
+    /// Extended thinking output
+    Thinking {
+        thinking: String,
+    },
+    /// Thinking encrypted by the provider
+    RedactedThinking {
+        #[allow(dead_code)]
+        data: String,
+    },
 }
 
 #[derive(Debug, Deserialize)]
@@ -481,6 +510,9 @@ enum AnthropicDelta {
     TextDelta { text: String },
     #[allow(dead_code)]
     InputJsonDelta { partial_json: String },
+    ThinkingDelta { thinking: String },
+    #[allow(dead_code)]
+    SignatureDelta { signature: String },
 }
 
 #[derive(Debug, Deserialize)]
@@ -507,6 +539,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -534,6 +568,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -565,6 +601,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -583,6 +621,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -595,4 +635,103 @@ mod tests {
             std::env::set_var("ANTHROPIC_API_KEY", val);
         }
     }
+
+    #[test]
+    fn test_thinking_blocks_go_to_metadata() {
+        let model = AnthropicModel {
+            config: ModelConfig {
+                model: "claude-3-7-sonnet-20250219".to_string(),
+                provider: ModelProvider::Anthropic,
+                api_key: Some("test-key".to_string()),
+                endpoint: None,
+                temperature: 1.0,
+                max_tokens: Some(4096),
+                timeout_secs: 60,
+                stop: vec![],
+                seed: None,
+                headers: HashMap::new(),
+                extra: HashMap::new(),
+            },
+            api_key: "test-key".to_string(),
+            client: Client::new(),
+        };
+
+        let response: AnthropicResponse = serde_json::from_value(serde_json::json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-3-7-sonnet-20250219",
+            "stop_reason": "end_turn",
+            "usage": { "input_tokens": 12, "output_tokens": 40 },
+            "content": [
+                { "type": "thinking", "thinking": "The pod restarts after OOM.", "signature": "sig" },
+                { "type": "redacted_thinking", "data": "opaque" },
+                { "type": "text", "text": "Raise the memory limit." }
+            ]
+        }))
+        .unwrap();
+
+        let converted = model.convert_anthropic_response(response);
+        assert_eq!(converted.content, "Raise the memory limit.");
+        assert_eq!(converted.reasoning(), Some("The pod restarts after OOM."));
+    }
+
+    #[test]
+    fn test_thinking_deltas_stream_as_reasoning() {
+        let line = r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"Check the OOM kills."}}"#;
+        match AnthropicModel::parse_stream_event_static(line) {
+            Some(Ok(StreamChunk::ReasoningDelta { delta })) => assert_eq!(delta, "Check the OOM kills."),
+            other => panic!("unexpected chunk: {:?}", other),
+        }
+
+        let line = r#"data: {"type":"content_block_delta","index":1,"delta":{"type":"text_delta","text":"Raise the limit."}}"#;
+        assert!(matches!(
+            AnthropicModel::parse_stream_event_static(line),
+            Some(Ok(StreamChunk::ContentDelta { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_stop_sequences_in_request() {
+        let mut model = AnthropicModel {
+            config: ModelConfig {
+                model: "claude-3-5-sonnet-20241022".to_string(),
+                provider: ModelProvider::Anthropic,
+                api_key: Some("test".to_string()),
+                endpoint: None,
+                temperature: 0.0,
+                max_tokens: Some(256),
+                timeout_secs: 60,
+                stop: vec!["END".to_string(), "\n\n".to_string()],
+                seed: Some(42),
+                headers: HashMap::new(),
+                extra: HashMap::new(),
+            },
+            api_key: "test".to_string(),
+            client: Client::new(),
+        };
+        let request = ModelRequest {
+            messages: vec![aof_core::RequestMessage {
+                role: aof_core::model::MessageRole::User,
+                content: "Count to ten".to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            }],
+            system: None,
+            tools: vec![],
+            temperature: None,
+            max_tokens: None,
+            stream: false,
+            extra: HashMap::new(),
+        };
+
+        let json = serde_json::to_value(model.to_anthropic_request(&request)).unwrap();
+        assert_eq!(json["stop_sequences"], serde_json::json!(["END", "\n\n"]));
+        // Anthropic has no seed parameter
+        assert!(json.get("seed").is_none());
+
+        model.config.stop.clear();
+        let json = serde_json::to_value(model.to_anthropic_request(&request)).unwrap();
+        assert!(json.get("stop_sequences").is_none());
+    }
 }
@@ -13,8 +13,10 @@ use tracing::{debug, error, warn};
 use aws_sdk_bedrockruntime::{
     operation::converse::ConverseOutput,
     types::{
-        ContentBlock, ConversationRole, ConverseStreamOutput as StreamOutputEnum, Message as BedrockMessage,
-        SystemContentBlock, Tool as BedrockTool, ToolConfiguration, ToolInputSchema, ToolSpecification,
+        ContentBlock, ContentBlockDelta, ContentBlockStart, ConversationRole,
+        ConverseStreamOutput as StreamOutputEnum, InferenceConfiguration, Message as BedrockMessage,
+        SystemContentBlock, Tool as BedrockTool, ToolConfiguration, ToolInputSchema,
+        ToolResultBlock, ToolResultContentBlock, ToolSpecification, ToolUseBlock,
     },
     Client,
 };
@@ -38,8 +40,10 @@ fn json_to_document(value: &serde_json::Value) -> Document {
             Document::Array(arr.iter().map(json_to_document).collect())
         }
         serde_json::Value::Number(n) => {
-            if let Some(i) = n.as_i64() {
-                Document::Number(aws_smithy_types::Number::PosInt(i as u64))
+            if let Some(u) = n.as_u64() {
+                Document::Number(aws_smithy_types::Number::PosInt(u))
+            } else if let Some(i) = n.as_i64() {
+                Document::Number(aws_smithy_types::Number::NegInt(i))
             } else if let Some(f) = n.as_f64() {
                 Document::Number(aws_smithy_types::Number::Float(f))
             } else {
@@ -66,6 +70,8 @@ fn document_to_json(doc: &Document) -> Result<serde_json::Value, String> {
             let values: Result<Vec<_>, _> = arr.iter().map(document_to_json).collect();
             Ok(serde_json::Value::Array(values?))
         }
+        Document::Number(aws_smithy_types::Number::PosInt(u)) => Ok(json!(u)),
+        Document::Number(aws_smithy_types::Number::NegInt(i)) => Ok(json!(i)),
         Document::Number(n) => {
             let f = n.to_f64_lossy();
             serde_json::Number::from_f64(f)
@@ -78,7 +84,88 @@ fn document_to_json(doc: &Document) -> Result<serde_json::Value, String> {
     }
 }
 
+#[cfg(feature = "bedrock")]
+fn map_stop_reason(reason: &str) -> StopReason {
+    match reason {
+        "end_turn" => StopReason::EndTurn,
+        "max_tokens" => StopReason::MaxTokens,
+        "stop_sequence" => StopReason::StopSequence,
+        "tool_use" => StopReason::ToolUse,
+        "content_filtered" | "guardrail_intervened" => StopReason::ContentFilter,
+        _ => StopReason::EndTurn,
+    }
+}
+
+#[cfg(feature = "bedrock")]
+fn build_message(role: ConversationRole, content: Vec<ContentBlock>) -> AofResult<BedrockMessage> {
+    BedrockMessage::builder()
+        .role(role)
+        .set_content(Some(content))
+        .build()
+        .map_err(|e| AofError::model(format!("Failed to build message: {}", e)))
+}
+
+/// Convert a tool-role message into a Converse tool result block
+#[cfg(feature = "bedrock")]
+fn tool_result_block(msg: &aof_core::RequestMessage) -> AofResult<ContentBlock> {
+    let tool_use_id = msg.tool_call_id.clone().ok_or_else(|| {
+        AofError::model("Tool result message is missing tool_call_id".to_string())
+    })?;
+
+    // Structured results are passed as JSON documents, anything else as text
+    let content = match serde_json::from_str::<serde_json::Value>(&msg.content) {
+        Ok(value @ serde_json::Value::Object(_)) => ToolResultContentBlock::Json(json_to_document(&value)),
+        _ => ToolResultContentBlock::Text(msg.content.clone()),
+    };
+
+    ToolResultBlock::builder()
+        .tool_use_id(tool_use_id)
+        .content(content)
+        .build()
+        .map(ContentBlock::ToolResult)
+        .map_err(|e| AofError::model(format!("Failed to build tool result: {}", e)))
+}
+
+/// Model family behind a Bedrock model id, which determines Converse API capabilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BedrockModelFamily {
+    /// Anthropic Claude (`anthropic.*`, including cross-region `us.anthropic.*`)
+    Anthropic,
+    /// Amazon Titan Text (`amazon.titan-text-*`): no system prompt or tool use
+    Titan,
+    /// Any other model served through the Converse API
+    Other,
+}
+
+impl BedrockModelFamily {
+    /// Detect the family from a Bedrock model id or inference profile id
+    pub fn from_model_id(model_id: &str) -> Self {
+        if model_id.contains("anthropic.") {
+            Self::Anthropic
+        } else if model_id.contains("amazon.titan") {
+            Self::Titan
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Whether the family accepts tool definitions
+    pub fn supports_tools(self) -> bool {
+        !matches!(self, Self::Titan)
+    }
+
+    /// Whether the family accepts a separate system prompt
+    pub fn supports_system_prompt(self) -> bool {
+        !matches!(self, Self::Titan)
+    }
+}
+
 /// Bedrock provider implementation
+///
+/// Uses the Bedrock Runtime Converse API. Requests are signed with SigV4 using
+/// the ambient AWS credential chain (env vars, profile, SSO, IMDS/IRSA).
+/// Configure the region with `extra.region` (or `AWS_REGION`), and override
+/// the endpoint (e.g. a VPC endpoint) with `endpoint`.
 #[allow(dead_code)]
 pub struct BedrockProvider {
     config: ModelConfig,
@@ -99,9 +186,13 @@ impl BedrockProvider {
             .unwrap_or_else(|| "us-east-1".to_string());
 
         // Initialize AWS SDK
-        let config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
+        let mut config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
             .region(aws_config::Region::new(region.clone()));
 
+        if let Some(endpoint) = &config.endpoint {
+            config_loader = config_loader.endpoint_url(endpoint);
+        }
+
         let sdk_config = config_loader.load().await;
         let client = Client::new(&sdk_config);
 
@@ -119,36 +210,135 @@ impl BedrockProvider {
         ))
     }
 
+    /// Create a provider from a pre-configured Bedrock client
+    ///
+    /// Useful for custom credential providers or endpoints.
+    #[cfg(feature = "bedrock")]
+    pub fn from_client(config: ModelConfig, client: Client) -> Self {
+        let region = client
+            .config()
+            .region()
+            .map(|r| r.to_string())
+            .unwrap_or_else(|| "us-east-1".to_string());
+
+        Self {
+            config,
+            client,
+            region,
+        }
+    }
+
+    /// Model family of the configured model id
+    pub fn family(&self) -> BedrockModelFamily {
+        BedrockModelFamily::from_model_id(&self.config.model)
+    }
+
     #[cfg(feature = "bedrock")]
     fn convert_messages(&self, request: &ModelRequest) -> AofResult<Vec<BedrockMessage>> {
         let mut messages = Vec::new();
+        // Consecutive tool results are sent together in one user message
+        let mut tool_results: Vec<ContentBlock> = Vec::new();
+
+        // Families without system prompt support get it prepended to the first user turn
+        let mut inline_system = if self.family().supports_system_prompt() {
+            None
+        } else {
+            request.system.clone()
+        };
 
         for msg in &request.messages {
-            if msg.role == MessageRole::System {
-                continue; // System messages handled separately
-            }
-
             let role = match msg.role {
                 MessageRole::User => ConversationRole::User,
                 MessageRole::Assistant => ConversationRole::Assistant,
-                MessageRole::Tool => ConversationRole::User, // Tool results as user
-                MessageRole::System => continue,
+                MessageRole::Tool => {
+                    tool_results.push(tool_result_block(msg)?);
+                    continue;
+                }
+                MessageRole::System => continue, // System messages handled separately
             };
 
-            let content = vec![ContentBlock::Text(msg.content.clone())];
+            if !tool_results.is_empty() {
+                messages.push(build_message(
+                    ConversationRole::User,
+                    std::mem::take(&mut tool_results),
+                )?);
+            }
 
-            messages.push(
-                BedrockMessage::builder()
-                    .role(role)
-                    .set_content(Some(content))
-                    .build()
-                    .map_err(|e| AofError::model(format!("Failed to build message: {}", e)))?,
-            );
+            let mut content = Vec::new();
+            let mut text = msg.content.clone();
+            if role == ConversationRole::User {
+                if let Some(system) = inline_system.take() {
+                    text = format!("{}\n\n{}", system, text);
+                }
+            }
+            if !text.is_empty() {
+                content.push(ContentBlock::Text(text));
+            }
+
+            // Replay the assistant's tool requests so results can be matched to them
+            if let Some(calls) = &msg.tool_calls {
+                for call in calls {
+                    let tool_use = ToolUseBlock::builder()
+                        .tool_use_id(&call.id)
+                        .name(&call.name)
+                        .input(json_to_document(&call.arguments))
+                        .build()
+                        .map_err(|e| AofError::model(format!("Failed to build tool use: {}", e)))?;
+                    content.push(ContentBlock::ToolUse(tool_use));
+                }
+            }
+
+            if content.is_empty() {
+                continue;
+            }
+
+            messages.push(build_message(role, content)?);
+        }
+
+        if !tool_results.is_empty() {
+            messages.push(build_message(ConversationRole::User, tool_results)?);
         }
 
         Ok(messages)
     }
 
+    #[cfg(feature = "bedrock")]
+    fn inference_config(&self, request: &ModelRequest) -> InferenceConfiguration {
+        let temperature = request.temperature.unwrap_or(self.config.temperature);
+        let max_tokens = request.max_tokens.or(self.config.max_tokens).unwrap_or(4096);
+
+        // The Converse API has no seed parameter; stop sequences are passed through
+        InferenceConfiguration::builder()
+            .temperature(temperature)
+            .max_tokens(max_tokens as i32)
+            .set_stop_sequences((!self.config.stop.is_empty()).then(|| self.config.stop.clone()))
+            .build()
+    }
+
+    #[cfg(feature = "bedrock")]
+    fn system_prompt(&self, request: &ModelRequest) -> Option<SystemContentBlock> {
+        if !self.family().supports_system_prompt() {
+            return None;
+        }
+        request.system.clone().map(SystemContentBlock::Text)
+    }
+
+    #[cfg(feature = "bedrock")]
+    fn tool_config(&self, request: &ModelRequest) -> AofResult<Option<ToolConfiguration>> {
+        if request.tools.is_empty() {
+            return Ok(None);
+        }
+        if !self.family().supports_tools() {
+            warn!(
+                "Model {} does not support tool use on Bedrock; ignoring {} tool(s)",
+                self.config.model,
+                request.tools.len()
+            );
+            return Ok(None);
+        }
+        self.convert_tools(&request.tools).map(Some)
+    }
+
     #[cfg(feature = "bedrock")]
     fn convert_tools(&self, tools: &[aof_core::ModelToolDefinition]) -> AofResult<ToolConfiguration> {
         let mut tool_specs = Vec::new();
@@ -178,14 +368,7 @@ impl BedrockProvider {
         let mut content = String::new();
         let mut tool_calls = Vec::new();
 
-        let stop_reason = match output.stop_reason().as_str() {
-            "end_turn" => StopReason::EndTurn,
-            "max_tokens" => StopReason::MaxTokens,
-            "stop_sequence" => StopReason::StopSequence,
-            "tool_use" => StopReason::ToolUse,
-            "content_filtered" => StopReason::ContentFilter,
-            _ => StopReason::EndTurn,
-        };
+        let stop_reason = map_stop_reason(output.stop_reason().as_str());
 
         let usage = output.usage().map(|u| Usage {
             input_tokens: u.input_tokens() as usize,
@@ -204,17 +387,9 @@ impl BedrockProvider {
                         }
                         ContentBlock::ToolUse(tool_use) => {
                             // Convert Document to JSON Value
-                            let input: serde_json::Value = tool_use.input()
-                                .as_object()
-                                .map(|obj| {
-                                    let mut map = serde_json::Map::new();
-                                    for (k, v) in obj {
-                                        if let Ok(json_val) = document_to_json(v) {
-                                            map.insert(k.clone(), json_val);
-                                        }
-                                    }
-                                    serde_json::Value::Object(map)
-                                })
+                            let input = document_to_json(tool_use.input())
+                                .ok()
+                                .filter(|v| v.is_object())
                                 .unwrap_or(json!({}));
 
                             tool_calls.push(ToolCall {
@@ -278,38 +453,20 @@ impl Model for BedrockProvider {
 
         let messages = self.convert_messages(request)?;
 
+        let system = self.system_prompt(request);
+        let inference_config = self.inference_config(request);
+        let tool_config = self.tool_config(request)?;
+
         let response = self
             .retry_request(|| async {
-                let mut builder = self
+                let result = self
                     .client
                     .converse()
                     .model_id(&self.config.model)
-                    .set_messages(Some(messages.clone()));
-
-                // Add system prompt if present
-                if let Some(system) = &request.system {
-                    builder = builder.system(SystemContentBlock::Text(system.clone()));
-                }
-
-                // Add inference config
-                let temperature = request.temperature.unwrap_or(self.config.temperature);
-                let max_tokens = request.max_tokens.or(self.config.max_tokens).unwrap_or(4096);
-
-                builder = builder
-                    .inference_config(
-                        aws_sdk_bedrockruntime::types::InferenceConfiguration::builder()
-                            .temperature(temperature)
-                            .max_tokens(max_tokens as i32)
-                            .build()
-                    );
-
-                // Add tools if present
-                if !request.tools.is_empty() {
-                    let tool_config = self.convert_tools(&request.tools)?;
-                    builder = builder.tool_config(tool_config);
-                }
-
-                let result = builder
+                    .set_messages(Some(messages.clone()))
+                    .set_system(system.clone().map(|s| vec![s]))
+                    .inference_config(inference_config.clone())
+                    .set_tool_config(tool_config.clone())
                     .send()
                     .await
                     .map_err(|e| AofError::model(format!("Bedrock API error: {}", e)))?;
@@ -335,71 +492,75 @@ impl Model for BedrockProvider {
 
         let messages = self.convert_messages(request)?;
 
-        let mut builder = self
+        let mut stream = self
             .client
             .converse_stream()
             .model_id(&self.config.model)
-            .set_messages(Some(messages));
-
-        // Add system prompt if present
-        if let Some(system) = &request.system {
-            builder = builder.system(SystemContentBlock::Text(system.clone()));
-        }
-
-        // Add inference config
-        let temperature = request.temperature.unwrap_or(self.config.temperature);
-        let max_tokens = request.max_tokens.or(self.config.max_tokens).unwrap_or(4096);
-
-        builder = builder
-            .inference_config(
-                aws_sdk_bedrockruntime::types::InferenceConfiguration::builder()
-                    .temperature(temperature)
-                    .max_tokens(max_tokens as i32)
-                    .build()
-            );
-
-        // Add tools if present
-        if !request.tools.is_empty() {
-            let tool_config = self.convert_tools(&request.tools)?;
-            builder = builder.tool_config(tool_config);
-        }
-
-        let mut stream = builder
+            .set_messages(Some(messages))
+            .set_system(self.system_prompt(request).map(|s| vec![s]))
+            .inference_config(self.inference_config(request))
+            .set_tool_config(self.tool_config(request)?)
             .send()
             .await
             .map_err(|e| AofError::model(format!("Bedrock streaming API error: {}", e)))?;
 
         let output_stream = async_stream::stream! {
+            // Tool use arrives as start + partial JSON deltas + stop
+            let mut pending_tool: Option<(String, String, String)> = None;
+            let mut stop_reason = None;
+            let mut usage = Usage::default();
+
             while let Some(event) = stream.stream.recv().await.transpose() {
                 match event {
                     Ok(output) => {
                         match output {
-                            StreamOutputEnum::ContentBlockDelta(delta) => {
-                                if let Some(text_delta) = delta.delta().and_then(|d| d.as_text().ok()) {
+                            StreamOutputEnum::ContentBlockStart(start) => {
+                                if let Some(ContentBlockStart::ToolUse(tool_use)) = start.start() {
+                                    pending_tool = Some((
+                                        tool_use.tool_use_id().to_string(),
+                                        tool_use.name().to_string(),
+                                        String::new(),
+                                    ));
+                                }
+                            }
+                            StreamOutputEnum::ContentBlockDelta(delta) => match delta.delta() {
+                                Some(ContentBlockDelta::Text(text)) => {
                                     yield Ok(StreamChunk::ContentDelta {
-                                        delta: text_delta.to_string(),
+                                        delta: text.to_string(),
+                                    });
+                                }
+                                Some(ContentBlockDelta::ToolUse(tool_delta)) => {
+                                    if let Some((_, _, input)) = pending_tool.as_mut() {
+                                        input.push_str(tool_delta.input());
+                                    }
+                                }
+                                _ => {}
+                            },
+                            StreamOutputEnum::ContentBlockStop(_) => {
+                                if let Some((id, name, input)) = pending_tool.take() {
+                                    let arguments = if input.trim().is_empty() {
+                                        json!({})
+                                    } else {
+                                        serde_json::from_str(&input).unwrap_or_else(|e| {
+                                            warn!("Invalid tool input JSON from Bedrock: {}", e);
+                                            json!({})
+                                        })
+                                    };
+                                    yield Ok(StreamChunk::ToolCall {
+                                        tool_call: ToolCall { id, name, arguments },
                                     });
                                 }
                             }
                             StreamOutputEnum::MessageStop(stop) => {
-                                let stop_reason = match stop.stop_reason().as_str() {
-                                    "end_turn" => StopReason::EndTurn,
-                                    "max_tokens" => StopReason::MaxTokens,
-                                    "stop_sequence" => StopReason::StopSequence,
-                                    "tool_use" => StopReason::ToolUse,
-                                    "content_filtered" => StopReason::ContentFilter,
-                                    _ => StopReason::EndTurn,
-                                };
-
-                                yield Ok(StreamChunk::Done {
-                                    usage: Usage::default(),
-                                    stop_reason,
-                                });
+                                stop_reason = Some(map_stop_reason(stop.stop_reason().as_str()));
                             }
                             StreamOutputEnum::Metadata(metadata) => {
-                                if let Some(_usage) = metadata.usage() {
-                                    // Store usage for final Done event
-                                    continue;
+                                // Metadata (with usage) follows messageStop
+                                if let Some(token_usage) = metadata.usage() {
+                                    usage = Usage {
+                                        input_tokens: token_usage.input_tokens() as usize,
+                                        output_tokens: token_usage.output_tokens() as usize,
+                                    };
                                 }
                             }
                             _ => continue,
@@ -412,6 +573,10 @@ impl Model for BedrockProvider {
                     }
                 }
             }
+
+            if let Some(stop_reason) = stop_reason {
+                yield Ok(StreamChunk::Done { usage, stop_reason });
+            }
         };
 
         Ok(Box::pin(output_stream))
@@ -459,6 +624,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra,
         };
@@ -482,6 +649,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra,
         };
@@ -490,4 +659,206 @@ mod tests {
         let tokens = provider.count_tokens("Hello, world!");
         assert!(tokens > 0);
     }
+
+    use aof_tools::{MockHttpServer, MockResponse};
+
+    /// Serve canned Converse responses on a local port, one per connection
+    async fn mock_bedrock(responses: Vec<serde_json::Value>) -> MockHttpServer {
+        MockHttpServer::start(responses.into_iter().map(MockResponse::json).collect()).await
+    }
+
+    fn mock_provider(model: &str, endpoint: &str) -> BedrockProvider {
+        let sdk_config = aws_sdk_bedrockruntime::Config::builder()
+            .behavior_version(aws_sdk_bedrockruntime::config::BehaviorVersion::latest())
+            .region(aws_sdk_bedrockruntime::config::Region::new("us-east-1"))
+            .credentials_provider(aws_sdk_bedrockruntime::config::Credentials::new(
+                "AKIDEXAMPLE",
+                "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+                None,
+                None,
+                "test",
+            ))
+            .endpoint_url(endpoint)
+            .build();
+
+        let config = ModelConfig {
+            model: model.to_string(),
+            provider: ModelProvider::Bedrock,
+            api_key: None,
+            endpoint: Some(endpoint.to_string()),
+            temperature: 0.2,
+            max_tokens: Some(1024),
+            timeout_secs: 30,
+            stop: vec![],
+            seed: None,
+            headers: HashMap::new(),
+            extra: HashMap::new(),
+        };
+
+        BedrockProvider::from_client(config, Client::from_conf(sdk_config))
+    }
+
+    fn request(messages: Vec<aof_core::RequestMessage>, tools: Vec<aof_core::ModelToolDefinition>) -> ModelRequest {
+        ModelRequest {
+            messages,
+            system: Some("You are an SRE assistant.".to_string()),
+            tools,
+            temperature: None,
+            max_tokens: None,
+            stream: false,
+            extra: HashMap::new(),
+        }
+    }
+
+    fn message(role: MessageRole, content: &str) -> aof_core::RequestMessage {
+        aof_core::RequestMessage {
+            role,
+            content: content.to_string(),
+            tool_calls: None,
+            tool_call_id: None,
+        }
+    }
+
+    fn pods_tool() -> aof_core::ModelToolDefinition {
+        aof_core::ModelToolDefinition {
+            name: "count_pods".to_string(),
+            description: "Count pods in a namespace".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": { "namespace": { "type": "string" } },
+                "required": ["namespace"]
+            }),
+        }
+    }
+
+    fn converse_text(text: &str) -> serde_json::Value {
+        json!({
+            "output": { "message": { "role": "assistant", "content": [{ "text": text }] } },
+            "stopReason": "end_turn",
+            "usage": { "inputTokens": 12, "outputTokens": 4, "totalTokens": 16 },
+            "metrics": { "latencyMs": 5 }
+        })
+    }
+
+    #[test]
+    fn test_model_family_detection() {
+        assert_eq!(
+            BedrockModelFamily::from_model_id("anthropic.claude-3-sonnet-20240229-v1:0"),
+            BedrockModelFamily::Anthropic
+        );
+        assert_eq!(
+            BedrockModelFamily::from_model_id("us.anthropic.claude-3-5-sonnet-20241022-v2:0"),
+            BedrockModelFamily::Anthropic
+        );
+        assert_eq!(
+            BedrockModelFamily::from_model_id("amazon.titan-text-express-v1"),
+            BedrockModelFamily::Titan
+        );
+        assert!(!BedrockModelFamily::Titan.supports_tools());
+    }
+
+    #[tokio::test]
+    async fn test_basic_invoke_against_mock_endpoint() {
+        let server = mock_bedrock(vec![converse_text("All pods are healthy.")]).await;
+        let provider = mock_provider("anthropic.claude-3-sonnet-20240229-v1:0", server.url());
+
+        let response = provider
+            .generate(&request(vec![message(MessageRole::User, "Status?")], vec![]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.content, "All pods are healthy.");
+        assert_eq!(response.stop_reason, StopReason::EndTurn);
+        assert_eq!(response.usage.input_tokens, 12);
+        assert_eq!(response.usage.output_tokens, 4);
+
+        let sent = &server.requests()[0];
+        assert!(sent.path.starts_with("/model/anthropic.claude-3-sonnet"));
+        assert!(sent.path.ends_with("/converse"));
+        assert!(sent.header("authorization").unwrap().starts_with("AWS4-HMAC-SHA256"));
+        let body = sent.json();
+        assert_eq!(body["system"][0]["text"], "You are an SRE assistant.");
+        assert_eq!(body["messages"][0]["content"][0]["text"], "Status?");
+        assert_eq!(body["inferenceConfig"]["maxTokens"], 1024);
+    }
+
+    #[tokio::test]
+    async fn test_tool_use_round_trip_against_mock_endpoint() {
+        let server = mock_bedrock(vec![
+            json!({
+                "output": { "message": { "role": "assistant", "content": [
+                    { "text": "Let me check." },
+                    { "toolUse": { "toolUseId": "tooluse_1", "name": "count_pods", "input": { "namespace": "prod" } } }
+                ] } },
+                "stopReason": "tool_use",
+                "usage": { "inputTokens": 30, "outputTokens": 20, "totalTokens": 50 },
+                "metrics": { "latencyMs": 5 }
+            }),
+            converse_text("There are 3 pods in prod."),
+        ])
+        .await;
+        let provider = mock_provider("anthropic.claude-3-sonnet-20240229-v1:0", server.url());
+
+        // Turn 1: the model asks for a tool
+        let mut messages = vec![message(MessageRole::User, "How many pods in prod?")];
+        let first = provider
+            .generate(&request(messages.clone(), vec![pods_tool()]))
+            .await
+            .unwrap();
+
+        assert_eq!(first.stop_reason, StopReason::ToolUse);
+        assert_eq!(first.tool_calls.len(), 1);
+        assert_eq!(first.tool_calls[0].name, "count_pods");
+        assert_eq!(first.tool_calls[0].arguments, json!({ "namespace": "prod" }));
+
+        // Turn 2: send the tool result back
+        messages.push(aof_core::RequestMessage {
+            role: MessageRole::Assistant,
+            content: first.content.clone(),
+            tool_calls: Some(first.tool_calls.clone()),
+            tool_call_id: None,
+        });
+        messages.push(aof_core::RequestMessage {
+            role: MessageRole::Tool,
+            content: json!({ "pods": 3 }).to_string(),
+            tool_calls: None,
+            tool_call_id: Some("tooluse_1".to_string()),
+        });
+        let second = provider
+            .generate(&request(messages, vec![pods_tool()]))
+            .await
+            .unwrap();
+        assert_eq!(second.content, "There are 3 pods in prod.");
+
+        let requests = server.requests();
+        let first_body = requests[0].json();
+        assert_eq!(first_body["toolConfig"]["tools"][0]["toolSpec"]["name"], "count_pods");
+
+        let second_body = requests[1].json();
+        let sent = &second_body["messages"];
+        assert_eq!(sent[1]["role"], "assistant");
+        assert_eq!(sent[1]["content"][1]["toolUse"]["toolUseId"], "tooluse_1");
+        assert_eq!(sent[2]["role"], "user");
+        assert_eq!(sent[2]["content"][0]["toolResult"]["toolUseId"], "tooluse_1");
+        assert_eq!(sent[2]["content"][0]["toolResult"]["content"][0]["json"]["pods"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_titan_folds_system_prompt_and_skips_tools() {
+        let server = mock_bedrock(vec![converse_text("ok")]).await;
+        let provider = mock_provider("amazon.titan-text-express-v1", server.url());
+
+        provider
+            .generate(&request(vec![message(MessageRole::User, "Summarize")], vec![pods_tool()]))
+            .await
+            .unwrap();
+
+        let body = server.requests()[0].json();
+        assert!(body.get("system").is_none());
+        assert!(body.get("toolConfig").is_none());
+        assert_eq!(
+            body["messages"][0]["content"][0]["text"],
+            "You are an SRE assistant.\n\nSummarize"
+        );
+    }
 }
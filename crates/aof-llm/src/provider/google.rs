@@ -173,6 +173,8 @@ impl GoogleModel {
             max_output_tokens: request.max_tokens.or(self.config.max_tokens),
             top_p: None,
             top_k: None,
+            stop_sequences: self.config.stop.clone(),
+            seed: self.config.seed,
         };
 
         // Debug: Log the final converted contents structure
@@ -599,6 +601,10 @@ struct GeminiGenerationConfig {
     top_p: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     top_k: Option<i32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop_sequences: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -661,6 +667,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(1000),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -669,4 +677,42 @@ mod tests {
         let count = model.count_tokens("Hello, world!");
         assert!(count >= 3 && count <= 4);
     }
+
+    #[test]
+    fn test_stop_and_seed_in_generation_config() {
+        let config = ModelConfig {
+            model: "gemini-2.0-flash".to_string(),
+            provider: ModelProvider::Google,
+            api_key: Some("test".to_string()),
+            endpoint: None,
+            temperature: 0.0,
+            max_tokens: Some(256),
+            timeout_secs: 60,
+            stop: vec!["END".to_string(), "\n\n".to_string()],
+            seed: Some(42),
+            headers: HashMap::new(),
+            extra: HashMap::new(),
+        };
+
+        let model = GoogleModel::new(config).unwrap();
+        let request = ModelRequest {
+            messages: vec![aof_core::RequestMessage {
+                role: MessageRole::User,
+                content: "Count to ten".to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            }],
+            system: None,
+            tools: vec![],
+            temperature: None,
+            max_tokens: None,
+            stream: false,
+            extra: HashMap::new(),
+        };
+
+        let json = serde_json::to_value(model.build_request(&request)).unwrap();
+        let generation_config = &json["generation_config"];
+        assert_eq!(generation_config["stop_sequences"], serde_json::json!(["END", "\n\n"]));
+        assert_eq!(generation_config["seed"], 42);
+    }
 }
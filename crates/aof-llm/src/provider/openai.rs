@@ -1,6 +1,7 @@
 use aof_core::{
     model::MessageRole, AofError, AofResult, Model, ModelConfig, ModelProvider, ModelRequest,
-    ModelResponse, StopReason, StreamChunk, ToolCall, Usage,
+    ModelResponse, StopReason, StreamChunk, ToolCall, Usage, REASONING_METADATA_KEY,
+    REASONING_TOKENS_METADATA_KEY,
 };
 use async_trait::async_trait;
 use futures::stream::{Stream, StreamExt};
@@ -19,12 +20,37 @@ impl OpenAIProvider {
     }
 }
 
+/// Azure OpenAI provider
+///
+/// Azure deployments speak the OpenAI chat completions API but are addressed by
+/// resource endpoint + deployment name + `api-version`, and authenticate with
+/// an `api-key` header.
+pub struct AzureOpenAIProvider;
+
+impl AzureOpenAIProvider {
+    pub fn create(config: ModelConfig) -> AofResult<Box<dyn Model>> {
+        Ok(Box::new(OpenAIModel::azure(config)?))
+    }
+}
+
+/// Default Azure OpenAI API version
+const AZURE_DEFAULT_API_VERSION: &str = "2024-06-01";
+
+/// Azure OpenAI deployment addressing
+#[derive(Debug, Clone)]
+struct AzureDeployment {
+    deployment: String,
+    api_version: String,
+}
+
 /// OpenAI model implementation
 pub struct OpenAIModel {
     config: ModelConfig,
     client: Client,
     api_key: String,
     endpoint: String,
+    /// Set when talking to an Azure OpenAI deployment
+    azure: Option<AzureDeployment>,
 }
 
 impl OpenAIModel {
@@ -56,9 +82,86 @@ impl OpenAIModel {
             client,
             api_key,
             endpoint,
+            azure: None,
+        })
+    }
+
+    /// Create a model for an Azure OpenAI deployment
+    ///
+    /// - endpoint: `config.endpoint` or `AZURE_OPENAI_ENDPOINT` (e.g. `https://myres.openai.azure.com`)
+    /// - api key: `config.api_key` or `AZURE_OPENAI_API_KEY`
+    /// - deployment: `extra.deployment`, `AZURE_OPENAI_DEPLOYMENT`, or the model name
+    /// - api version: `extra.api_version`, `AZURE_OPENAI_API_VERSION`, or 2024-06-01
+    pub fn azure(config: ModelConfig) -> AofResult<Self> {
+        let setting = |key: &str, env: &str| {
+            config
+                .extra
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .or_else(|| std::env::var(env).ok())
+        };
+
+        let api_key = config
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("AZURE_OPENAI_API_KEY").ok())
+            .ok_or_else(|| {
+                AofError::config("AZURE_OPENAI_API_KEY not found in config or environment")
+            })?;
+
+        let endpoint = config
+            .endpoint
+            .clone()
+            .or_else(|| std::env::var("AZURE_OPENAI_ENDPOINT").ok())
+            .ok_or_else(|| {
+                AofError::config("AZURE_OPENAI_ENDPOINT not found in config or environment")
+            })?
+            .trim_end_matches('/')
+            .to_string();
+
+        let deployment = setting("deployment", "AZURE_OPENAI_DEPLOYMENT")
+            .unwrap_or_else(|| config.model.clone());
+        let api_version = setting("api_version", "AZURE_OPENAI_API_VERSION")
+            .unwrap_or_else(|| AZURE_DEFAULT_API_VERSION.to_string());
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| AofError::model(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self {
+            config,
+            client,
+            api_key,
+            endpoint,
+            azure: Some(AzureDeployment {
+                deployment,
+                api_version,
+            }),
         })
     }
 
+    /// URL of the chat completions API for this model
+    fn chat_completions_url(&self) -> String {
+        match &self.azure {
+            Some(azure) => format!(
+                "{}/openai/deployments/{}/chat/completions?api-version={}",
+                self.endpoint, azure.deployment, azure.api_version
+            ),
+            None => format!("{}/chat/completions", self.endpoint),
+        }
+    }
+
+    /// Start a chat completions request with the right URL and auth header
+    fn post_chat_completions(&self) -> reqwest::RequestBuilder {
+        let builder = self.client.post(self.chat_completions_url());
+        match &self.azure {
+            Some(_) => builder.header("api-key", &self.api_key),
+            None => builder.header(header::AUTHORIZATION, format!("Bearer {}", self.api_key)),
+        }
+    }
+
     /// Build request payload
     fn build_request(&self, request: &ModelRequest) -> OpenAIRequest {
         // Convert messages
@@ -69,6 +172,7 @@ impl OpenAIModel {
                 content: Some(system.clone()),
                 tool_calls: None,
                 tool_call_id: None,
+                reasoning_content: None,
             }];
             msgs.extend(request.messages.iter().map(|m| OpenAIMessage {
                 role: match m.role {
@@ -94,6 +198,7 @@ impl OpenAIModel {
                         .collect()
                 }),
                 tool_call_id: m.tool_call_id.clone(),
+                reasoning_content: None,
             }));
             msgs
         } else {
@@ -124,6 +229,7 @@ impl OpenAIModel {
                             .collect()
                     }),
                     tool_call_id: m.tool_call_id.clone(),
+                reasoning_content: None,
                 })
                 .collect()
         };
@@ -155,6 +261,8 @@ impl OpenAIModel {
             max_tokens: request.max_tokens.or(self.config.max_tokens),
             stream: Some(request.stream),
             tools,
+            stop: self.config.stop.clone(),
+            seed: self.config.seed,
         }
     }
 
@@ -207,12 +315,29 @@ impl OpenAIModel {
             output_tokens: response.usage.completion_tokens,
         };
 
+        // Reasoning models report reasoning separately from the answer
+        let mut metadata = HashMap::new();
+        if let Some(reasoning) = choice.message.reasoning_content.as_ref().filter(|r| !r.is_empty()) {
+            metadata.insert(
+                REASONING_METADATA_KEY.to_string(),
+                serde_json::Value::String(reasoning.clone()),
+            );
+        }
+        if let Some(details) = &response.usage.completion_tokens_details {
+            if details.reasoning_tokens > 0 {
+                metadata.insert(
+                    REASONING_TOKENS_METADATA_KEY.to_string(),
+                    serde_json::json!(details.reasoning_tokens),
+                );
+            }
+        }
+
         Ok(ModelResponse {
             content,
             tool_calls,
             stop_reason,
             usage,
-            metadata: HashMap::new(),
+            metadata,
         })
     }
 
@@ -300,9 +425,7 @@ impl Model for OpenAIModel {
         );
 
         let response = self
-            .client
-            .post(format!("{}/chat/completions", self.endpoint))
-            .header(header::AUTHORIZATION, format!("Bearer {}", self.api_key))
+            .post_chat_completions()
             .header(header::CONTENT_TYPE, "application/json")
             .json(&payload)
             .send()
@@ -343,9 +466,7 @@ impl Model for OpenAIModel {
         );
 
         let response = self
-            .client
-            .post(format!("{}/chat/completions", self.endpoint))
-            .header(header::AUTHORIZATION, format!("Bearer {}", self.api_key))
+            .post_chat_completions()
             .header(header::CONTENT_TYPE, "application/json")
             .json(&payload)
             .send()
@@ -406,7 +527,11 @@ impl Model for OpenAIModel {
     }
 
     fn provider(&self) -> ModelProvider {
-        ModelProvider::OpenAI
+        if self.azure.is_some() {
+            ModelProvider::Azure
+        } else {
+            ModelProvider::OpenAI
+        }
     }
 
     fn count_tokens(&self, text: &str) -> usize {
@@ -430,6 +555,10 @@ struct OpenAIRequest {
     stream: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<OpenAITool>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -441,6 +570,9 @@ struct OpenAIMessage {
     tool_calls: Option<Vec<OpenAIToolCall>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tool_call_id: Option<String>,
+    /// Reasoning returned by reasoning models on OpenAI-compatible APIs
+    #[serde(default, skip_serializing)]
+    reasoning_content: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -485,6 +617,14 @@ struct OpenAIChoice {
 struct OpenAIUsage {
     prompt_tokens: usize,
     completion_tokens: usize,
+    #[serde(default)]
+    completion_tokens_details: Option<OpenAICompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAICompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: usize,
 }
 
 #[derive(Debug, Deserialize)]
@@ -540,6 +680,8 @@ mod tests {
             temperature: 0.7,
             max_tokens: Some(1000),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -563,4 +705,165 @@ mod tests {
         let chunk = parse_openai_stream_chunk(line);
         assert!(chunk.is_none());
     }
+
+    #[test]
+    fn test_parse_response_reasoning() {
+        let config = ModelConfig {
+            model: "o3-mini".to_string(),
+            provider: ModelProvider::OpenAI,
+            api_key: Some("test".to_string()),
+            endpoint: None,
+            temperature: 1.0,
+            max_tokens: None,
+            timeout_secs: 60,
+            stop: vec![],
+            seed: None,
+            headers: HashMap::new(),
+            extra: HashMap::new(),
+        };
+        let model = OpenAIModel::new(config).unwrap();
+
+        let response: OpenAIResponse = serde_json::from_value(serde_json::json!({
+            "choices": [{
+                "message": {
+                    "role": "assistant",
+                    "content": "Scale to 4 replicas.",
+                    "reasoning_content": "CPU is at 90% across 2 replicas."
+                },
+                "finish_reason": "stop"
+            }],
+            "usage": {
+                "prompt_tokens": 20,
+                "completion_tokens": 180,
+                "completion_tokens_details": { "reasoning_tokens": 150 }
+            }
+        }))
+        .unwrap();
+
+        let parsed = model.parse_response(response).unwrap();
+        assert_eq!(parsed.content, "Scale to 4 replicas.");
+        assert_eq!(parsed.reasoning(), Some("CPU is at 90% across 2 replicas."));
+        assert_eq!(parsed.reasoning_tokens(), 150);
+    }
+
+    fn azure_config(endpoint: &str) -> ModelConfig {
+        let mut extra = HashMap::new();
+        extra.insert("deployment".to_string(), serde_json::json!("gpt4o-prod"));
+        extra.insert("api_version".to_string(), serde_json::json!("2024-10-21"));
+
+        ModelConfig {
+            model: "gpt-4o".to_string(),
+            provider: ModelProvider::Azure,
+            api_key: Some("azure-test-key".to_string()),
+            endpoint: Some(endpoint.to_string()),
+            temperature: 0.7,
+            max_tokens: None,
+            timeout_secs: 30,
+            stop: vec![],
+            seed: None,
+            headers: HashMap::new(),
+            extra,
+        }
+    }
+
+    #[test]
+    fn test_azure_deployment_url() {
+        let model = OpenAIModel::azure(azure_config("https://myres.openai.azure.com/")).unwrap();
+        assert_eq!(
+            model.chat_completions_url(),
+            "https://myres.openai.azure.com/openai/deployments/gpt4o-prod/chat/completions?api-version=2024-10-21"
+        );
+        assert_eq!(model.provider(), ModelProvider::Azure);
+
+        // Deployment defaults to the model name, api-version to the built-in default
+        let mut config = azure_config("https://myres.openai.azure.com");
+        config.extra.clear();
+        let model = OpenAIModel::azure(config).unwrap();
+        assert_eq!(
+            model.chat_completions_url(),
+            format!(
+                "https://myres.openai.azure.com/openai/deployments/gpt-4o/chat/completions?api-version={}",
+                AZURE_DEFAULT_API_VERSION
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_azure_request_hits_deployment_url() {
+        use aof_tools::{MockHttpServer, MockResponse};
+
+        let server = MockHttpServer::start(vec![MockResponse::json(serde_json::json!({
+            "choices": [{
+                "message": { "role": "assistant", "content": "Hello from Azure" },
+                "finish_reason": "stop"
+            }],
+            "usage": { "prompt_tokens": 5, "completion_tokens": 3 }
+        }))])
+        .await;
+
+        let model = OpenAIModel::azure(azure_config(server.url())).unwrap();
+        let response = model
+            .generate(&ModelRequest {
+                messages: vec![aof_core::RequestMessage {
+                    role: MessageRole::User,
+                    content: "Hi".to_string(),
+                    tool_calls: None,
+                    tool_call_id: None,
+                }],
+                system: None,
+                tools: vec![],
+                temperature: None,
+                max_tokens: None,
+                stream: false,
+                extra: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.content, "Hello from Azure");
+
+        let sent = &server.requests()[0];
+        assert_eq!(
+            sent.request_line(),
+            "POST /openai/deployments/gpt4o-prod/chat/completions?api-version=2024-10-21 HTTP/1.1"
+        );
+        assert_eq!(sent.header("api-key"), Some("azure-test-key"));
+        assert!(sent.header("authorization").is_none());
+    }
+
+    #[test]
+    fn test_stop_and_seed_in_request() {
+        let config = ModelConfig {
+            model: "gpt-4o".to_string(),
+            provider: ModelProvider::OpenAI,
+            api_key: Some("test".to_string()),
+            endpoint: None,
+            temperature: 0.0,
+            max_tokens: Some(256),
+            timeout_secs: 60,
+            stop: vec!["END".to_string(), "\n\n".to_string()],
+            seed: Some(42),
+            headers: HashMap::new(),
+            extra: HashMap::new(),
+        };
+
+        let model = OpenAIModel::new(config).unwrap();
+        let request = ModelRequest {
+            messages: vec![aof_core::RequestMessage {
+                role: MessageRole::User,
+                content: "Count to ten".to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            }],
+            system: None,
+            tools: vec![],
+            temperature: None,
+            max_tokens: None,
+            stream: false,
+            extra: HashMap::new(),
+        };
+
+        let json = serde_json::to_value(model.build_request(&request)).unwrap();
+        assert_eq!(json["stop"], serde_json::json!(["END", "\n\n"]));
+        assert_eq!(json["seed"], 42);
+    }
 }
@@ -16,6 +16,8 @@ fn test_anthropic_provider_creation_with_api_key() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -37,6 +39,8 @@ fn test_anthropic_provider_creation_without_api_key() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -55,6 +59,8 @@ fn test_anthropic_provider_config() {
         temperature: 0.3,
         max_tokens: Some(2048),
         timeout_secs: 120,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -78,6 +84,8 @@ fn test_anthropic_provider_type() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -96,6 +104,8 @@ fn test_anthropic_token_counting() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -130,6 +140,8 @@ fn test_anthropic_custom_headers() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers,
         extra: HashMap::new(),
     };
@@ -148,6 +160,8 @@ fn test_anthropic_request_building() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -185,6 +199,8 @@ fn test_anthropic_with_tools() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -234,6 +250,8 @@ fn test_anthropic_temperature_override() {
         temperature: 0.7, // Default
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -267,6 +285,8 @@ fn test_anthropic_max_tokens_override() {
         temperature: 0.7,
         max_tokens: Some(4096), // Default
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
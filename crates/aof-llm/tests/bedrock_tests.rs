@@ -25,6 +25,8 @@ async fn test_bedrock_provider_creation() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra,
     };
@@ -49,6 +51,8 @@ async fn test_bedrock_provider_config() {
         temperature: 0.3,
         max_tokens: Some(2048),
         timeout_secs: 120,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra,
     };
@@ -76,6 +80,8 @@ async fn test_bedrock_provider_type() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra,
     };
@@ -99,6 +105,8 @@ async fn test_bedrock_token_counting() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra,
     };
@@ -137,6 +145,8 @@ async fn test_bedrock_region_configuration() {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: extra.clone(),
         };
@@ -172,6 +182,8 @@ async fn test_bedrock_different_models() {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra,
         };
@@ -218,6 +230,8 @@ async fn test_bedrock_temperature_override() {
         temperature: 0.7, // Default
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra,
     };
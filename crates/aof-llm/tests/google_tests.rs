@@ -18,6 +18,8 @@ fn test_google_provider_creation_with_api_key() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -39,6 +41,8 @@ fn test_google_provider_creation_without_api_key() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -57,6 +61,8 @@ fn test_google_provider_config() {
         temperature: 0.3,
         max_tokens: Some(2048),
         timeout_secs: 120,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -80,6 +86,8 @@ fn test_google_provider_type() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -98,6 +106,8 @@ fn test_google_token_counting() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -129,6 +139,8 @@ fn test_google_custom_endpoint() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -147,6 +159,8 @@ fn test_google_with_tools() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -196,6 +210,8 @@ fn test_google_temperature_override() {
         temperature: 0.7, // Default
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -229,6 +245,8 @@ fn test_google_max_tokens_override() {
         temperature: 0.7,
         max_tokens: Some(4096), // Default
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -302,6 +320,8 @@ fn test_google_different_models() {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -324,6 +344,8 @@ fn test_google_system_instruction() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -368,6 +390,8 @@ mod integration {
             temperature: 0.7,
             max_tokens: Some(100),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -409,6 +433,8 @@ mod integration {
             temperature: 0.0,
             max_tokens: Some(256),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -16,6 +16,8 @@ fn test_openai_provider_creation_with_api_key() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -37,6 +39,8 @@ fn test_openai_provider_creation_without_api_key() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -55,6 +59,8 @@ fn test_openai_provider_config() {
         temperature: 0.3,
         max_tokens: Some(2048),
         timeout_secs: 120,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -78,6 +84,8 @@ fn test_openai_provider_type() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -96,6 +104,8 @@ fn test_openai_token_counting() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -127,6 +137,8 @@ fn test_openai_custom_endpoint() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -151,6 +163,8 @@ fn test_openai_custom_headers() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers,
         extra: HashMap::new(),
     };
@@ -169,6 +183,8 @@ fn test_openai_with_tools() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -240,6 +256,8 @@ fn test_openai_temperature_override() {
         temperature: 0.7, // Default
         max_tokens: Some(4096),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -312,6 +330,8 @@ fn test_openai_different_models() {
             temperature: 0.7,
             max_tokens: Some(4096),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: HashMap::new(),
             extra: HashMap::new(),
         };
@@ -331,6 +351,8 @@ fn test_openai_timeout_configuration() {
         temperature: 0.7,
         max_tokens: Some(4096),
         timeout_secs: 30, // Custom timeout
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
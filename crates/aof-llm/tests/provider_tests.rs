@@ -14,6 +14,8 @@ async fn test_provider_factory_anthropic() {
         temperature: 0.7,
         max_tokens: Some(1024),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -35,6 +37,8 @@ async fn test_provider_factory_openai() {
         temperature: 0.7,
         max_tokens: Some(1024),
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -56,6 +60,8 @@ async fn test_provider_factory_unsupported() {
         temperature: 0.7,
         max_tokens: None,
         timeout_secs: 60,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -74,6 +80,8 @@ fn test_model_config_serialization() {
         temperature: 0.5,
         max_tokens: Some(2048),
         timeout_secs: 120,
+        stop: vec![],
+        seed: None,
         headers: HashMap::new(),
         extra: HashMap::new(),
     };
@@ -2,19 +2,131 @@
 //!
 //! Stores memory entries in a JSON file that persists across agent runs.
 //! Supports optional max_entries limit to prevent unbounded file growth.
+//!
+//! Several agents (or processes) may share one memory file. Every write
+//! holds an advisory lock on a lock file next to it, re-reads the file,
+//! applies its change and replaces the file atomically, so concurrent writers
+//! never drop each other's entries.
 
 use aof_core::{AofError, AofResult, MemoryBackend, MemoryEntry, MemoryQuery};
 use async_trait::async_trait;
+use fs2::FileExt;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+use tracing::debug;
+
+/// How a writer waits for another writer's lock on the memory file
+///
+/// Attempts back off exponentially from `initial_backoff` up to
+/// `max_backoff`. The lock is an OS advisory lock, so a crashed writer's lock
+/// is released with its process and never has to be broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockRetry {
+    /// Attempts before giving up
+    pub max_attempts: u32,
+    /// Wait after the first failed attempt
+    pub initial_backoff: Duration,
+    /// Upper bound for a single wait
+    pub max_backoff: Duration,
+}
+
+impl Default for LockRetry {
+    fn default() -> Self {
+        Self {
+            max_attempts: 100,
+            initial_backoff: Duration::from_millis(2),
+            max_backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+impl LockRetry {
+    /// Wait before retrying after `attempt` failed attempts (1-based)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32 << attempt.saturating_sub(1).min(16);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// Exclusive advisory lock on a memory file's lock file, released on drop
+///
+/// The lock file itself is left in place: unlinking it while another writer
+/// has it open would let two writers lock different files.
+struct FileLock {
+    file: std::fs::File,
+}
+
+impl FileLock {
+    /// Lock the lock file, retrying with backoff while another writer holds it
+    async fn acquire(path: PathBuf, retry: &LockRetry) -> AofResult<Self> {
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .await
+            .map_err(|e| {
+                AofError::memory(format!(
+                    "Failed to open memory lock {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?
+            .into_std()
+            .await;
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match file.try_lock_exclusive() {
+                Ok(()) => return Ok(Self { file }),
+                Err(e) if e.kind() == fs2::lock_contended_error().kind() => {
+                    if attempt >= retry.max_attempts {
+                        return Err(AofError::memory(format!(
+                            "Timed out waiting for memory lock {} after {} attempts",
+                            path.display(),
+                            attempt
+                        )));
+                    }
+                    let wait = retry.backoff(attempt);
+                    debug!("Memory lock {} is held, retrying in {:?}", path.display(), wait);
+                    tokio::time::sleep(wait).await;
+                }
+                Err(e) => {
+                    return Err(AofError::memory(format!(
+                        "Failed to lock {}: {}",
+                        path.display(),
+                        e
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = FileExt::unlock(&self.file);
+    }
+}
 
 /// File-based memory backend
 ///
 /// Stores all memory entries in a JSON file. Changes are written immediately
 /// to ensure persistence across agent runs.
 ///
+/// ## Concurrent Writers
+///
+/// Writes are serialized within the process and, through an advisory lock on
+/// a `<file>.lock` file, across backends and processes sharing the file. Each write
+/// merges into the current file contents and lands via a temp file and
+/// rename, so readers never see a half-written file. Lock contention is
+/// retried with backoff (see [`LockRetry`]).
+///
 /// ## Max Entries Limit
 ///
 /// You can configure a maximum number of entries to prevent unbounded file growth.
@@ -44,6 +156,10 @@ pub struct FileBackend {
     cache: Arc<RwLock<HashMap<String, MemoryEntry>>>,
     /// Maximum number of entries (oldest removed when exceeded)
     max_entries: Option<usize>,
+    /// Serializes writes from this backend (and its clones)
+    write_lock: Arc<Mutex<()>>,
+    /// How to wait for the file lock held by other writers
+    lock_retry: LockRetry,
 }
 
 impl FileBackend {
@@ -83,25 +199,7 @@ impl FileBackend {
         }
 
         // Load existing data or create empty
-        let mut cache: HashMap<String, MemoryEntry> = if path.exists() {
-            let content = tokio::fs::read_to_string(&path).await.map_err(|e| {
-                AofError::memory(format!("Failed to read memory file {}: {}", path.display(), e))
-            })?;
-
-            if content.trim().is_empty() {
-                HashMap::new()
-            } else {
-                serde_json::from_str(&content).map_err(|e| {
-                    AofError::memory(format!(
-                        "Failed to parse memory file {}: {}",
-                        path.display(),
-                        e
-                    ))
-                })?
-            }
-        } else {
-            HashMap::new()
-        };
+        let mut cache = Self::load(&path).await?;
 
         // Apply max_entries limit on load (trim oldest if over limit)
         if let Some(max) = max_entries {
@@ -114,16 +212,50 @@ impl FileBackend {
             path,
             cache: Arc::new(RwLock::new(cache)),
             max_entries,
+            write_lock: Arc::new(Mutex::new(())),
+            lock_retry: LockRetry::default(),
         };
 
         // Persist if we trimmed entries on load
         if max_entries.is_some() {
-            backend.persist().await?;
+            backend.update(|_| {}).await?;
         }
 
         Ok(backend)
     }
 
+    /// Set how writers wait for each other's lock on the file
+    pub fn with_lock_retry(mut self, lock_retry: LockRetry) -> Self {
+        self.lock_retry = lock_retry;
+        self
+    }
+
+    /// Read entries from a memory file (empty if it doesn't exist)
+    async fn load(path: &Path) -> AofResult<HashMap<String, MemoryEntry>> {
+        let content = match tokio::fs::read_to_string(path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => {
+                return Err(AofError::memory(format!(
+                    "Failed to read memory file {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        if content.trim().is_empty() {
+            return Ok(HashMap::new());
+        }
+        serde_json::from_str(&content).map_err(|e| {
+            AofError::memory(format!(
+                "Failed to parse memory file {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
     /// Trim cache to max_entries by removing oldest entries
     fn trim_oldest_entries(cache: &mut HashMap<String, MemoryEntry>, max: usize) {
         if cache.len() <= max {
@@ -147,22 +279,51 @@ impl FileBackend {
         }
     }
 
-    /// Persist current cache to file
-    async fn persist(&self) -> AofResult<()> {
-        let cache = self.cache.read().await;
-        let content = serde_json::to_string_pretty(&*cache).map_err(|e| {
+    /// Path next to the memory file with the given suffix appended
+    fn sibling_path(&self, suffix: &str) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(suffix);
+        self.path.with_file_name(name)
+    }
+
+    /// Apply a change to the stored entries
+    ///
+    /// Holds the write lock, re-reads the file so changes made by other
+    /// writers are kept, applies `change`, enforces max_entries and replaces
+    /// the file atomically. The cache is refreshed with the result.
+    async fn update(&self, change: impl FnOnce(&mut HashMap<String, MemoryEntry>)) -> AofResult<()> {
+        let _guard = self.write_lock.lock().await;
+        let _file_lock = FileLock::acquire(self.sibling_path(".lock"), &self.lock_retry).await?;
+
+        let mut entries = Self::load(&self.path).await?;
+        change(&mut entries);
+        if let Some(max) = self.max_entries {
+            Self::trim_oldest_entries(&mut entries, max);
+        }
+
+        let content = serde_json::to_string_pretty(&entries).map_err(|e| {
             AofError::memory(format!("Failed to serialize memory: {}", e))
         })?;
-        drop(cache);
 
-        tokio::fs::write(&self.path, content).await.map_err(|e| {
+        // Write a temp file and rename it over the original so the file is
+        // never observed half-written
+        let tmp_path = self.sibling_path(&format!(".{}.tmp", std::process::id()));
+        tokio::fs::write(&tmp_path, content).await.map_err(|e| {
             AofError::memory(format!(
                 "Failed to write memory file {}: {}",
+                tmp_path.display(),
+                e
+            ))
+        })?;
+        tokio::fs::rename(&tmp_path, &self.path).await.map_err(|e| {
+            AofError::memory(format!(
+                "Failed to replace memory file {}: {}",
                 self.path.display(),
                 e
             ))
         })?;
 
+        *self.cache.write().await = entries;
         Ok(())
     }
 
@@ -190,18 +351,11 @@ impl FileBackend {
 #[async_trait]
 impl MemoryBackend for FileBackend {
     async fn store(&self, key: &str, entry: MemoryEntry) -> AofResult<()> {
-        {
-            let mut cache = self.cache.write().await;
-            cache.insert(key.to_string(), entry);
-
-            // Enforce max_entries limit
-            if let Some(max) = self.max_entries {
-                if cache.len() > max {
-                    Self::trim_oldest_entries(&mut cache, max);
-                }
-            }
-        }
-        self.persist().await
+        // max_entries is enforced by update()
+        self.update(|entries| {
+            entries.insert(key.to_string(), entry);
+        })
+        .await
     }
 
     async fn retrieve(&self, key: &str) -> AofResult<Option<MemoryEntry>> {
@@ -211,10 +365,10 @@ impl MemoryBackend for FileBackend {
                 if entry.is_expired() {
                     drop(cache);
                     // Lazy cleanup: delete expired entry
-                    let mut cache = self.cache.write().await;
-                    cache.remove(key);
-                    drop(cache);
-                    self.persist().await?;
+                    self.update(|entries| {
+                        entries.remove(key);
+                    })
+                    .await?;
                     Ok(None)
                 } else {
                     Ok(Some(entry.clone()))
@@ -225,11 +379,10 @@ impl MemoryBackend for FileBackend {
     }
 
     async fn delete(&self, key: &str) -> AofResult<()> {
-        {
-            let mut cache = self.cache.write().await;
-            cache.remove(key);
-        }
-        self.persist().await
+        self.update(|entries| {
+            entries.remove(key);
+        })
+        .await
     }
 
     async fn list_keys(&self, prefix: Option<&str>) -> AofResult<Vec<String>> {
@@ -246,11 +399,7 @@ impl MemoryBackend for FileBackend {
     }
 
     async fn clear(&self) -> AofResult<()> {
-        {
-            let mut cache = self.cache.write().await;
-            cache.clear();
-        }
-        self.persist().await
+        self.update(|entries| entries.clear()).await
     }
 
     async fn search(&self, query: &MemoryQuery) -> AofResult<Vec<MemoryEntry>> {
@@ -447,6 +596,100 @@ mod tests {
         assert!(backend.retrieve("key5").await.unwrap().is_some());
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_file_backend_concurrent_writers_keep_all_entries() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("memory.json");
+
+        // Two backends on the same file, as two agents would have
+        let agents = [
+            FileBackend::new(&path).await.unwrap(),
+            FileBackend::new(&path).await.unwrap(),
+        ];
+
+        let mut writers = Vec::new();
+        for (a, backend) in agents.iter().enumerate() {
+            for w in 0..4 {
+                let backend = backend.clone();
+                writers.push(tokio::spawn(async move {
+                    for i in 0..10 {
+                        let key = format!("agent{}:writer{}:{}", a, w, i);
+                        backend.store(&key, MemoryEntry::new(&key, json!(i))).await.unwrap();
+                    }
+                }));
+            }
+        }
+        for writer in writers {
+            writer.await.unwrap();
+        }
+
+        let reloaded = FileBackend::new(&path).await.unwrap();
+        assert_eq!(reloaded.len().await, 80);
+        assert_eq!(reloaded.list_keys(Some("agent0:")).await.unwrap().len(), 40);
+        assert_eq!(reloaded.list_keys(Some("agent1:")).await.unwrap().len(), 40);
+
+        // No temp files left behind; the lock file stays for the next writer
+        let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .filter(|name| name != "memory.json" && name != "memory.json.lock")
+            .collect();
+        assert!(leftovers.is_empty(), "{:?}", leftovers);
+    }
+
+    #[tokio::test]
+    async fn test_file_backend_lock_contention_times_out() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("memory.json");
+        let backend = FileBackend::new(&path).await.unwrap().with_lock_retry(LockRetry {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        });
+
+        // Another writer holds the lock
+        let holder = std::fs::File::create(dir.path().join("memory.json.lock")).unwrap();
+        holder.lock_exclusive().unwrap();
+
+        let err = backend
+            .store("key1", MemoryEntry::new("key1", json!(1)))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Timed out waiting for memory lock"));
+        assert!(backend.retrieve("key1").await.unwrap().is_none());
+
+        // Once released, writes go through
+        holder.unlock().unwrap();
+        backend.store("key1", MemoryEntry::new("key1", json!(1))).await.unwrap();
+        assert!(backend.retrieve("key1").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_file_backend_ignores_unheld_lock_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("memory.json");
+        let backend = FileBackend::new(&path).await.unwrap().with_lock_retry(LockRetry {
+            max_attempts: 1,
+            ..Default::default()
+        });
+
+        // Left behind by a crashed writer, whose lock died with it
+        std::fs::write(dir.path().join("memory.json.lock"), "").unwrap();
+
+        backend.store("key1", MemoryEntry::new("key1", json!(1))).await.unwrap();
+        assert!(backend.retrieve("key1").await.unwrap().is_some());
+    }
+
+    #[test]
+    fn test_lock_retry_backoff() {
+        let retry = LockRetry::default();
+        assert_eq!(retry.backoff(1), Duration::from_millis(2));
+        assert_eq!(retry.backoff(2), Duration::from_millis(4));
+        assert_eq!(retry.backoff(4), Duration::from_millis(16));
+        assert_eq!(retry.backoff(10), Duration::from_millis(100));
+        assert_eq!(retry.backoff(u32::MAX), Duration::from_millis(100));
+    }
+
     #[tokio::test]
     async fn test_file_backend_max_entries_on_load() {
         let dir = tempdir().unwrap();
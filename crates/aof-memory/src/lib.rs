@@ -26,7 +26,7 @@
 pub mod backend;
 
 // Re-export main types
-pub use backend::file::FileBackend;
+pub use backend::file::{FileBackend, LockRetry};
 pub use backend::memory::InMemoryBackend;
 pub use backend::SimpleMemory;
 
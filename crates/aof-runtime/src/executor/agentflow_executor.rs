@@ -169,6 +169,16 @@ impl AgentFlowExecutor {
             }
         }
 
+        // Top-level deadline for the whole run
+        let timeout_seconds = self
+            .flow
+            .spec
+            .config
+            .as_ref()
+            .and_then(|c| c.timeout_seconds);
+        let deadline = timeout_seconds
+            .map(|secs| tokio::time::Instant::now() + std::time::Duration::from_secs(secs));
+
         // Execute nodes until completion
         loop {
             if state.current_nodes.is_empty() {
@@ -179,9 +189,35 @@ impl AgentFlowExecutor {
             // Get next nodes to execute
             let nodes_to_execute: Vec<String> = state.current_nodes.drain(..).collect();
             let mut next_nodes: Vec<String> = Vec::new();
+            let mut pending = nodes_to_execute.into_iter();
+
+            while let Some(node_id) = pending.next() {
+                let result = match deadline {
+                    Some(deadline) => {
+                        // Dropping the node future on expiry cancels whatever it was awaiting
+                        match tokio::time::timeout_at(deadline, self.execute_node(&node_id, &mut state))
+                            .await
+                        {
+                            Ok(result) => result,
+                            Err(_) => {
+                                let mut unfinished = vec![node_id.clone()];
+                                unfinished.extend(pending.by_ref());
+                                unfinished.extend(next_nodes.drain(..));
+                                self.mark_timed_out(
+                                    &mut state,
+                                    &node_id,
+                                    unfinished,
+                                    timeout_seconds.unwrap_or_default(),
+                                )
+                                .await;
+                                break;
+                            }
+                        }
+                    }
+                    None => self.execute_node(&node_id, &mut state).await,
+                };
 
-            for node_id in nodes_to_execute {
-                match self.execute_node(&node_id, &mut state).await {
+                match result {
                     Ok(successors) => {
                         next_nodes.extend(successors);
                     }
@@ -206,7 +242,10 @@ impl AgentFlowExecutor {
                 }
             }
 
-            if state.status == FlowExecutionStatus::Failed {
+            if matches!(
+                state.status,
+                FlowExecutionStatus::Failed | FlowExecutionStatus::TimedOut
+            ) {
                 break;
             }
 
@@ -234,6 +273,51 @@ impl AgentFlowExecutor {
         Ok(state)
     }
 
+    /// Stop a run that exceeded its top-level timeout, keeping completed results
+    async fn mark_timed_out(
+        &self,
+        state: &mut AgentFlowState,
+        node_id: &str,
+        unfinished: Vec<String>,
+        timeout_seconds: u64,
+    ) {
+        let message = format!(
+            "Flow exceeded its timeout of {}s; node '{}' was cancelled",
+            timeout_seconds, node_id
+        );
+        warn!("{}", message);
+
+        state.node_results.insert(
+            node_id.to_string(),
+            NodeResult {
+                node_id: node_id.to_string(),
+                status: NodeExecutionStatus::Failed,
+                output: None,
+                started_at: Utc::now(),
+                ended_at: Some(Utc::now()),
+                duration_ms: None,
+                error: Some("Cancelled: flow timed out".to_string()),
+            },
+        );
+
+        // Nodes that never finished stay current so the partial state shows where it stopped
+        state.current_nodes = unfinished;
+        state.status = FlowExecutionStatus::TimedOut;
+        state.error = Some(FlowError {
+            error_type: "FlowTimeout".to_string(),
+            message: message.clone(),
+            node_id: Some(node_id.to_string()),
+            details: None,
+        });
+        state.updated_at = Utc::now();
+
+        self.emit_event(AgentFlowEvent::NodeFailed {
+            node_id: node_id.to_string(),
+            error: message,
+        })
+        .await;
+    }
+
     /// Execute a single node
     async fn execute_node(
         &self,
@@ -388,6 +472,11 @@ impl AgentFlowExecutor {
             .map(|i| self.expand_variables(i, state))
             .unwrap_or_default();
 
+        // Replay mode: serve the canned response without loading any agent
+        if let Some(output) = self.replay_agent_node(node, &input).await? {
+            return Ok(output);
+        }
+
         // Check if we have inline config or agent reference
         if let Some(inline) = &node.config.inline {
             // Inline agent configuration - create agent on the fly
@@ -408,6 +497,51 @@ impl AgentFlowExecutor {
         Ok(agent_result)
     }
 
+    /// Resolve an agent node from the runtime's canned responses
+    ///
+    /// Returns `None` when the runtime is not in replay mode. In replay mode the
+    /// node id is looked up first, then the agent name; a node with neither
+    /// registered fails rather than falling back to a model call.
+    async fn replay_agent_node(
+        &self,
+        node: &FlowNode,
+        input: &str,
+    ) -> AofResult<Option<serde_json::Value>> {
+        let runtime = self.runtime.read().await;
+        if !runtime.is_replay() {
+            return Ok(None);
+        }
+
+        let agent_name = node
+            .config
+            .inline
+            .as_ref()
+            .map(|inline| inline.name.clone())
+            .or_else(|| node.config.agent.clone())
+            .unwrap_or_else(|| node.id.clone());
+
+        let response = runtime
+            .canned_response(&node.id)
+            .or_else(|| runtime.canned_response(&agent_name))
+            .ok_or_else(|| {
+                AofError::Config(format!(
+                    "No canned response for node '{}' (agent '{}')",
+                    node.id, agent_name
+                ))
+            })?;
+
+        debug!("Replaying canned response for node {}", node.id);
+
+        Ok(Some(serde_json::json!({
+            "agent": agent_name,
+            "input": input,
+            "output": response,
+            "input_tokens": 0,
+            "output_tokens": 0,
+            "requires_approval": false
+        })))
+    }
+
     /// Run an inline agent (defined directly in the flow)
     async fn run_inline_agent(
         &self,
@@ -430,7 +564,15 @@ impl AgentFlowExecutor {
             max_iterations: 10,
             temperature: inline.temperature.unwrap_or(0.7),
             max_tokens: inline.max_tokens,
+            stop: Vec::new(),
+            seed: None,
             output_schema: None,
+            guardrails: vec![],
+            max_tool_calls_per_turn: None,
+            max_tool_calls_per_run: None,
+            max_continuations: None,
+            tool_result_page_size: None,
+            tool_discovery: false,
             extra: std::collections::HashMap::new(),
         };
 
@@ -1710,4 +1852,179 @@ spec:
         assert!(executor.evaluate_condition("5 > 3"));
         assert!(executor.evaluate_condition("3 < 5"));
     }
+
+    const CANNED_FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/canned-responses.yaml"
+    );
+
+    /// triage -> (page | ticket) -> join -> done, routed on the trigger payload
+    fn two_branch_flow() -> AgentFlow {
+        serde_yaml::from_str(
+            r#"
+apiVersion: aof.dev/v1
+kind: AgentFlow
+metadata:
+  name: incident-routing
+spec:
+  trigger:
+    type: HTTP
+  nodes:
+    - id: triage
+      type: Agent
+      config:
+        agent: triage-agent
+        input: "Investigate ${trigger}"
+    - id: page
+      type: Agent
+      config:
+        agent: pager-agent
+        input: "${triage.output}"
+    - id: ticket
+      type: Agent
+      config:
+        agent: ticket-agent
+        input: "${triage.output}"
+    - id: join
+      type: Join
+    - id: done
+      type: End
+  connections:
+    - from: start
+      to: triage
+    - from: triage
+      to: page
+      when: "${trigger} == critical"
+    - from: triage
+      to: ticket
+      when: "${trigger} != critical"
+    - from: page
+      to: join
+    - from: ticket
+      to: join
+    - from: join
+      to: done
+"#,
+        )
+        .unwrap()
+    }
+
+    fn replay_executor() -> AgentFlowExecutor {
+        let responses = Runtime::load_canned_responses(CANNED_FIXTURE).unwrap();
+        AgentFlowExecutor::with_runtime(
+            two_branch_flow(),
+            Runtime::new().with_canned_responses(responses),
+        )
+    }
+
+    fn agent_output(state: &AgentFlowState, node_id: &str) -> String {
+        state.node_results[node_id].output.as_ref().unwrap()["output"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_replay_critical_branch() {
+        let state = replay_executor()
+            .execute(serde_json::json!("critical"))
+            .await
+            .unwrap();
+
+        assert_eq!(state.status, FlowExecutionStatus::Completed);
+        assert!(agent_output(&state, "triage").contains("crash-looping"));
+        assert_eq!(agent_output(&state, "page"), "Paged the on-call SRE with the triage summary.");
+        assert!(!state.node_results.contains_key("ticket"));
+        assert!(state.node_results.contains_key("join"));
+        assert!(state.node_results.contains_key("done"));
+    }
+
+    #[tokio::test]
+    async fn test_replay_non_critical_branch() {
+        let state = replay_executor()
+            .execute(serde_json::json!("warning"))
+            .await
+            .unwrap();
+
+        assert_eq!(state.status, FlowExecutionStatus::Completed);
+        assert!(!state.node_results.contains_key("page"));
+        assert_eq!(
+            agent_output(&state, "ticket"),
+            "Opened a low-priority ticket with the triage summary."
+        );
+        assert!(state.node_results.contains_key("done"));
+
+        // Canned runs report no token usage
+        let triage = state.node_results["triage"].output.as_ref().unwrap();
+        assert_eq!(triage["input_tokens"], 0);
+        assert_eq!(triage["output_tokens"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_replay_missing_response_fails_without_model_call() {
+        let mut responses = HashMap::new();
+        responses.insert("triage".to_string(), "summary".to_string());
+        let executor = AgentFlowExecutor::with_runtime(
+            two_branch_flow(),
+            Runtime::new().with_canned_responses(responses),
+        );
+
+        let state = executor.execute(serde_json::json!("critical")).await.unwrap();
+
+        assert_eq!(state.status, FlowExecutionStatus::Failed);
+        let error = state.error.unwrap();
+        assert_eq!(error.node_id.as_deref(), Some("page"));
+        assert!(error.message.contains("No canned response"));
+    }
+
+    #[tokio::test]
+    async fn test_flow_timeout_returns_partial_state() {
+        let flow: AgentFlow = serde_yaml::from_str(
+            r#"
+apiVersion: aof.dev/v1
+kind: AgentFlow
+metadata:
+  name: slow-flow
+spec:
+  trigger:
+    type: HTTP
+  config:
+    timeoutSeconds: 1
+  nodes:
+    - id: prepare
+      type: Transform
+      config:
+        script: "export stage=ready"
+    - id: pause
+      type: Wait
+      config:
+        duration: 30s
+    - id: done
+      type: End
+  connections:
+    - from: start
+      to: prepare
+    - from: prepare
+      to: pause
+    - from: pause
+      to: done
+"#,
+        )
+        .unwrap();
+
+        let executor = AgentFlowExecutor::with_runtime(flow, Runtime::new());
+        let started = std::time::Instant::now();
+        let state = executor.execute(serde_json::json!({})).await.unwrap();
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        assert_eq!(state.status, FlowExecutionStatus::TimedOut);
+        assert_eq!(state.node_results["prepare"].status, NodeExecutionStatus::Completed);
+        assert_eq!(state.node_results["pause"].status, NodeExecutionStatus::Failed);
+        assert!(!state.node_results.contains_key("done"));
+        assert_eq!(state.current_nodes, vec!["pause".to_string()]);
+
+        let error = state.error.unwrap();
+        assert_eq!(error.error_type, "FlowTimeout");
+        assert_eq!(error.node_id.as_deref(), Some("pause"));
+    }
 }
@@ -3,9 +3,17 @@
 pub mod agent_executor;
 pub mod agentflow_executor;
 pub mod runtime;
+pub mod tool_discovery;
+pub mod tool_feedback;
+pub mod tool_pages;
+pub mod trace;
 pub mod workflow_executor;
 
-pub use agent_executor::{AgentExecutor, StreamEvent};
+pub use agent_executor::{AgentExecutor, StreamEvent, ToolExecutorLayer};
 pub use agentflow_executor::{AgentFlowEvent, AgentFlowExecutor};
-pub use runtime::Runtime;
+pub use runtime::{builtin_tool_registry, builtin_tool_registry_with_commit_cache, Runtime};
+pub use tool_discovery::LIST_TOOLS_TOOL;
+pub use tool_feedback::{ToolErrorCategory, ToolErrorFeedback, ERROR_CATEGORY_METADATA};
+pub use tool_pages::{ToolResultPager, FETCH_MORE_TOOL};
+pub use trace::{ExecutionTrace, TraceStep, TraceToolCall};
 pub use workflow_executor::{ApprovalDecision, HumanInput, WorkflowEvent, WorkflowExecutor};
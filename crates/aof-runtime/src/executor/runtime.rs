@@ -3,9 +3,9 @@
 //! The Runtime loads agent configurations, creates models, tools, and memory,
 //! and executes agents with proper lifecycle management.
 
-use super::{AgentExecutor, agent_executor::StreamEvent};
+use super::{AgentExecutor, agent_executor::{StreamEvent, ToolExecutorLayer}};
 use aof_core::{
-    AgentConfig, AgentContext, AofError, AofResult, McpServerConfig, McpTransport,
+    AgentConfig, AgentContext, AofError, AofResult, GuardrailChain, McpServerConfig, McpTransport,
     ModelConfig, ModelProvider, Tool, ToolDefinition, ToolExecutor, ToolInput, ToolSpec,
 };
 use aof_llm::create_model;
@@ -17,6 +17,47 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
+// Known system/builtin tools
+// Unified CLI tools (recommended - simple 'command' argument approach)
+// Legacy per-operation tools (backward compatibility)
+const SYSTEM_TOOLS: &[&str] = &[
+    // Core tools
+    "shell", "bash", "sh",
+    // File tools
+    "read_file", "write_file", "list_directory", "search_files",
+    // Unified CLI tools (RECOMMENDED)
+    "kubectl", "git", "docker", "terraform", "aws", "helm",
+    // Legacy kubectl tools
+    "kubectl_get", "kubectl_apply", "kubectl_delete", "kubectl_logs",
+    "kubectl_exec", "kubectl_describe",
+    // Pod logs, config drift and quota tools
+    "pod_logs", "k8s_config_diff", "k8s_resource_quota",
+    // Manifest rendering tools
+    "helm_template", "kustomize_build",
+    // Legacy docker tools
+    "docker_ps", "docker_stats", "docker_logs", "docker_build", "docker_run",
+    "docker_exec", "docker_images",
+    // Legacy git tools
+    "git_status", "git_diff", "git_log", "git_commit", "git_branch",
+    "git_checkout", "git_pull", "git_push", "git_diff_summary", "git_changelog",
+    // Legacy terraform tools
+    "terraform_init", "terraform_plan", "terraform_apply",
+    "terraform_destroy", "terraform_output", "terraform_state",
+    // Observability tools
+    "prometheus_query", "loki_query", "elasticsearch_query",
+    "victoriametrics_query",
+    // HTTP tool
+    "http", "http_request",
+    // Network diagnostic tools
+    "net_diag", "cert_check",
+    // Data transform tool
+    "data_transform",
+    // Kubernetes API tool
+    "k8s_api",
+    // Feature flag tool
+    "feature_flag",
+];
+
 /// Top-level runtime for agent execution
 ///
 /// The Runtime coordinates all aspects of agent execution:
@@ -28,6 +69,10 @@ use tracing::{debug, info, warn};
 pub struct Runtime {
     /// Loaded agents
     agents: HashMap<String, Arc<AgentExecutor>>,
+    /// Canned responses keyed by agent or flow node name (replay mode)
+    canned_responses: Option<HashMap<String, String>>,
+    /// Wraps the tools of every agent (e.g. a safety check on each call)
+    tool_layer: Option<ToolExecutorLayer>,
 }
 
 impl Runtime {
@@ -35,9 +80,81 @@ impl Runtime {
     pub fn new() -> Self {
         Self {
             agents: HashMap::new(),
+            canned_responses: None,
+            tool_layer: None,
         }
     }
 
+    /// Route every agent's tool calls through `layer`
+    pub fn with_tool_layer(mut self, layer: ToolExecutorLayer) -> Self {
+        self.set_tool_layer(layer);
+        self
+    }
+
+    /// Route every agent's tool calls through `layer` (mutable)
+    ///
+    /// Applies to agents loaded later and to loaded agents not currently
+    /// executing; replaces any earlier layer.
+    pub fn set_tool_layer(&mut self, layer: ToolExecutorLayer) {
+        for (name, agent) in self.agents.iter_mut() {
+            match Arc::get_mut(agent) {
+                Some(agent) => agent.set_tool_layer(&layer),
+                None => warn!("Agent '{}' is in use; its tools keep the previous layer until reloaded", name),
+            }
+        }
+        self.tool_layer = Some(layer);
+    }
+
+    /// Seed the runtime with canned responses for deterministic replay
+    ///
+    /// In replay mode no model is ever called: agent executions (and AgentFlow
+    /// agent nodes) return the canned response registered for the node id or
+    /// agent name, and fail if none is registered. This makes a flow's
+    /// branching and joining logic testable without model calls.
+    ///
+    /// # Arguments
+    /// * `responses` - Map of agent name or flow node id to response text
+    pub fn with_canned_responses(mut self, responses: HashMap<String, String>) -> Self {
+        self.canned_responses = Some(responses);
+        self
+    }
+
+    /// Load canned responses from a YAML or JSON fixture mapping names to responses
+    pub fn load_canned_responses(path: &str) -> AofResult<HashMap<String, String>> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AofError::config(format!("Failed to read canned responses {}: {}", path, e))
+        })?;
+
+        serde_yaml::from_str(&content).map_err(|e| {
+            AofError::config(format!("Failed to parse canned responses {}: {}", path, e))
+        })
+    }
+
+    /// Whether the runtime is in replay mode (seeded with canned responses)
+    pub fn is_replay(&self) -> bool {
+        self.canned_responses.is_some()
+    }
+
+    /// Look up the canned response for an agent or flow node
+    pub fn canned_response(&self, name: &str) -> Option<&str> {
+        self.canned_responses
+            .as_ref()
+            .and_then(|responses| responses.get(name))
+            .map(String::as_str)
+    }
+
+    // Helper: Resolve a canned response in replay mode, failing if none is registered
+    fn replay(&self, agent_name: &str) -> Option<AofResult<String>> {
+        self.canned_responses.as_ref()?;
+        Some(
+            self.canned_response(agent_name)
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    AofError::agent(format!("No canned response for agent: {}", agent_name))
+                }),
+        )
+    }
+
     /// Load an agent from YAML configuration file
     ///
     /// Supports both flat and Kubernetes-style YAML formats:
@@ -81,6 +198,9 @@ impl Runtime {
         let agent_name = config.name.clone();
         info!("Loading agent: {}", agent_name);
 
+        // Fail fast on invalid guardrail configuration
+        GuardrailChain::from_specs(&config.guardrails)?;
+
         // Create model from config
         let model_config = self.create_model_config(&config)?;
         let model = create_model(model_config).await?;
@@ -137,7 +257,7 @@ impl Runtime {
                     if !builtin_tool_names.is_empty() {
                         // Create combined executor with both builtin and MCP tools
                         info!("Creating combined executor: builtin={:?}, mcp available", builtin_tool_names);
-                        let builtin_exec = self.create_system_executor(&builtin_tool_names)?;
+                        let builtin_exec = self.create_system_executor(&builtin_tool_names, &config.commit_cached_tools())?;
                         Some(Arc::new(CombinedToolExecutor {
                             primary: builtin_exec,
                             secondary: Some(mcp_exec),
@@ -148,7 +268,7 @@ impl Runtime {
                 } else if !builtin_tool_names.is_empty() {
                     // MCP failed, but we have builtin tools - use those
                     info!("MCP initialization failed, using builtin tools only: {:?}", builtin_tool_names);
-                    Some(self.create_system_executor(&builtin_tool_names)?)
+                    Some(self.create_system_executor(&builtin_tool_names, &config.commit_cached_tools())?)
                 } else {
                     // No MCP and no builtin tools
                     warn!("No tools available: MCP initialization failed and no builtin tools configured");
@@ -156,7 +276,7 @@ impl Runtime {
                 }
             } else if !builtin_tool_names.is_empty() {
                 info!("Creating system executor for type-based tools: {:?}", builtin_tool_names);
-                Some(self.create_system_executor(&builtin_tool_names)?)
+                Some(self.create_system_executor(&builtin_tool_names, &config.commit_cached_tools())?)
             } else {
                 None
             }
@@ -171,60 +291,7 @@ impl Runtime {
                 }
             }
         } else if !config.tools.is_empty() {
-            // Separate built-in tools from MCP tools
-            let builtin_tools: Vec<&str> = config.tools.iter()
-                .filter(|t| t.is_builtin())
-                .map(|t| t.name())
-                .collect();
-            let mcp_tools: Vec<&str> = config.tools.iter()
-                .filter(|t| t.is_mcp())
-                .map(|t| t.name())
-                .collect();
-            info!("Tool separation: builtin={:?}, mcp={:?}", builtin_tools, mcp_tools);
-
-            // Known system/builtin tools
-            // Unified CLI tools (recommended - simple 'command' argument approach)
-            // Legacy per-operation tools (backward compatibility)
-            let system_tools = [
-                // Core tools
-                "shell", "bash", "sh",
-                // File tools
-                "read_file", "write_file", "list_directory", "search_files",
-                // Unified CLI tools (RECOMMENDED)
-                "kubectl", "git", "docker", "terraform", "aws", "helm",
-                // Legacy kubectl tools
-                "kubectl_get", "kubectl_apply", "kubectl_delete", "kubectl_logs",
-                "kubectl_exec", "kubectl_describe",
-                // Legacy docker tools
-                "docker_ps", "docker_stats", "docker_logs", "docker_build", "docker_run",
-                "docker_exec", "docker_images",
-                // Legacy git tools
-                "git_status", "git_diff", "git_log", "git_commit", "git_branch",
-                "git_checkout", "git_pull", "git_push",
-                // Legacy terraform tools
-                "terraform_init", "terraform_plan", "terraform_apply",
-                "terraform_destroy", "terraform_output",
-                // Observability tools
-                "prometheus_query", "loki_query", "elasticsearch_query",
-                "victoriametrics_query",
-                // HTTP tool
-                "http", "http_request",
-            ];
-
-            let has_system_tools = builtin_tools.iter().any(|t| system_tools.contains(t));
-            let has_mcp_tools = !mcp_tools.is_empty();
-            info!("Tool detection: has_system_tools={}, has_mcp_tools={}", has_system_tools, has_mcp_tools);
-
-            if has_system_tools && !has_mcp_tools {
-                info!("Creating system tool executor for builtin tools: {:?}", builtin_tools);
-                let tool_names: Vec<String> = builtin_tools.iter().map(|s| s.to_string()).collect();
-                Some(self.create_system_executor(&tool_names)?)
-            } else if has_mcp_tools {
-                let tool_names: Vec<String> = config.tool_names().iter().map(|s| s.to_string()).collect();
-                Some(self.create_tool_executor(&tool_names).await?)
-            } else {
-                None
-            }
+            self.create_executor_for_tool_specs(&config).await?
         } else {
             None
         };
@@ -234,9 +301,9 @@ impl Runtime {
         debug!("Memory backend created for agent: {}", agent_name);
 
         // Create agent executor
-        let executor = AgentExecutor::new(config, model, tool_executor, Some(memory));
+        let executor = AgentExecutor::new(config, model, tool_executor, Some(memory))?;
 
-        self.agents.insert(agent_name.clone(), Arc::new(executor));
+        self.register_agent(executor);
         info!("Agent loaded successfully: {}", agent_name);
 
         Ok(agent_name)
@@ -251,6 +318,10 @@ impl Runtime {
     /// # Returns
     /// The agent's final response
     pub async fn execute(&self, agent_name: &str, input: &str) -> AofResult<String> {
+        if let Some(response) = self.replay(agent_name) {
+            return response;
+        }
+
         let executor = self
             .agents
             .get(agent_name)
@@ -273,6 +344,10 @@ impl Runtime {
         agent_name: &str,
         context: &mut AgentContext,
     ) -> AofResult<String> {
+        if let Some(response) = self.replay(agent_name) {
+            return response;
+        }
+
         let executor = self
             .agents
             .get(agent_name)
@@ -294,6 +369,10 @@ impl Runtime {
         agent_name: &str,
         input: &str,
     ) -> AofResult<(String, usize, usize)> {
+        if let Some(response) = self.replay(agent_name) {
+            return response.map(|r| (r, 0, 0));
+        }
+
         let executor = self
             .agents
             .get(agent_name)
@@ -346,6 +425,10 @@ impl Runtime {
         input: &str,
         stream_tx: mpsc::Sender<StreamEvent>,
     ) -> AofResult<String> {
+        if let Some(response) = self.replay(agent_name) {
+            return response;
+        }
+
         let executor = self
             .agents
             .get(agent_name)
@@ -442,6 +525,34 @@ impl Runtime {
         }
     }
 
+    /// Register an already-built agent executor under its configured name
+    ///
+    /// Useful for embedding applications and tests that construct executors
+    /// with their own model.
+    pub fn register_agent(&mut self, mut executor: AgentExecutor) -> String {
+        if let Some(layer) = &self.tool_layer {
+            executor.set_tool_layer(layer);
+        }
+        let agent_name = executor.config().name.clone();
+        self.agents.insert(agent_name.clone(), Arc::new(executor));
+        agent_name
+    }
+
+    /// Prime an agent's model connection with a minimal request
+    ///
+    /// A no-op in replay mode, where no model is ever called.
+    pub async fn warmup(&self, agent_name: &str) -> AofResult<()> {
+        if self.is_replay() {
+            return Ok(());
+        }
+
+        let executor = self
+            .agents
+            .get(agent_name)
+            .ok_or_else(|| AofError::agent(format!("Agent not found: {}", agent_name)))?;
+        executor.warmup().await
+    }
+
     /// List all loaded agents
     pub fn list_agents(&self) -> Vec<String> {
         self.agents.keys().cloned().collect()
@@ -449,7 +560,7 @@ impl Runtime {
 
     /// Check if an agent is loaded
     pub fn has_agent(&self, name: &str) -> bool {
-        self.agents.contains_key(name)
+        self.agents.contains_key(name) || self.canned_response(name).is_some()
     }
 
     /// Get agent executor by name
@@ -482,6 +593,8 @@ impl Runtime {
             temperature: config.temperature,
             max_tokens: config.max_tokens,
             timeout_secs: 60,
+            stop: config.stop.clone(),
+            seed: config.seed,
             headers: HashMap::new(),
             extra: HashMap::new(),
         })
@@ -685,14 +798,50 @@ impl Runtime {
         })))
     }
 
+    // Helper: Create a tool executor from the simple `tools` list
+    //
+    // Built-in tools get a system executor unless MCP tools are also listed,
+    // in which case the MCP executor serves all of them.
+    async fn create_executor_for_tool_specs(
+        &self,
+        config: &AgentConfig,
+    ) -> AofResult<Option<Arc<dyn ToolExecutor>>> {
+        // Separate built-in tools from MCP tools
+        let builtin_tools: Vec<&str> = config.tools.iter()
+            .filter(|t| t.is_builtin())
+            .map(|t| t.name())
+            .collect();
+        let mcp_tools: Vec<&str> = config.tools.iter()
+            .filter(|t| t.is_mcp())
+            .map(|t| t.name())
+            .collect();
+        info!("Tool separation: builtin={:?}, mcp={:?}", builtin_tools, mcp_tools);
+
+        let has_system_tools = builtin_tools.iter().any(|t| SYSTEM_TOOLS.contains(t));
+        let has_mcp_tools = !mcp_tools.is_empty();
+        info!("Tool detection: has_system_tools={}, has_mcp_tools={}", has_system_tools, has_mcp_tools);
+
+        if has_system_tools && !has_mcp_tools {
+            info!("Creating system tool executor for builtin tools: {:?}", builtin_tools);
+            let tool_names: Vec<String> = builtin_tools.iter().map(|s| s.to_string()).collect();
+            Ok(Some(self.create_system_executor(&tool_names, &config.commit_cached_tools())?))
+        } else if has_mcp_tools {
+            let tool_names: Vec<String> = config.tool_names().iter().map(|s| s.to_string()).collect();
+            Ok(Some(self.create_tool_executor(&tool_names).await?))
+        } else {
+            Ok(None)
+        }
+    }
+
     // Helper: Create system tool executor for shell/kubectl commands
     fn create_system_executor(
         &self,
         tool_names: &[String],
+        commit_cached: &[String],
     ) -> AofResult<Arc<dyn ToolExecutor>> {
         info!("Creating system tool executor with {} tools", tool_names.len());
         // Use the new aof-tools based executor
-        Ok(create_builtin_executor_for_tools(tool_names))
+        Ok(create_builtin_executor_for_tools(tool_names, commit_cached))
     }
 
     // Helper: Create memory backend from config
@@ -813,6 +962,7 @@ impl ToolExecutor for McpToolExecutor {
             data: result,
             error: None,
             execution_time_ms,
+            metadata: Default::default(),
         })
     }
 
@@ -866,6 +1016,7 @@ impl ToolExecutor for MultiMcpToolExecutor {
                         data: result,
                         error: None,
                         execution_time_ms,
+                        metadata: Default::default(),
                     });
                 }
                 Err(e) => {
@@ -886,6 +1037,7 @@ impl ToolExecutor for MultiMcpToolExecutor {
             data: serde_json::json!({}),
             error: Some(error_msg),
             execution_time_ms,
+            metadata: Default::default(),
         })
     }
 
@@ -946,6 +1098,7 @@ impl ToolExecutor for CombinedToolExecutor {
             data: serde_json::json!({}),
             error: Some(format!("Tool '{}' not found in any executor", name)),
             execution_time_ms: 0,
+            metadata: Default::default(),
         })
     }
 
@@ -965,7 +1118,30 @@ impl ToolExecutor for CombinedToolExecutor {
 }
 
 /// Helper function to create a BuiltinToolExecutor from aof-tools
-fn create_builtin_executor_for_tools(tool_names: &[String]) -> Arc<dyn ToolExecutor> {
+fn create_builtin_executor_for_tools(tool_names: &[String], commit_cached: &[String]) -> Arc<dyn ToolExecutor> {
+    let registry = builtin_tool_registry_with_commit_cache(tool_names, commit_cached);
+    info!("Registered {} built-in tools: {:?}", registry.len(), registry.list_names());
+    Arc::new(registry.into_executor())
+}
+
+/// Build a registry holding the named built-in tools
+///
+/// Unknown names are logged and skipped, so callers should check the registry
+/// for the tools they need. Names that map to the same tool (`shell`, `bash`)
+/// or repeat are registered once.
+pub fn builtin_tool_registry(tool_names: &[String]) -> aof_tools::ToolRegistry {
+    builtin_tool_registry_with_commit_cache(tool_names, &[])
+}
+
+/// Build a registry holding the named built-in tools, caching the results of
+/// the `commit_cached` ones per git commit
+///
+/// The cached tools share one `CommitCache`. Names in `commit_cached` that
+/// aren't registered are logged and skipped.
+pub fn builtin_tool_registry_with_commit_cache(
+    tool_names: &[String],
+    commit_cached: &[String],
+) -> aof_tools::ToolRegistry {
     use aof_tools::ToolRegistry;
 
     let mut registry = ToolRegistry::new();
@@ -976,126 +1152,174 @@ fn create_builtin_executor_for_tools(tool_names: &[String]) -> Arc<dyn ToolExecu
         match name.as_str() {
             // File tools
             "read_file" => {
-                registry.register(aof_tools::ReadFileTool::new());
+                registry.try_register(aof_tools::ReadFileTool::new());
             }
             "write_file" => {
-                registry.register(aof_tools::WriteFileTool::new());
+                registry.try_register(aof_tools::WriteFileTool::new());
             }
             "list_directory" => {
-                registry.register(aof_tools::ListDirTool::new());
+                registry.try_register(aof_tools::ListDirTool::new());
             }
             "search_files" => {
-                registry.register(aof_tools::SearchFilesTool::new());
+                registry.try_register(aof_tools::SearchFilesTool::new());
             }
 
             // Shell tool
             "shell" | "bash" | "sh" => {
-                registry.register(aof_tools::ShellTool::new());
+                registry.try_register(aof_tools::ShellTool::new());
             }
 
             // Kubectl tools
             "kubectl_get" => {
-                registry.register(aof_tools::KubectlGetTool::new());
+                registry.try_register(aof_tools::KubectlGetTool::new());
             }
             "kubectl_apply" => {
-                registry.register(aof_tools::KubectlApplyTool::new());
+                registry.try_register(aof_tools::KubectlApplyTool::new());
             }
             "kubectl_delete" => {
-                registry.register(aof_tools::KubectlDeleteTool::new());
+                registry.try_register(aof_tools::KubectlDeleteTool::new());
             }
             "kubectl_logs" => {
-                registry.register(aof_tools::KubectlLogsTool::new());
+                registry.try_register(aof_tools::KubectlLogsTool::new());
             }
             "kubectl_exec" => {
-                registry.register(aof_tools::KubectlExecTool::new());
+                registry.try_register(aof_tools::KubectlExecTool::new());
             }
             "kubectl_describe" => {
-                registry.register(aof_tools::KubectlDescribeTool::new());
+                registry.try_register(aof_tools::KubectlDescribeTool::new());
+            }
+            "pod_logs" => {
+                registry.try_register(aof_tools::PodLogsTool::new());
+            }
+            "k8s_config_diff" => {
+                registry.try_register(aof_tools::K8sConfigDiffTool::new());
+            }
+            "k8s_resource_quota" => {
+                registry.try_register(aof_tools::ResourceQuotaTool::new());
+            }
+            "helm_template" => {
+                registry.try_register(aof_tools::HelmTemplateTool::new());
+            }
+            "kustomize_build" => {
+                registry.try_register(aof_tools::KustomizeBuildTool::new());
             }
 
             // Docker tools
             "docker_ps" => {
-                registry.register(aof_tools::DockerPsTool::new());
+                registry.try_register(aof_tools::DockerPsTool::new());
             }
             "docker_stats" => {
-                registry.register(aof_tools::DockerStatsTool::new());
+                registry.try_register(aof_tools::DockerStatsTool::new());
             }
             "docker_build" => {
-                registry.register(aof_tools::DockerBuildTool::new());
+                registry.try_register(aof_tools::DockerBuildTool::new());
             }
             "docker_run" => {
-                registry.register(aof_tools::DockerRunTool::new());
+                registry.try_register(aof_tools::DockerRunTool::new());
             }
             "docker_logs" => {
-                registry.register(aof_tools::DockerLogsTool::new());
+                registry.try_register(aof_tools::DockerLogsTool::new());
             }
             "docker_exec" => {
-                registry.register(aof_tools::DockerExecTool::new());
+                registry.try_register(aof_tools::DockerExecTool::new());
             }
             "docker_images" => {
-                registry.register(aof_tools::DockerImagesTool::new());
+                registry.try_register(aof_tools::DockerImagesTool::new());
             }
 
             // Git tools
             "git_status" => {
-                registry.register(aof_tools::GitStatusTool::new());
+                registry.try_register(aof_tools::GitStatusTool::new());
             }
             "git_diff" => {
-                registry.register(aof_tools::GitDiffTool::new());
+                registry.try_register(aof_tools::GitDiffTool::new());
             }
             "git_log" => {
-                registry.register(aof_tools::GitLogTool::new());
+                registry.try_register(aof_tools::GitLogTool::new());
             }
             "git_commit" => {
-                registry.register(aof_tools::GitCommitTool::new());
+                registry.try_register(aof_tools::GitCommitTool::new());
             }
             "git_branch" => {
-                registry.register(aof_tools::GitBranchTool::new());
+                registry.try_register(aof_tools::GitBranchTool::new());
             }
             "git_checkout" => {
-                registry.register(aof_tools::GitCheckoutTool::new());
+                registry.try_register(aof_tools::GitCheckoutTool::new());
             }
             "git_pull" => {
-                registry.register(aof_tools::GitPullTool::new());
+                registry.try_register(aof_tools::GitPullTool::new());
             }
             "git_push" => {
-                registry.register(aof_tools::GitPushTool::new());
+                registry.try_register(aof_tools::GitPushTool::new());
+            }
+            "git_diff_summary" => {
+                registry.try_register(aof_tools::GitDiffSummaryTool::new());
+            }
+            "git_changelog" => {
+                registry.try_register(aof_tools::GitChangelogTool::new());
             }
 
             // Terraform tools
             "terraform_init" => {
-                registry.register(aof_tools::TerraformInitTool::new());
+                registry.try_register(aof_tools::TerraformInitTool::new());
             }
             "terraform_plan" => {
-                registry.register(aof_tools::TerraformPlanTool::new());
+                registry.try_register(aof_tools::TerraformPlanTool::new());
             }
             "terraform_apply" => {
-                registry.register(aof_tools::TerraformApplyTool::new());
+                registry.try_register(aof_tools::TerraformApplyTool::new());
             }
             "terraform_destroy" => {
-                registry.register(aof_tools::TerraformDestroyTool::new());
+                registry.try_register(aof_tools::TerraformDestroyTool::new());
             }
             "terraform_output" => {
-                registry.register(aof_tools::TerraformOutputTool::new());
+                registry.try_register(aof_tools::TerraformOutputTool::new());
+            }
+            "terraform_state" => {
+                registry.try_register(aof_tools::TerraformStateTool::new());
             }
 
             // HTTP tool
             "http_request" | "http" => {
-                registry.register(aof_tools::HttpTool::new());
+                registry.try_register(aof_tools::HttpTool::new());
+            }
+
+            // Network diagnostic tools
+            "net_diag" => {
+                registry.try_register(aof_tools::NetDiagTool::new());
+            }
+            "cert_check" => {
+                registry.try_register(aof_tools::CertCheckTool::new());
+            }
+
+            // Data transform tool
+            "data_transform" => {
+                registry.try_register(aof_tools::DataTransformTool::new());
+            }
+
+            // Kubernetes API tool (opt-in `k8s-api` feature)
+            #[cfg(feature = "k8s-api")]
+            "k8s_api" => {
+                registry.try_register(aof_tools::K8sApiTool::new());
+            }
+
+            // Feature flag tool
+            "feature_flag" => {
+                registry.try_register(aof_tools::FeatureFlagTool::new());
             }
 
             // Observability tools
             "prometheus_query" => {
-                registry.register(aof_tools::PrometheusQueryTool::new());
+                registry.try_register(aof_tools::PrometheusQueryTool::new());
             }
             "loki_query" => {
-                registry.register(aof_tools::LokiQueryTool::new());
+                registry.try_register(aof_tools::LokiQueryTool::new());
             }
             "elasticsearch_query" => {
-                registry.register(aof_tools::ElasticsearchQueryTool::new());
+                registry.try_register(aof_tools::ElasticsearchQueryTool::new());
             }
             "victoriametrics_query" => {
-                registry.register(aof_tools::VictoriaMetricsQueryTool::new());
+                registry.try_register(aof_tools::VictoriaMetricsQueryTool::new());
             }
 
             // ====================================================================
@@ -1103,22 +1327,22 @@ fn create_builtin_executor_for_tools(tool_names: &[String]) -> Arc<dyn ToolExecu
             // These take a 'command' argument and let the LLM construct the command
             // ====================================================================
             "kubectl" => {
-                registry.register(aof_tools::KubectlTool::new());
+                registry.try_register(aof_tools::KubectlTool::new());
             }
             "git" => {
-                registry.register(aof_tools::GitTool::new());
+                registry.try_register(aof_tools::GitTool::new());
             }
             "docker" => {
-                registry.register(aof_tools::DockerTool::new());
+                registry.try_register(aof_tools::DockerTool::new());
             }
             "terraform" => {
-                registry.register(aof_tools::TerraformTool::new());
+                registry.try_register(aof_tools::TerraformTool::new());
             }
             "aws" => {
-                registry.register(aof_tools::AwsTool::new());
+                registry.try_register(aof_tools::AwsTool::new());
             }
             "helm" => {
-                registry.register(aof_tools::HelmTool::new());
+                registry.try_register(aof_tools::HelmTool::new());
             }
 
             _ => {
@@ -1127,8 +1351,19 @@ fn create_builtin_executor_for_tools(tool_names: &[String]) -> Arc<dyn ToolExecu
         }
     }
 
-    info!("Registered {} built-in tools: {:?}", registry.len(), registry.list_names());
-    Arc::new(registry.into_executor())
+    if !commit_cached.is_empty() {
+        let cache = Arc::new(aof_tools::CommitCache::new());
+        for name in commit_cached {
+            match registry.get(name) {
+                Some(tool) => {
+                    registry.register_or_replace(aof_tools::CommitCachedTool::from_arc(tool, cache.clone()));
+                }
+                None => warn!("Tool '{}' is not registered, not caching its results", name),
+            }
+        }
+    }
+
+    registry
 }
 
 /// Legacy: System tool executor for shell, kubectl, and other local commands
@@ -1188,12 +1423,14 @@ impl ToolExecutor for SystemToolExecutor {
                 data,
                 error: None,
                 execution_time_ms,
+                metadata: Default::default(),
             }),
             Err(e) => Ok(aof_core::ToolResult {
                 success: false,
                 data: serde_json::json!({}),
                 error: Some(e.to_string()),
                 execution_time_ms,
+                metadata: Default::default(),
             }),
         }
     }
@@ -1320,6 +1557,38 @@ impl SystemToolExecutor {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_commit_cached_tools_stay_registered() {
+        let names = vec!["git_log".to_string(), "git_status".to_string()];
+        let cached = vec!["git_log".to_string(), "git_push".to_string()];
+        let registry = builtin_tool_registry_with_commit_cache(&names, &cached);
+
+        let mut registered = registry.list_names();
+        registered.sort();
+        assert_eq!(registered, vec!["git_log", "git_status"]);
+        assert_eq!(registry.get("git_log").unwrap().config().name, "git_log");
+    }
+
+    #[tokio::test]
+    async fn test_manifest_tool_only_agent_gets_executor() {
+        let runtime = Runtime::new();
+        let yaml = r#"
+            name: helm-agent
+            model: claude-3-5-sonnet
+            tools:
+              - helm_template
+        "#;
+        let config: AgentConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let executor = runtime
+            .create_executor_for_tool_specs(&config)
+            .await
+            .unwrap()
+            .expect("helm_template should get a system executor");
+        let names: Vec<String> = executor.list_tools().into_iter().map(|t| t.name).collect();
+        assert_eq!(names, vec!["helm_template"]);
+    }
+
     #[test]
     fn test_runtime_creation() {
         let runtime = Runtime::new();
@@ -1342,7 +1611,15 @@ mod tests {
             max_iterations: 10,
             temperature: 0.7,
             max_tokens: None,
+            stop: Vec::new(),
+            seed: None,
             output_schema: None,
+            guardrails: vec![],
+            max_tool_calls_per_turn: None,
+            max_tool_calls_per_run: None,
+            max_continuations: None,
+            tool_result_page_size: None,
+            tool_discovery: false,
             extra: HashMap::new(),
         };
 
@@ -1368,7 +1645,15 @@ mod tests {
             max_iterations: 10,
             temperature: 0.7,
             max_tokens: None,
+            stop: Vec::new(),
+            seed: None,
             output_schema: None,
+            guardrails: vec![],
+            max_tool_calls_per_turn: None,
+            max_tool_calls_per_run: None,
+            max_continuations: None,
+            tool_result_page_size: None,
+            tool_discovery: false,
             extra: HashMap::new(),
         };
 
@@ -0,0 +1,118 @@
+//! Tool discovery for the model
+//!
+//! The `list_tools` meta-tool lets the model look up the tools it can call
+//! mid-conversation (names, descriptions and, on request, parameter schemas)
+//! instead of relying only on what the system prompt said at the start. It is
+//! answered by the executor from its tool registry; no tool is run.
+//!
+//! Offered when `tool_discovery` is enabled on the agent (it is off by default)
+//! and the agent has tools.
+
+use aof_core::{ModelToolDefinition, ToolDefinition, ToolResult};
+
+/// Name of the meta-tool the model calls to list its tools
+pub const LIST_TOOLS_TOOL: &str = "list_tools";
+
+/// Definition of the `list_tools` meta-tool offered to the model
+pub fn tool_definition() -> ModelToolDefinition {
+    ModelToolDefinition {
+        name: LIST_TOOLS_TOOL.to_string(),
+        description: "List the tools available to you, with their descriptions. \
+            Use when unsure whether a tool exists for a task."
+            .to_string(),
+        parameters: serde_json::json!({
+            "type": "object",
+            "properties": {
+                "filter": {
+                    "type": "string",
+                    "description": "Only tools whose name or description contains this text"
+                },
+                "include_parameters": {
+                    "type": "boolean",
+                    "description": "Include each tool's parameter schema"
+                }
+            }
+        }),
+    }
+}
+
+/// Answer a `list_tools` call from the registered tool definitions
+pub fn list_tools(tools: &[ToolDefinition], arguments: &serde_json::Value) -> ToolResult {
+    let filter = arguments
+        .get("filter")
+        .and_then(|f| f.as_str())
+        .map(str::to_lowercase)
+        .filter(|f| !f.is_empty());
+    let include_parameters = arguments
+        .get("include_parameters")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let mut tools: Vec<&ToolDefinition> = tools
+        .iter()
+        .filter(|t| match &filter {
+            Some(f) => t.name.to_lowercase().contains(f) || t.description.to_lowercase().contains(f),
+            None => true,
+        })
+        .collect();
+    tools.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let listing: Vec<serde_json::Value> = tools
+        .iter()
+        .map(|t| {
+            let mut entry = serde_json::json!({
+                "name": t.name,
+                "description": t.description,
+            });
+            if include_parameters {
+                entry["parameters"] = t.parameters.clone();
+            }
+            entry
+        })
+        .collect();
+
+    ToolResult::success(serde_json::json!({
+        "count": listing.len(),
+        "tools": listing,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definitions() -> Vec<ToolDefinition> {
+        vec![
+            ToolDefinition {
+                name: "kubectl".to_string(),
+                description: "Execute kubectl commands".to_string(),
+                parameters: serde_json::json!({ "type": "object", "required": ["command"] }),
+            },
+            ToolDefinition {
+                name: "git_changelog".to_string(),
+                description: "Structured changelog for a commit range".to_string(),
+                parameters: serde_json::json!({ "type": "object" }),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_lists_registered_tools() {
+        let result = list_tools(&definitions(), &serde_json::json!({}));
+        assert!(result.success);
+        assert_eq!(result.data["count"], 2);
+        assert_eq!(result.data["tools"][0]["name"], "git_changelog");
+        assert_eq!(result.data["tools"][1]["description"], "Execute kubectl commands");
+        assert!(result.data["tools"][1].get("parameters").is_none());
+    }
+
+    #[test]
+    fn test_filter_and_parameters() {
+        let result = list_tools(
+            &definitions(),
+            &serde_json::json!({ "filter": "KUBE", "include_parameters": true }),
+        );
+        assert_eq!(result.data["count"], 1);
+        assert_eq!(result.data["tools"][0]["parameters"]["required"][0], "command");
+    }
+}
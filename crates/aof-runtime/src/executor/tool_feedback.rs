@@ -0,0 +1,263 @@
+//! Structured tool-error feedback
+//!
+//! When a tool call fails, the model gets a consistent error object instead of the
+//! raw error string: what kind of failure it was, the original message, and a
+//! suggested correction. Models recover from failures far more reliably when the
+//! next step is spelled out.
+
+use aof_core::{AofError, ToolResult};
+use serde::{Deserialize, Serialize};
+
+/// Result metadata key holding the `ToolErrorCategory` of a failure the
+/// runtime has already categorized (executor errors, budget rejections)
+pub const ERROR_CATEGORY_METADATA: &str = "error_category";
+
+/// Category of a tool failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolErrorCategory {
+    /// The model asked for a tool that isn't available
+    UnknownTool,
+    /// Arguments were missing, malformed, or failed validation
+    InvalidArguments,
+    /// The target resource, file, or path doesn't exist
+    NotFound,
+    /// The tool lacks permission for the operation
+    PermissionDenied,
+    /// The tool didn't finish in time
+    Timeout,
+    /// A dependency (API server, network, MCP server) was unreachable
+    Unavailable,
+    /// A call budget or rate limit was hit
+    LimitExceeded,
+    /// The tool ran but failed for another reason
+    ExecutionFailed,
+}
+
+impl ToolErrorCategory {
+    /// Classify a tool error message
+    pub fn classify(message: &str) -> Self {
+        let msg = message.to_lowercase();
+        let has = |needles: &[&str]| needles.iter().any(|n| msg.contains(n));
+
+        if has(&["tool not found", "unknown tool", "not found on any"]) {
+            Self::UnknownTool
+        } else if has(&["rate limit", "too many requests", "quota exceeded"]) {
+            Self::LimitExceeded
+        } else if has(&["timed out", "timeout", "deadline exceeded"]) {
+            Self::Timeout
+        } else if has(&["permission denied", "forbidden", "unauthorized", "access denied"]) {
+            Self::PermissionDenied
+        } else if has(&[
+            "validation",
+            "invalid",
+            "missing required",
+            "missing field",
+            "required parameter",
+            "failed to parse",
+            "expected",
+        ]) {
+            Self::InvalidArguments
+        } else if has(&["not found", "no such", "does not exist", "doesn't exist"]) {
+            Self::NotFound
+        } else if has(&["connection refused", "connection reset", "network", "unavailable", "unreachable"]) {
+            Self::Unavailable
+        } else {
+            Self::ExecutionFailed
+        }
+    }
+
+    /// Categorize an error returned by a tool executor
+    ///
+    /// Only timeouts and unreachable dependencies are retryable, so the
+    /// runtime's retry loop and the model's `retryable` hint agree.
+    pub fn from_error(error: &AofError) -> Self {
+        match error {
+            AofError::Timeout(_) => Self::Timeout,
+            AofError::Io(_) => Self::Unavailable,
+            AofError::Model(msg) | AofError::Mcp(msg) if msg.contains("timeout") => Self::Timeout,
+            AofError::Model(msg) if msg.contains("network") => Self::Unavailable,
+            AofError::Mcp(msg) if msg.contains("connection") => Self::Unavailable,
+            AofError::ResourceExhausted(_) => Self::LimitExceeded,
+            AofError::Serialization(_) => Self::InvalidArguments,
+            AofError::Config(_) | AofError::InvalidState(_) => Self::ExecutionFailed,
+            other => match Self::classify(&other.to_string()) {
+                category if category.is_retryable() => Self::ExecutionFailed,
+                category => category,
+            },
+        }
+    }
+
+    /// Category recorded on a result under `ERROR_CATEGORY_METADATA`
+    pub fn recorded(result: &ToolResult) -> Option<Self> {
+        result
+            .metadata
+            .get(ERROR_CATEGORY_METADATA)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Record this category on a failed result
+    pub fn mark(self, result: ToolResult) -> ToolResult {
+        result.with_metadata(ERROR_CATEGORY_METADATA, serde_json::json!(self))
+    }
+
+    /// Whether the same call may succeed if simply repeated
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Timeout | Self::Unavailable)
+    }
+
+    /// Suggested correction for the model
+    pub fn suggestion(&self) -> &'static str {
+        match self {
+            Self::UnknownTool => "Only call tools from the list of available tools.",
+            Self::InvalidArguments => {
+                "Check the arguments against the tool's input schema and call it again with corrected values."
+            }
+            Self::NotFound => {
+                "Verify the name, namespace, or path exists (list what is available first) before calling again."
+            }
+            Self::PermissionDenied => {
+                "Do not repeat the same call. Try a different approach or report the missing permission."
+            }
+            Self::Timeout => {
+                "Narrow the request (fewer resources, a smaller time range, a limit) or try again."
+            }
+            Self::Unavailable => "The backing service could not be reached. Try again, or continue without this tool.",
+            Self::LimitExceeded => "Answer with the information already gathered instead of calling more tools.",
+            Self::ExecutionFailed => {
+                "Read the error message, adjust the call and retry, or use a different tool."
+            }
+        }
+    }
+}
+
+/// Structured description of a failed tool call, sent back to the model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolErrorFeedback {
+    /// Tool that failed
+    pub tool: String,
+    /// Failure category
+    pub category: ToolErrorCategory,
+    /// Original error message
+    pub message: String,
+    /// Suggested correction
+    pub suggestion: String,
+    /// Whether repeating the same call may succeed
+    pub retryable: bool,
+    /// Partial output the tool produced before failing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<serde_json::Value>,
+}
+
+impl ToolErrorFeedback {
+    /// Build feedback for a failed tool result
+    pub fn from_result(tool: &str, result: &ToolResult) -> Self {
+        let message = result
+            .error
+            .clone()
+            .unwrap_or_else(|| "Tool failed without an error message".to_string());
+        let category =
+            ToolErrorCategory::recorded(result).unwrap_or_else(|| ToolErrorCategory::classify(&message));
+
+        // Budget rejections already carry the message as their data
+        let output = match &result.data {
+            serde_json::Value::Null => None,
+            serde_json::Value::Object(map)
+                if map.len() == 1 && map.get("error").and_then(|e| e.as_str()) == Some(message.as_str()) =>
+            {
+                None
+            }
+            data => Some(data.clone()),
+        };
+
+        Self {
+            tool: tool.to_string(),
+            category,
+            message,
+            suggestion: category.suggestion().to_string(),
+            retryable: category.is_retryable(),
+            output,
+        }
+    }
+}
+
+/// Content of the tool message added to the conversation for a tool result
+///
+/// Successful results pass their data through; failures become a
+/// `{"error": ToolErrorFeedback}` object.
+pub fn tool_result_content(tool: &str, result: &ToolResult) -> String {
+    if result.success {
+        return serde_json::to_string(&result.data).unwrap_or_else(|_| "{}".to_string());
+    }
+
+    let feedback = ToolErrorFeedback::from_result(tool, result);
+    serde_json::json!({ "error": feedback }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        let cases = [
+            ("Tool not found: kubectl_magic", ToolErrorCategory::UnknownTool),
+            ("Missing required parameter: namespace", ToolErrorCategory::InvalidArguments),
+            ("Error from server (NotFound): pods \"api-0\" not found", ToolErrorCategory::NotFound),
+            ("Error from server (Forbidden): secrets is forbidden", ToolErrorCategory::PermissionDenied),
+            ("Tool kubectl timed out after 30s (attempt 3/3)", ToolErrorCategory::Timeout),
+            ("dial tcp 10.0.0.1:6443: connection refused", ToolErrorCategory::Unavailable),
+            ("429 Too Many Requests", ToolErrorCategory::LimitExceeded),
+            ("exit status 2", ToolErrorCategory::ExecutionFailed),
+        ];
+        for (message, expected) in cases {
+            assert_eq!(ToolErrorCategory::classify(message), expected, "{}", message);
+        }
+    }
+
+    #[test]
+    fn test_from_error() {
+        let cases = [
+            (AofError::Timeout("30s".into()), ToolErrorCategory::Timeout),
+            (AofError::Mcp("connection closed".into()), ToolErrorCategory::Unavailable),
+            (AofError::Config("bad".into()), ToolErrorCategory::ExecutionFailed),
+            (AofError::Tool("validation failed: name".into()), ToolErrorCategory::InvalidArguments),
+            // Only the variants above are retried
+            (AofError::Tool("upstream timed out".into()), ToolErrorCategory::ExecutionFailed),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(ToolErrorCategory::from_error(&error), expected, "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_recorded_category_wins() {
+        // The message alone would classify as not_found
+        let result = ToolErrorCategory::LimitExceeded.mark(ToolResult::error("Tool call 'get' not found in budget"));
+        let feedback = ToolErrorFeedback::from_result("get", &result);
+
+        assert_eq!(feedback.category, ToolErrorCategory::LimitExceeded);
+        assert!(!feedback.retryable);
+    }
+
+    #[test]
+    fn test_failure_content_is_structured() {
+        let result = ToolResult::error("Missing required parameter: namespace");
+        let content: serde_json::Value =
+            serde_json::from_str(&tool_result_content("kubectl", &result)).unwrap();
+
+        let error = &content["error"];
+        assert_eq!(error["tool"], "kubectl");
+        assert_eq!(error["category"], "invalid_arguments");
+        assert_eq!(error["message"], "Missing required parameter: namespace");
+        assert!(error["suggestion"].as_str().unwrap().contains("input schema"));
+        assert_eq!(error["retryable"], false);
+        assert!(error.get("output").is_none());
+    }
+
+    #[test]
+    fn test_success_content_passes_through() {
+        let result = ToolResult::success(serde_json::json!({"pods": 3}));
+        assert_eq!(tool_result_content("kubectl", &result), r#"{"pods":3}"#);
+    }
+}
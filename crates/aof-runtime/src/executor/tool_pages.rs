@@ -0,0 +1,243 @@
+//! Cursor-based paging of large tool results
+//!
+//! Instead of truncating a tool result that is too large for the context, the
+//! executor stashes the full result and gives the model the first page plus a
+//! cursor. The model calls the `fetch_more` meta-tool with that cursor to read
+//! the next page, so context stays bounded while the whole result remains
+//! reachable.
+//!
+//! Page sizes and offsets count characters, not bytes, so a page never splits
+//! a multi-byte character.
+//!
+//! Cursors have the form `<tool_call_id>-<nonce>:<offset>`. The nonce is
+//! random per stashed result, so concurrent runs of the same agent never read
+//! each other's results even when providers reuse tool call IDs. The stash
+//! keeps at most `MAX_STASHED_RESULTS` results for `STASH_TTL`; older ones
+//! are evicted and their cursors stop working.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use aof_core::{ModelToolDefinition, ToolResult};
+
+/// Name of the meta-tool the model calls to read the next page
+pub const FETCH_MORE_TOOL: &str = "fetch_more";
+
+/// Max number of stashed results per executor
+pub const MAX_STASHED_RESULTS: usize = 64;
+
+/// How long a stashed result can be paged through
+pub const STASH_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// A stashed result and when it was stashed
+#[derive(Debug)]
+struct Stashed {
+    content: String,
+    stashed_at: Instant,
+}
+
+/// Stash of paged tool results
+#[derive(Debug, Default)]
+pub struct ToolResultPager {
+    /// Max characters per page (None: paging disabled)
+    page_size: Option<usize>,
+    /// Full results by cursor key (`<tool_call_id>-<nonce>`)
+    stash: Mutex<HashMap<String, Stashed>>,
+}
+
+impl ToolResultPager {
+    /// Pager with the given page size (None disables paging)
+    pub fn new(page_size: Option<usize>) -> Self {
+        Self {
+            page_size: page_size.filter(|size| *size > 0),
+            stash: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether results are paged
+    pub fn is_enabled(&self) -> bool {
+        self.page_size.is_some()
+    }
+
+    /// Definition of the `fetch_more` meta-tool offered to the model
+    pub fn tool_definition() -> ModelToolDefinition {
+        ModelToolDefinition {
+            name: FETCH_MORE_TOOL.to_string(),
+            description: "Fetch the next page of a tool result that was split into pages. \
+                Pass the `cursor` returned with the previous page."
+                .to_string(),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "cursor": {
+                        "type": "string",
+                        "description": "Cursor from the previous page"
+                    }
+                },
+                "required": ["cursor"]
+            }),
+        }
+    }
+
+    /// Content for the model: the full content if it fits, otherwise the first page
+    pub fn paginate(&self, tool_call_id: &str, content: String) -> String {
+        let Some(page_size) = self.page_size else {
+            return content;
+        };
+        if content.chars().count() <= page_size {
+            return content;
+        }
+
+        let key = format!("{}-{}", tool_call_id, &uuid::Uuid::new_v4().simple().to_string()[..12]);
+        let page = self.page(&key, &content, 0, page_size);
+
+        let mut stash = self.stash.lock().unwrap();
+        stash.retain(|_, stashed| stashed.stashed_at.elapsed() < STASH_TTL);
+        while stash.len() >= MAX_STASHED_RESULTS {
+            let Some(oldest) = stash
+                .iter()
+                .min_by_key(|(_, stashed)| stashed.stashed_at)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            stash.remove(&oldest);
+        }
+        stash.insert(
+            key,
+            Stashed {
+                content,
+                stashed_at: Instant::now(),
+            },
+        );
+        page.to_string()
+    }
+
+    /// Answer a `fetch_more` call
+    pub fn fetch(&self, arguments: &serde_json::Value) -> ToolResult {
+        let Some(page_size) = self.page_size else {
+            return ToolResult::error("Tool result paging is not enabled");
+        };
+        let Some(cursor) = arguments.get("cursor").and_then(|c| c.as_str()) else {
+            return ToolResult::error("Missing required parameter: cursor");
+        };
+        let Some((id, offset)) = cursor
+            .rsplit_once(':')
+            .and_then(|(id, offset)| Some((id, offset.parse::<usize>().ok()?)))
+        else {
+            return ToolResult::error(format!("Invalid cursor '{}'", cursor));
+        };
+
+        let stash = self.stash.lock().unwrap();
+        match stash.get(id).filter(|stashed| stashed.stashed_at.elapsed() < STASH_TTL) {
+            Some(Stashed { content, .. }) if offset < content.chars().count() => {
+                ToolResult::success(self.page(id, content, offset, page_size))
+            }
+            Some(_) => ToolResult::error(format!("Cursor '{}' is past the end of the result", cursor)),
+            None => ToolResult::error(format!(
+                "No paged result found for cursor '{}' (it may have expired; call the tool again)",
+                cursor
+            )),
+        }
+    }
+
+    // Helper: One page starting at character `offset`, with the cursor for the next page
+    fn page(&self, id: &str, content: &str, offset: usize, page_size: usize) -> serde_json::Value {
+        let total = content.chars().count();
+        let end = (offset + page_size).min(total);
+        let page: String = content.chars().skip(offset).take(end - offset).collect();
+
+        let remaining = total - end;
+        let next_cursor = (remaining > 0).then(|| format!("{}:{}", id, end));
+        serde_json::json!({
+            "page": page,
+            "offset": offset,
+            "total_chars": total,
+            "remaining_chars": remaining,
+            "next_cursor": next_cursor,
+            "note": if remaining > 0 {
+                format!("Result continues. Call {} with cursor \"{}:{}\" for the next page.", FETCH_MORE_TOOL, id, end)
+            } else {
+                "End of result.".to_string()
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_results_pass_through() {
+        let pager = ToolResultPager::new(Some(100));
+        assert_eq!(pager.paginate("call-1", "short".to_string()), "short");
+        assert_eq!(ToolResultPager::new(None).paginate("call-1", "x".repeat(500)).len(), 500);
+        // 12 characters fit a 12-character page even though they are 24 bytes
+        assert_eq!(ToolResultPager::new(Some(12)).paginate("call-1", "ü".repeat(12)), "ü".repeat(12));
+    }
+
+    #[test]
+    fn test_pages_cover_whole_result() {
+        let pager = ToolResultPager::new(Some(10));
+        let content = "ü".repeat(12); // 24 bytes, two per char
+
+        let first: serde_json::Value = serde_json::from_str(&pager.paginate("call-1", content.clone())).unwrap();
+        assert_eq!(first["page"].as_str().unwrap().chars().count(), 10);
+        assert_eq!(first["total_chars"], 12);
+        assert_eq!(first["remaining_chars"], 2);
+        let mut text = first["page"].as_str().unwrap().to_string();
+        let mut cursor = first["next_cursor"].clone();
+        while let Some(c) = cursor.as_str() {
+            let next = pager.fetch(&serde_json::json!({ "cursor": c }));
+            assert!(next.success);
+            text.push_str(next.data["page"].as_str().unwrap());
+            cursor = next.data["next_cursor"].clone();
+        }
+        assert_eq!(text, content);
+
+        let key = first["next_cursor"].as_str().unwrap().rsplit_once(':').unwrap().0.to_string();
+        assert!(!pager.fetch(&serde_json::json!({ "cursor": format!("{}:999", key) })).success);
+        assert!(!pager.fetch(&serde_json::json!({ "cursor": "call-1:10" })).success);
+        assert!(!pager.fetch(&serde_json::json!({ "cursor": "other:0" })).success);
+        assert!(!pager.fetch(&serde_json::json!({})).success);
+    }
+
+    #[test]
+    fn test_multibyte_chars_count_once() {
+        let pager = ToolResultPager::new(Some(1));
+        let content = "日本".to_string(); // three bytes per char
+
+        let first: serde_json::Value = serde_json::from_str(&pager.paginate("call-1", content)).unwrap();
+        assert_eq!(first["page"], "日");
+        let next = pager.fetch(&serde_json::json!({ "cursor": first["next_cursor"] }));
+        assert_eq!(next.data["page"], "本");
+        assert!(next.data["next_cursor"].is_null());
+    }
+
+    #[test]
+    fn test_reused_call_ids_do_not_collide() {
+        let pager = ToolResultPager::new(Some(4));
+        let a: serde_json::Value = serde_json::from_str(&pager.paginate("call_0", "aaaaaaaa".to_string())).unwrap();
+        let b: serde_json::Value = serde_json::from_str(&pager.paginate("call_0", "bbbbbbbb".to_string())).unwrap();
+
+        assert_eq!(pager.fetch(&serde_json::json!({ "cursor": a["next_cursor"] })).data["page"], "aaaa");
+        assert_eq!(pager.fetch(&serde_json::json!({ "cursor": b["next_cursor"] })).data["page"], "bbbb");
+    }
+
+    #[test]
+    fn test_stash_is_bounded() {
+        let pager = ToolResultPager::new(Some(1));
+        let cursors: Vec<String> = (0..MAX_STASHED_RESULTS + 1)
+            .map(|i| {
+                let first: serde_json::Value =
+                    serde_json::from_str(&pager.paginate(&format!("call-{}", i), "xy".to_string())).unwrap();
+                first["next_cursor"].as_str().unwrap().to_string()
+            })
+            .collect();
+
+        assert_eq!(pager.stash.lock().unwrap().len(), MAX_STASHED_RESULTS);
+        assert!(pager.fetch(&serde_json::json!({ "cursor": cursors.last().unwrap() })).success);
+    }
+}
@@ -0,0 +1,154 @@
+//! Structured execution traces for debugging agent runs
+//!
+//! A trace records every iteration of a run: the request sent to the model,
+//! the raw model response with its token usage, and each tool call with the
+//! result the model was given. Traces serialize to JSON so a misbehaving run
+//! can be inspected step by step after the fact.
+
+use std::path::Path;
+use std::time::Duration;
+
+use aof_core::{AofResult, ExecutionMetadata, ModelRequest, ModelResponse, RequestMessage, ToolCall, ToolResult, Usage};
+use serde::{Deserialize, Serialize};
+
+/// Complete trace of one agent run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExecutionTrace {
+    /// Agent name
+    pub agent: String,
+    /// Model used for the run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// User input that started the run
+    pub input: String,
+    /// One step per model call
+    pub steps: Vec<TraceStep>,
+    /// Final answer (None if the run failed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Error that ended the run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Total token usage
+    pub usage: Usage,
+    /// Wall-clock duration of the run
+    pub duration_ms: u64,
+}
+
+/// One iteration: a model call and the tool calls it requested
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceStep {
+    /// Iteration number (1-based)
+    pub iteration: usize,
+    /// System prompt sent with the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    /// Messages sent to the model
+    pub messages: Vec<RequestMessage>,
+    /// Names of the tools offered to the model
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+    /// Raw model response, including any reasoning
+    pub response: ModelResponse,
+    /// Tool calls executed (or rejected) for this response
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<TraceToolCall>,
+    /// Time spent in the model call
+    pub model_time_ms: u64,
+}
+
+/// A tool call and its outcome
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceToolCall {
+    /// Tool call ID assigned by the model
+    pub id: String,
+    /// Tool name
+    pub name: String,
+    /// Arguments passed by the model
+    pub arguments: serde_json::Value,
+    /// Whether the tool succeeded
+    pub success: bool,
+    /// Data returned by the tool
+    pub result: serde_json::Value,
+    /// Error message if the tool failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Content the model received for this call (may be a page of the result)
+    pub content: String,
+}
+
+impl ExecutionTrace {
+    /// Empty trace for a run of `agent` on `input`
+    pub fn new(agent: impl Into<String>, input: impl Into<String>) -> Self {
+        Self {
+            agent: agent.into(),
+            input: input.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Record a model call
+    pub(crate) fn record_model_call(
+        &mut self,
+        iteration: usize,
+        request: &ModelRequest,
+        response: &ModelResponse,
+        elapsed: Duration,
+    ) {
+        self.steps.push(TraceStep {
+            iteration,
+            system: request.system.clone(),
+            messages: request.messages.clone(),
+            tools: request.tools.iter().map(|t| t.name.clone()).collect(),
+            response: response.clone(),
+            tool_calls: Vec::new(),
+            model_time_ms: elapsed.as_millis() as u64,
+        });
+    }
+
+    /// Record a tool call against the latest step
+    pub(crate) fn record_tool_call(&mut self, tool_call: &ToolCall, result: &ToolResult, content: &str) {
+        if let Some(step) = self.steps.last_mut() {
+            step.tool_calls.push(TraceToolCall {
+                id: tool_call.id.clone(),
+                name: tool_call.name.clone(),
+                arguments: tool_call.arguments.clone(),
+                success: result.success,
+                result: result.data.clone(),
+                error: result.error.clone(),
+                content: content.to_string(),
+            });
+        }
+    }
+
+    /// Record how the run ended
+    pub(crate) fn finish(&mut self, result: &AofResult<String>, metadata: &ExecutionMetadata, elapsed: Duration) {
+        match result {
+            Ok(output) => self.output = Some(output.clone()),
+            Err(e) => self.error = Some(e.to_string()),
+        }
+        self.model = metadata.model.clone();
+        self.usage = Usage {
+            input_tokens: metadata.input_tokens,
+            output_tokens: metadata.output_tokens,
+        };
+        self.duration_ms = elapsed.as_millis() as u64;
+    }
+
+    /// Total number of tool calls across all steps
+    pub fn tool_call_count(&self) -> usize {
+        self.steps.iter().map(|s| s.tool_calls.len()).sum()
+    }
+
+    /// Write the trace as pretty-printed JSON, creating parent directories
+    pub fn write_to(&self, path: impl AsRef<Path>) -> AofResult<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
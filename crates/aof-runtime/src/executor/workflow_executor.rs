@@ -172,6 +172,16 @@ impl WorkflowExecutor {
         })
         .await;
 
+        // Top-level deadline for the whole run
+        let deadline = self
+            .workflow
+            .spec
+            .timeout
+            .as_deref()
+            .map(parse_duration)
+            .transpose()?
+            .map(|timeout| tokio::time::Instant::now() + timeout);
+
         // Execute workflow loop
         loop {
             let current_step = {
@@ -197,8 +207,21 @@ impl WorkflowExecutor {
                 }
             };
 
-            // Execute the step
-            match self.execute_step(&step).await {
+            // Execute the step, cancelling it if the workflow runs out of time
+            let result = match deadline {
+                Some(deadline) => {
+                    match tokio::time::timeout_at(deadline, self.execute_step(&step)).await {
+                        Ok(result) => result,
+                        Err(_) => {
+                            self.set_timed_out(&step.name).await;
+                            break;
+                        }
+                    }
+                }
+                None => self.execute_step(&step).await,
+            };
+
+            match result {
                 Ok(next_step) => {
                     match next_step {
                         Some(next) => {
@@ -540,18 +563,20 @@ impl WorkflowExecutor {
             strategy
         );
 
-        // Create tasks for each branch
-        let mut handles = Vec::new();
+        // Run each branch as a task in a JoinSet. Dropping the set aborts every
+        // branch still running, so branches stop when the join strategy is
+        // satisfied early or when the workflow timeout cancels this step.
+        let mut branch_tasks = tokio::task::JoinSet::new();
         let runtime = Arc::clone(&self.runtime);
         let state = Arc::clone(&self.state);
 
-        for branch in branches {
+        for (index, branch) in branches.iter().enumerate() {
             let branch_name = branch.name.clone();
             let branch_steps = branch.steps.clone();
             let runtime = Arc::clone(&runtime);
             let state = Arc::clone(&state);
 
-            let handle = tokio::spawn(async move {
+            branch_tasks.spawn(async move {
                 let mut results = Vec::new();
                 for branch_step in branch_steps {
                     if let Some(agent_name) = branch_step.agent {
@@ -564,20 +589,18 @@ impl WorkflowExecutor {
                         }
                     }
                 }
-                results
+                (index, results)
             });
-
-            handles.push(handle);
         }
 
         // Wait based on join strategy
         let results = match strategy {
             JoinStrategy::All => {
-                // Wait for all to complete
+                // Wait for all to complete, keeping branch order
                 let mut all_results = Vec::new();
-                for handle in handles {
-                    match handle.await {
-                        Ok(results) => all_results.extend(results),
+                while let Some(joined) = branch_tasks.join_next().await {
+                    match joined {
+                        Ok(branch_results) => all_results.push(branch_results),
                         Err(e) => {
                             return Err(AofError::workflow(format!(
                                 "Parallel branch panicked: {}",
@@ -586,35 +609,40 @@ impl WorkflowExecutor {
                         }
                     }
                 }
-                all_results
+                all_results.sort_by_key(|(index, _)| *index);
+                all_results.into_iter().flat_map(|(_, results)| results).collect()
             }
             JoinStrategy::Any => {
-                // Wait for first to complete
-                let (result, _idx, _remaining) = futures::future::select_all(handles).await;
-                match result {
-                    Ok(results) => results,
-                    Err(e) => {
+                // Wait for first to complete; the rest are aborted
+                match branch_tasks.join_next().await {
+                    Some(Ok((_, results))) => results,
+                    Some(Err(e)) => {
                         return Err(AofError::workflow(format!("Parallel branch panicked: {}", e)));
                     }
+                    None => Vec::new(),
                 }
             }
             JoinStrategy::Majority => {
-                // Wait for majority (50%+1)
+                // Wait for majority (50%+1); the rest are aborted
                 let threshold = (branches.len() / 2) + 1;
                 let mut completed = Vec::new();
-                let mut remaining = handles;
+                let mut finished = 0;
 
-                while completed.len() < threshold && !remaining.is_empty() {
-                    let (result, _, rest) = futures::future::select_all(remaining).await;
-                    remaining = rest;
-                    if let Ok(results) = result {
-                        completed.extend(results);
+                while finished < threshold {
+                    match branch_tasks.join_next().await {
+                        Some(Ok((_, results))) => {
+                            finished += 1;
+                            completed.extend(results);
+                        }
+                        Some(Err(_)) => {}
+                        None => break,
                     }
                 }
 
                 completed
             }
         };
+        branch_tasks.abort_all();
 
         // Merge results into state
         let mut merged_output = serde_json::json!({
@@ -906,6 +934,46 @@ impl WorkflowExecutor {
         error!("Workflow error: {}", message);
     }
 
+    /// Mark the workflow as timed out, keeping completed step results
+    async fn set_timed_out(&self, step_name: &str) {
+        let message = format!(
+            "Workflow exceeded its timeout of {}; step '{}' was cancelled",
+            self.workflow.spec.timeout.as_deref().unwrap_or_default(),
+            step_name
+        );
+
+        {
+            let mut state = self.state.write().await;
+            state.step_results.insert(
+                step_name.to_string(),
+                StepResult {
+                    step_name: step_name.to_string(),
+                    status: StepStatus::Failed,
+                    output: None,
+                    started_at: Utc::now(),
+                    ended_at: Some(Utc::now()),
+                    duration_ms: None,
+                    error: Some("Cancelled: workflow timed out".to_string()),
+                },
+            );
+            state.status = WorkflowStatus::TimedOut;
+            state.error = Some(WorkflowError {
+                error_type: "timeout".to_string(),
+                message: message.clone(),
+                step: Some(step_name.to_string()),
+                details: None,
+            });
+            state.updated_at = Utc::now();
+        }
+
+        warn!("{}", message);
+        self.emit_event(WorkflowEvent::StepFailed {
+            step_name: step_name.to_string(),
+            error: message,
+        })
+        .await;
+    }
+
     /// Emit a workflow event
     async fn emit_event(&self, event: WorkflowEvent) {
         if let Some(ref tx) = self.event_tx {
@@ -986,6 +1054,7 @@ mod tests {
                 checkpointing: None,
                 recovery: None,
                 fleet: None,
+                timeout: None,
             },
         };
 
@@ -1033,6 +1102,7 @@ mod tests {
                 checkpointing: None,
                 recovery: None,
                 fleet: None,
+                timeout: None,
             },
         };
 
@@ -1051,4 +1121,150 @@ mod tests {
             Some(&serde_json::json!(42))
         );
     }
+
+    #[tokio::test]
+    async fn test_workflow_timeout_cancels_in_flight_step() {
+        let workflow: Workflow = serde_yaml::from_str(
+            r#"
+apiVersion: aof.dev/v1
+kind: Workflow
+metadata:
+  name: slow-approval
+spec:
+  entrypoint: validate
+  timeout: 200ms
+  steps:
+    - name: validate
+      type: validation
+      next: review
+    - name: review
+      type: approval
+      config:
+        approvers:
+          - role: sre-team
+      next: done
+    - name: done
+      type: terminal
+      status: completed
+"#,
+        )
+        .unwrap();
+
+        // Keep the sender alive so the approval step blocks until cancelled
+        let (_approval_tx, approval_rx) = mpsc::channel(1);
+        let mut executor = WorkflowExecutor::new(workflow, Arc::new(Runtime::new()))
+            .with_approval_channel(approval_rx);
+
+        let state = executor.execute(serde_json::json!({})).await.unwrap();
+
+        assert_eq!(state.status, WorkflowStatus::TimedOut);
+        assert_eq!(state.completed_steps, vec!["validate".to_string()]);
+        assert_eq!(state.step_results["validate"].status, StepStatus::Completed);
+        assert_eq!(state.step_results["review"].status, StepStatus::Failed);
+
+        let error = state.error.unwrap();
+        assert_eq!(error.error_type, "timeout");
+        assert_eq!(error.step.as_deref(), Some("review"));
+    }
+
+    /// Model that never answers and records when its call is dropped
+    struct HangingModel {
+        config: aof_core::ModelConfig,
+        dropped: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    /// Sets the flag when the in-flight call is dropped
+    struct DropFlag(Arc<std::sync::atomic::AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl aof_core::Model for HangingModel {
+        async fn generate(&self, _request: &aof_core::ModelRequest) -> AofResult<aof_core::ModelResponse> {
+            let _flag = DropFlag(self.dropped.clone());
+            tokio::time::sleep(std::time::Duration::from_secs(3600)).await;
+            Err(AofError::agent("unreachable"))
+        }
+
+        async fn generate_stream(
+            &self,
+            _request: &aof_core::ModelRequest,
+        ) -> AofResult<std::pin::Pin<Box<dyn futures::Stream<Item = AofResult<aof_core::StreamChunk>> + Send>>>
+        {
+            Err(AofError::agent("streaming not supported"))
+        }
+
+        fn config(&self) -> &aof_core::ModelConfig {
+            &self.config
+        }
+
+        fn provider(&self) -> aof_core::ModelProvider {
+            aof_core::ModelProvider::Custom
+        }
+    }
+
+    #[tokio::test]
+    async fn test_workflow_timeout_aborts_parallel_branches() {
+        let dropped = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let model = HangingModel {
+            config: aof_core::ModelConfig {
+                model: "hanging".to_string(),
+                provider: aof_core::ModelProvider::Custom,
+                api_key: None,
+                endpoint: None,
+                temperature: 0.0,
+                max_tokens: None,
+                timeout_secs: 3600,
+                stop: vec![],
+                seed: None,
+                headers: HashMap::new(),
+                extra: HashMap::new(),
+            },
+            dropped: dropped.clone(),
+        };
+        let agent: aof_core::AgentConfig = serde_yaml::from_str("name: slow\nmodel: hanging\n").unwrap();
+        let mut runtime = Runtime::new();
+        runtime.register_agent(crate::AgentExecutor::new(agent, Box::new(model), None, None).unwrap());
+
+        let workflow: Workflow = serde_yaml::from_str(
+            r#"
+apiVersion: aof.dev/v1
+kind: Workflow
+metadata:
+  name: slow-fanout
+spec:
+  entrypoint: analyze
+  timeout: 200ms
+  steps:
+    - name: analyze
+      type: parallel
+      branches:
+        - name: logs
+          steps:
+            - agent: slow
+      next: done
+    - name: done
+      type: terminal
+      status: completed
+"#,
+        )
+        .unwrap();
+
+        let mut executor = WorkflowExecutor::new(workflow, Arc::new(runtime));
+        let state = executor.execute(serde_json::json!({})).await.unwrap();
+        assert_eq!(state.status, WorkflowStatus::TimedOut);
+
+        // The branch task was aborted rather than left running in the background
+        for _ in 0..50 {
+            if dropped.load(std::sync::atomic::Ordering::SeqCst) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+    }
 }
@@ -1,15 +1,23 @@
 //! Consensus algorithms for multi-agent coordination
 //!
-//! This module implements 5 consensus algorithms for fleet coordination:
+//! This module implements 6 consensus algorithms for fleet coordination:
 //! - **Majority**: >50% agreement wins (fast, tolerates outliers)
 //! - **Unanimous**: 100% agreement required (high confidence)
 //! - **Weighted**: Per-agent weights (senior reviewers count more)
 //! - **FirstWins**: First response wins (fastest, no consensus overhead)
 //! - **HumanReview**: Flags for human operator decision (high-stakes scenarios)
+//! - **MajorityTieBreak**: Majority voting; ties resolved by a configured rule
+//!
+//! Answers are grouped by exact (normalized) text unless
+//! `similarity_threshold` is set, in which case paraphrases whose similarity
+//! reaches the threshold count as the same answer. Similarity defaults to
+//! token overlap; plug in an embedding-based `ResponseSimilarity` with
+//! `ConsensusEngine::with_similarity`.
 
-use aof_core::{AofError, AofResult, ConsensusAlgorithm, ConsensusConfig};
+use aof_core::{AofResult, ConsensusAlgorithm, ConsensusConfig, TieBreaker};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 /// Result of a consensus operation
@@ -41,6 +49,60 @@ pub struct ConsensusResult {
 
     /// Algorithm used
     pub algorithm: ConsensusAlgorithm,
+
+    /// Why the agents disagreed (set when consensus was not reached)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disagreement: Option<DisagreementReport>,
+
+    /// Rule that broke a tie between the top answers (if one was needed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tie_breaker: Option<TieBreaker>,
+}
+
+/// Agents that gave the same answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnswerCluster {
+    /// Representative answer (from the first agent in the cluster)
+    pub answer: String,
+
+    /// Agents that gave this answer
+    pub agents: Vec<String>,
+
+    /// Combined weight of those agents
+    pub weight: f32,
+
+    /// Terms only this cluster's answers use
+    pub distinctive_terms: Vec<String>,
+}
+
+/// Structured explanation of a failed consensus for human adjudication
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisagreementReport {
+    /// Distinct answers, largest cluster first
+    pub clusters: Vec<AnswerCluster>,
+
+    /// Key differences between the clusters, one line each
+    pub differences: Vec<String>,
+}
+
+impl DisagreementReport {
+    /// Human-readable summary for task errors and review queues
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!("{} distinct answers:", self.clusters.len())];
+        for (i, cluster) in self.clusters.iter().enumerate() {
+            lines.push(format!(
+                "  {}. [{}] {}",
+                i + 1,
+                cluster.agents.join(", "),
+                preview(&cluster.answer, 120)
+            ));
+        }
+        if !self.differences.is_empty() {
+            lines.push("Key differences:".to_string());
+            lines.extend(self.differences.iter().map(|d| format!("  - {}", d)));
+        }
+        lines.join("\n")
+    }
 }
 
 /// Result from a single agent
@@ -129,6 +191,27 @@ impl AgentResult {
     }
 }
 
+/// Scores how similar two answers are, from 0.0 (unrelated) to 1.0 (same)
+pub trait ResponseSimilarity: Send + Sync {
+    /// Similarity of two responses
+    fn similarity(&self, a: &str, b: &str) -> f32;
+}
+
+/// Jaccard overlap of the answers' content words
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenOverlap;
+
+impl ResponseSimilarity for TokenOverlap {
+    fn similarity(&self, a: &str, b: &str) -> f32 {
+        let a: HashSet<String> = answer_terms(a).into_iter().collect();
+        let b: HashSet<String> = answer_terms(b).into_iter().collect();
+        if a.is_empty() && b.is_empty() {
+            return 1.0;
+        }
+        a.intersection(&b).count() as f32 / a.union(&b).count() as f32
+    }
+}
+
 /// Consensus engine for evaluating agent results
 pub struct ConsensusEngine {
     /// Configuration for consensus
@@ -136,6 +219,9 @@ pub struct ConsensusEngine {
 
     /// Agent weights (can be overridden per-agent)
     agent_weights: HashMap<String, f32>,
+
+    /// Similarity used when `similarity_threshold` is set
+    similarity: Arc<dyn ResponseSimilarity>,
 }
 
 impl ConsensusEngine {
@@ -149,8 +235,12 @@ impl ConsensusEngine {
                 allow_partial: false,
                 weights: HashMap::new(),
                 min_confidence: None,
+                tie_breaker: TieBreaker::default(),
+                tiebreaker_agent: None,
+                similarity_threshold: None,
             },
             agent_weights: HashMap::new(),
+            similarity: Arc::new(TokenOverlap),
         }
     }
 
@@ -160,9 +250,16 @@ impl ConsensusEngine {
         Self {
             config,
             agent_weights,
+            similarity: Arc::new(TokenOverlap),
         }
     }
 
+    /// Replace the similarity used to cluster paraphrased answers
+    pub fn with_similarity(mut self, similarity: impl ResponseSimilarity + 'static) -> Self {
+        self.similarity = Arc::new(similarity);
+        self
+    }
+
     /// Set agent weight
     pub fn set_weight(&mut self, agent_name: &str, weight: f32) {
         self.agent_weights.insert(agent_name.to_string(), weight);
@@ -190,15 +287,90 @@ impl ConsensusEngine {
                 requires_human_review: true,
                 review_reason: Some("No agent results to evaluate".to_string()),
                 algorithm: self.config.algorithm,
+                disagreement: None,
+                tie_breaker: None,
             });
         }
 
-        match self.config.algorithm {
+        let mut consensus = match self.config.algorithm {
             ConsensusAlgorithm::Majority => self.evaluate_majority(results),
             ConsensusAlgorithm::Unanimous => self.evaluate_unanimous(results),
             ConsensusAlgorithm::Weighted => self.evaluate_weighted(results),
             ConsensusAlgorithm::FirstWins => self.evaluate_first_wins(results),
             ConsensusAlgorithm::HumanReview => self.evaluate_human_review(results),
+            ConsensusAlgorithm::MajorityTieBreak => self.evaluate_majority_tie_break(results),
+        }?;
+
+        if !consensus.reached {
+            consensus.disagreement = Some(self.explain_disagreement(&consensus.all_results));
+        }
+        Ok(consensus)
+    }
+
+    /// Group results into answer clusters and describe how they differ
+    pub fn explain_disagreement(&self, results: &[AgentResult]) -> DisagreementReport {
+        // Cluster with the same key used for voting, keeping first-seen order
+        let mut keys: Vec<String> = Vec::new();
+        let mut clusters: Vec<AnswerCluster> = Vec::new();
+        for (result, key) in results.iter().zip(self.response_keys(results)) {
+            let weight = self.get_weight(&result.agent_name);
+            match keys.iter().position(|k| *k == key) {
+                Some(i) => {
+                    clusters[i].agents.push(result.agent_name.clone());
+                    clusters[i].weight += weight;
+                }
+                None => {
+                    keys.push(key);
+                    clusters.push(AnswerCluster {
+                        answer: result.response.clone(),
+                        agents: vec![result.agent_name.clone()],
+                        weight,
+                        distinctive_terms: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        // Terms each cluster uses that no other cluster does
+        let terms: Vec<Vec<String>> = clusters.iter().map(|c| answer_terms(&c.answer)).collect();
+        for (i, cluster) in clusters.iter_mut().enumerate() {
+            cluster.distinctive_terms = terms[i]
+                .iter()
+                .filter(|term| {
+                    terms
+                        .iter()
+                        .enumerate()
+                        .all(|(j, other)| j == i || !other.contains(term))
+                })
+                .take(MAX_DISTINCTIVE_TERMS)
+                .cloned()
+                .collect();
+        }
+
+        clusters.sort_by(|a, b| {
+            b.agents
+                .len()
+                .cmp(&a.agents.len())
+                .then(b.weight.partial_cmp(&a.weight).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        let differences = clusters
+            .iter()
+            .filter(|c| !c.distinctive_terms.is_empty())
+            .map(|c| {
+                format!(
+                    "{} ({} agent{}): {}",
+                    c.agents.join(", "),
+                    c.agents.len(),
+                    if c.agents.len() == 1 { "" } else { "s" },
+                    c.distinctive_terms.join(", ")
+                )
+            })
+            .collect();
+
+        DisagreementReport {
+            clusters,
+            differences,
         }
     }
 
@@ -210,10 +382,9 @@ impl ConsensusEngine {
             .min_votes
             .unwrap_or((total / 2 + 1) as u32);
 
-        // Group results by similarity (using response hash for now)
+        // Group results by answer
         let mut groups: HashMap<String, Vec<&AgentResult>> = HashMap::new();
-        for result in &results {
-            let key = self.response_key(&result.response);
+        for (result, key) in results.iter().zip(self.response_keys(&results)) {
             groups.entry(key).or_default().push(result);
         }
 
@@ -262,6 +433,8 @@ impl ConsensusEngine {
                 None
             },
             algorithm: ConsensusAlgorithm::Majority,
+            disagreement: None,
+            tie_breaker: None,
         })
     }
 
@@ -270,10 +443,8 @@ impl ConsensusEngine {
         let total = results.len();
 
         // Check if all responses are similar
-        let first_key = results.first().map(|r| self.response_key(&r.response));
-        let all_agree = first_key.as_ref().map_or(false, |key| {
-            results.iter().all(|r| self.response_key(&r.response) == *key)
-        });
+        let keys = self.response_keys(&results);
+        let all_agree = keys.first().is_some_and(|first| keys.iter().all(|key| key == first));
 
         let confidence = if all_agree { 1.0 } else { 0.0 };
         let winner = if all_agree {
@@ -301,6 +472,8 @@ impl ConsensusEngine {
                 None
             },
             algorithm: ConsensusAlgorithm::Unanimous,
+            disagreement: None,
+            tie_breaker: None,
         })
     }
 
@@ -309,8 +482,7 @@ impl ConsensusEngine {
         // Group by response similarity and sum weights
         let mut groups: HashMap<String, (f32, Vec<&AgentResult>)> = HashMap::new();
 
-        for result in &results {
-            let key = self.response_key(&result.response);
+        for (result, key) in results.iter().zip(self.response_keys(&results)) {
             let weight = self.get_weight(&result.agent_name);
             let entry = groups.entry(key).or_insert((0.0, Vec::new()));
             entry.0 += weight;
@@ -360,6 +532,8 @@ impl ConsensusEngine {
                 None
             },
             algorithm: ConsensusAlgorithm::Weighted,
+            disagreement: None,
+            tie_breaker: None,
         })
     }
 
@@ -380,6 +554,8 @@ impl ConsensusEngine {
             requires_human_review: false,
             review_reason: None,
             algorithm: ConsensusAlgorithm::FirstWins,
+            disagreement: None,
+            tie_breaker: None,
         })
     }
 
@@ -424,14 +600,167 @@ impl ConsensusEngine {
             requires_human_review: true,
             review_reason: Some("Human review required for final decision".to_string()),
             algorithm: ConsensusAlgorithm::HumanReview,
+            disagreement: None,
+            tie_breaker: None,
+        })
+    }
+
+    /// Majority voting with a tie-breaker
+    ///
+    /// The most voted answer wins if it has a majority (or `min_votes`). When
+    /// several answers share the top vote count, the configured `TieBreaker`
+    /// picks one; a tie the rule can't break is left for human review. A tie
+    /// never has a majority, so a tie winner only has to meet an explicit
+    /// `min_votes`.
+    fn evaluate_majority_tie_break(&self, results: Vec<AgentResult>) -> AofResult<ConsensusResult> {
+        let total = results.len();
+        let min_votes = self.config.min_votes.unwrap_or((total / 2 + 1) as u32);
+
+        // Group results by answer, keeping first-seen order
+        let mut groups: Vec<(String, Vec<&AgentResult>)> = Vec::new();
+        for (result, key) in results.iter().zip(self.response_keys(&results)) {
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, group)) => group.push(result),
+                None => groups.push((key, vec![result])),
+            }
+        }
+
+        let top_votes = groups.iter().map(|(_, g)| g.len()).max().unwrap_or(0);
+        let tied: Vec<&Vec<&AgentResult>> = groups
+            .iter()
+            .filter(|(_, g)| g.len() == top_votes)
+            .map(|(_, g)| g)
+            .collect();
+
+        let rule = self.config.tie_breaker;
+        let (winner_group, tie_breaker, reached) = if tied.len() == 1 {
+            (Some(tied[0]), None, top_votes as u32 >= min_votes)
+        } else {
+            let winner = self.break_tie(&tied);
+            info!(
+                "Tie between {} answers with {} votes each; {:?} {}",
+                tied.len(),
+                top_votes,
+                rule,
+                if winner.is_some() { "broke the tie" } else { "could not break the tie" }
+            );
+            let enough_votes = top_votes as u32 >= self.config.min_votes.unwrap_or(0);
+            (winner, Some(rule), winner.is_some() && enough_votes)
+        };
+
+        let votes = winner_group.map(|g| g.len() as u32).unwrap_or(0);
+        let winner = winner_group.and_then(|g| g.first()).map(|r| (*r).clone());
+        let confidence = votes as f32 / total as f32;
+        let below_confidence = self
+            .config
+            .min_confidence
+            .map(|min| confidence < min)
+            .unwrap_or(false);
+        let requires_review = !reached || below_confidence;
+
+        let total_weight = winner_group
+            .map(|g| g.iter().map(|r| self.get_weight(&r.agent_name)).sum())
+            .unwrap_or(0.0);
+        let review_reason = if !reached && tied.len() > 1 && winner_group.is_none() {
+            Some(format!(
+                "Tie between {} answers could not be broken by {:?}",
+                tied.len(),
+                rule
+            ))
+        } else if !reached {
+            Some(format!("No majority: {} of {} votes (need {})", votes, total, min_votes))
+        } else if below_confidence {
+            Some(format!("Consensus confidence {:.2} below threshold", confidence))
+        } else {
+            None
+        };
+
+        Ok(ConsensusResult {
+            reached,
+            result: winner,
+            all_results: results,
+            votes,
+            total_weight,
+            confidence,
+            requires_human_review: requires_review,
+            review_reason,
+            algorithm: ConsensusAlgorithm::MajorityTieBreak,
+            disagreement: None,
+            tie_breaker,
         })
     }
 
-    /// Generate a key for grouping similar responses
-    /// This is a simple implementation - could be enhanced with semantic similarity
+    // Helper: Pick one of the tied groups with the configured rule (None if still tied)
+    fn break_tie<'a>(&self, tied: &[&'a Vec<&'a AgentResult>]) -> Option<&'a Vec<&'a AgentResult>> {
+        let score = |group: &Vec<&AgentResult>| -> f32 {
+            match self.config.tie_breaker {
+                TieBreaker::HighestWeight => group.iter().map(|r| self.get_weight(&r.agent_name)).sum(),
+                TieBreaker::HighestConfidence => {
+                    let scores: Vec<f32> = group.iter().filter_map(|r| r.confidence).collect();
+                    if scores.is_empty() {
+                        0.0
+                    } else {
+                        scores.iter().sum::<f32>() / scores.len() as f32
+                    }
+                }
+                TieBreaker::Agent => 0.0,
+            }
+        };
+
+        if self.config.tie_breaker == TieBreaker::Agent {
+            let agent = self.config.tiebreaker_agent.as_deref()?;
+            return tied
+                .iter()
+                .find(|g| g.iter().any(|r| r.agent_name == agent))
+                .copied();
+        }
+
+        let scores: Vec<f32> = tied.iter().map(|g| score(*g)).collect();
+        let best = scores.iter().cloned().fold(f32::MIN, f32::max);
+        let mut leaders = tied.iter().zip(&scores).filter(|(_, s)| **s == best);
+        match (leaders.next(), leaders.next()) {
+            (Some((group, _)), None) => Some(*group),
+            _ => None,
+        }
+    }
+
+    /// Grouping key for each result; results with the same key agree
+    ///
+    /// With a `similarity_threshold`, each result joins the most similar
+    /// earlier cluster that reaches the threshold and takes its key.
+    fn response_keys(&self, results: &[AgentResult]) -> Vec<String> {
+        let Some(threshold) = self.config.similarity_threshold else {
+            return results.iter().map(|r| self.response_key(&r.response)).collect();
+        };
+
+        // (key, representative response) per cluster
+        let mut clusters: Vec<(String, &str)> = Vec::new();
+        let mut keys = Vec::with_capacity(results.len());
+        for result in results {
+            let best = clusters
+                .iter()
+                .map(|(key, representative)| {
+                    (key, self.similarity.similarity(representative, &result.response))
+                })
+                .filter(|(_, score)| *score >= threshold)
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            match best {
+                Some((key, score)) => {
+                    debug!("{} joins answer cluster (similarity {:.2})", result.agent_name, score);
+                    keys.push(key.clone());
+                }
+                None => {
+                    let key = self.response_key(&result.response);
+                    clusters.push((key.clone(), &result.response));
+                    keys.push(key);
+                }
+            }
+        }
+        keys
+    }
+
+    /// Generate a key for grouping identical responses
     fn response_key(&self, response: &str) -> String {
-        // For now, use a normalized hash of the response
-        // In production, this could use embeddings for semantic similarity
         let normalized = response.trim().to_lowercase();
 
         // Use first 100 chars + length as a simple fingerprint
@@ -443,6 +772,40 @@ impl ConsensusEngine {
     }
 }
 
+/// Distinctive terms reported per cluster
+const MAX_DISTINCTIVE_TERMS: usize = 8;
+
+/// Words too common to tell answers apart
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "are", "but", "not", "you", "with", "this", "that", "was", "has",
+    "have", "from", "its", "into", "than", "then", "there", "which", "will", "would", "could",
+    "should", "can", "been", "also", "our", "they", "their", "due",
+];
+
+/// Lowercased content words of an answer, in order of first use
+fn answer_terms(answer: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in answer
+        .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+        .map(|w| w.trim_matches('-').to_lowercase())
+    {
+        if word.chars().count() >= 3 && !STOP_WORDS.contains(&word.as_str()) && !terms.contains(&word) {
+            terms.push(word);
+        }
+    }
+    terms
+}
+
+/// First `max` characters of `text` on one line
+fn preview(text: &str, max: usize) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= max {
+        line
+    } else {
+        format!("{}...", line.chars().take(max).collect::<String>())
+    }
+}
+
 impl Default for ConsensusEngine {
     fn default() -> Self {
         Self::new()
@@ -496,6 +859,9 @@ mod tests {
             allow_partial: false,
             weights: HashMap::new(),
             min_confidence: None,
+            tie_breaker: TieBreaker::default(),
+            tiebreaker_agent: None,
+            similarity_threshold: None,
         };
         let engine = ConsensusEngine::from_config(config);
 
@@ -519,6 +885,9 @@ mod tests {
             allow_partial: false,
             weights: HashMap::new(),
             min_confidence: None,
+            tie_breaker: TieBreaker::default(),
+            tiebreaker_agent: None,
+            similarity_threshold: None,
         };
         let engine = ConsensusEngine::from_config(config);
 
@@ -547,6 +916,9 @@ mod tests {
             allow_partial: false,
             weights,
             min_confidence: Some(0.5),
+            tie_breaker: TieBreaker::default(),
+            tiebreaker_agent: None,
+            similarity_threshold: None,
         };
         let engine = ConsensusEngine::from_config(config);
 
@@ -576,6 +948,9 @@ mod tests {
             allow_partial: false,
             weights: HashMap::new(),
             min_confidence: None,
+            tie_breaker: TieBreaker::default(),
+            tiebreaker_agent: None,
+            similarity_threshold: None,
         };
         let engine = ConsensusEngine::from_config(config);
 
@@ -599,6 +974,9 @@ mod tests {
             allow_partial: false,
             weights: HashMap::new(),
             min_confidence: None,
+            tie_breaker: TieBreaker::default(),
+            tiebreaker_agent: None,
+            similarity_threshold: None,
         };
         let engine = ConsensusEngine::from_config(config);
 
@@ -633,6 +1011,9 @@ mod tests {
             allow_partial: false,
             weights: HashMap::new(),
             min_confidence: Some(0.8), // High threshold
+            tie_breaker: TieBreaker::default(),
+            tiebreaker_agent: None,
+            similarity_threshold: None,
         };
         let engine = ConsensusEngine::from_config(config);
 
@@ -647,4 +1028,217 @@ mod tests {
         assert!(consensus.reached); // Vote threshold met
         assert!(consensus.requires_human_review); // But confidence below threshold
     }
+
+    #[test]
+    fn test_two_cluster_disagreement_report() {
+        let engine = ConsensusEngine::new();
+        let results = vec![
+            create_result("agent-1", "Root cause: OOMKilled pods after the memory limit change", 1.0),
+            create_result("agent-2", "Root cause: DNS timeouts from the coredns rollout", 1.0),
+            create_result("agent-3", "Root cause: OOMKilled pods after the memory limit change", 1.0),
+            create_result("agent-4", "Root cause: DNS timeouts from the coredns rollout", 1.0),
+        ];
+
+        let consensus = engine.evaluate(results).unwrap();
+        assert!(!consensus.reached);
+
+        let report = consensus.disagreement.expect("failed consensus has a report");
+        assert_eq!(report.clusters.len(), 2);
+        assert_eq!(report.clusters[0].agents, vec!["agent-1", "agent-3"]);
+        assert_eq!(report.clusters[1].agents, vec!["agent-2", "agent-4"]);
+        assert!(report.clusters[0].distinctive_terms.contains(&"oomkilled".to_string()));
+        assert!(report.clusters[1].distinctive_terms.contains(&"coredns".to_string()));
+        // Shared words don't count as differences
+        assert!(!report.clusters[0].distinctive_terms.contains(&"root".to_string()));
+
+        let summary = report.summary();
+        assert!(summary.contains("2 distinct answers"));
+        assert!(summary.contains("[agent-1, agent-3]"));
+        assert!(summary.contains("[agent-2, agent-4]"));
+    }
+
+    #[test]
+    fn test_no_report_when_consensus_reached() {
+        let engine = ConsensusEngine::new();
+        let results = vec![
+            create_result("agent-1", "Answer X", 1.0),
+            create_result("agent-2", "Answer X", 1.0),
+        ];
+
+        let consensus = engine.evaluate(results).unwrap();
+        assert!(consensus.reached);
+        assert!(consensus.disagreement.is_none());
+    }
+
+    fn tie_break_engine(tie_breaker: TieBreaker, tiebreaker_agent: Option<&str>) -> ConsensusEngine {
+        let mut weights = HashMap::new();
+        weights.insert("senior".to_string(), 3.0);
+        ConsensusEngine::from_config(ConsensusConfig {
+            algorithm: ConsensusAlgorithm::MajorityTieBreak,
+            min_votes: None,
+            timeout_ms: None,
+            allow_partial: false,
+            weights,
+            min_confidence: None,
+            tie_breaker,
+            tiebreaker_agent: tiebreaker_agent.map(str::to_string),
+            similarity_threshold: None,
+        })
+    }
+
+    fn two_two_tie() -> Vec<AgentResult> {
+        vec![
+            create_result("junior-1", "Roll back the deploy", 1.0).with_confidence(0.9),
+            create_result("senior", "Scale up the cluster", 1.0).with_confidence(0.6),
+            create_result("junior-2", "Roll back the deploy", 1.0).with_confidence(0.9),
+            create_result("lead", "Scale up the cluster", 1.0).with_confidence(0.6),
+        ]
+    }
+
+    #[test]
+    fn test_tie_broken_by_weight() {
+        let engine = tie_break_engine(TieBreaker::HighestWeight, None);
+
+        let consensus = engine.evaluate(two_two_tie()).unwrap();
+        assert!(consensus.reached);
+        assert_eq!(consensus.votes, 2);
+        assert_eq!(consensus.result.unwrap().response, "Scale up the cluster");
+        assert_eq!(consensus.tie_breaker, Some(TieBreaker::HighestWeight));
+        assert_eq!(consensus.algorithm, ConsensusAlgorithm::MajorityTieBreak);
+    }
+
+    #[test]
+    fn test_tie_broken_by_confidence() {
+        let engine = tie_break_engine(TieBreaker::HighestConfidence, None);
+
+        let consensus = engine.evaluate(two_two_tie()).unwrap();
+        assert!(consensus.reached);
+        assert_eq!(consensus.result.unwrap().response, "Roll back the deploy");
+        assert_eq!(consensus.tie_breaker, Some(TieBreaker::HighestConfidence));
+    }
+
+    #[test]
+    fn test_tie_broken_by_tiebreaker_agent() {
+        let engine = tie_break_engine(TieBreaker::Agent, Some("junior-2"));
+
+        let consensus = engine.evaluate(two_two_tie()).unwrap();
+        assert!(consensus.reached);
+        assert_eq!(consensus.result.unwrap().response, "Roll back the deploy");
+        assert_eq!(consensus.tie_breaker, Some(TieBreaker::Agent));
+    }
+
+    #[test]
+    fn test_unbreakable_tie_needs_review() {
+        // Equal weights can't break the tie
+        let engine = tie_break_engine(TieBreaker::HighestWeight, None);
+        let results = vec![
+            create_result("agent-1", "Answer A", 1.0),
+            create_result("agent-2", "Answer B", 1.0),
+        ];
+
+        let consensus = engine.evaluate(results).unwrap();
+        assert!(!consensus.reached);
+        assert!(consensus.requires_human_review);
+        assert_eq!(consensus.tie_breaker, Some(TieBreaker::HighestWeight));
+        assert!(consensus.review_reason.unwrap().contains("could not be broken"));
+        assert_eq!(consensus.disagreement.unwrap().clusters.len(), 2);
+    }
+
+    #[test]
+    fn test_tie_winner_must_meet_explicit_min_votes() {
+        let mut engine = tie_break_engine(TieBreaker::HighestWeight, None);
+        engine.config.min_votes = Some(3);
+
+        let consensus = engine.evaluate(two_two_tie()).unwrap();
+        assert!(!consensus.reached);
+        assert!(consensus.requires_human_review);
+        assert_eq!(consensus.review_reason.unwrap(), "No majority: 2 of 4 votes (need 3)");
+    }
+
+    #[test]
+    fn test_clear_majority_needs_no_tie_break() {
+        let engine = tie_break_engine(TieBreaker::Agent, Some("senior"));
+        let results = vec![
+            create_result("agent-1", "Answer A", 1.0),
+            create_result("agent-2", "Answer A", 1.0),
+            create_result("senior", "Answer B", 1.0),
+        ];
+
+        let consensus = engine.evaluate(results).unwrap();
+        assert!(consensus.reached);
+        assert_eq!(consensus.result.unwrap().response, "Answer A");
+        assert!(consensus.tie_breaker.is_none());
+    }
+
+    fn similarity_engine(threshold: f32) -> ConsensusEngine {
+        ConsensusEngine::from_config(ConsensusConfig {
+            algorithm: ConsensusAlgorithm::Majority,
+            min_votes: None,
+            timeout_ms: None,
+            allow_partial: false,
+            weights: HashMap::new(),
+            min_confidence: None,
+            tie_breaker: TieBreaker::default(),
+            tiebreaker_agent: None,
+            similarity_threshold: Some(threshold),
+        })
+    }
+
+    #[test]
+    fn test_paraphrases_form_one_cluster() {
+        let results = vec![
+            create_result("agent-1", "The root cause is the OOMKilled payment pod due to a low memory limit", 1.0),
+            create_result("agent-2", "Root cause: payment pod was OOMKilled because its memory limit is too low", 1.0),
+            create_result("agent-3", "DNS resolution failures after the coredns rollout", 1.0),
+        ];
+
+        let consensus = similarity_engine(0.6).evaluate(results.clone()).unwrap();
+        assert!(consensus.reached);
+        assert_eq!(consensus.votes, 2);
+        assert_eq!(consensus.result.unwrap().agent_name, "agent-1");
+
+        // Exact matching treats the paraphrases as different answers
+        let exact = ConsensusEngine::new().evaluate(results.clone()).unwrap();
+        assert!(!exact.reached);
+
+        // A threshold above their overlap keeps them apart
+        let strict = similarity_engine(0.95).evaluate(results).unwrap();
+        assert!(!strict.reached);
+        assert_eq!(strict.disagreement.unwrap().clusters.len(), 3);
+    }
+
+    #[test]
+    fn test_token_overlap_similarity() {
+        let overlap = TokenOverlap;
+        assert_eq!(overlap.similarity("Restart the pod", "restart THE pod"), 1.0);
+        assert_eq!(overlap.similarity("Restart the pod", "Scale the cluster"), 0.0);
+        let partial = overlap.similarity("Restart the payment pod", "Restart the pod");
+        assert!(partial > 0.5 && partial < 1.0);
+    }
+
+    struct PrefixSimilarity;
+
+    impl ResponseSimilarity for PrefixSimilarity {
+        fn similarity(&self, a: &str, b: &str) -> f32 {
+            if a.split(':').next() == b.split(':').next() {
+                1.0
+            } else {
+                0.0
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_similarity() {
+        let engine = similarity_engine(0.9).with_similarity(PrefixSimilarity);
+        let results = vec![
+            create_result("agent-1", "rollback: the deploy broke checkout", 1.0),
+            create_result("agent-2", "rollback: v2.3 introduced the regression", 1.0),
+            create_result("agent-3", "scale: traffic spike", 1.0),
+        ];
+
+        let consensus = engine.evaluate(results).unwrap();
+        assert!(consensus.reached);
+        assert_eq!(consensus.votes, 2);
+    }
 }
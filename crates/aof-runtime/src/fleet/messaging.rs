@@ -0,0 +1,172 @@
+//! Agent-to-agent messaging for peer coordination
+//!
+//! In peer mode with `communication.rounds > 0`, agents don't answer in
+//! isolation: after each round their responses are posted to a shared
+//! `MessageBoard`, and every agent runs again with the messages addressed to
+//! it, so agents can build on each other's partial findings before consensus.
+//!
+//! Delivery follows the configured `MessagePattern`:
+//! - **Broadcast / PubSub**: an agent's whole response goes to every peer
+//! - **Direct / RequestReply**: only lines of the form `@peer: message` are
+//!   delivered, each to the named peer
+//!
+//! Rounds are capped at `MAX_MESSAGE_ROUNDS` so agents can't keep each other
+//! talking forever.
+
+use aof_core::{CommunicationConfig, MessagePattern};
+use serde::{Deserialize, Serialize};
+
+/// Upper bound on exchange rounds, whatever the configuration says
+pub const MAX_MESSAGE_ROUNDS: u32 = 5;
+
+/// A message from one agent to its peers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FleetMessage {
+    /// Round the message was sent in (0 = the agents' first answers)
+    pub round: u32,
+
+    /// Sending agent
+    pub from: String,
+
+    /// Recipient (None: every peer)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Message text
+    pub content: String,
+}
+
+/// Shared channel that peer agents post to and read from between rounds
+#[derive(Debug, Clone, Default)]
+pub struct MessageBoard {
+    pattern: MessagePattern,
+    include_sender: bool,
+    messages: Vec<FleetMessage>,
+}
+
+impl MessageBoard {
+    /// Empty board delivering with the given pattern
+    pub fn new(pattern: MessagePattern) -> Self {
+        Self {
+            pattern,
+            include_sender: false,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Board for a fleet's communication configuration
+    pub fn from_config(config: &CommunicationConfig) -> Self {
+        Self {
+            include_sender: config.broadcast.as_ref().is_some_and(|b| b.include_sender),
+            ..Self::new(config.pattern)
+        }
+    }
+
+    /// Post a message as-is
+    pub fn post(&mut self, message: FleetMessage) {
+        self.messages.push(message);
+    }
+
+    /// Post an agent's response according to the pattern; returns messages posted
+    pub fn post_response(&mut self, round: u32, from: &str, response: &str) -> usize {
+        let before = self.messages.len();
+        match self.pattern {
+            MessagePattern::Broadcast | MessagePattern::PubSub => {
+                if !response.trim().is_empty() {
+                    self.post(FleetMessage {
+                        round,
+                        from: from.to_string(),
+                        to: None,
+                        content: response.trim().to_string(),
+                    });
+                }
+            }
+            MessagePattern::Direct | MessagePattern::RequestReply => {
+                for (to, content) in response.lines().filter_map(parse_direct_line) {
+                    self.post(FleetMessage {
+                        round,
+                        from: from.to_string(),
+                        to: Some(to.to_string()),
+                        content: content.to_string(),
+                    });
+                }
+            }
+        }
+        self.messages.len() - before
+    }
+
+    /// Messages from `round` that `agent` can see
+    pub fn inbox(&self, agent: &str, round: u32) -> Vec<&FleetMessage> {
+        self.messages
+            .iter()
+            .filter(|m| m.round == round)
+            .filter(|m| match &m.to {
+                Some(to) => to == agent,
+                None => self.include_sender || m.from != agent,
+            })
+            .collect()
+    }
+
+    /// Every message posted so far
+    pub fn messages(&self) -> &[FleetMessage] {
+        &self.messages
+    }
+}
+
+/// Input for an agent's next round: the task plus the peer messages it received
+pub fn round_input(original_input: &serde_json::Value, round: u32, inbox: &[&FleetMessage]) -> serde_json::Value {
+    serde_json::json!({
+        "original_input": original_input,
+        "round": round,
+        "peer_messages": inbox
+            .iter()
+            .map(|m| serde_json::json!({ "from": m.from, "content": m.content }))
+            .collect::<Vec<_>>(),
+        "instructions": "Other agents working on the same task shared these messages. \
+            Build on their findings where they hold up, then give your updated answer.",
+    })
+}
+
+// Helper: Split an `@peer: message` line
+fn parse_direct_line(line: &str) -> Option<(&str, &str)> {
+    let (to, content) = line.trim().strip_prefix('@')?.split_once(':')?;
+    let (to, content) = (to.trim(), content.trim());
+    (!to.is_empty() && !to.contains(char::is_whitespace) && !content.is_empty()).then_some((to, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broadcast_reaches_peers_not_sender() {
+        let mut board = MessageBoard::new(MessagePattern::Broadcast);
+        assert_eq!(board.post_response(0, "agent-1", "Disk is full on node-3"), 1);
+        board.post_response(0, "agent-2", "Pods are evicted");
+
+        let inbox = board.inbox("agent-3", 0);
+        assert_eq!(inbox.len(), 2);
+        assert_eq!(inbox[0].from, "agent-1");
+
+        let own = board.inbox("agent-1", 0);
+        assert_eq!(own.len(), 1);
+        assert_eq!(own[0].from, "agent-2");
+        assert!(board.inbox("agent-3", 1).is_empty());
+    }
+
+    #[test]
+    fn test_direct_messages_reach_addressee_only() {
+        let mut board = MessageBoard::new(MessagePattern::Direct);
+        let posted = board.post_response(
+            0,
+            "agent-1",
+            "Node-3 looks unhealthy.\n@agent-2: check kubelet logs on node-3\n@: no recipient",
+        );
+        assert_eq!(posted, 1);
+
+        let inbox = board.inbox("agent-2", 0);
+        assert_eq!(inbox.len(), 1);
+        assert_eq!(inbox[0].content, "check kubelet logs on node-3");
+        assert!(board.inbox("agent-3", 0).is_empty());
+    }
+}
@@ -11,9 +11,16 @@
 
 pub mod consensus;
 pub mod deep;
+pub mod messaging;
+pub mod store;
 
-pub use consensus::{AgentResult, ConsensusEngine, ConsensusResult};
+pub use consensus::{
+    AgentResult, AnswerCluster, ConsensusEngine, ConsensusResult, DisagreementReport, ResponseSimilarity,
+    TokenOverlap,
+};
 pub use deep::{DeepFleetExecutor, DeepResult, Finding, InvestigationPlan, InvestigationStep};
+pub use messaging::{FleetMessage, MessageBoard, MAX_MESSAGE_ROUNDS};
+pub use store::{FleetStore, RetentionPolicy};
 
 use aof_core::{
     AgentConfig, AgentFleet, AgentInstanceState, AgentInstanceStatus, AgentRole, AofError,
@@ -27,6 +34,9 @@ use uuid::Uuid;
 
 use crate::Runtime;
 
+/// Longest a single agent's warmup request may take before it is abandoned
+pub const WARMUP_TIMEOUT_SECS: u64 = 10;
+
 /// Fleet coordinator for managing multi-agent collaboration
 pub struct FleetCoordinator {
     /// Fleet configuration
@@ -46,6 +56,9 @@ pub struct FleetCoordinator {
 
     /// Round-robin counter for task distribution
     rr_counter: Arc<RwLock<usize>>,
+
+    /// Persistent store for state and task history (optional)
+    store: Option<FleetStore>,
 }
 
 /// Agent info for tier display
@@ -104,6 +117,12 @@ pub enum FleetEvent {
         confidence: f64,
         duration_ms: u64,
     },
+    /// Peer agents exchanged messages (for peer mode with communication rounds)
+    MessagesExchanged {
+        task_id: String,
+        round: u32,
+        messages: Vec<FleetMessage>,
+    },
     /// Consensus reached (for peer mode)
     ConsensusReached {
         task_id: String,
@@ -127,6 +146,7 @@ impl FleetCoordinator {
             event_tx: None,
             task_queue: Arc::new(RwLock::new(Vec::new())),
             rr_counter: Arc::new(RwLock::new(0)),
+            store: None,
         }
     }
 
@@ -144,6 +164,19 @@ impl FleetCoordinator {
         self
     }
 
+    /// Persist state and task history to a store
+    ///
+    /// Takes precedence over `spec.persistence`.
+    pub fn with_store(mut self, store: FleetStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Persistent store, if any
+    pub fn store(&self) -> Option<&FleetStore> {
+        self.store.as_ref()
+    }
+
     /// Get fleet configuration
     pub fn fleet(&self) -> &AgentFleet {
         &self.fleet
@@ -161,6 +194,9 @@ impl FleetCoordinator {
         // Validate configuration
         self.fleet.validate()?;
 
+        // Restore history from a previous run
+        self.open_store().await?;
+
         // Update state
         {
             let mut state = self.state.write().await;
@@ -175,6 +211,10 @@ impl FleetCoordinator {
             self.start_agent_instances(&fleet_agent).await?;
         }
 
+        if self.fleet.spec.coordination.warmup {
+            self.warmup_agents().await;
+        }
+
         // Update state to ready
         {
             let mut state = self.state.write().await;
@@ -199,6 +239,58 @@ impl FleetCoordinator {
         Ok(())
     }
 
+    /// Open the configured store and restore metrics and task history from it
+    async fn open_store(&mut self) -> AofResult<()> {
+        if self.store.is_none() {
+            if let Some(config) = &self.fleet.spec.persistence {
+                let backend = aof_memory::FileBackend::new(&config.path).await?;
+                let retention = RetentionPolicy {
+                    max_tasks: config.max_tasks,
+                    max_age_secs: config.max_age_secs,
+                };
+                self.store = Some(
+                    FleetStore::new(Arc::new(backend), self.fleet.metadata.name.clone())
+                        .with_retention(retention),
+                );
+            }
+        }
+
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+        store.apply_retention().await?;
+        if let Some(saved) = store.load_state().await? {
+            info!(
+                "Restored {} completed tasks for fleet '{}'",
+                saved.completed_tasks.len(),
+                self.fleet.metadata.name
+            );
+            let mut state = self.state.write().await;
+            state.metrics = saved.metrics;
+            state.completed_tasks = saved.completed_tasks;
+        }
+        Ok(())
+    }
+
+    // Helper: Persist a finished task and the metrics; failures are logged, not returned
+    async fn persist_task(&self, task: &FleetTask) {
+        let Some(store) = &self.store else {
+            return;
+        };
+
+        let state = {
+            let mut state = self.state.write().await;
+            store.retention().trim(&mut state.completed_tasks);
+            state.clone()
+        };
+        if let Err(e) = store.save_task(task).await {
+            warn!("Failed to persist task {}: {}", task.task_id, e);
+        }
+        if let Err(e) = store.save_state(&state).await {
+            warn!("Failed to persist fleet state: {}", e);
+        }
+    }
+
     /// Start agent instances for a fleet agent
     async fn start_agent_instances(&self, fleet_agent: &FleetAgent) -> AofResult<()> {
         // Load agent config
@@ -251,6 +343,45 @@ impl FleetCoordinator {
         Ok(())
     }
 
+    /// Warm up every agent's model in parallel
+    ///
+    /// Each request gets `WARMUP_TIMEOUT_SECS`. Failures and timeouts are
+    /// logged and never block startup; the first real task will simply pay
+    /// the cold-start cost for that agent.
+    async fn warmup_agents(&self) {
+        // Take the executors out so the runtime isn't locked during the requests
+        let executors: Vec<_> = {
+            let runtime = self.runtime.read().await;
+            if runtime.is_replay() {
+                return;
+            }
+            self.fleet
+                .spec
+                .agents
+                .iter()
+                .filter_map(|agent| {
+                    let executor = runtime.get_agent(&agent.name)?;
+                    Some((agent.name.clone(), executor))
+                })
+                .collect()
+        };
+
+        let timeout = std::time::Duration::from_secs(WARMUP_TIMEOUT_SECS);
+        let warmups = executors.into_iter().map(|(name, executor)| async move {
+            let start = std::time::Instant::now();
+            match tokio::time::timeout(timeout, executor.warmup()).await {
+                Ok(Ok(())) => debug!(
+                    "Warmed up agent '{}' in {}ms",
+                    name,
+                    start.elapsed().as_millis()
+                ),
+                Ok(Err(e)) => warn!("Warmup failed for agent '{}': {}", name, e),
+                Err(_) => warn!("Warmup for agent '{}' timed out after {}s", name, WARMUP_TIMEOUT_SECS),
+            }
+        });
+        futures::future::join_all(warmups).await;
+    }
+
     /// Load agent configuration from fleet agent definition
     async fn load_agent_config(&self, fleet_agent: &FleetAgent) -> AofResult<AgentConfig> {
         if let Some(ref config_path) = fleet_agent.config {
@@ -275,7 +406,15 @@ impl FleetCoordinator {
                 max_iterations: spec.max_iterations.map(|v| v as usize).unwrap_or(10),
                 temperature: spec.temperature.unwrap_or(0.7),
                 max_tokens: None,
+                stop: Vec::new(),
+                seed: None,
                 output_schema: None,
+                guardrails: vec![],
+                max_tool_calls_per_turn: None,
+                max_tool_calls_per_run: None,
+                max_continuations: None,
+                tool_result_page_size: None,
+                tool_discovery: false,
                 extra: std::collections::HashMap::new(),
             })
         } else {
@@ -336,7 +475,7 @@ impl FleetCoordinator {
         };
 
         // Execute based on coordination mode
-        match self.fleet.spec.coordination.mode {
+        let result = match self.fleet.spec.coordination.mode {
             CoordinationMode::Hierarchical => {
                 self.execute_hierarchical(task).await
             }
@@ -355,7 +494,12 @@ impl FleetCoordinator {
             CoordinationMode::Deep => {
                 self.execute_deep(task).await
             }
+        };
+
+        if let Ok(Some(task)) = &result {
+            self.persist_task(task).await;
         }
+        result
     }
 
     /// Execute task in hierarchical mode (manager delegates to workers)
@@ -426,8 +570,8 @@ impl FleetCoordinator {
         task.status = FleetTaskStatus::Running;
         task.started_at = Some(chrono::Utc::now());
 
-        // Execute on all agents in parallel
-        let agent_results = self.execute_agents_parallel(&agents, &task.input).await;
+        // Execute on all agents in parallel, exchanging messages between rounds if configured
+        let agent_results = self.execute_peer_rounds(&task, &agents).await;
 
         // Check if we should use aggregation instead of consensus
         if let Some(agg_mode) = aggregation_mode {
@@ -509,6 +653,9 @@ impl FleetCoordinator {
         let total_input_tokens: usize = agent_results.iter().map(|r| r.input_tokens).sum();
         let total_output_tokens: usize = agent_results.iter().map(|r| r.output_tokens).sum();
 
+        // Keep per-agent answers for the task result before evaluate takes ownership
+        let agent_summaries = agent_results_json(&agent_results);
+
         // Evaluate consensus
         let consensus = engine.evaluate(agent_results)?;
 
@@ -529,6 +676,7 @@ impl FleetCoordinator {
                 "confidence": consensus.confidence,
                 "votes": consensus.votes,
                 "requires_review": consensus.requires_human_review,
+                "agent_results": agent_summaries,
                 "usage": {
                     "input_tokens": total_input_tokens,
                     "output_tokens": total_output_tokens,
@@ -538,14 +686,21 @@ impl FleetCoordinator {
             task.status = FleetTaskStatus::Completed;
         } else {
             task.status = FleetTaskStatus::Failed;
-            task.error = Some(format!(
+            let mut error = format!(
                 "Failed to reach consensus: {} votes, confidence {:.2}. Review: {}",
                 consensus.votes,
                 consensus.confidence,
                 consensus.review_reason.unwrap_or_default()
-            ));
-            // Still include token usage even on failure
+            );
+            if let Some(report) = &consensus.disagreement {
+                error.push('\n');
+                error.push_str(&report.summary());
+            }
+            task.error = Some(error);
+            // Still include agent answers, the disagreement and token usage even on failure
             task.result = Some(serde_json::json!({
+                "agent_results": agent_summaries,
+                "disagreement": consensus.disagreement,
                 "usage": {
                     "input_tokens": total_input_tokens,
                     "output_tokens": total_output_tokens,
@@ -571,6 +726,77 @@ impl FleetCoordinator {
         Ok(Some(task))
     }
 
+    /// Run peer agents, then let them exchange messages for the configured rounds
+    ///
+    /// Each round, the previous round's responses are posted to a shared
+    /// `MessageBoard` and every agent runs again with the messages it can see.
+    /// Stops early when nothing was delivered. Token usage and time add up
+    /// across rounds; an agent failing in a round keeps its previous answer.
+    async fn execute_peer_rounds(
+        &self,
+        task: &FleetTask,
+        agents: &[AgentInstanceState],
+    ) -> Vec<AgentResult> {
+        let mut results = self.execute_agents_parallel(agents, &task.input).await;
+
+        let Some(config) = self.fleet.spec.communication.as_ref().filter(|c| c.rounds > 0) else {
+            return results;
+        };
+        let rounds = config.rounds.min(MAX_MESSAGE_ROUNDS);
+        if config.rounds > MAX_MESSAGE_ROUNDS {
+            warn!(
+                "communication.rounds {} capped at {}",
+                config.rounds, MAX_MESSAGE_ROUNDS
+            );
+        }
+
+        let mut board = MessageBoard::from_config(config);
+        for round in 1..=rounds {
+            let posted: usize = results
+                .iter()
+                .map(|r| board.post_response(round - 1, &r.agent_name, &r.response))
+                .sum();
+            if posted == 0 {
+                debug!("No messages to deliver after round {}, stopping exchange", round - 1);
+                break;
+            }
+            self.state.write().await.metrics.messages_exchanged += posted as u64;
+
+            self.emit_event(FleetEvent::MessagesExchanged {
+                task_id: task.task_id.clone(),
+                round: round - 1,
+                messages: board
+                    .messages()
+                    .iter()
+                    .filter(|m| m.round == round - 1)
+                    .cloned()
+                    .collect(),
+            })
+            .await;
+
+            let inputs: Vec<serde_json::Value> = agents
+                .iter()
+                .map(|agent| {
+                    let inbox = board.inbox(&agent.agent_name, round - 1);
+                    messaging::round_input(&task.input, round, &inbox)
+                })
+                .collect();
+            let next = self.execute_agents_with_inputs(agents, &inputs).await;
+
+            info!("Peer message round {} completed with {} answers", round, next.len());
+            for mut updated in next {
+                if let Some(previous) = results.iter_mut().find(|r| r.agent_name == updated.agent_name) {
+                    updated.input_tokens += previous.input_tokens;
+                    updated.output_tokens += previous.output_tokens;
+                    updated.execution_time_ms += previous.execution_time_ms;
+                    *previous = updated;
+                }
+            }
+        }
+
+        results
+    }
+
     /// Execute task in tiered mode (tier-based parallel execution with consensus)
     async fn execute_tiered(&self, mut task: FleetTask) -> AofResult<Option<FleetTask>> {
         task.status = FleetTaskStatus::Running;
@@ -585,6 +811,8 @@ impl FleetCoordinator {
 
         let mut current_input = task.input.clone();
         let mut all_tier_results: Vec<ConsensusResult> = Vec::new();
+        let mut agent_summaries: Vec<serde_json::Value> = Vec::new();
+        let mut disagreements: Vec<serde_json::Value> = Vec::new();
 
         // Execute each tier sequentially, passing results to next tier
         for tier in &tiers {
@@ -659,6 +887,15 @@ impl FleetCoordinator {
             };
 
             let tier_consensus = engine.evaluate(tier_results)?;
+            agent_summaries.extend(agent_results_json(&tier_consensus.all_results).into_iter().map(
+                |mut summary| {
+                    summary["tier"] = serde_json::json!(tier);
+                    summary
+                },
+            ));
+            if let Some(report) = &tier_consensus.disagreement {
+                disagreements.push(serde_json::json!({ "tier": tier, "report": report }));
+            }
             all_tier_results.push(tier_consensus.clone());
 
             // Prepare input for next tier
@@ -717,7 +954,13 @@ impl FleetCoordinator {
         }
 
         // Apply final aggregation
-        let final_result = self.aggregate_tier_results(&task, all_tier_results, &current_input).await?;
+        let mut final_result = self.aggregate_tier_results(&task, all_tier_results, &current_input).await?;
+        if let Some(obj) = final_result.as_object_mut() {
+            obj.insert("agent_results".to_string(), serde_json::Value::Array(agent_summaries));
+            if !disagreements.is_empty() {
+                obj.insert("disagreements".to_string(), serde_json::Value::Array(disagreements));
+            }
+        }
 
         task.result = Some(final_result.clone());
         task.status = FleetTaskStatus::Completed;
@@ -795,10 +1038,20 @@ impl FleetCoordinator {
         &self,
         agents: &[AgentInstanceState],
         input: &serde_json::Value,
+    ) -> Vec<AgentResult> {
+        let inputs = vec![input.clone(); agents.len()];
+        self.execute_agents_with_inputs(agents, &inputs).await
+    }
+
+    /// Execute agents in parallel, each with its own input
+    async fn execute_agents_with_inputs(
+        &self,
+        agents: &[AgentInstanceState],
+        inputs: &[serde_json::Value],
     ) -> Vec<AgentResult> {
         let mut handles = Vec::new();
 
-        for agent in agents {
+        for (agent, input) in agents.iter().zip(inputs) {
             let agent_name = agent.agent_name.clone();
             let input_str = serde_json::to_string(input).unwrap_or_default();
             let runtime = self.runtime.clone();
@@ -1222,6 +1475,24 @@ impl FleetCoordinator {
     }
 }
 
+/// Per-agent answers included in fleet task results
+fn agent_results_json(results: &[AgentResult]) -> Vec<serde_json::Value> {
+    results
+        .iter()
+        .map(|r| {
+            serde_json::json!({
+                "agent": r.agent_name,
+                "tier": r.tier,
+                "response": r.response,
+                "confidence": r.confidence,
+                "execution_time_ms": r.execution_time_ms,
+                "input_tokens": r.input_tokens,
+                "output_tokens": r.output_tokens,
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1287,4 +1558,133 @@ spec:
         let state = coordinator.state().await;
         assert_eq!(state.metrics.total_tasks, 1);
     }
+
+    /// Model that counts calls, records the latest user message and optionally fails
+    struct CountingModel {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+        inputs: Arc<std::sync::Mutex<Vec<String>>>,
+        reply: String,
+        fail: bool,
+        config: aof_core::ModelConfig,
+    }
+
+    #[async_trait::async_trait]
+    impl aof_core::Model for CountingModel {
+        async fn generate(&self, request: &aof_core::ModelRequest) -> AofResult<aof_core::ModelResponse> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if let Some(message) = request.messages.last() {
+                self.inputs.lock().unwrap().push(message.content.clone());
+            }
+            if self.fail {
+                return Err(AofError::model("connection refused".to_string()));
+            }
+            Ok(aof_core::ModelResponse {
+                content: self.reply.clone(),
+                tool_calls: vec![],
+                stop_reason: aof_core::StopReason::EndTurn,
+                usage: aof_core::Usage::default(),
+                metadata: std::collections::HashMap::new(),
+            })
+        }
+
+        async fn generate_stream(
+            &self,
+            _request: &aof_core::ModelRequest,
+        ) -> AofResult<std::pin::Pin<Box<dyn futures::Stream<Item = AofResult<aof_core::StreamChunk>> + Send>>>
+        {
+            Err(AofError::model("CountingModel does not stream".to_string()))
+        }
+
+        fn config(&self) -> &aof_core::ModelConfig {
+            &self.config
+        }
+
+        fn provider(&self) -> aof_core::ModelProvider {
+            aof_core::ModelProvider::Custom
+        }
+    }
+
+    /// Runtime with counting models loaded for the test fleet's agents
+    fn counting_runtime(failing: &[&str], hanging: &[&str]) -> (Runtime, Vec<Arc<std::sync::atomic::AtomicUsize>>) {
+        let (runtime, models) = recording_runtime(failing);
+        (runtime, models.into_iter().map(|(calls, _)| calls).collect())
+    }
+
+    type ModelRecord = (Arc<std::sync::atomic::AtomicUsize>, Arc<std::sync::Mutex<Vec<String>>>);
+
+    /// Runtime whose agents answer "finding from <agent>" and record their inputs
+    fn recording_runtime(failing: &[&str]) -> (Runtime, Vec<ModelRecord>) {
+        let mut runtime = Runtime::new();
+        let mut records = Vec::new();
+        for name in ["worker-1", "worker-2"] {
+            let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let inputs = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let config: AgentConfig =
+                serde_yaml::from_str(&format!("name: {}\nmodel: openai:gpt-4\n", name)).unwrap();
+            let model = CountingModel {
+                calls: calls.clone(),
+                inputs: inputs.clone(),
+                reply: format!("finding from {}", name),
+                fail: failing.contains(&name),
+                hang: hanging.contains(&name),
+                config: aof_core::ModelConfig {
+                    model: "mock-model".to_string(),
+                    provider: aof_core::ModelProvider::Custom,
+                    api_key: None,
+                    endpoint: None,
+                    temperature: 0.7,
+                    max_tokens: None,
+                    timeout_secs: 60,
+                    stop: vec![],
+                    seed: None,
+                    headers: std::collections::HashMap::new(),
+                    extra: std::collections::HashMap::new(),
+                },
+            };
+            runtime.register_agent(crate::AgentExecutor::new(config, Box::new(model), None, None).unwrap());
+            records.push((calls, inputs));
+        }
+        (runtime, records)
+    }
+
+    #[tokio::test]
+    async fn test_warmup_invokes_each_model_once() {
+        let (runtime, counters) = counting_runtime(&[], &[]);
+        let coordinator = FleetCoordinator::new(create_test_fleet(), runtime);
+
+        coordinator.warmup_agents().await;
+
+        for calls in &counters {
+            assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        }
+    }
+
+    #[test]
+    fn test_no_warmup_by_default() {
+        assert!(!aof_core::CoordinationConfig::default().warmup);
+        assert!(!create_test_fleet().spec.coordination.warmup);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_failure_is_not_fatal() {
+        let (runtime, counters) = counting_runtime(&["worker-1"], &[]);
+        let coordinator = FleetCoordinator::new(create_test_fleet(), runtime);
+
+        coordinator.warmup_agents().await;
+
+        assert_eq!(counters[0].load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(counters[1].load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_warmup_gives_up_on_unresponsive_model() {
+        let (runtime, counters) = counting_runtime(&[], &["worker-1"]);
+        let coordinator = FleetCoordinator::new(create_test_fleet(), runtime);
+
+        // Returns once the hung request times out
+        coordinator.warmup_agents().await;
+
+        assert_eq!(counters[0].load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(counters[1].load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,273 @@
+//! Persistence for fleet state and completed task history
+//!
+//! `FleetStore` keeps a fleet's metrics and finished tasks in any
+//! `MemoryBackend` (a `FileBackend` for history that survives restarts), so a
+//! long-running fleet can be restarted without losing its history and the
+//! history can be queried later.
+//!
+//! Keys are namespaced per fleet:
+//! - `fleet:<name>:state` - fleet state (without the task history)
+//! - `fleet:<name>:task:<task_id>` - one completed or failed task
+//!
+//! A `RetentionPolicy` bounds the history by count and/or age; it is applied
+//! whenever a task is saved.
+
+use std::sync::Arc;
+
+use aof_core::{
+    AofError, AofResult, FleetState, FleetTask, FleetTaskStatus, MemoryBackend, MemoryEntry,
+    MemoryQuery,
+};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Limits on how much task history is kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    /// Keep at most this many tasks (oldest removed first)
+    #[serde(default)]
+    pub max_tasks: Option<usize>,
+
+    /// Remove tasks completed more than this many seconds ago
+    #[serde(default)]
+    pub max_age_secs: Option<u64>,
+}
+
+impl RetentionPolicy {
+    /// Keep at most `max_tasks` tasks
+    pub fn max_tasks(max_tasks: usize) -> Self {
+        Self {
+            max_tasks: Some(max_tasks),
+            ..Self::default()
+        }
+    }
+
+    /// Keep tasks for at most `max_age_secs` seconds
+    pub fn max_age_secs(max_age_secs: u64) -> Self {
+        Self {
+            max_age_secs: Some(max_age_secs),
+            ..Self::default()
+        }
+    }
+
+    /// Apply the policy to an in-memory history ordered oldest first
+    pub fn trim(&self, tasks: &mut Vec<FleetTask>) {
+        if let Some(max_age) = self.max_age_secs {
+            let cutoff = chrono::Utc::now() - chrono::Duration::seconds(max_age as i64);
+            tasks.retain(|t| finished_at(t) >= cutoff);
+        }
+        if let Some(max_tasks) = self.max_tasks {
+            let excess = tasks.len().saturating_sub(max_tasks);
+            tasks.drain(..excess);
+        }
+    }
+}
+
+/// Fleet state and task history stored in a memory backend
+#[derive(Clone)]
+pub struct FleetStore {
+    backend: Arc<dyn MemoryBackend>,
+    fleet_name: String,
+    retention: RetentionPolicy,
+}
+
+impl FleetStore {
+    /// Store for the named fleet, keeping all history
+    pub fn new(backend: Arc<dyn MemoryBackend>, fleet_name: impl Into<String>) -> Self {
+        Self {
+            backend,
+            fleet_name: fleet_name.into(),
+            retention: RetentionPolicy::default(),
+        }
+    }
+
+    /// Set the retention policy
+    pub fn with_retention(mut self, retention: RetentionPolicy) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Retention policy in effect
+    pub fn retention(&self) -> RetentionPolicy {
+        self.retention
+    }
+
+    /// Save fleet state; the task history is stored separately
+    pub async fn save_state(&self, state: &FleetState) -> AofResult<()> {
+        let mut snapshot = state.clone();
+        snapshot.completed_tasks.clear();
+        let value = serde_json::to_value(&snapshot)?;
+        self.backend
+            .store(&self.state_key(), MemoryEntry::new(self.state_key(), value))
+            .await
+    }
+
+    /// Load the last saved state, with its task history, if any
+    pub async fn load_state(&self) -> AofResult<Option<FleetState>> {
+        let Some(entry) = self.backend.retrieve(&self.state_key()).await? else {
+            return Ok(None);
+        };
+        let mut state: FleetState = serde_json::from_value(entry.value).map_err(|e| {
+            AofError::memory(format!("Invalid stored state for fleet {}: {}", self.fleet_name, e))
+        })?;
+        state.completed_tasks = self.tasks(None).await?;
+        Ok(Some(state))
+    }
+
+    /// Save a finished task and apply the retention policy
+    pub async fn save_task(&self, task: &FleetTask) -> AofResult<()> {
+        let key = self.task_key(&task.task_id);
+        let mut entry = MemoryEntry::new(key.clone(), serde_json::to_value(task)?)
+            .with_metadata("fleet", self.fleet_name.clone())
+            .with_metadata("status", status_name(task.status));
+        entry.timestamp = finished_at(task).timestamp_millis().max(0) as u64;
+        self.backend.store(&key, entry).await?;
+
+        self.apply_retention().await?;
+        Ok(())
+    }
+
+    /// Stored tasks, oldest first, optionally only those with a given status
+    pub async fn tasks(&self, status: Option<FleetTaskStatus>) -> AofResult<Vec<FleetTask>> {
+        let mut query = MemoryQuery {
+            prefix: Some(self.task_prefix()),
+            include_expired: true,
+            ..MemoryQuery::default()
+        };
+        if let Some(status) = status {
+            query.metadata.insert("status".to_string(), status_name(status));
+        }
+
+        let mut tasks: Vec<FleetTask> = self
+            .backend
+            .search(&query)
+            .await?
+            .into_iter()
+            .filter(|entry| entry.key.starts_with(&self.task_prefix()))
+            .filter_map(|entry| serde_json::from_value(entry.value).ok())
+            .collect();
+        tasks.sort_by_key(finished_at);
+        Ok(tasks)
+    }
+
+    /// Remove tasks the retention policy no longer allows; returns how many
+    pub async fn apply_retention(&self) -> AofResult<usize> {
+        let tasks = self.tasks(None).await?;
+        let mut kept = tasks.clone();
+        self.retention.trim(&mut kept);
+
+        let mut removed = 0;
+        for task in tasks.iter().filter(|t| !kept.iter().any(|k| k.task_id == t.task_id)) {
+            self.backend.delete(&self.task_key(&task.task_id)).await?;
+            removed += 1;
+        }
+        if removed > 0 {
+            debug!("Retention removed {} tasks from fleet {}", removed, self.fleet_name);
+        }
+        Ok(removed)
+    }
+
+    // Helper: Key of the fleet state entry
+    fn state_key(&self) -> String {
+        format!("fleet:{}:state", self.fleet_name)
+    }
+
+    // Helper: Key prefix shared by the fleet's task entries
+    fn task_prefix(&self) -> String {
+        format!("fleet:{}:task:", self.fleet_name)
+    }
+
+    // Helper: Key of one task entry
+    fn task_key(&self, task_id: &str) -> String {
+        format!("{}{}", self.task_prefix(), task_id)
+    }
+}
+
+/// When a task finished (falls back to when it was created)
+fn finished_at(task: &FleetTask) -> chrono::DateTime<chrono::Utc> {
+    task.completed_at.unwrap_or(task.created_at)
+}
+
+/// Serialized name of a task status, as used in entry metadata
+fn status_name(status: FleetTaskStatus) -> String {
+    serde_json::to_value(status)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aof_memory::{FileBackend, InMemoryBackend};
+
+    fn finished_task(id: &str, status: FleetTaskStatus, age_secs: i64) -> FleetTask {
+        let completed = chrono::Utc::now() - chrono::Duration::seconds(age_secs);
+        FleetTask {
+            task_id: id.to_string(),
+            input: serde_json::json!({ "query": id }),
+            assigned_to: None,
+            status,
+            result: Some(serde_json::json!({ "response": format!("done {}", id) })),
+            error: None,
+            created_at: completed,
+            started_at: Some(completed),
+            completed_at: Some(completed),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tasks_persist_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fleet.json");
+
+        {
+            let backend = Arc::new(FileBackend::new(&path).await.unwrap());
+            let store = FleetStore::new(backend, "rca-team");
+            let mut state = FleetState::new("rca-team");
+            state.metrics.completed_tasks = 1;
+            state.metrics.failed_tasks = 1;
+            store.save_state(&state).await.unwrap();
+            store.save_task(&finished_task("t1", FleetTaskStatus::Completed, 20)).await.unwrap();
+            store.save_task(&finished_task("t2", FleetTaskStatus::Failed, 10)).await.unwrap();
+        }
+
+        // A new backend on the same file sees everything
+        let backend = Arc::new(FileBackend::new(&path).await.unwrap());
+        let store = FleetStore::new(backend, "rca-team");
+        let state = store.load_state().await.unwrap().unwrap();
+        assert_eq!(state.metrics.completed_tasks, 1);
+        let ids: Vec<_> = state.completed_tasks.iter().map(|t| t.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["t1", "t2"]);
+
+        let failed = store.tasks(Some(FleetTaskStatus::Failed)).await.unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].task_id, "t2");
+    }
+
+    #[tokio::test]
+    async fn test_retention_trims_oldest_tasks() {
+        let store = FleetStore::new(Arc::new(InMemoryBackend::new()), "rca-team")
+            .with_retention(RetentionPolicy::max_tasks(2));
+
+        for (id, age) in [("t1", 30), ("t2", 20), ("t3", 10)] {
+            store.save_task(&finished_task(id, FleetTaskStatus::Completed, age)).await.unwrap();
+        }
+
+        let ids: Vec<_> = store.tasks(None).await.unwrap().into_iter().map(|t| t.task_id).collect();
+        assert_eq!(ids, vec!["t2", "t3"]);
+    }
+
+    #[tokio::test]
+    async fn test_retention_by_age() {
+        let store = FleetStore::new(Arc::new(InMemoryBackend::new()), "rca-team")
+            .with_retention(RetentionPolicy::max_age_secs(3600));
+
+        store.save_task(&finished_task("old", FleetTaskStatus::Completed, 7200)).await.unwrap();
+        store.save_task(&finished_task("new", FleetTaskStatus::Completed, 60)).await.unwrap();
+
+        let tasks = store.tasks(None).await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].task_id, "new");
+    }
+}
@@ -13,12 +13,15 @@ pub mod orchestrator;
 pub mod task;
 
 pub use executor::{
-    AgentExecutor, AgentFlowEvent, AgentFlowExecutor, ApprovalDecision, HumanInput, Runtime,
-    StreamEvent, WorkflowEvent, WorkflowExecutor,
+    builtin_tool_registry, builtin_tool_registry_with_commit_cache, AgentExecutor, AgentFlowEvent,
+    AgentFlowExecutor, ApprovalDecision, ExecutionTrace,
+    HumanInput, Runtime, StreamEvent, ToolErrorCategory, ToolErrorFeedback, ToolExecutorLayer, ToolResultPager,
+    TraceStep, TraceToolCall, ERROR_CATEGORY_METADATA,
+    WorkflowEvent, WorkflowExecutor, FETCH_MORE_TOOL, LIST_TOOLS_TOOL,
 };
 pub use fleet::{FleetCoordinator, FleetEvent};
 pub use orchestrator::RuntimeOrchestrator;
-pub use task::{Task, TaskHandle, TaskStatus};
+pub use task::{Task, TaskHandle, TaskProgress, TaskResult, TaskStatus};
 
 // Re-export core types
 pub use aof_core::{AofError, AofResult};
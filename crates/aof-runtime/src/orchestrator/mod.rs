@@ -91,7 +91,15 @@ impl RuntimeOrchestrator {
             let start = std::time::Instant::now();
 
             // Execute task
-            match executor(task).await {
+            let outcome = executor(task).await;
+
+            // A task cancelled while running keeps its cancelled status
+            if handle_clone.status().await == TaskStatus::Cancelled {
+                info!("Task finished after cancellation: {}", task_id);
+                return;
+            }
+
+            match outcome {
                 Ok(output) => {
                     let result = TaskResult::success(task_id.clone(), output)
                         .with_execution_time(start.elapsed().as_millis() as u64);
@@ -302,4 +310,31 @@ mod tests {
         let handle = orchestrator.get_task("task-1").unwrap();
         assert_eq!(handle.status().await, TaskStatus::Cancelled);
     }
+
+    #[tokio::test]
+    async fn test_cancelled_running_task_stays_cancelled() {
+        let orchestrator = RuntimeOrchestrator::new();
+        let task = Task::new(
+            "task-1".to_string(),
+            "Test Task".to_string(),
+            "test-agent".to_string(),
+            "test input".to_string(),
+        );
+        let handle = orchestrator.submit_task(task);
+
+        orchestrator
+            .execute_task("task-1", |_task| async {
+                tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                Ok("Finished anyway".to_string())
+            })
+            .await
+            .unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        orchestrator.cancel_task("task-1").await.unwrap();
+
+        let result = handle.wait().await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        assert_eq!(handle.status().await, TaskStatus::Cancelled);
+        assert!(!result.success);
+    }
 }
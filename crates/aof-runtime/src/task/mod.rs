@@ -3,10 +3,17 @@
 //! Provides task scheduling and execution coordination for agents.
 
 use aof_core::AofResult;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::executor::StreamEvent;
+
+/// Lines of streamed output kept in a task's progress
+pub const PROGRESS_OUTPUT_LINES: usize = 20;
+
 /// Task execution status
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -72,10 +79,70 @@ impl Task {
     }
 }
 
+/// Progress of a running task, built from the agent's stream events
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskProgress {
+    /// When the task started running
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// When progress was last reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// Current agent iteration
+    pub iteration: usize,
+
+    /// Tool calls finished so far
+    pub tool_calls: usize,
+
+    /// Tools currently running
+    #[serde(default)]
+    pub running_tools: Vec<String>,
+
+    /// Most recent tool output, oldest first (at most `PROGRESS_OUTPUT_LINES`)
+    #[serde(default)]
+    pub output: VecDeque<String>,
+}
+
+impl TaskProgress {
+    /// Update progress from an agent stream event
+    pub fn record(&mut self, event: &StreamEvent) {
+        match event {
+            StreamEvent::IterationStart { iteration, .. } => self.iteration = *iteration,
+            StreamEvent::ToolCallStart { tool_name, .. } => self.running_tools.push(tool_name.clone()),
+            StreamEvent::ToolCallComplete { tool_name, success, error, output, .. } => {
+                if let Some(pos) = self.running_tools.iter().position(|t| t == tool_name) {
+                    self.running_tools.remove(pos);
+                }
+                self.tool_calls += 1;
+                let line = if *success {
+                    format!("✓ {}: {}", tool_name, output.as_deref().unwrap_or("done"))
+                } else {
+                    format!("✗ {}: {}", tool_name, error.as_deref().unwrap_or("failed"))
+                };
+                self.push_output(line);
+            }
+            StreamEvent::Error { message } => self.push_output(format!("✗ {}", message)),
+            _ => return,
+        }
+        self.updated_at = Some(Utc::now());
+    }
+
+    /// Append a line of output, dropping the oldest beyond the limit
+    pub fn push_output(&mut self, line: impl Into<String>) {
+        self.output.push_back(line.into());
+        while self.output.len() > PROGRESS_OUTPUT_LINES {
+            self.output.pop_front();
+        }
+    }
+}
+
 /// Task handle for async operations
 pub struct TaskHandle {
     task: Arc<RwLock<Task>>,
     result: Arc<RwLock<Option<TaskResult>>>,
+    progress: Arc<RwLock<TaskProgress>>,
 }
 
 impl TaskHandle {
@@ -84,6 +151,7 @@ impl TaskHandle {
         Self {
             task: Arc::new(RwLock::new(task)),
             result: Arc::new(RwLock::new(None)),
+            progress: Arc::new(RwLock::new(TaskProgress::default())),
         }
     }
 
@@ -116,6 +184,9 @@ impl TaskHandle {
 
     /// Update task status
     pub async fn update_status(&self, status: TaskStatus) {
+        if status == TaskStatus::Running {
+            self.progress.write().await.started_at.get_or_insert_with(Utc::now);
+        }
         self.task.write().await.status = status;
     }
 
@@ -123,6 +194,21 @@ impl TaskHandle {
     pub async fn set_result(&self, result: TaskResult) {
         *self.result.write().await = Some(result);
     }
+
+    /// Task result, once the task has finished
+    pub async fn result(&self) -> Option<TaskResult> {
+        self.result.read().await.clone()
+    }
+
+    /// Current progress
+    pub async fn progress(&self) -> TaskProgress {
+        self.progress.read().await.clone()
+    }
+
+    /// Update progress from an agent stream event
+    pub async fn record_progress(&self, event: &StreamEvent) {
+        self.progress.write().await.record(event);
+    }
 }
 
 /// Task execution result
@@ -241,6 +327,59 @@ mod tests {
         assert_eq!(handle.status().await, TaskStatus::Running);
     }
 
+    #[tokio::test]
+    async fn test_task_progress_from_stream_events() {
+        let handle = TaskHandle::new(Task::new(
+            "task-1".to_string(),
+            "Test Task".to_string(),
+            "test-agent".to_string(),
+            "test input".to_string(),
+        ));
+        handle.update_status(TaskStatus::Running).await;
+
+        handle
+            .record_progress(&StreamEvent::IterationStart { iteration: 2, max_iterations: 10 })
+            .await;
+        handle
+            .record_progress(&StreamEvent::ToolCallStart {
+                tool_name: "kubectl".to_string(),
+                tool_id: "t1".to_string(),
+                arguments: serde_json::json!({}),
+            })
+            .await;
+
+        let progress = handle.progress().await;
+        assert!(progress.started_at.is_some());
+        assert_eq!(progress.iteration, 2);
+        assert_eq!(progress.running_tools, vec!["kubectl"]);
+
+        handle
+            .record_progress(&StreamEvent::ToolCallComplete {
+                tool_name: "kubectl".to_string(),
+                tool_id: "t1".to_string(),
+                success: true,
+                execution_time_ms: 12,
+                error: None,
+                output: Some("3 pods running".to_string()),
+            })
+            .await;
+
+        let progress = handle.progress().await;
+        assert!(progress.running_tools.is_empty());
+        assert_eq!(progress.tool_calls, 1);
+        assert_eq!(progress.output, vec!["✓ kubectl: 3 pods running"]);
+    }
+
+    #[test]
+    fn test_task_progress_output_is_bounded() {
+        let mut progress = TaskProgress::default();
+        for i in 0..PROGRESS_OUTPUT_LINES + 5 {
+            progress.push_output(format!("line {}", i));
+        }
+        assert_eq!(progress.output.len(), PROGRESS_OUTPUT_LINES);
+        assert_eq!(progress.output.front().map(String::as_str), Some("line 5"));
+    }
+
     #[test]
     fn test_task_result_success() {
         let result = TaskResult::success("task-1".to_string(), "Success!".to_string());
@@ -31,6 +31,8 @@ impl MockModel {
                 temperature: 0.7,
                 max_tokens: None,
                 timeout_secs: 60,
+                stop: vec![],
+                seed: None,
                 headers: HashMap::new(),
                 extra: HashMap::new(),
             },
@@ -124,7 +126,15 @@ async fn test_executor_simple_execution() {
         max_iterations: 10,
         temperature: 0.7,
         max_tokens: Some(1000),
+        stop: Vec::new(),
+        seed: None,
         output_schema: None,
+        guardrails: vec![],
+        max_tool_calls_per_turn: None,
+        max_tool_calls_per_run: None,
+        max_continuations: None,
+        tool_result_page_size: None,
+        tool_discovery: false,
         extra: HashMap::new(),
     };
 
@@ -140,7 +150,7 @@ async fn test_executor_simple_execution() {
     }];
 
     let model = Box::new(MockModel::new(responses));
-    let executor = AgentExecutor::new(config, model, None, None);
+    let executor = AgentExecutor::new(config, model, None, None).unwrap();
 
     let mut context = AgentContext::new("Hello");
     let result = executor.execute(&mut context).await.unwrap();
@@ -164,7 +174,15 @@ async fn test_executor_with_tool_calls() {
         max_iterations: 10,
         temperature: 0.7,
         max_tokens: None,
+        stop: Vec::new(),
+        seed: None,
         output_schema: None,
+        guardrails: vec![],
+        max_tool_calls_per_turn: None,
+        max_tool_calls_per_run: None,
+        max_continuations: None,
+        tool_result_page_size: None,
+        tool_discovery: false,
         extra: HashMap::new(),
     };
 
@@ -200,7 +218,7 @@ async fn test_executor_with_tool_calls() {
     let model = Box::new(MockModel::new(responses));
     let tool_executor = Arc::new(MockToolExecutor { should_fail: false });
 
-    let executor = AgentExecutor::new(config, model, Some(tool_executor), None);
+    let executor = AgentExecutor::new(config, model, Some(tool_executor), None).unwrap();
 
     let mut context = AgentContext::new("Do something with a tool");
     let result = executor.execute(&mut context).await.unwrap();
@@ -224,7 +242,15 @@ async fn test_executor_max_iterations() {
         max_iterations: 2,
         temperature: 0.7,
         max_tokens: None,
+        stop: Vec::new(),
+        seed: None,
         output_schema: None,
+        guardrails: vec![],
+        max_tool_calls_per_turn: None,
+        max_tool_calls_per_run: None,
+        max_continuations: None,
+        tool_result_page_size: None,
+        tool_discovery: false,
         extra: HashMap::new(),
     };
 
@@ -268,7 +294,7 @@ async fn test_executor_max_iterations() {
     let model = Box::new(MockModel::new(responses));
     let tool_executor = Arc::new(MockToolExecutor { should_fail: false });
 
-    let executor = AgentExecutor::new(config, model, Some(tool_executor), None);
+    let executor = AgentExecutor::new(config, model, Some(tool_executor), None).unwrap();
 
     let mut context = AgentContext::new("Keep calling tools");
     let result = executor.execute(&mut context).await;
@@ -291,7 +317,15 @@ async fn test_executor_tool_failure() {
         max_iterations: 10,
         temperature: 0.7,
         max_tokens: None,
+        stop: Vec::new(),
+        seed: None,
         output_schema: None,
+        guardrails: vec![],
+        max_tool_calls_per_turn: None,
+        max_tool_calls_per_run: None,
+        max_continuations: None,
+        tool_result_page_size: None,
+        tool_discovery: false,
         extra: HashMap::new(),
     };
 
@@ -319,7 +353,7 @@ async fn test_executor_tool_failure() {
     let model = Box::new(MockModel::new(responses));
     let tool_executor = Arc::new(MockToolExecutor { should_fail: true });
 
-    let executor = AgentExecutor::new(config, model, Some(tool_executor), None);
+    let executor = AgentExecutor::new(config, model, Some(tool_executor), None).unwrap();
 
     let mut context = AgentContext::new("Test tool failure");
     let result = executor.execute(&mut context).await.unwrap();
@@ -350,7 +384,15 @@ async fn test_executor_stop_reasons() {
             max_iterations: 10,
             temperature: 0.7,
             max_tokens: None,
+            stop: Vec::new(),
+            seed: None,
             output_schema: None,
+            guardrails: vec![],
+            max_tool_calls_per_turn: None,
+            max_tool_calls_per_run: None,
+            max_continuations: None,
+            tool_result_page_size: None,
+            tool_discovery: false,
             extra: HashMap::new(),
         };
 
@@ -363,7 +405,7 @@ async fn test_executor_stop_reasons() {
         }];
 
         let model = Box::new(MockModel::new(responses));
-        let executor = AgentExecutor::new(config, model, None, None);
+        let executor = AgentExecutor::new(config, model, None, None).unwrap();
 
         let mut context = AgentContext::new("Test");
         let result = executor.execute(&mut context).await.unwrap();
@@ -386,7 +428,15 @@ async fn test_executor_content_filter() {
         max_iterations: 10,
         temperature: 0.7,
         max_tokens: None,
+        stop: Vec::new(),
+        seed: None,
         output_schema: None,
+        guardrails: vec![],
+        max_tool_calls_per_turn: None,
+        max_tool_calls_per_run: None,
+        max_continuations: None,
+        tool_result_page_size: None,
+        tool_discovery: false,
         extra: HashMap::new(),
     };
 
@@ -399,7 +449,7 @@ async fn test_executor_content_filter() {
     }];
 
     let model = Box::new(MockModel::new(responses));
-    let executor = AgentExecutor::new(config, model, None, None);
+    let executor = AgentExecutor::new(config, model, None, None).unwrap();
 
     let mut context = AgentContext::new("Test content filter");
     let result = executor.execute(&mut context).await;
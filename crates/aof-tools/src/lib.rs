@@ -29,6 +29,10 @@
 //! - `git` - Legacy per-operation Git tools
 //! - `terraform` - Legacy per-operation Terraform tools
 //! - `http` - HTTP request tool
+//! - `network` - Network diagnostics and certificate expiry checks
+//! - `transform` - In-process jq transforms over JSON/YAML
+//! - `k8s-api` - Kubernetes API reads without the kubectl binary
+//! - `featureflags` - LaunchDarkly/Unleash feature flag management
 //! - `all` - Enable all tools
 //!
 //! # Example
@@ -37,9 +41,9 @@
 //! use aof_tools::{ToolRegistry, KubectlTool, GitTool, ShellTool};
 //!
 //! let mut registry = ToolRegistry::new();
-//! registry.register(KubectlTool::new());
-//! registry.register(GitTool::new());
-//! registry.register(ShellTool::new());
+//! registry.register(KubectlTool::new())?;
+//! registry.register(GitTool::new())?;
+//! registry.register(ShellTool::new())?;
 //!
 //! // Use with agent
 //! let executor = registry.into_executor();
@@ -75,6 +79,27 @@ pub use tools::cli::AwsTool;
 /// Unified helm tool - execute any helm command
 pub use tools::cli::HelmTool;
 
+/// How unified tools parse and run their `command` string
+pub use tools::common::CommandPolicy;
+
+/// Runs the external commands behind CLI tools (swappable for tests)
+pub use tools::common::{CommandOutput, CommandRunner, ProcessRunner};
+#[cfg(feature = "test-util")]
+pub use tools::common::FakeRunner;
+
+/// Local HTTP server for testing API clients
+#[cfg(feature = "test-util")]
+pub use tools::mock_http::{MockHttpServer, MockResponse, RecordedRequest};
+
+/// Output format negotiation for tools that return rows
+pub use tools::output::{OutputFormat, RowView};
+
+/// Connectivity probes for configured contexts
+pub use tools::context_health::{
+    ContextHealth, ContextProbe, HealthReport, HealthStatus, ProbeOutcome, ProbeTarget,
+    DEFAULT_PROBE_TIMEOUT_SECS,
+};
+
 // ============================================================================
 // File and Shell Tools
 // ============================================================================
@@ -88,6 +113,24 @@ pub use tools::shell::ShellTool;
 #[cfg(feature = "http")]
 pub use tools::http::HttpTool;
 
+#[cfg(feature = "reqwest")]
+pub use tools::rate_limit::{BackoffPolicy, QuotaReport, QuotaTracker, RateLimit};
+
+#[cfg(feature = "network")]
+pub use tools::netdiag::NetDiagTool;
+
+#[cfg(feature = "network")]
+pub use tools::cert::CertCheckTool;
+
+#[cfg(feature = "transform")]
+pub use tools::transform::DataTransformTool;
+
+#[cfg(feature = "k8s-api")]
+pub use tools::k8s_api::K8sApiTool;
+
+#[cfg(feature = "featureflags")]
+pub use tools::feature_flags::FeatureFlagTool;
+
 #[cfg(feature = "observability")]
 pub use tools::observability::{ObservabilityTools, PrometheusQueryTool, LokiQueryTool, ElasticsearchQueryTool, VictoriaMetricsQueryTool};
 
@@ -104,15 +147,45 @@ pub use tools::servicenow::{ServiceNowTools, ServiceNowIncidentCreateTool, Servi
 // Legacy Per-Operation Tools (Backward Compatibility)
 // ============================================================================
 
+#[cfg(feature = "kubectl")]
+pub use tools::pod_logs::{PodLogsTool, LogLine};
+
+#[cfg(feature = "kubectl")]
+pub use tools::k8s_config_diff::{K8sConfigDiffTool, ConfigKeyDiff, KeyChange};
+
+#[cfg(feature = "kubectl")]
+pub use tools::k8s_resource_quota::{ResourceQuotaTool, QuotaSummary, ResourceUsage, NodeCapacity};
+
+#[cfg(feature = "kubectl")]
+pub use tools::manifests::{split_manifest, split_rendered, ManifestResource, RenderedResource};
+
+#[cfg(feature = "kubectl")]
+pub use tools::helm::{HelmTemplateTool, LintMessage};
+
+#[cfg(feature = "kubectl")]
+pub use tools::kustomize::KustomizeBuildTool;
+
 #[cfg(feature = "kubectl")]
 pub use tools::kubectl::{KubectlTools, KubectlGetTool, KubectlApplyTool, KubectlDeleteTool, KubectlLogsTool, KubectlExecTool, KubectlDescribeTool};
 
 #[cfg(feature = "docker")]
 pub use tools::docker::{DockerTools, DockerPsTool, DockerStatsTool, DockerBuildTool, DockerRunTool, DockerLogsTool, DockerExecTool, DockerImagesTool};
 
+#[cfg(feature = "git")]
+pub use tools::commit_cache::{CommitCache, CommitCachedTool};
+
+#[cfg(feature = "git")]
+pub use tools::git_diff_summary::{GitDiffSummaryTool, FileCategory, FileChange};
+
+#[cfg(feature = "git")]
+pub use tools::git_changelog::{GitChangelogTool, ChangelogEntry, ChangelogGroup};
+
 #[cfg(feature = "git")]
 pub use tools::git::{GitTools, GitStatusTool, GitDiffTool, GitLogTool, GitCommitTool, GitBranchTool, GitCheckoutTool, GitPullTool, GitPushTool};
 
+#[cfg(feature = "terraform")]
+pub use tools::terraform_state::{TerraformStateTool, StateAddress};
+
 #[cfg(feature = "terraform")]
 pub use tools::terraform::{TerraformTools, TerraformInitTool, TerraformPlanTool, TerraformApplyTool, TerraformDestroyTool, TerraformOutputTool};
 
@@ -67,7 +67,7 @@ impl ToolRegistry {
         registry.register_category(crate::tools::file::FileTools::all());
 
         #[cfg(feature = "shell")]
-        registry.register(crate::tools::shell::ShellTool::new());
+        registry.register_or_replace(crate::tools::shell::ShellTool::new());
 
         #[cfg(feature = "kubectl")]
         registry.register_category(crate::tools::kubectl::KubectlTools::all());
@@ -84,30 +84,61 @@ impl ToolRegistry {
         registry
     }
 
-    /// Register a single tool
-    pub fn register<T: Tool + 'static>(&mut self, tool: T) -> &mut Self {
+    /// Register a single tool; errors if a tool with the same name exists
+    pub fn register<T: Tool + 'static>(&mut self, tool: T) -> AofResult<&mut Self> {
+        let name = tool.config().name.clone();
+        if self.tools.contains_key(&name) {
+            return Err(AofError::tool(format!("Tool already registered: {}", name)));
+        }
+        Ok(self.register_or_replace(tool))
+    }
+
+    /// Register a single tool, replacing any tool with the same name
+    pub fn register_or_replace<T: Tool + 'static>(&mut self, tool: T) -> &mut Self {
         let name = tool.config().name.clone();
         info!(tool = %name, "Registering tool");
-        self.tools.insert(name, Arc::new(tool));
+        if self.tools.insert(name.clone(), Arc::new(tool)).is_some() {
+            debug!(tool = %name, "Replaced existing tool");
+        }
         self
     }
 
-    /// Register a tool with a specific category
+    /// Register a single tool unless the name is taken; returns whether it was added
+    pub fn try_register<T: Tool + 'static>(&mut self, tool: T) -> bool {
+        if self.tools.contains_key(&tool.config().name) {
+            debug!(tool = %tool.config().name, "Tool already registered, skipping");
+            return false;
+        }
+        self.register_or_replace(tool);
+        true
+    }
+
+    /// Register a repo-scoped tool whose results are cached per git commit
+    #[cfg(feature = "git")]
+    pub fn register_commit_cached<T: Tool + 'static>(
+        &mut self,
+        tool: T,
+        cache: Arc<crate::tools::commit_cache::CommitCache>,
+    ) -> AofResult<&mut Self> {
+        self.register(crate::tools::commit_cache::CommitCachedTool::new(tool, cache))
+    }
+
+    /// Register a tool with a specific category; errors on a duplicate name
     pub fn register_with_category<T: Tool + 'static>(
         &mut self,
         tool: T,
         category: ToolCategory,
-    ) -> &mut Self {
+    ) -> AofResult<&mut Self> {
         let name = tool.config().name.clone();
-        self.tools.insert(name.clone(), Arc::new(tool));
+        self.register(tool)?;
         self.categories
             .entry(category)
             .or_default()
             .push(name);
-        self
+        Ok(self)
     }
 
-    /// Register multiple tools from a category
+    /// Register multiple tools from a category, replacing tools with the same name
     pub fn register_category(&mut self, tools: Vec<Box<dyn Tool>>) -> &mut Self {
         for tool in tools {
             let name = tool.config().name.clone();
@@ -116,7 +147,7 @@ impl ToolRegistry {
         self
     }
 
-    /// Register tools with category tracking
+    /// Register tools with category tracking, replacing tools with the same name
     pub fn register_category_with_name(
         &mut self,
         category: ToolCategory,
@@ -218,7 +249,13 @@ impl ToolExecutor for BuiltinToolExecutor {
         debug!(tool = %name, "Executing built-in tool");
         let start = std::time::Instant::now();
 
-        match tool.execute(input).await {
+        // Quota of vendor APIs the tool called ends up in its result metadata
+        #[cfg(feature = "reqwest")]
+        let outcome = crate::tools::rate_limit::track_call(tool.execute(input)).await;
+        #[cfg(not(feature = "reqwest"))]
+        let outcome = tool.execute(input).await;
+
+        match outcome {
             Ok(result) => {
                 let elapsed = start.elapsed().as_millis() as u64;
                 debug!(tool = %name, elapsed_ms = %elapsed, success = %result.success, "Tool execution complete");
@@ -345,7 +382,7 @@ mod tests {
     #[test]
     fn test_registry_register() {
         let mut registry = ToolRegistry::new();
-        registry.register(MockTool::new("test_tool"));
+        registry.register(MockTool::new("test_tool")).unwrap();
 
         assert_eq!(registry.len(), 1);
         assert!(registry.get("test_tool").is_some());
@@ -355,8 +392,8 @@ mod tests {
     #[test]
     fn test_registry_list_names() {
         let mut registry = ToolRegistry::new();
-        registry.register(MockTool::new("tool1"));
-        registry.register(MockTool::new("tool2"));
+        registry.register(MockTool::new("tool1")).unwrap();
+        registry.register(MockTool::new("tool2")).unwrap();
 
         let names = registry.list_names();
         assert_eq!(names.len(), 2);
@@ -364,10 +401,53 @@ mod tests {
         assert!(names.contains(&"tool2".to_string()));
     }
 
+    #[test]
+    fn test_register_rejects_duplicate() {
+        let mut registry = ToolRegistry::new();
+        registry.register(MockTool::new("kubectl")).unwrap();
+
+        let err = registry.register(MockTool::new("kubectl")).err().unwrap();
+        assert!(err.to_string().contains("Tool already registered: kubectl"));
+        assert_eq!(registry.len(), 1);
+
+        let err = registry
+            .register_with_category(MockTool::new("kubectl"), ToolCategory::Kubectl)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("already registered"));
+        assert!(registry.list_by_category(&ToolCategory::Kubectl).is_empty());
+    }
+
+    #[test]
+    fn test_register_or_replace_overwrites() {
+        let mut registry = ToolRegistry::new();
+        registry.register(MockTool::new("kubectl")).unwrap();
+
+        let mut replacement = MockTool::new("kubectl");
+        replacement.config.description = "Replacement".to_string();
+        registry.register_or_replace(replacement);
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("kubectl").unwrap().config().description, "Replacement");
+    }
+
+    #[test]
+    fn test_try_register_keeps_first() {
+        let mut registry = ToolRegistry::new();
+        assert!(registry.try_register(MockTool::new("kubectl")));
+
+        let mut duplicate = MockTool::new("kubectl");
+        duplicate.config.description = "Duplicate".to_string();
+        assert!(!registry.try_register(duplicate));
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("kubectl").unwrap().config().description, "Mock tool: kubectl");
+    }
+
     #[tokio::test]
     async fn test_executor_execute() {
         let mut registry = ToolRegistry::new();
-        registry.register(MockTool::new("test_tool"));
+        registry.register(MockTool::new("test_tool")).unwrap();
 
         let executor = registry.into_executor();
         let input = ToolInput::new(serde_json::json!({}));
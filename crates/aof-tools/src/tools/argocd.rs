@@ -26,6 +26,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all ArgoCD tools
 pub struct ArgoCDTools;
@@ -193,7 +194,7 @@ impl Tool for ArgoCDAppListTool {
             params.push(("repo", r));
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -351,7 +352,7 @@ impl Tool for ArgoCDAppGetTool {
             url = format!("{}?refresh={}", url, ref_mode);
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -558,7 +559,7 @@ impl Tool for ArgoCDAppSyncTool {
             payload["syncOptions"] = serde_json::json!({"items": opts});
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -692,7 +693,7 @@ impl Tool for ArgoCDAppRollbackTool {
             app_name
         );
 
-        let get_response = match client.get(&get_url).send().await {
+        let get_response = match client.get(&get_url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -745,7 +746,7 @@ impl Tool for ArgoCDAppRollbackTool {
             "dryRun": dry_run
         });
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -857,7 +858,7 @@ impl Tool for ArgoCDAppHistoryTool {
             app_name
         );
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -1000,7 +1001,7 @@ impl Tool for ArgoCDAppDiffTool {
             params.push(("revision", rev.clone()));
         }
 
-        let manifests_response = match client.get(&manifests_url).query(&params).send().await {
+        let manifests_response = match client.get(&manifests_url).query(&params).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -1032,7 +1033,7 @@ impl Tool for ArgoCDAppDiffTool {
             app_name
         );
 
-        let app_response = match client.get(&app_url).send().await {
+        let app_response = match client.get(&app_url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
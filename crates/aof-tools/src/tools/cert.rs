@@ -0,0 +1,307 @@
+//! Certificate Expiry Tool
+//!
+//! Tool for monitoring TLS certificate expiry across endpoints or PEM files.
+//!
+//! ## Features
+//!
+//! - Inspect the leaf certificate served by one or more `host:port` endpoints
+//!   (IPv6 as `[addr]:port`)
+//! - Inspect the leaf certificate in a local PEM file
+//! - Report issuer, subject, SANs and days until expiry
+//! - Flag certificates expiring within a configurable threshold
+//!
+//! ## Prerequisites
+//!
+//! - Requires `network` feature flag
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, SystemTime};
+use tracing::debug;
+
+use super::common::{create_schema, tool_config_with_timeout};
+use super::netdiag::{fetch_peer_certificate, parse_certificate, CertificateInfo};
+
+/// Default expiry warning threshold in days
+const DEFAULT_WARN_DAYS: i64 = 30;
+
+/// Default TLS port when an endpoint does not specify one
+const DEFAULT_TLS_PORT: u16 = 443;
+
+/// Certificate expiry check tool
+pub struct CertCheckTool {
+    config: ToolConfig,
+}
+
+impl CertCheckTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "endpoints": {
+                    "type": "array",
+                    "description": "Endpoints to check as host:port or [ipv6]:port (port defaults to 443)",
+                    "items": { "type": "string" }
+                },
+                "pem_file": {
+                    "type": "string",
+                    "description": "Path to a PEM file; the first certificate is treated as the leaf"
+                },
+                "warn_days": {
+                    "type": "integer",
+                    "description": "Flag certificates expiring within this many days",
+                    "default": 30
+                },
+                "timeout_secs": {
+                    "type": "integer",
+                    "description": "Connection timeout per endpoint in seconds",
+                    "default": 10
+                }
+            }),
+            vec![],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "cert_check",
+                "Check TLS certificate expiry for host:port endpoints or a PEM file. Returns issuer, subject, SANs, days until expiry, and flags certificates expiring soon.",
+                parameters,
+                120,
+            ),
+        }
+    }
+}
+
+impl Default for CertCheckTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for CertCheckTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let endpoints: Vec<String> = input.get_arg("endpoints").unwrap_or_default();
+        let pem_file: Option<String> = input.get_arg("pem_file").ok();
+        let warn_days: i64 = input.get_arg("warn_days").unwrap_or(DEFAULT_WARN_DAYS);
+        let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(10);
+
+        if endpoints.is_empty() && pem_file.is_none() {
+            return Ok(ToolResult::error(
+                "Provide at least one of 'endpoints' or 'pem_file'",
+            ));
+        }
+
+        debug!(endpoints = ?endpoints, pem_file = ?pem_file, warn_days, "Checking certificates");
+
+        let now = SystemTime::now();
+        let mut reports = Vec::new();
+
+        if let Some(path) = &pem_file {
+            let report = match tokio::fs::read(path).await {
+                Ok(data) => match parse_pem_certificate(&data) {
+                    Ok(info) => certificate_report(path, &info, warn_days, now),
+                    Err(e) => error_report(path, e),
+                },
+                Err(e) => error_report(path, format!("Failed to read {}: {}", path, e)),
+            };
+            reports.push(report);
+        }
+
+        for endpoint in &endpoints {
+            let report = match parse_endpoint(endpoint) {
+                Ok((host, port)) => {
+                    match fetch_peer_certificate(&host, port, Duration::from_secs(timeout_secs))
+                        .await
+                        .and_then(|der| parse_certificate(&der))
+                    {
+                        Ok(info) => certificate_report(endpoint, &info, warn_days, now),
+                        Err(e) => error_report(endpoint, e),
+                    }
+                }
+                Err(e) => error_report(endpoint, e),
+            };
+            reports.push(report);
+        }
+
+        let count = |key: &str| reports.iter().filter(|r| r[key] == true).count();
+        let summary = serde_json::json!({
+            "total": reports.len(),
+            "expiring_soon": count("expiring_soon"),
+            "expired": count("expired"),
+            "errors": reports.iter().filter(|r| r.get("error").is_some()).count(),
+        });
+
+        Ok(ToolResult::success(serde_json::json!({
+            "warn_days": warn_days,
+            "summary": summary,
+            "certificates": reports,
+        })))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Parse the first certificate in a PEM-encoded buffer
+pub fn parse_pem_certificate(data: &[u8]) -> Result<CertificateInfo, String> {
+    let (_, pem) = x509_parser::pem::parse_x509_pem(data)
+        .map_err(|e| format!("Failed to parse PEM: {}", e))?;
+    if pem.label != "CERTIFICATE" {
+        return Err(format!("Expected a CERTIFICATE PEM block, found '{}'", pem.label));
+    }
+    parse_certificate(&pem.contents)
+}
+
+/// Split `host:port` into its parts, defaulting the port to 443
+///
+/// IPv6 addresses take the bracketed form (`[::1]:8443`) when they carry a
+/// port; a bare `::1` uses the default port. The returned host has no brackets.
+fn parse_endpoint(endpoint: &str) -> Result<(String, u16), String> {
+    let endpoint = endpoint
+        .trim()
+        .trim_start_matches("https://")
+        .trim_end_matches('/');
+
+    if let Ok(addr) = endpoint.parse::<SocketAddr>() {
+        return Ok((addr.ip().to_string(), addr.port()));
+    }
+    let unbracketed = endpoint
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(endpoint);
+    if let Ok(ip) = unbracketed.parse::<IpAddr>() {
+        return Ok((ip.to_string(), DEFAULT_TLS_PORT));
+    }
+    if endpoint.starts_with('[') {
+        return Err(format!("Invalid IPv6 endpoint '{}'", endpoint));
+    }
+
+    match endpoint.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse::<u16>()
+                .map_err(|_| format!("Invalid port in endpoint '{}'", endpoint))?;
+            Ok((host.to_string(), port))
+        }
+        None => Ok((endpoint.to_string(), DEFAULT_TLS_PORT)),
+    }
+}
+
+/// Build the per-certificate report, flagging expiry against `warn_days`
+fn certificate_report(
+    source: &str,
+    info: &CertificateInfo,
+    warn_days: i64,
+    now: SystemTime,
+) -> serde_json::Value {
+    let days = info.days_until_expiry(now);
+    serde_json::json!({
+        "source": source,
+        "subject": info.subject,
+        "issuer": info.issuer,
+        "sans": info.sans,
+        "not_before": info.not_before,
+        "not_after": info.not_after,
+        "days_until_expiry": days,
+        "expired": days < 0,
+        "expiring_soon": days < warn_days,
+    })
+}
+
+fn error_report(source: &str, error: String) -> serde_json::Value {
+    serde_json::json!({
+        "source": source,
+        "error": error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/test-cert.pem");
+
+    /// Fixture validity: 2025-01-01T00:00:00Z .. 2026-01-01T00:00:00Z
+    fn at(unix: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(unix)
+    }
+
+    #[test]
+    fn test_cert_check_config() {
+        let tool = CertCheckTool::new();
+        let config = tool.config();
+
+        assert_eq!(config.name, "cert_check");
+        assert!(config.description.contains("expiry"));
+    }
+
+    #[test]
+    fn test_parse_fixture_certificate() {
+        let data = std::fs::read(FIXTURE).unwrap();
+        let info = parse_pem_certificate(&data).unwrap();
+
+        assert!(info.subject.contains("CN=test.aof.sh"));
+        assert!(info.issuer.contains("O=AOF Test"));
+        assert_eq!(info.sans, vec!["test.aof.sh", "www.test.aof.sh", "127.0.0.1"]);
+        assert_eq!(info.not_after, "2026-01-01T00:00:00+00:00");
+        assert_eq!(info.not_after_unix, 1_767_225_600);
+    }
+
+    #[test]
+    fn test_expiry_threshold_flag() {
+        let data = std::fs::read(FIXTURE).unwrap();
+        let info = parse_pem_certificate(&data).unwrap();
+
+        // 2025-12-01: 31 days left, outside a 30 day threshold
+        let report = certificate_report("fixture", &info, 30, at(1_764_547_200));
+        assert_eq!(report["days_until_expiry"], 31);
+        assert_eq!(report["expiring_soon"], false);
+        assert_eq!(report["expired"], false);
+
+        // 2025-12-15: 17 days left, inside the threshold
+        let report = certificate_report("fixture", &info, 30, at(1_765_756_800));
+        assert_eq!(report["days_until_expiry"], 17);
+        assert_eq!(report["expiring_soon"], true);
+
+        // 2026-02-01: already expired
+        let report = certificate_report("fixture", &info, 30, at(1_769_904_000));
+        assert_eq!(report["expired"], true);
+    }
+
+    #[test]
+    fn test_parse_endpoint() {
+        assert_eq!(parse_endpoint("example.com:8443").unwrap(), ("example.com".to_string(), 8443));
+        assert_eq!(parse_endpoint("example.com").unwrap(), ("example.com".to_string(), 443));
+        assert_eq!(parse_endpoint("https://example.com/").unwrap(), ("example.com".to_string(), 443));
+        assert!(parse_endpoint("example.com:https").is_err());
+    }
+
+    #[test]
+    fn test_parse_ipv6_endpoint() {
+        assert_eq!(parse_endpoint("[::1]:8443").unwrap(), ("::1".to_string(), 8443));
+        assert_eq!(parse_endpoint("https://[2001:db8::1]/").unwrap(), ("2001:db8::1".to_string(), 443));
+        assert_eq!(parse_endpoint("2001:db8::1").unwrap(), ("2001:db8::1".to_string(), 443));
+        assert_eq!(parse_endpoint("10.0.0.1:443").unwrap(), ("10.0.0.1".to_string(), 443));
+        assert!(parse_endpoint("[::1]:https").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_pem_file() {
+        let tool = CertCheckTool::new();
+        let input = ToolInput::new(serde_json::json!({ "pem_file": FIXTURE }));
+
+        let result = tool.execute(input).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.data["summary"]["total"], 1);
+        assert_eq!(result.data["certificates"][0]["subject"], "CN=test.aof.sh, O=AOF Test");
+    }
+
+    #[tokio::test]
+    async fn test_execute_requires_input() {
+        let tool = CertCheckTool::new();
+        let result = tool.execute(ToolInput::new(serde_json::json!({}))).await.unwrap();
+        assert!(!result.success);
+    }
+}
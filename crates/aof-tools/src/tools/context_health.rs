@@ -0,0 +1,397 @@
+//! Context Health Probes
+//!
+//! Cheap connectivity checks for every configured context, backing
+//! `aofctl contexts health` and the `/contexts health` bot command.
+//!
+//! Each context is probed for the connections it configures:
+//!
+//! - Kubernetes: `kubectl version -o json` against the context's kubeconfig and
+//!   cluster/context (fails fast when the API server is unreachable)
+//! - AWS: `aws sts get-caller-identity` with the context's profile and region
+//!
+//! Probes run concurrently and the results are rendered as a status table via
+//! `aof-viz`.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use aof_viz::{RenderConfig, ToolRenderer};
+use serde::Serialize;
+use tokio::task::JoinSet;
+
+use super::common::CommandRunner;
+
+/// Timeout for a single probe
+pub const DEFAULT_PROBE_TIMEOUT_SECS: u64 = 10;
+
+/// A connection to check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProbeTarget {
+    /// Kubernetes API server
+    Kubernetes {
+        kubeconfig: Option<String>,
+        /// kubeconfig context to use
+        context: Option<String>,
+    },
+    /// AWS credentials
+    Aws {
+        profile: Option<String>,
+        region: Option<String>,
+    },
+}
+
+impl ProbeTarget {
+    /// Short label for tables ("kubernetes", "aws")
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Kubernetes { .. } => "kubernetes",
+            Self::Aws { .. } => "aws",
+        }
+    }
+
+    /// Program and arguments for the probe
+    fn command(&self, timeout_secs: u64) -> (&'static str, Vec<String>) {
+        let mut args = Vec::new();
+        match self {
+            Self::Kubernetes { kubeconfig, context } => {
+                if let Some(kubeconfig) = kubeconfig {
+                    args.push(format!("--kubeconfig={}", kubeconfig));
+                }
+                if let Some(context) = context {
+                    args.push(format!("--context={}", context));
+                }
+                args.push(format!("--request-timeout={}s", timeout_secs));
+                args.extend(["version".to_string(), "-o".to_string(), "json".to_string()]);
+                ("kubectl", args)
+            }
+            Self::Aws { profile, region } => {
+                args.extend(["sts".to_string(), "get-caller-identity".to_string()]);
+                if let Some(profile) = profile {
+                    args.push(format!("--profile={}", profile));
+                }
+                if let Some(region) = region {
+                    args.push(format!("--region={}", region));
+                }
+                args.push("--output=json".to_string());
+                ("aws", args)
+            }
+        }
+    }
+
+    /// Short description of a successful probe's output
+    fn describe_success(&self, stdout: &str) -> String {
+        let json: serde_json::Value = serde_json::from_str(stdout).unwrap_or_default();
+        match self {
+            Self::Kubernetes { .. } => json
+                .pointer("/serverVersion/gitVersion")
+                .and_then(|v| v.as_str())
+                .map(|v| format!("server {}", v))
+                .unwrap_or_else(|| "reachable".to_string()),
+            Self::Aws { .. } => json
+                .get("Account")
+                .and_then(|v| v.as_str())
+                .map(|v| format!("account {}", v))
+                .unwrap_or_else(|| "reachable".to_string()),
+        }
+    }
+}
+
+/// The connections configured for one context
+#[derive(Debug, Clone)]
+pub struct ContextProbe {
+    /// Context name
+    pub context: String,
+    /// Connections to check (empty: nothing to probe)
+    pub targets: Vec<ProbeTarget>,
+}
+
+/// Result of one probe
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeOutcome {
+    /// Probe kind ("kubernetes", "aws")
+    pub kind: String,
+    pub reachable: bool,
+    /// Server version / account on success, first error line on failure
+    pub detail: String,
+    pub duration_ms: u64,
+}
+
+/// Overall status of a context
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// Every probe succeeded
+    Reachable,
+    /// Some probes failed
+    Degraded,
+    /// Every probe failed
+    Unreachable,
+    /// The context configures no connections
+    Unconfigured,
+}
+
+impl HealthStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Reachable => "✅ reachable",
+            Self::Degraded => "⚠️ degraded",
+            Self::Unreachable => "❌ unreachable",
+            Self::Unconfigured => "➖ no probes",
+        }
+    }
+}
+
+/// Probe results for one context
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextHealth {
+    pub context: String,
+    pub status: HealthStatus,
+    pub probes: Vec<ProbeOutcome>,
+}
+
+impl ContextHealth {
+    /// Aggregate probe outcomes into a context status
+    pub fn new(context: impl Into<String>, probes: Vec<ProbeOutcome>) -> Self {
+        let reachable = probes.iter().filter(|p| p.reachable).count();
+        let status = if probes.is_empty() {
+            HealthStatus::Unconfigured
+        } else if reachable == probes.len() {
+            HealthStatus::Reachable
+        } else if reachable == 0 {
+            HealthStatus::Unreachable
+        } else {
+            HealthStatus::Degraded
+        };
+        Self {
+            context: context.into(),
+            status,
+            probes,
+        }
+    }
+
+    /// One-line summary of the probes
+    fn detail(&self) -> String {
+        self.probes
+            .iter()
+            .map(|p| format!("{}: {}", p.kind, p.detail))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// Health of every context
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HealthReport {
+    /// Contexts, in the order they were given
+    pub contexts: Vec<ContextHealth>,
+}
+
+impl HealthReport {
+    /// Probe every context concurrently
+    pub async fn probe(
+        runner: Arc<dyn CommandRunner>,
+        probes: Vec<ContextProbe>,
+        timeout_secs: u64,
+    ) -> Self {
+        let mut tasks = JoinSet::new();
+        for (index, probe) in probes.iter().enumerate() {
+            for target in probe.targets.clone() {
+                let runner = runner.clone();
+                tasks.spawn(async move {
+                    (index, run_probe(runner.as_ref(), &target, timeout_secs).await)
+                });
+            }
+        }
+
+        let mut outcomes: Vec<Vec<ProbeOutcome>> = vec![Vec::new(); probes.len()];
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok((index, outcome)) = joined {
+                outcomes[index].push(outcome);
+            }
+        }
+
+        let contexts = probes
+            .into_iter()
+            .zip(outcomes)
+            .map(|(probe, mut outcomes)| {
+                outcomes.sort_by(|a, b| a.kind.cmp(&b.kind));
+                ContextHealth::new(probe.context, outcomes)
+            })
+            .collect();
+        Self { contexts }
+    }
+
+    /// Number of fully reachable contexts
+    pub fn reachable_count(&self) -> usize {
+        self.contexts
+            .iter()
+            .filter(|c| c.status == HealthStatus::Reachable)
+            .count()
+    }
+
+    /// Whether any probed context has a failing probe
+    pub fn has_failures(&self) -> bool {
+        self.contexts
+            .iter()
+            .any(|c| matches!(c.status, HealthStatus::Degraded | HealthStatus::Unreachable))
+    }
+
+    /// Status table plus a summary line
+    pub fn render(&self, config: RenderConfig) -> String {
+        if self.contexts.is_empty() {
+            return "No contexts configured".to_string();
+        }
+
+        let rows: Vec<Vec<String>> = self
+            .contexts
+            .iter()
+            .map(|c| vec![c.context.clone(), c.status.label().to_string(), c.detail()])
+            .collect();
+        let rows: Vec<Vec<&str>> = rows
+            .iter()
+            .map(|r| r.iter().map(String::as_str).collect())
+            .collect();
+
+        let table = ToolRenderer::new(config).render_table(&["CONTEXT", "STATUS", "DETAIL"], &rows);
+        format!(
+            "{}\n\n{}/{} contexts reachable",
+            table,
+            self.reachable_count(),
+            self.contexts.len()
+        )
+    }
+}
+
+async fn run_probe(runner: &dyn CommandRunner, target: &ProbeTarget, timeout_secs: u64) -> ProbeOutcome {
+    let (program, args) = target.command(timeout_secs);
+    let started = Instant::now();
+    let result = runner.run(program, &args, None, timeout_secs).await;
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let (reachable, detail) = match result {
+        Ok(output) if output.success => (true, target.describe_success(&output.stdout)),
+        Ok(output) => (
+            false,
+            output
+                .stderr
+                .lines()
+                .map(str::trim)
+                .find(|l| !l.is_empty())
+                .unwrap_or("probe failed")
+                .to_string(),
+        ),
+        Err(e) => (false, e),
+    };
+
+    ProbeOutcome {
+        kind: target.kind().to_string(),
+        reachable,
+        detail,
+        duration_ms,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::{CommandOutput, FakeRunner};
+
+    fn outcome(kind: &str, reachable: bool, detail: &str) -> ProbeOutcome {
+        ProbeOutcome {
+            kind: kind.to_string(),
+            reachable,
+            detail: detail.to_string(),
+            duration_ms: 5,
+        }
+    }
+
+    #[test]
+    fn test_aggregate_status() {
+        let up = || outcome("kubernetes", true, "server v1.29.2");
+        let down = || outcome("aws", false, "ExpiredToken");
+
+        assert_eq!(ContextHealth::new("a", vec![up()]).status, HealthStatus::Reachable);
+        assert_eq!(ContextHealth::new("b", vec![up(), down()]).status, HealthStatus::Degraded);
+        assert_eq!(ContextHealth::new("c", vec![down()]).status, HealthStatus::Unreachable);
+        assert_eq!(ContextHealth::new("d", vec![]).status, HealthStatus::Unconfigured);
+    }
+
+    #[tokio::test]
+    async fn test_probe_mixed_results_and_render() {
+        let runner = Arc::new(FakeRunner::new(|program, args| match program {
+            "kubectl" if args.iter().any(|a| a == "--context=prod") => {
+                CommandOutput::ok(r#"{"serverVersion": {"gitVersion": "v1.29.2"}}"#)
+            }
+            "kubectl" => CommandOutput::failed(1, "\nUnable to connect to the server: dial tcp 10.0.0.1:443: i/o timeout\n"),
+            "aws" => CommandOutput::ok(r#"{"Account": "123456789012"}"#),
+            _ => CommandOutput::failed(1, "unexpected"),
+        }));
+
+        let probes = vec![
+            ContextProbe {
+                context: "prod".to_string(),
+                targets: vec![
+                    ProbeTarget::Kubernetes { kubeconfig: None, context: Some("prod".to_string()) },
+                    ProbeTarget::Aws { profile: Some("prod".to_string()), region: Some("us-east-1".to_string()) },
+                ],
+            },
+            ContextProbe {
+                context: "staging".to_string(),
+                targets: vec![ProbeTarget::Kubernetes {
+                    kubeconfig: Some("/kube/staging".to_string()),
+                    context: Some("staging".to_string()),
+                }],
+            },
+            ContextProbe {
+                context: "mixed".to_string(),
+                targets: vec![
+                    ProbeTarget::Kubernetes { kubeconfig: None, context: Some("dev".to_string()) },
+                    ProbeTarget::Aws { profile: None, region: None },
+                ],
+            },
+            ContextProbe { context: "local".to_string(), targets: vec![] },
+        ];
+
+        let report = HealthReport::probe(runner.clone(), probes, 5).await;
+
+        let statuses: Vec<HealthStatus> = report.contexts.iter().map(|c| c.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                HealthStatus::Reachable,
+                HealthStatus::Unreachable,
+                HealthStatus::Degraded,
+                HealthStatus::Unconfigured
+            ]
+        );
+        assert_eq!(report.contexts[0].probes[0].detail, "account 123456789012");
+        assert_eq!(report.contexts[0].probes[1].detail, "server v1.29.2");
+        assert!(report.contexts[1].probes[0].detail.starts_with("Unable to connect"));
+        assert_eq!(report.reachable_count(), 1);
+        assert!(report.has_failures());
+
+        let calls = runner.calls();
+        assert_eq!(calls.len(), 5);
+        assert!(calls.contains(&vec![
+            "kubectl".to_string(),
+            "--kubeconfig=/kube/staging".to_string(),
+            "--context=staging".to_string(),
+            "--request-timeout=5s".to_string(),
+            "version".to_string(),
+            "-o".to_string(),
+            "json".to_string(),
+        ]));
+
+        let rendered = report.render(RenderConfig::terminal());
+        assert!(rendered.starts_with("CONTEXT"));
+        assert!(rendered.contains("✅ reachable"));
+        assert!(rendered.contains("❌ unreachable"));
+        assert!(rendered.contains("⚠️ degraded"));
+        assert!(rendered.ends_with("1/4 contexts reachable"));
+    }
+
+    #[test]
+    fn test_render_empty() {
+        assert_eq!(HealthReport::default().render(RenderConfig::default()), "No contexts configured");
+    }
+}
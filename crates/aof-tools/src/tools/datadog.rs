@@ -39,6 +39,7 @@ use reqwest::Client;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::{self, QuotaReport};
 
 /// Collection of all Datadog tools
 pub struct DatadogTools;
@@ -92,6 +93,7 @@ async fn create_datadog_client(api_key: &str, app_key: &str) -> AofResult<Client
 /// Handle Datadog API response
 async fn handle_datadog_response(
     response: reqwest::Response,
+    quota: QuotaReport,
     operation: &str,
 ) -> AofResult<ToolResult> {
     let status = response.status().as_u16();
@@ -115,14 +117,14 @@ async fn handle_datadog_response(
             .map(|e| format!("{:?}", e))
             .unwrap_or_else(|| "Unknown error".to_string());
 
-        return Ok(ToolResult::error(format!(
+        return Ok(quota.annotate(ToolResult::error(format!(
             "{} returned status {}: {}",
             operation, status, error_msg
-        )));
+        ))));
     }
 
     // Success
-    Ok(ToolResult::success(body))
+    Ok(quota.annotate(ToolResult::success(body)))
 }
 
 /// Parse time parameter (Unix timestamp, ISO 8601, or relative time)
@@ -277,7 +279,7 @@ impl Tool for DatadogMetricQueryTool {
         ];
 
         // Execute request
-        let response = match client.get(&url).query(&params).send().await {
+        let (response, quota) = match rate_limit::send(client.get(&url).query(&params)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -288,7 +290,7 @@ impl Tool for DatadogMetricQueryTool {
         };
 
         // Handle response
-        handle_datadog_response(response, "Datadog metric query").await
+        handle_datadog_response(response, quota, "Datadog metric query").await
     }
 
     fn config(&self) -> &ToolConfig {
@@ -408,7 +410,7 @@ impl Tool for DatadogLogQueryTool {
         });
 
         // Execute request
-        let response = match client.post(&url).json(&body).send().await {
+        let (response, quota) = match rate_limit::send(client.post(&url).json(&body)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -419,7 +421,7 @@ impl Tool for DatadogLogQueryTool {
         };
 
         // Handle response
-        handle_datadog_response(response, "Datadog log query").await
+        handle_datadog_response(response, quota, "Datadog log query").await
     }
 
     fn config(&self) -> &ToolConfig {
@@ -521,7 +523,7 @@ impl Tool for DatadogMonitorListTool {
         }
 
         // Execute request
-        let response = match client.get(&url).query(&params).send().await {
+        let (response, quota) = match rate_limit::send(client.get(&url).query(&params)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -532,7 +534,7 @@ impl Tool for DatadogMonitorListTool {
         };
 
         // Handle response
-        handle_datadog_response(response, "Datadog monitor list").await
+        handle_datadog_response(response, quota, "Datadog monitor list").await
     }
 
     fn config(&self) -> &ToolConfig {
@@ -640,7 +642,7 @@ impl Tool for DatadogMonitorMuteTool {
         );
 
         // Execute request
-        let response = match client.post(&url).json(&body).send().await {
+        let (response, quota) = match rate_limit::send(client.post(&url).json(&body)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -651,7 +653,7 @@ impl Tool for DatadogMonitorMuteTool {
         };
 
         // Handle response
-        handle_datadog_response(response, "Datadog monitor mute").await
+        handle_datadog_response(response, quota, "Datadog monitor mute").await
     }
 
     fn config(&self) -> &ToolConfig {
@@ -770,7 +772,7 @@ impl Tool for DatadogEventPostTool {
         let url = format!("{}/api/v1/events", endpoint.trim_end_matches('/'));
 
         // Execute request
-        let response = match client.post(&url).json(&body).send().await {
+        let (response, quota) = match rate_limit::send(client.post(&url).json(&body)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -781,7 +783,7 @@ impl Tool for DatadogEventPostTool {
         };
 
         // Handle response
-        handle_datadog_response(response, "Datadog event post").await
+        handle_datadog_response(response, quota, "Datadog event post").await
     }
 
     fn config(&self) -> &ToolConfig {
@@ -897,7 +899,7 @@ impl Tool for DatadogDowntimeCreateTool {
         let url = format!("{}/api/v1/downtime", endpoint.trim_end_matches('/'));
 
         // Execute request
-        let response = match client.post(&url).json(&body).send().await {
+        let (response, quota) = match rate_limit::send(client.post(&url).json(&body)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!(
@@ -908,7 +910,7 @@ impl Tool for DatadogDowntimeCreateTool {
         };
 
         // Handle response
-        handle_datadog_response(response, "Datadog downtime create").await
+        handle_datadog_response(response, quota, "Datadog downtime create").await
     }
 
     fn config(&self) -> &ToolConfig {
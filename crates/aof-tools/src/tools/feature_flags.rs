@@ -0,0 +1,650 @@
+//! Feature Flag Tool
+//!
+//! Tool for reading and toggling feature flags in LaunchDarkly or Unleash,
+//! typically to switch off a misbehaving feature during an incident.
+//!
+//! ## Actions
+//!
+//! - `list` - Flags in a project with their state in one environment
+//! - `get` - One flag's state and targeting summary
+//! - `toggle` - Turn a flag on or off in one environment
+//!
+//! `toggle` changes live behavior, so the tool refuses it unless the operator
+//! enables it with `with_toggle_allowed(true)` or env var
+//! AOF_FEATURE_FLAG_ALLOW_TOGGLE=true. It is also classified as a write
+//! (`feature_flag toggle`), so safe mode blocks it even when enabled. `list`
+//! and `get` are reads.
+//!
+//! ## Prerequisites
+//!
+//! - Requires `featureflags` feature flag
+//! - LaunchDarkly: an API access token with writer role for `toggle`
+//!   (`api_key` or env var LAUNCHDARKLY_API_KEY)
+//! - Unleash: an admin API token (`api_key` or env var UNLEASH_API_TOKEN) and
+//!   the instance URL (`endpoint` or env var UNLEASH_URL)
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+use serde_json::{json, Value};
+use tracing::debug;
+
+use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::{self, QuotaReport};
+
+const LAUNCHDARKLY_ENDPOINT: &str = "https://app.launchdarkly.com";
+
+/// Env var that lets agents toggle flags when set to `true` or `1`
+const ALLOW_TOGGLE_ENV: &str = "AOF_FEATURE_FLAG_ALLOW_TOGGLE";
+
+/// Flag provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Provider {
+    LaunchDarkly,
+    Unleash,
+}
+
+impl Provider {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "launchdarkly" | "ld" => Some(Self::LaunchDarkly),
+            "unleash" => Some(Self::Unleash),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::LaunchDarkly => "launchdarkly",
+            Self::Unleash => "unleash",
+        }
+    }
+
+    fn token_env(&self) -> &'static str {
+        match self {
+            Self::LaunchDarkly => "LAUNCHDARKLY_API_KEY",
+            Self::Unleash => "UNLEASH_API_TOKEN",
+        }
+    }
+}
+
+/// Where and how to reach the provider
+struct FlagApi {
+    provider: Provider,
+    client: Client,
+    endpoint: String,
+    api_key: String,
+    project: String,
+    environment: String,
+}
+
+impl FlagApi {
+    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("{}{}", self.endpoint, path))
+            .header("Authorization", &self.api_key)
+    }
+
+    /// Send a request, returning the JSON body or an error message
+    async fn call(&self, request: RequestBuilder, quota: &mut QuotaReport) -> Result<Value, String> {
+        let (response, report) = rate_limit::send(request)
+            .await
+            .map_err(|e| format!("{} request failed: {}", self.provider.name(), e))?;
+        *quota = report;
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            let body: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+            let message = body
+                .get("message")
+                .and_then(|m| m.as_str())
+                .map(String::from)
+                .unwrap_or(text);
+            return Err(format!(
+                "{} returned status {}: {}",
+                self.provider.name(),
+                status.as_u16(),
+                message
+            ));
+        }
+        if text.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        serde_json::from_str(&text).map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    async fn list(&self, quota: &mut QuotaReport) -> Result<Value, String> {
+        let flags: Vec<Value> = match self.provider {
+            Provider::LaunchDarkly => {
+                let request = self
+                    .request(reqwest::Method::GET, &format!("/api/v2/flags/{}", self.project))
+                    .query(&[("env", self.environment.as_str()), ("summary", "true")]);
+                let body = self.call(request, quota).await?;
+                items(&body, "items")
+                    .iter()
+                    .map(|flag| launchdarkly_state(flag, &self.environment, false))
+                    .collect()
+            }
+            Provider::Unleash => {
+                let request = self.request(
+                    reqwest::Method::GET,
+                    &format!("/api/admin/projects/{}/features", self.project),
+                );
+                let body = self.call(request, quota).await?;
+                items(&body, "features")
+                    .iter()
+                    .map(|flag| unleash_state(flag, &self.environment, false))
+                    .collect()
+            }
+        };
+
+        Ok(json!({
+            "provider": self.provider.name(),
+            "project": self.project,
+            "environment": self.environment,
+            "count": flags.len(),
+            "flags": flags,
+        }))
+    }
+
+    async fn get(&self, flag: &str, quota: &mut QuotaReport) -> Result<Value, String> {
+        let state = match self.provider {
+            Provider::LaunchDarkly => {
+                let request = self
+                    .request(
+                        reqwest::Method::GET,
+                        &format!("/api/v2/flags/{}/{}", self.project, flag),
+                    )
+                    .query(&[("env", self.environment.as_str())]);
+                launchdarkly_state(&self.call(request, quota).await?, &self.environment, true)
+            }
+            Provider::Unleash => {
+                let request = self.request(
+                    reqwest::Method::GET,
+                    &format!("/api/admin/projects/{}/features/{}", self.project, flag),
+                );
+                unleash_state(&self.call(request, quota).await?, &self.environment, true)
+            }
+        };
+        Ok(self.with_context(state))
+    }
+
+    async fn toggle(
+        &self,
+        flag: &str,
+        enabled: bool,
+        comment: Option<&str>,
+        quota: &mut QuotaReport,
+    ) -> Result<Value, String> {
+        match self.provider {
+            Provider::LaunchDarkly => {
+                let mut body = json!({
+                    "environmentKey": self.environment,
+                    "instructions": [{ "kind": if enabled { "turnFlagOn" } else { "turnFlagOff" } }],
+                });
+                if let Some(comment) = comment {
+                    body["comment"] = json!(comment);
+                }
+                let request = self
+                    .request(
+                        reqwest::Method::PATCH,
+                        &format!("/api/v2/flags/{}/{}", self.project, flag),
+                    )
+                    .header(
+                        "Content-Type",
+                        "application/json; domain-model=launchdarkly.semanticpatch",
+                    )
+                    .body(body.to_string());
+                let updated = self.call(request, quota).await?;
+                Ok(self.with_context(launchdarkly_state(&updated, &self.environment, true)))
+            }
+            Provider::Unleash => {
+                let request = self.request(
+                    reqwest::Method::POST,
+                    &format!(
+                        "/api/admin/projects/{}/features/{}/environments/{}/{}",
+                        self.project,
+                        flag,
+                        self.environment,
+                        if enabled { "on" } else { "off" }
+                    ),
+                );
+                self.call(request, quota).await?;
+                // Unleash returns no body; read back the new state
+                self.get(flag, quota).await
+            }
+        }
+    }
+
+    fn with_context(&self, mut state: Value) -> Value {
+        state["provider"] = json!(self.provider.name());
+        state["project"] = json!(self.project);
+        state["environment"] = json!(self.environment);
+        state
+    }
+}
+
+/// Feature flag management tool
+pub struct FeatureFlagTool {
+    config: ToolConfig,
+    allow_toggle: bool,
+}
+
+impl FeatureFlagTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            json!({
+                "action": {
+                    "type": "string",
+                    "description": "Operation to perform. toggle is a write and is refused unless the operator enabled it",
+                    "enum": ["list", "get", "toggle"],
+                    "default": "get"
+                },
+                "provider": {
+                    "type": "string",
+                    "description": "Flag provider",
+                    "enum": ["launchdarkly", "unleash"],
+                    "default": "launchdarkly"
+                },
+                "endpoint": {
+                    "type": "string",
+                    "description": "API base URL (LaunchDarkly default: https://app.launchdarkly.com; Unleash: env var UNLEASH_URL)"
+                },
+                "api_key": {
+                    "type": "string",
+                    "description": "API token. Can use env var LAUNCHDARKLY_API_KEY or UNLEASH_API_TOKEN"
+                },
+                "project": {
+                    "type": "string",
+                    "description": "Project key",
+                    "default": "default"
+                },
+                "environment": {
+                    "type": "string",
+                    "description": "Environment key (e.g., 'production')",
+                    "default": "production"
+                },
+                "flag": {
+                    "type": "string",
+                    "description": "Flag key (required for get and toggle)"
+                },
+                "enabled": {
+                    "type": "boolean",
+                    "description": "New state for toggle: true turns the flag on, false turns it off"
+                },
+                "comment": {
+                    "type": "string",
+                    "description": "Reason recorded in the flag's audit log (LaunchDarkly)"
+                }
+            }),
+            vec!["action"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "feature_flag",
+                "List, inspect and toggle feature flags in LaunchDarkly or Unleash. Returns each flag's on/off state and a targeting summary. Toggling is a write operation.",
+                parameters,
+                30,
+            ),
+            allow_toggle: std::env::var(ALLOW_TOGGLE_ENV)
+                .map(|v| matches!(v.trim().to_lowercase().as_str(), "true" | "1"))
+                .unwrap_or(false),
+        }
+    }
+
+    /// Allow or refuse the `toggle` action, overriding AOF_FEATURE_FLAG_ALLOW_TOGGLE
+    pub fn with_toggle_allowed(mut self, allow: bool) -> Self {
+        self.allow_toggle = allow;
+        self
+    }
+}
+
+impl Default for FeatureFlagTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for FeatureFlagTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let action: String = input.get_arg("action").unwrap_or_else(|_| "get".to_string());
+        if action == "toggle" && !self.allow_toggle {
+            return Ok(ToolResult::error(format!(
+                "Refused: toggling feature flags is disabled. An operator must enable it \
+                 (env var {}=true) before agents can change flag state; list and get still work.",
+                ALLOW_TOGGLE_ENV
+            )));
+        }
+        let provider_name: String = input
+            .get_arg("provider")
+            .unwrap_or_else(|_| "launchdarkly".to_string());
+        let Some(provider) = Provider::parse(&provider_name) else {
+            return Ok(ToolResult::error(format!(
+                "Unknown provider '{}'. Valid providers: launchdarkly, unleash",
+                provider_name
+            )));
+        };
+
+        let endpoint: Option<String> = input.get_arg("endpoint").ok().or_else(|| match provider {
+            Provider::LaunchDarkly => Some(LAUNCHDARKLY_ENDPOINT.to_string()),
+            Provider::Unleash => std::env::var("UNLEASH_URL").ok(),
+        });
+        let Some(endpoint) = endpoint else {
+            return Ok(ToolResult::error(
+                "Missing required parameter: endpoint (or env var UNLEASH_URL)".to_string(),
+            ));
+        };
+        let api_key: Option<String> = input
+            .get_arg("api_key")
+            .ok()
+            .or_else(|| std::env::var(provider.token_env()).ok());
+        let Some(api_key) = api_key else {
+            return Ok(ToolResult::error(format!(
+                "Missing required parameter: api_key (or env var {})",
+                provider.token_env()
+            )));
+        };
+
+        let flag: Option<String> = input.get_arg("flag").ok();
+        let enabled: Option<bool> = input.get_arg("enabled").ok();
+        let comment: Option<String> = input.get_arg("comment").ok();
+
+        let api = FlagApi {
+            provider,
+            client: Client::new(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            api_key,
+            project: input.get_arg("project").unwrap_or_else(|_| "default".to_string()),
+            environment: input
+                .get_arg("environment")
+                .unwrap_or_else(|_| "production".to_string()),
+        };
+
+        debug!(
+            provider = provider.name(),
+            action = %action,
+            flag = ?flag,
+            environment = %api.environment,
+            "Feature flag operation"
+        );
+
+        let mut quota = QuotaReport::default();
+        let result = match (action.as_str(), flag) {
+            ("list", _) => api.list(&mut quota).await,
+            ("get", Some(flag)) => api.get(&flag, &mut quota).await,
+            ("toggle", Some(flag)) => match enabled {
+                Some(enabled) => api.toggle(&flag, enabled, comment.as_deref(), &mut quota).await,
+                None => Err("Missing required parameter: enabled (needed for toggle)".to_string()),
+            },
+            ("get" | "toggle", None) => Err(format!(
+                "Missing required parameter: flag (needed for {})",
+                action
+            )),
+            (other, _) => Err(format!(
+                "Unknown action '{}'. Valid actions: list, get, toggle",
+                other
+            )),
+        };
+
+        Ok(quota.annotate(match result {
+            Ok(value) => ToolResult::success(value),
+            Err(e) => ToolResult::error(e),
+        }))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+// Helper: Array field of a response body
+fn items<'a>(body: &'a Value, field: &str) -> &'a [Value] {
+    body.get(field)
+        .and_then(|v| v.as_array())
+        .map(|v| v.as_slice())
+        .unwrap_or_default()
+}
+
+/// State of a LaunchDarkly flag in one environment
+fn launchdarkly_state(flag: &Value, environment: &str, detailed: bool) -> Value {
+    let env = flag
+        .get("environments")
+        .and_then(|e| e.get(environment))
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    let mut state = json!({
+        "flag": flag.get("key"),
+        "name": flag.get("name"),
+        "enabled": env.get("on").and_then(|v| v.as_bool()).unwrap_or(false),
+    });
+
+    if detailed {
+        let variation_name = |index: Option<&Value>| {
+            index
+                .and_then(|i| i.as_u64())
+                .and_then(|i| flag.get("variations")?.get(i as usize))
+                .map(|v| v.get("name").cloned().unwrap_or_else(|| v["value"].clone()))
+        };
+        let fallthrough = env.get("fallthrough");
+        let individual_targets: usize = items(&env, "targets")
+            .iter()
+            .map(|t| items(t, "values").len())
+            .sum();
+
+        state["targeting"] = json!({
+            "rules": items(&env, "rules").len(),
+            "individual_targets": individual_targets,
+            "prerequisites": items(&env, "prerequisites").len(),
+            "fallthrough": match fallthrough.and_then(|f| f.get("rollout")) {
+                Some(_) => json!("percentage rollout"),
+                None => json!(variation_name(fallthrough.and_then(|f| f.get("variation")))),
+            },
+            "off_variation": variation_name(env.get("offVariation")),
+        });
+        state["last_modified"] = env.get("lastModified").cloned().unwrap_or(Value::Null);
+    }
+    state
+}
+
+/// State of an Unleash feature in one environment
+fn unleash_state(feature: &Value, environment: &str, detailed: bool) -> Value {
+    let env = items(feature, "environments")
+        .iter()
+        .find(|e| e.get("name").and_then(|n| n.as_str()) == Some(environment))
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    let mut state = json!({
+        "flag": feature.get("name"),
+        "name": feature.get("name"),
+        "enabled": env.get("enabled").and_then(|v| v.as_bool()).unwrap_or(false),
+    });
+
+    if detailed {
+        let strategies: Vec<Value> = items(&env, "strategies")
+            .iter()
+            .map(|s| {
+                json!({
+                    "name": s.get("name"),
+                    "constraints": items(s, "constraints").len(),
+                    "parameters": s.get("parameters"),
+                })
+            })
+            .collect();
+        state["targeting"] = json!({
+            "strategies": strategies,
+            "variants": items(&env, "variants").len(),
+        });
+        state["stale"] = feature.get("stale").cloned().unwrap_or(Value::Null);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::mock_http::{MockHttpServer, MockResponse};
+
+    /// Serve canned JSON bodies in order
+    async fn mock_api(bodies: Vec<Value>) -> MockHttpServer {
+        MockHttpServer::start(bodies.into_iter().map(MockResponse::json).collect()).await
+    }
+
+    fn ld_flag(on: bool) -> Value {
+        json!({
+            "key": "new-checkout",
+            "name": "New checkout",
+            "variations": [{ "value": true, "name": "Enabled" }, { "value": false, "name": "Disabled" }],
+            "environments": {
+                "production": {
+                    "on": on,
+                    "offVariation": 1,
+                    "fallthrough": { "variation": 0 },
+                    "rules": [{ "clauses": [] }, { "clauses": [] }],
+                    "targets": [{ "values": ["user-1", "user-2"], "variation": 0 }],
+                    "lastModified": 1700000000000u64
+                }
+            }
+        })
+    }
+
+    fn unleash_feature(enabled: bool) -> Value {
+        json!({
+            "name": "new-checkout",
+            "stale": false,
+            "environments": [
+                { "name": "development", "enabled": true, "strategies": [] },
+                {
+                    "name": "production",
+                    "enabled": enabled,
+                    "strategies": [{
+                        "name": "flexibleRollout",
+                        "parameters": { "rollout": "25" },
+                        "constraints": [{ "contextName": "region" }]
+                    }]
+                }
+            ]
+        })
+    }
+
+    fn input(endpoint: &str, args: Value) -> ToolInput {
+        let mut args = args;
+        args["endpoint"] = json!(endpoint);
+        args["api_key"] = json!("test-token");
+        ToolInput::new(args)
+    }
+
+    #[tokio::test]
+    async fn test_launchdarkly_get_and_toggle() {
+        let server = mock_api(vec![ld_flag(true), ld_flag(false)]).await;
+        let endpoint = server.url();
+        let tool = FeatureFlagTool::new().with_toggle_allowed(true);
+
+        let result = tool
+            .execute(input(endpoint, json!({ "action": "get", "flag": "new-checkout" })))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["enabled"], true);
+        assert_eq!(result.data["targeting"]["rules"], 2);
+        assert_eq!(result.data["targeting"]["individual_targets"], 2);
+        assert_eq!(result.data["targeting"]["fallthrough"], "Enabled");
+        assert_eq!(result.data["targeting"]["off_variation"], "Disabled");
+        assert_eq!(
+            server.requests()[0].request_line(),
+            "GET /api/v2/flags/default/new-checkout?env=production HTTP/1.1"
+        );
+
+        let result = tool
+            .execute(input(
+                endpoint,
+                json!({ "action": "toggle", "flag": "new-checkout", "enabled": false, "comment": "INC-42" }),
+            ))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["enabled"], false);
+        let request = &server.requests()[1];
+        assert_eq!(request.request_line(), "PATCH /api/v2/flags/default/new-checkout HTTP/1.1");
+        let payload = request.json();
+        assert_eq!(payload["instructions"][0]["kind"], "turnFlagOff");
+        assert_eq!(payload["environmentKey"], "production");
+        assert_eq!(payload["comment"], "INC-42");
+    }
+
+    #[tokio::test]
+    async fn test_unleash_toggle_reads_back_state() {
+        let server = mock_api(vec![Value::Null, unleash_feature(true)]).await;
+        let endpoint = server.url();
+
+        let result = FeatureFlagTool::new()
+            .with_toggle_allowed(true)
+            .execute(input(
+                endpoint,
+                json!({ "action": "toggle", "provider": "unleash", "flag": "new-checkout", "enabled": true }),
+            ))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["enabled"], true);
+        assert_eq!(result.data["targeting"]["strategies"][0]["name"], "flexibleRollout");
+        assert_eq!(result.data["targeting"]["strategies"][0]["constraints"], 1);
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].request_line(),
+            "POST /api/admin/projects/default/features/new-checkout/environments/production/on HTTP/1.1"
+        );
+        assert_eq!(
+            requests[1].request_line(),
+            "GET /api/admin/projects/default/features/new-checkout HTTP/1.1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_toggle_requires_flag_and_state() {
+        let tool = FeatureFlagTool::new().with_toggle_allowed(true);
+        let result = tool
+            .execute(input("http://127.0.0.1:1", json!({ "action": "toggle", "flag": "x" })))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("enabled"));
+
+        let result = tool
+            .execute(input("http://127.0.0.1:1", json!({ "action": "get" })))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("flag"));
+    }
+
+    #[tokio::test]
+    async fn test_toggle_refused_unless_allowed() {
+        let server = mock_api(vec![ld_flag(true)]).await;
+        let endpoint = server.url();
+        let tool = FeatureFlagTool::new().with_toggle_allowed(false);
+
+        let result = tool
+            .execute(input(
+                endpoint,
+                json!({ "action": "toggle", "flag": "new-checkout", "enabled": false }),
+            ))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains(ALLOW_TOGGLE_ENV));
+
+        // Reads still work, and the refused toggle never reached the API
+        let result = tool
+            .execute(input(endpoint, json!({ "action": "get", "flag": "new-checkout" })))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+    }
+}
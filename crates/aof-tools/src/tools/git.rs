@@ -12,6 +12,7 @@
 //! - `git_checkout` - Switch branches
 //! - `git_pull` - Pull changes
 //! - `git_push` - Push changes
+//! - `git_diff_summary` - Summarize a ref-to-ref diff (see [`super::git_diff_summary`])
 //!
 //! ## Prerequisites
 //!
@@ -43,6 +44,7 @@ impl GitTools {
             Box::new(GitCheckoutTool::new()),
             Box::new(GitPullTool::new()),
             Box::new(GitPushTool::new()),
+            Box::new(super::git_diff_summary::GitDiffSummaryTool::new()),
         ]
     }
 
@@ -0,0 +1,408 @@
+//! Git Changelog Tool
+//!
+//! Tool for collecting the commits in a range as a structured changelog, e.g.
+//! for a release agent that writes release notes.
+//!
+//! ## Features
+//!
+//! - Commits with subject, body, author and date (from `git log`)
+//! - Grouped by conventional-commit type (`feat`, `fix`, `chore`, ...), with
+//!   scope and breaking-change markers parsed out
+//! - Non-conventional commits collected in an `other` group
+//!
+//! ## Prerequisites
+//!
+//! - Requires `git` feature flag
+//! - Git must be installed and in PATH
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::debug;
+
+use super::common::{
+    create_schema, tool_config_with_timeout, CommandOutput, CommandRunner, ProcessRunner,
+};
+use super::git_diff_summary::validate_ref;
+
+/// Field separator in the `git log` format (ASCII unit separator)
+const FIELD_SEP: char = '\u{1f}';
+
+/// Record separator in the `git log` format (ASCII record separator)
+const RECORD_SEP: char = '\u{1e}';
+
+/// `git log` format: hash, author, email, date, subject, body
+const LOG_FORMAT: &str = "--format=%H%x1f%an%x1f%ae%x1f%aI%x1f%s%x1f%b%x1e";
+
+/// Conventional-commit types with their changelog titles, in output order
+const COMMIT_TYPES: &[(&str, &str)] = &[
+    ("feat", "Features"),
+    ("fix", "Bug Fixes"),
+    ("perf", "Performance"),
+    ("refactor", "Refactoring"),
+    ("revert", "Reverts"),
+    ("docs", "Documentation"),
+    ("test", "Tests"),
+    ("build", "Build"),
+    ("ci", "CI"),
+    ("style", "Style"),
+    ("chore", "Chores"),
+];
+
+/// Group for commits that don't follow the convention
+const OTHER_TYPE: &str = "other";
+
+/// One commit in the changelog
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangelogEntry {
+    /// Full commit hash
+    pub sha: String,
+    /// Conventional-commit type, or `other`
+    pub commit_type: String,
+    /// Scope from `type(scope): ...`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Subject without the type prefix (the whole subject for `other`)
+    pub description: String,
+    /// Commit message body
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub body: String,
+    pub author: String,
+    pub email: String,
+    /// Author date (ISO 8601)
+    pub date: String,
+    /// Marked `!` or with a `BREAKING CHANGE:` footer
+    pub breaking: bool,
+}
+
+/// Commits of one type
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogGroup {
+    pub commit_type: String,
+    pub title: String,
+    pub entries: Vec<ChangelogEntry>,
+}
+
+/// Git changelog tool
+pub struct GitChangelogTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl GitChangelogTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "from": {
+                    "type": "string",
+                    "description": "Start of the range, exclusive (e.g., the previous release tag 'v1.2.0'). Omit for all history"
+                },
+                "to": {
+                    "type": "string",
+                    "description": "End of the range, inclusive",
+                    "default": "HEAD"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Repository path",
+                    "default": "."
+                },
+                "include_merges": {
+                    "type": "boolean",
+                    "description": "Include merge commits",
+                    "default": false
+                }
+            }),
+            vec![],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "git_changelog",
+                "Collect the commits in a git range as a structured changelog: subject, body and author of each commit, grouped by conventional-commit type (feat, fix, chore, ...) with breaking changes called out. Non-conventional commits go in an 'other' group.",
+                parameters,
+                60,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    async fn git(&self, path: &str, args: Vec<String>) -> Result<CommandOutput, String> {
+        self.runner
+            .run("git", &args, Some(path), self.config.timeout_secs)
+            .await
+    }
+}
+
+impl Default for GitChangelogTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for GitChangelogTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let from: Option<String> = input.get_arg("from").ok();
+        let to: String = input.get_arg("to").unwrap_or_else(|_| "HEAD".to_string());
+        let path: String = input.get_arg("path").unwrap_or_else(|_| ".".to_string());
+        let include_merges: bool = input.get_arg("include_merges").unwrap_or(false);
+
+        let checked = match &from {
+            Some(from) => validate_ref("from", from),
+            None => Ok(()),
+        };
+        if let Err(e) = checked.and_then(|_| validate_ref("to", &to)) {
+            return Ok(ToolResult::error(e));
+        }
+
+        let range = match from {
+            Some(ref from) => format!("{}..{}", from, to),
+            None => to.clone(),
+        };
+        debug!(range = %range, path = %path, "Collecting changelog");
+
+        let mut args = vec!["log".to_string(), LOG_FORMAT.to_string()];
+        if !include_merges {
+            args.push("--no-merges".to_string());
+        }
+        args.push("--end-of-options".to_string());
+        args.push(range.clone());
+
+        let log = match self.git(&path, args).await {
+            Ok(output) if output.success => output.stdout,
+            Ok(output) => {
+                return Ok(ToolResult::error(format!(
+                    "git log failed: {}",
+                    output.stderr.trim()
+                )))
+            }
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        let entries = parse_log(&log);
+        let breaking: Vec<&ChangelogEntry> = entries.iter().filter(|e| e.breaking).collect();
+        let contributors: BTreeSet<&str> = entries.iter().map(|e| e.author.as_str()).collect();
+
+        Ok(ToolResult::success(serde_json::json!({
+            "range": range,
+            "commit_count": entries.len(),
+            "breaking_changes": breaking,
+            "contributors": contributors,
+            "groups": group_entries(entries.clone()),
+        })))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Parse `git log` output written with `LOG_FORMAT`, newest first
+fn parse_log(log: &str) -> Vec<ChangelogEntry> {
+    log.split(RECORD_SEP)
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(6, FIELD_SEP);
+            let sha = fields.next()?.trim();
+            if sha.is_empty() {
+                return None;
+            }
+            let author = fields.next()?.to_string();
+            let email = fields.next()?.to_string();
+            let date = fields.next()?.to_string();
+            let subject = fields.next()?.trim();
+            let body = fields.next().unwrap_or_default().trim().to_string();
+
+            let (commit_type, scope, description, bang) = match parse_conventional(subject) {
+                Some((t, s, d, b)) => (t, s, d, b),
+                None => (OTHER_TYPE.to_string(), None, subject.to_string(), false),
+            };
+            let breaking = bang
+                || body
+                    .lines()
+                    .any(|l| l.starts_with("BREAKING CHANGE:") || l.starts_with("BREAKING-CHANGE:"));
+
+            Some(ChangelogEntry {
+                sha: sha.to_string(),
+                commit_type,
+                scope,
+                description,
+                body,
+                author,
+                email,
+                date,
+                breaking,
+            })
+        })
+        .collect()
+}
+
+/// Split a `type(scope)!: description` subject; None if it isn't conventional
+fn parse_conventional(subject: &str) -> Option<(String, Option<String>, String, bool)> {
+    let (prefix, description) = subject.split_once(':')?;
+    let description = description.trim();
+    let (prefix, bang) = match prefix.strip_suffix('!') {
+        Some(p) => (p, true),
+        None => (prefix, false),
+    };
+    let (commit_type, scope) = match prefix.split_once('(') {
+        Some((t, rest)) => (t, Some(rest.strip_suffix(')')?.trim().to_string())),
+        None => (prefix, None),
+    };
+
+    let commit_type = commit_type.to_lowercase();
+    if description.is_empty() || !COMMIT_TYPES.iter().any(|(t, _)| *t == commit_type) {
+        return None;
+    }
+    Some((commit_type, scope.filter(|s| !s.is_empty()), description.to_string(), bang))
+}
+
+/// Group entries by type in changelog order, dropping empty groups
+fn group_entries(entries: Vec<ChangelogEntry>) -> Vec<ChangelogGroup> {
+    COMMIT_TYPES
+        .iter()
+        .copied()
+        .chain(std::iter::once((OTHER_TYPE, "Other")))
+        .filter_map(|(commit_type, title)| {
+            let group: Vec<ChangelogEntry> = entries
+                .iter()
+                .filter(|e| e.commit_type == commit_type)
+                .cloned()
+                .collect();
+            (!group.is_empty()).then(|| ChangelogGroup {
+                commit_type: commit_type.to_string(),
+                title: title.to_string(),
+                entries: group,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::FakeRunner;
+
+    fn record(sha: &str, author: &str, subject: &str, body: &str) -> String {
+        format!(
+            "{sha}\u{1f}{author}\u{1f}{author}@example.com\u{1f}2024-05-01T10:00:00+00:00\u{1f}{subject}\u{1f}{body}\n\u{1e}\n"
+        )
+    }
+
+    fn release_log() -> String {
+        [
+            record("a1", "Ana", "feat(fleet)!: persist task history", "BREAKING CHANGE: state moved to FileBackend"),
+            record("b2", "Raj", "fix: handle empty kubeconfig", ""),
+            record("c3", "Ana", "Update README badges", ""),
+            record("d4", "Lee", "chore(deps): bump tokio to 1.37", ""),
+            record("e5", "Raj", "feat: add helm_template tool", "Renders charts locally."),
+            record("f6", "Lee", "wip: half-done experiment", ""),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_parse_conventional() {
+        assert_eq!(
+            parse_conventional("feat(fleet)!: persist history"),
+            Some(("feat".to_string(), Some("fleet".to_string()), "persist history".to_string(), true))
+        );
+        assert_eq!(
+            parse_conventional("Fix: typo"),
+            Some(("fix".to_string(), None, "typo".to_string(), false))
+        );
+        assert!(parse_conventional("Update README").is_none());
+        assert!(parse_conventional("wip: experiment").is_none());
+        assert!(parse_conventional("feat(fleet: missing paren").is_none());
+    }
+
+    #[test]
+    fn test_commits_grouped_by_type() {
+        let entries = parse_log(&release_log());
+        assert_eq!(entries.len(), 6);
+        assert_eq!(entries[0].scope.as_deref(), Some("fleet"));
+        assert!(entries[0].breaking);
+        assert_eq!(entries[4].body, "Renders charts locally.");
+
+        let groups = group_entries(entries);
+        let summary: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|g| {
+                (g.commit_type.as_str(), g.entries.iter().map(|e| e.sha.as_str()).collect())
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("feat", vec!["a1", "e5"]),
+                ("fix", vec!["b2"]),
+                ("chore", vec!["d4"]),
+                ("other", vec!["c3", "f6"]),
+            ]
+        );
+        assert_eq!(groups[3].title, "Other");
+        assert_eq!(groups[3].entries[0].description, "Update README badges");
+    }
+
+    #[tokio::test]
+    async fn test_changelog_for_range() {
+        let log = release_log();
+        let runner = Arc::new(FakeRunner::new(move |_, _| CommandOutput::ok(log.clone())));
+        let tool = GitChangelogTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({ "from": "v1.2.0" })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["range"], "v1.2.0..HEAD");
+        assert_eq!(result.data["commit_count"], 6);
+        assert_eq!(result.data["contributors"], serde_json::json!(["Ana", "Lee", "Raj"]));
+        assert_eq!(result.data["breaking_changes"][0]["sha"], "a1");
+        assert_eq!(result.data["groups"][0]["title"], "Features");
+
+        let calls = runner.calls();
+        assert_eq!(calls[0][..3], ["git", "log", LOG_FORMAT]);
+        assert_eq!(calls[0][3..], ["--no-merges", "--end-of-options", "v1.2.0..HEAD"]);
+    }
+
+    #[tokio::test]
+    async fn test_option_like_refs_are_rejected() {
+        let runner = Arc::new(FakeRunner::new(|_, _| CommandOutput::ok("")));
+        let tool = GitChangelogTool::new().with_runner(runner.clone());
+
+        for args in [
+            serde_json::json!({ "from": "--output=/tmp/x" }),
+            serde_json::json!({ "to": "-p" }),
+        ] {
+            let result = tool.execute(ToolInput::new(args)).await.unwrap();
+            assert!(!result.success);
+            assert!(result.error.unwrap().contains("cannot start with '-'"));
+        }
+        assert!(runner.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bad_range_is_an_error() {
+        let tool = GitChangelogTool::new().with_runner(Arc::new(FakeRunner::new(|_, _| {
+            CommandOutput::failed(128, "fatal: ambiguous argument 'v9..HEAD': unknown revision")
+        })));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({ "from": "v9" })))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("unknown revision"));
+    }
+}
@@ -0,0 +1,586 @@
+//! Git Diff Summary Tool
+//!
+//! Tool for summarizing the changes between two refs, e.g. to write a PR
+//! description.
+//!
+//! ## Features
+//!
+//! - Per-file and total lines added/removed (from `git diff --numstat`)
+//! - Files grouped by category (source, tests, docs, config, build, ci, dependencies)
+//! - Raw diff capped to a token budget, keeping the largest hunks of the most
+//!   relevant files first; lockfile churn only fills budget left over after
+//!   everything else (binary files are listed, without diff text)
+//!
+//! ## Prerequisites
+//!
+//! - Requires `git` feature flag
+//! - Git must be installed and in PATH
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::debug;
+
+use super::common::{
+    create_schema, tool_config_with_timeout, CommandOutput, CommandRunner, ProcessRunner,
+};
+use super::output::{self, OutputFormat, RowView};
+
+/// Text and table form of the result: one row per changed file
+const FILES_VIEW: RowView = RowView {
+    rows: "files",
+    columns: &["path", "category", "insertions", "deletions"],
+};
+
+/// Default diff budget, in tokens
+const DEFAULT_TOKEN_BUDGET: usize = 4000;
+
+/// Rough characters-per-token ratio used for budgeting
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Check a user-supplied ref before passing it to git
+///
+/// Refs starting with `-` would be parsed as options (e.g.
+/// `--output=/etc/passwd`); callers also pass `--end-of-options` so git
+/// treats whatever follows as revisions.
+pub(crate) fn validate_ref(name: &str, value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err(format!("'{}' must not be empty", name));
+    }
+    if value.starts_with('-') {
+        return Err(format!("Invalid {} '{}': refs cannot start with '-'", name, value));
+    }
+    Ok(())
+}
+
+/// Category of a changed file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileCategory {
+    Source,
+    Tests,
+    Config,
+    Build,
+    Ci,
+    Docs,
+    Dependencies,
+    Other,
+}
+
+impl FileCategory {
+    /// Categorize a path
+    pub fn of(path: &str) -> Self {
+        let lower = path.to_lowercase();
+        let file = lower.rsplit('/').next().unwrap_or(&lower);
+        let ext = file.rsplit_once('.').map(|(_, e)| e).unwrap_or("");
+
+        if matches!(
+            file,
+            "cargo.lock"
+                | "package-lock.json"
+                | "yarn.lock"
+                | "pnpm-lock.yaml"
+                | "go.sum"
+                | "poetry.lock"
+                | "gemfile.lock"
+        ) {
+            Self::Dependencies
+        } else if lower.starts_with(".github/")
+            || lower.starts_with(".gitlab-ci")
+            || lower.starts_with(".circleci/")
+        {
+            Self::Ci
+        } else if matches!(
+            file,
+            "cargo.toml"
+                | "package.json"
+                | "go.mod"
+                | "makefile"
+                | "dockerfile"
+                | "build.rs"
+                | "pyproject.toml"
+        ) {
+            Self::Build
+        } else if lower.contains("/tests/")
+            || lower.starts_with("tests/")
+            || file.starts_with("test_")
+            || file.contains("_test.")
+            || file.contains(".test.")
+            || file.contains(".spec.")
+        {
+            Self::Tests
+        } else if matches!(ext, "md" | "rst" | "adoc" | "txt") || lower.starts_with("docs/") {
+            Self::Docs
+        } else if matches!(ext, "yaml" | "yml" | "json" | "toml" | "ini" | "env" | "conf") {
+            Self::Config
+        } else if matches!(
+            ext,
+            "rs" | "go" | "py" | "ts" | "tsx" | "js" | "jsx" | "java" | "kt" | "rb" | "c" | "h"
+                | "cpp" | "cs" | "swift" | "sh" | "tf"
+        ) {
+            Self::Source
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Relevance weight when choosing hunks for the capped diff
+    fn weight(&self) -> usize {
+        match self {
+            Self::Source => 8,
+            Self::Tests => 5,
+            Self::Config | Self::Build => 4,
+            Self::Ci => 3,
+            Self::Docs => 2,
+            Self::Other => 2,
+            Self::Dependencies => 0,
+        }
+    }
+}
+
+/// Line counts for one changed file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileChange {
+    pub path: String,
+    pub category: FileCategory,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub binary: bool,
+}
+
+/// Totals for one file category
+#[derive(Debug, Clone, Default, Serialize)]
+struct CategorySummary {
+    files: Vec<String>,
+    insertions: usize,
+    deletions: usize,
+}
+
+/// One hunk of the unified diff
+struct Hunk {
+    file_index: usize,
+    text: String,
+    changed_lines: usize,
+}
+
+/// A file section of the unified diff: its header and hunks
+struct FileDiff {
+    path: String,
+    header: String,
+}
+
+/// Git diff summary tool
+pub struct GitDiffSummaryTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl GitDiffSummaryTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "base": {
+                    "type": "string",
+                    "description": "Base ref (e.g., 'main', 'origin/main', a commit SHA)"
+                },
+                "head": {
+                    "type": "string",
+                    "description": "Head ref",
+                    "default": "HEAD"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Repository path",
+                    "default": "."
+                },
+                "token_budget": {
+                    "type": "integer",
+                    "description": "Approximate token budget for the included raw diff",
+                    "default": DEFAULT_TOKEN_BUDGET
+                },
+                "format": OutputFormat::schema()
+            }),
+            vec!["base"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "git_diff_summary",
+                "Summarize changes between two git refs for a PR description: files changed by category, lines added/removed, and the diff capped to a token budget with the most relevant hunks first.",
+                parameters,
+                60,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    async fn git(&self, path: &str, args: &[&str]) -> Result<CommandOutput, String> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        self.runner
+            .run("git", &args, Some(path), self.config.timeout_secs)
+            .await
+    }
+}
+
+impl Default for GitDiffSummaryTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for GitDiffSummaryTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let base: String = input.get_arg("base")?;
+        let head: String = input.get_arg("head").unwrap_or_else(|_| "HEAD".to_string());
+        let path: String = input.get_arg("path").unwrap_or_else(|_| ".".to_string());
+        let token_budget: usize = input
+            .get_arg("token_budget")
+            .unwrap_or(DEFAULT_TOKEN_BUDGET);
+        let format = match OutputFormat::from_input(&input, OutputFormat::Json) {
+            Ok(format) => format,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        for (name, value) in [("base", &base), ("head", &head)] {
+            if let Err(e) = validate_ref(name, value) {
+                return Ok(ToolResult::error(e));
+            }
+        }
+
+        let range = format!("{}..{}", base, head);
+        debug!(range = %range, path = %path, "Summarizing git diff");
+
+        let numstat = match self.git(&path, &["diff", "--numstat", "--end-of-options", &range]).await {
+            Ok(output) if output.success => output.stdout,
+            Ok(output) => {
+                return Ok(ToolResult::error(format!(
+                    "git diff failed: {}",
+                    output.stderr.trim()
+                )))
+            }
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        let diff = match self.git(&path, &["diff", "--end-of-options", &range]).await {
+            Ok(output) if output.success => output.stdout,
+            Ok(output) => {
+                return Ok(ToolResult::error(format!(
+                    "git diff failed: {}",
+                    output.stderr.trim()
+                )))
+            }
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        let files = parse_numstat(&numstat);
+        let (body, included, omitted) = cap_diff(&diff, &files, token_budget);
+
+        let mut categories: BTreeMap<FileCategory, CategorySummary> = BTreeMap::new();
+        for file in &files {
+            let entry = categories.entry(file.category).or_default();
+            entry.files.push(file.path.clone());
+            entry.insertions += file.insertions;
+            entry.deletions += file.deletions;
+        }
+
+        let result = serde_json::json!({
+            "base": base,
+            "head": head,
+            "files_changed": files.len(),
+            "insertions": files.iter().map(|f| f.insertions).sum::<usize>(),
+            "deletions": files.iter().map(|f| f.deletions).sum::<usize>(),
+            "categories": categories,
+            "files": files,
+            "diff": body,
+            "diff_truncated": omitted > 0,
+            "hunks_included": included,
+            "hunks_omitted": omitted,
+            "estimated_tokens": body.len() / CHARS_PER_TOKEN,
+        });
+
+        Ok(ToolResult::success(output::shape(result, format, &FILES_VIEW)))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Parse `git diff --numstat` output (binary files report `-` counts)
+fn parse_numstat(output: &str) -> Vec<FileChange> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let added = parts.next()?;
+            let removed = parts.next()?;
+            let path = parts.next()?.to_string();
+            let binary = added == "-" && removed == "-";
+            Some(FileChange {
+                category: FileCategory::of(&path),
+                insertions: added.parse().unwrap_or(0),
+                deletions: removed.parse().unwrap_or(0),
+                binary,
+                path,
+            })
+        })
+        .collect()
+}
+
+/// Split a unified diff into file sections and hunks
+fn parse_diff(diff: &str) -> (Vec<FileDiff>, Vec<Hunk>) {
+    let mut files: Vec<FileDiff> = Vec::new();
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let mut in_hunk = false;
+
+    for line in diff.split_inclusive('\n') {
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            let path = rest
+                .trim_end()
+                .rsplit_once(" b/")
+                .map(|(_, b)| b.to_string())
+                .unwrap_or_else(|| rest.trim_end().to_string());
+            files.push(FileDiff { path, header: line.to_string() });
+            in_hunk = false;
+        } else if line.starts_with("@@") && !files.is_empty() {
+            hunks.push(Hunk {
+                file_index: files.len() - 1,
+                text: line.to_string(),
+                changed_lines: 0,
+            });
+            in_hunk = true;
+        } else if in_hunk {
+            let hunk = hunks.last_mut().expect("in_hunk implies a hunk");
+            if line.starts_with('+') || line.starts_with('-') {
+                hunk.changed_lines += 1;
+            }
+            hunk.text.push_str(line);
+        } else if let Some(file) = files.last_mut() {
+            file.header.push_str(line);
+        }
+    }
+
+    (files, hunks)
+}
+
+/// Keep the most relevant hunks that fit the budget, in original diff order
+///
+/// Returns the capped diff and the number of included and omitted hunks.
+fn cap_diff(diff: &str, changes: &[FileChange], token_budget: usize) -> (String, usize, usize) {
+    let (files, hunks) = parse_diff(diff);
+    let budget = token_budget.saturating_mul(CHARS_PER_TOKEN);
+
+    let category_of = |file_index: usize| {
+        let path = &files[file_index].path;
+        changes
+            .iter()
+            .find(|c| &c.path == path)
+            .map(|c| c.category)
+            .unwrap_or_else(|| FileCategory::of(path))
+    };
+
+    // Highest relevance first: category weight scaled by hunk size, so
+    // weight-0 hunks (lockfiles) are only considered once everything else is
+    let mut order: Vec<usize> = (0..hunks.len()).collect();
+    order.sort_by_key(|&i| {
+        let hunk = &hunks[i];
+        std::cmp::Reverse(category_of(hunk.file_index).weight() * hunk.changed_lines.max(1))
+    });
+
+    let mut selected = vec![false; hunks.len()];
+    let mut header_used = vec![false; files.len()];
+    let mut used = 0;
+    for i in order {
+        let hunk = &hunks[i];
+        let header_cost = if header_used[hunk.file_index] {
+            0
+        } else {
+            files[hunk.file_index].header.len()
+        };
+        let cost = header_cost + hunk.text.len();
+        if used + cost > budget {
+            continue;
+        }
+        used += cost;
+        selected[i] = true;
+        header_used[hunk.file_index] = true;
+    }
+
+    let mut body = String::new();
+    let mut last_file = None;
+    for (i, hunk) in hunks.iter().enumerate() {
+        if !selected[i] {
+            continue;
+        }
+        if last_file != Some(hunk.file_index) {
+            body.push_str(&files[hunk.file_index].header);
+            last_file = Some(hunk.file_index);
+        }
+        body.push_str(&hunk.text);
+    }
+
+    let included = selected.iter().filter(|s| **s).count();
+    (body, included, hunks.len() - included)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::FakeRunner;
+
+    const NUMSTAT: &str = "\
+40\t2\tsrc/server.rs
+12\t0\ttests/server_test.rs
+3\t1\tREADME.md
+250\t180\tCargo.lock
+-\t-\tassets/logo.png
+";
+
+    fn sample_diff() -> String {
+        let server_hunk: String = (0..40)
+            .map(|i| format!("+    let handler_{} = route({});\n", i, i))
+            .collect();
+        let lock_hunk: String = (0..250)
+            .map(|i| format!("+checksum = \"{:064}\"\n", i))
+            .collect();
+        format!(
+            "diff --git a/src/server.rs b/src/server.rs\n\
+index 1111111..2222222 100644\n\
+--- a/src/server.rs\n\
++++ b/src/server.rs\n\
+@@ -10,4 +10,42 @@ fn main() {{\n\
+-    let old = 1;\n\
+-    let older = 2;\n\
+{server_hunk}\
+diff --git a/tests/server_test.rs b/tests/server_test.rs\n\
+new file mode 100644\n\
+--- /dev/null\n\
++++ b/tests/server_test.rs\n\
+@@ -0,0 +1,12 @@\n\
++#[test]\n\
++fn routes() {{}}\n\
+diff --git a/README.md b/README.md\n\
+--- a/README.md\n\
++++ b/README.md\n\
+@@ -1,3 +1,5 @@\n\
+-Old intro\n\
++New intro\n\
++More docs\n\
+diff --git a/Cargo.lock b/Cargo.lock\n\
+--- a/Cargo.lock\n\
++++ b/Cargo.lock\n\
+@@ -1,180 +1,250 @@\n\
+{lock_hunk}"
+        )
+    }
+
+    fn tool() -> (GitDiffSummaryTool, Arc<FakeRunner>) {
+        let diff = sample_diff();
+        let runner = Arc::new(FakeRunner::new(move |_, args| {
+            if args.iter().any(|a| a == "--numstat") {
+                CommandOutput::ok(NUMSTAT)
+            } else {
+                CommandOutput::ok(diff.clone())
+            }
+        }));
+        (GitDiffSummaryTool::new().with_runner(runner.clone()), runner)
+    }
+
+    #[test]
+    fn test_file_categories() {
+        assert_eq!(FileCategory::of("crates/aof-core/src/agent.rs"), FileCategory::Source);
+        assert_eq!(FileCategory::of("crates/aof-core/tests/agent.rs"), FileCategory::Tests);
+        assert_eq!(FileCategory::of("web/app.spec.ts"), FileCategory::Tests);
+        assert_eq!(FileCategory::of("docs/guide.md"), FileCategory::Docs);
+        assert_eq!(FileCategory::of(".github/workflows/ci.yml"), FileCategory::Ci);
+        assert_eq!(FileCategory::of("crates/aof-tools/Cargo.toml"), FileCategory::Build);
+        assert_eq!(FileCategory::of("Cargo.lock"), FileCategory::Dependencies);
+        assert_eq!(FileCategory::of("config/agent.yaml"), FileCategory::Config);
+    }
+
+    #[tokio::test]
+    async fn test_stat_summary() {
+        let (tool, runner) = tool();
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"base": "main", "head": "feature"})))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        let data = &result.data;
+        assert_eq!(data["files_changed"], 5);
+        assert_eq!(data["insertions"], 305);
+        assert_eq!(data["deletions"], 183);
+        assert_eq!(data["categories"]["source"]["files"], serde_json::json!(["src/server.rs"]));
+        assert_eq!(data["categories"]["source"]["insertions"], 40);
+        assert_eq!(data["categories"]["tests"]["insertions"], 12);
+        assert_eq!(data["categories"]["dependencies"]["deletions"], 180);
+        assert_eq!(data["files"][4]["binary"], true);
+
+        let calls = runner.calls();
+        assert_eq!(calls[0], ["git", "diff", "--numstat", "--end-of-options", "main..feature"]);
+        assert_eq!(calls[1], ["git", "diff", "--end-of-options", "main..feature"]);
+    }
+
+    #[tokio::test]
+    async fn test_option_like_refs_are_rejected() {
+        let (tool, runner) = tool();
+        for args in [
+            serde_json::json!({"base": "--output=/tmp/x"}),
+            serde_json::json!({"base": "main", "head": "-p"}),
+        ] {
+            let result = tool.execute(ToolInput::new(args)).await.unwrap();
+            assert!(!result.success);
+            assert!(result.error.unwrap().contains("cannot start with '-'"));
+        }
+        assert!(runner.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_diff_capped_to_budget() {
+        let (tool, _) = tool();
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"base": "main", "token_budget": 400})))
+            .await
+            .unwrap();
+
+        let data = &result.data;
+        let body = data["diff"].as_str().unwrap();
+        assert!(body.len() <= 400 * CHARS_PER_TOKEN);
+        assert_eq!(data["diff_truncated"], true);
+        assert_eq!(data["hunks_included"], 2);
+        assert_eq!(data["hunks_omitted"], 2);
+
+        // The large source hunk wins; the lockfile hunk is too big for what's left
+        assert!(body.starts_with("diff --git a/src/server.rs"));
+        assert!(body.contains("let handler_39 = route(39);"));
+        assert!(!body.contains("checksum"));
+
+        // The test hunk doesn't fit what's left, but the smaller README hunk does
+        assert!(!body.contains("fn routes()"));
+        assert!(body.contains("+New intro"));
+    }
+
+    #[tokio::test]
+    async fn test_full_diff_within_budget() {
+        let (tool, _) = tool();
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"base": "main", "token_budget": 100000})))
+            .await
+            .unwrap();
+
+        // With room to spare, lockfile churn is included too (last)
+        assert_eq!(result.data["hunks_included"], 4);
+        assert_eq!(result.data["hunks_omitted"], 0);
+        assert!(result.data["diff"].as_str().unwrap().contains("checksum"));
+    }
+}
@@ -30,6 +30,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all GitHub Actions tools
 pub struct GitHubActionsTools;
@@ -195,7 +196,7 @@ impl Tool for GitHubWorkflowListTool {
         let response = match client
             .get(&url)
             .query(&[("per_page", per_page), ("page", page)])
-            .send()
+            .send_tracked()
             .await
         {
             Ok(r) => r,
@@ -321,7 +322,7 @@ impl Tool for GitHubWorkflowDispatchTool {
             payload["inputs"] = inp;
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -509,7 +510,7 @@ impl Tool for GitHubRunListTool {
             params.push(("exclude_pull_requests", "true".to_string()));
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -613,7 +614,7 @@ impl Tool for GitHubRunGetTool {
             owner, repo, run_id
         );
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -711,7 +712,7 @@ impl Tool for GitHubRunCancelTool {
             owner, repo, run_id
         );
 
-        let response = match client.post(&url).send().await {
+        let response = match client.post(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -839,7 +840,7 @@ impl Tool for GitHubRunRerunTool {
             serde_json::json!({})
         };
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -943,7 +944,7 @@ impl Tool for GitHubRunForceCancelTool {
             owner, repo, run_id
         );
 
-        let response = match client.post(&url).send().await {
+        let response = match client.post(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -1079,7 +1080,7 @@ impl Tool for GitHubArtifactsListTool {
             params.push(("name", n));
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -1189,7 +1190,7 @@ impl Tool for GitHubArtifactsDownloadTool {
         );
 
         // Just get the redirect URL, don't actually download
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -1309,7 +1310,7 @@ impl Tool for GitHubRunLogsTool {
             owner, repo, run_id
         );
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -28,6 +28,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all GitLab CI tools
 pub struct GitLabCITools;
@@ -308,7 +309,7 @@ impl Tool for GitLabPipelineListTool {
             params.push(("updated_before", before));
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(resp) => resp,
             Err(e) => {
                 if e.is_timeout() {
@@ -426,7 +427,7 @@ impl Tool for GitLabPipelineGetTool {
         let client = create_gitlab_client(&token)?;
         let url = build_project_url(&endpoint, &project_id, &format!("pipelines/{}", pipeline_id));
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(resp) => resp,
             Err(e) => {
                 if e.is_timeout() {
@@ -557,7 +558,7 @@ impl Tool for GitLabPipelineCreateTool {
             payload["variables"] = serde_json::Value::Array(vars);
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(resp) => resp,
             Err(e) => {
                 if e.is_timeout() {
@@ -675,7 +676,7 @@ impl Tool for GitLabPipelineCancelTool {
             &format!("pipelines/{}/cancel", pipeline_id),
         );
 
-        let response = match client.post(&url).send().await {
+        let response = match client.post(&url).send_tracked().await {
             Ok(resp) => resp,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Request failed: {}", e)));
@@ -780,7 +781,7 @@ impl Tool for GitLabPipelineRetryTool {
             &format!("pipelines/{}/retry", pipeline_id),
         );
 
-        let response = match client.post(&url).send().await {
+        let response = match client.post(&url).send_tracked().await {
             Ok(resp) => resp,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Request failed: {}", e)));
@@ -932,7 +933,7 @@ impl Tool for GitLabJobListTool {
             }
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(resp) => resp,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Request failed: {}", e)));
@@ -1032,7 +1033,7 @@ impl Tool for GitLabJobGetTool {
         let client = create_gitlab_client(&token)?;
         let url = build_project_url(&endpoint, &project_id, &format!("jobs/{}", job_id));
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(resp) => resp,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Request failed: {}", e)));
@@ -1127,7 +1128,7 @@ impl Tool for GitLabJobLogTool {
 
         // First get job details for name and status
         let job_url = build_project_url(&endpoint, &project_id, &format!("jobs/{}", job_id));
-        let job_response = client.get(&job_url).send().await;
+        let job_response = client.get(&job_url).send_tracked().await;
 
         let (job_name, job_status) = if let Ok(resp) = job_response {
             if let Ok(job_body) = resp.json::<serde_json::Value>().await {
@@ -1157,7 +1158,7 @@ impl Tool for GitLabJobLogTool {
         let response = match client
             .get(&log_url)
             .header(reqwest::header::ACCEPT, "text/plain")
-            .send()
+            .send_tracked()
             .await
         {
             Ok(resp) => resp,
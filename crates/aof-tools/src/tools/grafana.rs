@@ -26,6 +26,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit;
 
 /// Collection of all Grafana tools
 pub struct GrafanaTools;
@@ -188,7 +189,7 @@ impl Tool for GrafanaQueryTool {
             query_payload["queries"][0]["intervalMs"] = serde_json::json!(interval);
         }
 
-        let response = match client.post(&url).json(&query_payload).send().await {
+        let (response, quota) = match rate_limit::send(client.post(&url).json(&query_payload)).await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -226,11 +227,16 @@ impl Tool for GrafanaQueryTool {
         }
 
         if status == 429 {
-            let retry_after = "unknown"; // Response headers not easily accessible here
-            return Ok(ToolResult::error(format!(
+            let retry_after = quota
+                .rate_limit
+                .as_ref()
+                .and_then(|r| r.retry_after)
+                .map(|d| format!("{}s", d.as_secs()))
+                .unwrap_or_else(|| "unknown".to_string());
+            return Ok(quota.annotate(ToolResult::error(format!(
                 "Rate limited. Retry after: {}",
                 retry_after
-            )));
+            ))));
         }
 
         if status >= 500 {
@@ -249,11 +255,11 @@ impl Tool for GrafanaQueryTool {
             )));
         }
 
-        Ok(ToolResult::success(serde_json::json!({
+        Ok(quota.annotate(ToolResult::success(serde_json::json!({
             "results": body.get("results"),
             "datasource_uid": datasource_uid,
             "query": query
-        })))
+        }))))
     }
 
     fn config(&self) -> &ToolConfig {
@@ -325,7 +331,7 @@ impl Tool for GrafanaDashboardGetTool {
 
         let url = format!("{}/api/dashboards/uid/{}", endpoint.trim_end_matches('/'), dashboard_uid);
 
-        let response = match client.get(&url).send().await {
+        let (response, quota) = match rate_limit::send(client.get(&url)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Failed to get dashboard: {}", e)));
@@ -348,10 +354,10 @@ impl Tool for GrafanaDashboardGetTool {
             )));
         }
 
-        Ok(ToolResult::success(serde_json::json!({
+        Ok(quota.annotate(ToolResult::success(serde_json::json!({
             "dashboard": body.get("dashboard"),
             "meta": body.get("meta")
-        })))
+        }))))
     }
 
     fn config(&self) -> &ToolConfig {
@@ -463,7 +469,7 @@ impl Tool for GrafanaDashboardListTool {
             }
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let (response, quota) = match rate_limit::send(client.get(&url).query(&params)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Failed to list dashboards: {}", e)));
@@ -487,10 +493,10 @@ impl Tool for GrafanaDashboardListTool {
 
         let count = body.as_array().map(|a| a.len()).unwrap_or(0);
 
-        Ok(ToolResult::success(serde_json::json!({
+        Ok(quota.annotate(ToolResult::success(serde_json::json!({
             "dashboards": body,
             "count": count
-        })))
+        }))))
     }
 
     fn config(&self) -> &ToolConfig {
@@ -596,7 +602,7 @@ impl Tool for GrafanaAlertListTool {
             params.push(("folderId", fid.to_string()));
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let (response, quota) = match rate_limit::send(client.get(&url).query(&params)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Failed to list alerts: {}", e)));
@@ -620,10 +626,10 @@ impl Tool for GrafanaAlertListTool {
 
         let count = body.as_array().map(|a| a.len()).unwrap_or(0);
 
-        Ok(ToolResult::success(serde_json::json!({
+        Ok(quota.annotate(ToolResult::success(serde_json::json!({
             "alerts": body,
             "count": count
-        })))
+        }))))
     }
 
     fn config(&self) -> &ToolConfig {
@@ -745,7 +751,7 @@ impl Tool for GrafanaAlertSilenceTool {
             payload["createdBy"] = serde_json::json!(creator);
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let (response, quota) = match rate_limit::send(client.post(&url).json(&payload)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Failed to create silence: {}", e)));
@@ -768,11 +774,11 @@ impl Tool for GrafanaAlertSilenceTool {
             )));
         }
 
-        Ok(ToolResult::success(serde_json::json!({
+        Ok(quota.annotate(ToolResult::success(serde_json::json!({
             "silence_id": body.get("silenceID"),
             "starts_at": starts_at,
             "ends_at": ends_at
-        })))
+        }))))
     }
 
     fn config(&self) -> &ToolConfig {
@@ -896,7 +902,7 @@ impl Tool for GrafanaAnnotationCreateTool {
             payload["panelId"] = serde_json::json!(pid);
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let (response, quota) = match rate_limit::send(client.post(&url).json(&payload)).await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Failed to create annotation: {}", e)));
@@ -919,10 +925,10 @@ impl Tool for GrafanaAnnotationCreateTool {
             )));
         }
 
-        Ok(ToolResult::success(serde_json::json!({
+        Ok(quota.annotate(ToolResult::success(serde_json::json!({
             "annotation_id": body.get("id"),
             "message": body.get("message").unwrap_or(&serde_json::json!("Annotation created"))
-        })))
+        }))))
     }
 
     fn config(&self) -> &ToolConfig {
@@ -0,0 +1,319 @@
+//! Helm Template Tool
+//!
+//! Renders a Helm chart locally so the output can be reviewed before anything
+//! is applied, e.g. in a GitOps review flow.
+//!
+//! ## Features
+//!
+//! - `helm template` with values files and `--set` overrides
+//! - Rendered output as one manifest stream, or split per resource
+//! - Optional `helm lint` pass, with messages parsed by severity
+//!
+//! ## Prerequisites
+//!
+//! - Requires `kubectl` feature flag
+//! - helm must be installed and in PATH (no cluster access needed)
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::debug;
+
+use super::common::{
+    create_schema, tool_config_with_timeout, CommandOutput, CommandRunner, ProcessRunner,
+};
+use super::manifests::split_rendered;
+
+/// A message reported by `helm lint`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LintMessage {
+    /// Severity (INFO, WARNING, ERROR)
+    pub level: String,
+    /// Message text, including the file it refers to
+    pub message: String,
+}
+
+/// Helm template tool
+pub struct HelmTemplateTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl HelmTemplateTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "chart": {
+                    "type": "string",
+                    "description": "Chart path, packaged chart, or repo/chart reference"
+                },
+                "release_name": {
+                    "type": "string",
+                    "description": "Release name used while rendering (default: release)"
+                },
+                "namespace": {
+                    "type": "string",
+                    "description": "Namespace used while rendering"
+                },
+                "values_files": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Values files passed with -f, in order"
+                },
+                "set": {
+                    "type": "object",
+                    "description": "Value overrides passed with --set (key: value)"
+                },
+                "split": {
+                    "type": "boolean",
+                    "description": "Return each rendered resource separately",
+                    "default": false
+                },
+                "validate": {
+                    "type": "boolean",
+                    "description": "Also run helm lint with the same values",
+                    "default": false
+                }
+            }),
+            vec!["chart"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "helm_template",
+                "Render a Helm chart locally with helm template and return the manifests (optionally split per resource). Set validate to also run helm lint. Use to review what a chart produces before applying it.",
+                parameters,
+                120,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    async fn helm(&self, args: Vec<String>) -> Result<CommandOutput, String> {
+        self.runner
+            .run("helm", &args, None, self.config.timeout_secs)
+            .await
+    }
+}
+
+impl Default for HelmTemplateTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for HelmTemplateTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let chart: String = input.get_arg("chart")?;
+        let release_name: String = input
+            .get_arg("release_name")
+            .unwrap_or_else(|_| "release".to_string());
+        let namespace: Option<String> = input.get_arg("namespace").ok();
+        let values_files: Vec<String> = input.get_arg("values_files").unwrap_or_default();
+        let overrides: BTreeMap<String, serde_json::Value> = input.get_arg("set").unwrap_or_default();
+        let split: bool = input.get_arg("split").unwrap_or(false);
+        let validate: bool = input.get_arg("validate").unwrap_or(false);
+
+        let mut value_args = Vec::new();
+        for file in &values_files {
+            value_args.push(format!("--values={}", file));
+        }
+        for (key, value) in &overrides {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            value_args.push(format!("--set={}={}", key, value));
+        }
+
+        let mut result = serde_json::json!({
+            "chart": chart,
+            "release_name": release_name,
+        });
+
+        if validate {
+            let mut args = vec!["lint".to_string(), chart.clone()];
+            args.extend(value_args.iter().cloned());
+
+            debug!(chart = %chart, "Linting helm chart");
+            let output = match self.helm(args).await {
+                Ok(output) => output,
+                Err(e) => return Ok(ToolResult::error(format!("Failed to run helm: {}", e))),
+            };
+            let messages = parse_lint(&output.stdout);
+            result["lint"] = serde_json::json!({
+                "passed": output.success,
+                "messages": messages,
+            });
+            if !output.success && messages.is_empty() {
+                result["lint"]["stderr"] = serde_json::json!(output.stderr.trim());
+            }
+        }
+
+        let mut args = vec!["template".to_string(), release_name.clone(), chart.clone()];
+        if let Some(ref ns) = namespace {
+            args.push(format!("--namespace={}", ns));
+        }
+        args.extend(value_args);
+
+        debug!(chart = %chart, release = %release_name, "Rendering helm chart");
+        let output = match self.helm(args).await {
+            Ok(output) => output,
+            Err(e) => return Ok(ToolResult::error(format!("Failed to run helm: {}", e))),
+        };
+        if !output.success {
+            return Ok(ToolResult::error(format!(
+                "helm template failed for '{}': {}",
+                chart,
+                output.stderr.trim()
+            )));
+        }
+
+        let resources = match split_rendered(&output.stdout) {
+            Ok(resources) => resources,
+            Err(e) => return Ok(ToolResult::error(format!("helm template output for '{}' is not valid: {}", chart, e))),
+        };
+        result["resource_count"] = serde_json::json!(resources.len());
+        if split {
+            result["resources"] = serde_json::json!(resources);
+        } else {
+            result["manifests"] = serde_json::json!(output.stdout);
+        }
+
+        Ok(ToolResult::success(result))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Parse `[LEVEL] message` lines from `helm lint` output
+fn parse_lint(stdout: &str) -> Vec<LintMessage> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (level, message) = line.trim().strip_prefix('[')?.split_once(']')?;
+            Some(LintMessage {
+                level: level.to_string(),
+                message: message.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::FakeRunner;
+
+    const RENDERED: &str = "---\n# Source: web/templates/service.yaml\napiVersion: v1\nkind: Service\nmetadata:\n  name: prod-web\n---\n# Source: web/templates/deployment.yaml\napiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: prod-web\nspec:\n  replicas: 3\n";
+
+    const LINT_FAILED: &str = "==> Linting ./charts/web\n[INFO] Chart.yaml: icon is recommended\n[ERROR] templates/deployment.yaml: unable to parse YAML\n\nError: 1 chart(s) linted, 1 chart(s) failed\n";
+
+    fn fake_helm(lint: CommandOutput) -> Arc<FakeRunner> {
+        Arc::new(FakeRunner::new(move |_, args| match args[0].as_str() {
+            "template" => CommandOutput::ok(RENDERED),
+            "lint" => lint.clone(),
+            _ => CommandOutput::failed(1, "unexpected command"),
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_template_split_per_resource() {
+        let runner = fake_helm(CommandOutput::ok(""));
+        let tool = HelmTemplateTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "chart": "./charts/web",
+                "release_name": "prod",
+                "namespace": "prod",
+                "values_files": ["values.yaml", "values-prod.yaml"],
+                "set": { "replicas": 3 },
+                "split": true
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["resource_count"], 2);
+        let resources = result.data["resources"].as_array().unwrap();
+        assert_eq!(resources[0]["kind"], "Service");
+        assert_eq!(resources[1]["kind"], "Deployment");
+        assert_eq!(resources[1]["source"], "web/templates/deployment.yaml");
+        assert!(result.data.get("lint").is_none());
+
+        let calls = runner.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(
+            calls[0],
+            vec![
+                "helm",
+                "template",
+                "prod",
+                "./charts/web",
+                "--namespace=prod",
+                "--values=values.yaml",
+                "--values=values-prod.yaml",
+                "--set=replicas=3",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_reports_lint_errors() {
+        let runner = fake_helm(CommandOutput {
+            exit_code: 1,
+            stdout: LINT_FAILED.to_string(),
+            stderr: String::new(),
+            success: false,
+        });
+        let tool = HelmTemplateTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "chart": "./charts/web",
+                "validate": true
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["lint"]["passed"], false);
+        let messages = result.data["lint"]["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1]["level"], "ERROR");
+        assert!(messages[1]["message"].as_str().unwrap().contains("unable to parse YAML"));
+        assert!(result.data["manifests"].as_str().unwrap().contains("kind: Deployment"));
+
+        let calls = runner.calls();
+        assert_eq!(calls[0][..3], ["helm", "lint", "./charts/web"]);
+        assert_eq!(calls[1][1], "template");
+    }
+
+    #[tokio::test]
+    async fn test_template_failure_is_an_error() {
+        let tool = HelmTemplateTool::new().with_runner(Arc::new(FakeRunner::new(|_, _| {
+            CommandOutput::failed(1, "Error: chart \"missing\" not found")
+        })));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({ "chart": "missing" })))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not found"));
+    }
+}
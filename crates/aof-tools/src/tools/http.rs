@@ -23,6 +23,7 @@ use std::collections::HashMap;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// HTTP request tool
 pub struct HttpTool {
@@ -136,7 +137,7 @@ impl Tool for HttpTool {
 
         // Execute request
         let start = std::time::Instant::now();
-        let response = match request.send().await {
+        let response = match request.send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("HTTP request failed: {}", e)));
@@ -0,0 +1,466 @@
+//! Kubernetes API Tool
+//!
+//! Tool for reading Kubernetes resources directly from the API server, without
+//! the kubectl binary.
+//!
+//! ## Features
+//!
+//! - `get`, `list` and `describe` for common resource kinds
+//! - Namespace, label selector and field selector support
+//! - Structured JSON output (managed fields stripped, secret values redacted)
+//! - `describe` includes the object's recent events
+//!
+//! ## Prerequisites
+//!
+//! - Requires `k8s-api` feature flag
+//! - In-cluster service account credentials or a kubeconfig
+//!
+//! ## Usage
+//!
+//! Useful in minimal containers where kubectl isn't installed. Credentials are
+//! resolved the same way kubectl does: in-cluster config first, then
+//! `KUBECONFIG` / `~/.kube/config`.
+
+use aof_core::guardrail::REDACTED;
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use kube::api::{Api, ApiResource, DynamicObject, ListParams};
+use kube::Client;
+use tracing::debug;
+
+use super::common::{create_schema, tool_config_with_timeout};
+
+/// A resource kind the tool knows how to address
+struct KnownKind {
+    aliases: &'static [&'static str],
+    group: &'static str,
+    version: &'static str,
+    kind: &'static str,
+    plural: &'static str,
+    namespaced: bool,
+}
+
+const KNOWN_KINDS: &[KnownKind] = &[
+    KnownKind { aliases: &["pod", "pods", "po"], group: "", version: "v1", kind: "Pod", plural: "pods", namespaced: true },
+    KnownKind { aliases: &["service", "services", "svc"], group: "", version: "v1", kind: "Service", plural: "services", namespaced: true },
+    KnownKind { aliases: &["configmap", "configmaps", "cm"], group: "", version: "v1", kind: "ConfigMap", plural: "configmaps", namespaced: true },
+    KnownKind { aliases: &["secret", "secrets"], group: "", version: "v1", kind: "Secret", plural: "secrets", namespaced: true },
+    KnownKind { aliases: &["event", "events", "ev"], group: "", version: "v1", kind: "Event", plural: "events", namespaced: true },
+    KnownKind { aliases: &["endpoints", "ep"], group: "", version: "v1", kind: "Endpoints", plural: "endpoints", namespaced: true },
+    KnownKind { aliases: &["serviceaccount", "serviceaccounts", "sa"], group: "", version: "v1", kind: "ServiceAccount", plural: "serviceaccounts", namespaced: true },
+    KnownKind { aliases: &["persistentvolumeclaim", "persistentvolumeclaims", "pvc"], group: "", version: "v1", kind: "PersistentVolumeClaim", plural: "persistentvolumeclaims", namespaced: true },
+    KnownKind { aliases: &["persistentvolume", "persistentvolumes", "pv"], group: "", version: "v1", kind: "PersistentVolume", plural: "persistentvolumes", namespaced: false },
+    KnownKind { aliases: &["node", "nodes", "no"], group: "", version: "v1", kind: "Node", plural: "nodes", namespaced: false },
+    KnownKind { aliases: &["namespace", "namespaces", "ns"], group: "", version: "v1", kind: "Namespace", plural: "namespaces", namespaced: false },
+    KnownKind { aliases: &["deployment", "deployments", "deploy"], group: "apps", version: "v1", kind: "Deployment", plural: "deployments", namespaced: true },
+    KnownKind { aliases: &["statefulset", "statefulsets", "sts"], group: "apps", version: "v1", kind: "StatefulSet", plural: "statefulsets", namespaced: true },
+    KnownKind { aliases: &["daemonset", "daemonsets", "ds"], group: "apps", version: "v1", kind: "DaemonSet", plural: "daemonsets", namespaced: true },
+    KnownKind { aliases: &["replicaset", "replicasets", "rs"], group: "apps", version: "v1", kind: "ReplicaSet", plural: "replicasets", namespaced: true },
+    KnownKind { aliases: &["job", "jobs"], group: "batch", version: "v1", kind: "Job", plural: "jobs", namespaced: true },
+    KnownKind { aliases: &["cronjob", "cronjobs", "cj"], group: "batch", version: "v1", kind: "CronJob", plural: "cronjobs", namespaced: true },
+    KnownKind { aliases: &["ingress", "ingresses", "ing"], group: "networking.k8s.io", version: "v1", kind: "Ingress", plural: "ingresses", namespaced: true },
+    KnownKind { aliases: &["horizontalpodautoscaler", "horizontalpodautoscalers", "hpa"], group: "autoscaling", version: "v2", kind: "HorizontalPodAutoscaler", plural: "horizontalpodautoscalers", namespaced: true },
+];
+
+impl KnownKind {
+    fn find(name: &str) -> Option<&'static KnownKind> {
+        let name = name.to_lowercase();
+        KNOWN_KINDS.iter().find(|k| k.aliases.contains(&name.as_str()))
+    }
+
+    fn api_resource(&self) -> ApiResource {
+        let api_version = if self.group.is_empty() {
+            self.version.to_string()
+        } else {
+            format!("{}/{}", self.group, self.version)
+        };
+        ApiResource {
+            group: self.group.to_string(),
+            version: self.version.to_string(),
+            api_version,
+            kind: self.kind.to_string(),
+            plural: self.plural.to_string(),
+        }
+    }
+}
+
+/// Kubernetes API read tool
+pub struct K8sApiTool {
+    config: ToolConfig,
+    client: Option<Client>,
+}
+
+impl K8sApiTool {
+    pub fn new() -> Self {
+        let kinds: Vec<&str> = KNOWN_KINDS.iter().map(|k| k.plural).collect();
+        let parameters = create_schema(
+            serde_json::json!({
+                "action": {
+                    "type": "string",
+                    "description": "Operation to perform",
+                    "enum": ["get", "list", "describe"],
+                    "default": "list"
+                },
+                "kind": {
+                    "type": "string",
+                    "description": format!("Resource kind (singular, plural or short name): {}", kinds.join(", "))
+                },
+                "name": {
+                    "type": "string",
+                    "description": "Resource name (required for get and describe)"
+                },
+                "namespace": {
+                    "type": "string",
+                    "description": "Namespace (default: the credentials' default namespace)"
+                },
+                "all_namespaces": {
+                    "type": "boolean",
+                    "description": "List across all namespaces",
+                    "default": false
+                },
+                "label_selector": {
+                    "type": "string",
+                    "description": "Label selector (e.g., 'app=nginx,env=prod')"
+                },
+                "field_selector": {
+                    "type": "string",
+                    "description": "Field selector (e.g., 'status.phase=Running')"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of items to list"
+                }
+            }),
+            vec!["kind"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "k8s_api",
+                "Read Kubernetes resources directly from the API server (no kubectl needed). Supports get, list and describe with namespace and label selectors; returns structured JSON.",
+                parameters,
+                60,
+            ),
+            client: None,
+        }
+    }
+
+    /// Use an existing client instead of resolving in-cluster/kubeconfig credentials
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    async fn client(&self) -> Result<Client, String> {
+        match &self.client {
+            Some(client) => Ok(client.clone()),
+            None => Client::try_default()
+                .await
+                .map_err(|e| format!("Failed to load Kubernetes credentials: {}", e)),
+        }
+    }
+}
+
+impl Default for K8sApiTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for K8sApiTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let action: String = input.get_arg("action").unwrap_or_else(|_| "list".to_string());
+        let kind_name: String = input.get_arg("kind")?;
+        let name: Option<String> = input.get_arg("name").ok();
+        let namespace: Option<String> = input.get_arg("namespace").ok();
+        let all_namespaces: bool = input.get_arg("all_namespaces").unwrap_or(false);
+        let label_selector: Option<String> = input.get_arg("label_selector").ok();
+        let field_selector: Option<String> = input.get_arg("field_selector").ok();
+        let limit: Option<u32> = input.get_arg("limit").ok();
+
+        let Some(kind) = KnownKind::find(&kind_name) else {
+            let known: Vec<&str> = KNOWN_KINDS.iter().map(|k| k.plural).collect();
+            return Ok(ToolResult::error(format!(
+                "Unsupported kind '{}'. Supported kinds: {}",
+                kind_name,
+                known.join(", ")
+            )));
+        };
+
+        if matches!(action.as_str(), "get" | "describe") && name.is_none() {
+            return Ok(ToolResult::error(format!(
+                "Missing required parameter: name (needed for {})",
+                action
+            )));
+        }
+
+        let client = match self.client().await {
+            Ok(client) => client,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        let ar = kind.api_resource();
+        let namespace = if kind.namespaced && !(action == "list" && all_namespaces) {
+            Some(namespace.unwrap_or_else(|| client.default_namespace().to_string()))
+        } else {
+            None
+        };
+        let api: Api<DynamicObject> = match &namespace {
+            Some(ns) => Api::namespaced_with(client.clone(), ns, &ar),
+            None => Api::all_with(client.clone(), &ar),
+        };
+
+        debug!(action = %action, kind = %kind.kind, namespace = ?namespace, "Querying Kubernetes API");
+
+        match action.as_str() {
+            "list" => {
+                let mut params = ListParams::default();
+                if let Some(ref selector) = label_selector {
+                    params = params.labels(selector);
+                }
+                if let Some(ref selector) = field_selector {
+                    params = params.fields(selector);
+                }
+                if let Some(limit) = limit {
+                    params = params.limit(limit);
+                }
+
+                match api.list(&params).await {
+                    Ok(list) => {
+                        let items: Vec<serde_json::Value> =
+                            list.items.iter().map(|obj| clean_object(kind, obj)).collect();
+                        Ok(ToolResult::success(serde_json::json!({
+                            "kind": kind.kind,
+                            "namespace": namespace,
+                            "count": items.len(),
+                            "items": items
+                        })))
+                    }
+                    Err(e) => Ok(ToolResult::error(format!(
+                        "Failed to list {}: {}",
+                        kind.plural, e
+                    ))),
+                }
+            }
+            "get" | "describe" => {
+                let name = name.unwrap_or_default();
+                let object = match api.get(&name).await {
+                    Ok(obj) => clean_object(kind, &obj),
+                    Err(e) => {
+                        return Ok(ToolResult::error(format!(
+                            "Failed to get {} '{}': {}",
+                            kind.kind, name, e
+                        )))
+                    }
+                };
+
+                if action == "get" {
+                    return Ok(ToolResult::success(object));
+                }
+
+                let events = match related_events(&client, kind, &name, namespace.as_deref()).await {
+                    Ok(events) => events,
+                    Err(e) => {
+                        debug!("Failed to load events for {}/{}: {}", kind.kind, name, e);
+                        vec![]
+                    }
+                };
+                Ok(ToolResult::success(serde_json::json!({
+                    "object": object,
+                    "events": events
+                })))
+            }
+            other => Ok(ToolResult::error(format!(
+                "Unknown action '{}'. Valid actions: get, list, describe",
+                other
+            ))),
+        }
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Serialize an object, dropping noise and redacting secret values
+fn clean_object(kind: &KnownKind, obj: &DynamicObject) -> serde_json::Value {
+    let mut value = serde_json::to_value(obj).unwrap_or_default();
+
+    if let Some(metadata) = value.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+        metadata.remove("managedFields");
+        if let Some(annotations) = metadata.get_mut("annotations").and_then(|a| a.as_object_mut()) {
+            annotations.remove("kubectl.kubernetes.io/last-applied-configuration");
+        }
+    }
+
+    if kind.kind == "Secret" {
+        for field in ["data", "stringData"] {
+            if let Some(data) = value.get_mut(field).and_then(|d| d.as_object_mut()) {
+                for v in data.values_mut() {
+                    *v = serde_json::Value::String(REDACTED.to_string());
+                }
+            }
+        }
+    }
+
+    value
+}
+
+/// Events whose involvedObject is the given resource, most recent last
+async fn related_events(
+    client: &Client,
+    kind: &KnownKind,
+    name: &str,
+    namespace: Option<&str>,
+) -> Result<Vec<serde_json::Value>, kube::Error> {
+    let ar = KnownKind::find("events")
+        .expect("events is a known kind")
+        .api_resource();
+    let api: Api<DynamicObject> = match namespace {
+        Some(ns) => Api::namespaced_with(client.clone(), ns, &ar),
+        None => Api::all_with(client.clone(), &ar),
+    };
+    let params = ListParams::default().fields(&format!(
+        "involvedObject.name={},involvedObject.kind={}",
+        name, kind.kind
+    ));
+
+    let list = api.list(&params).await?;
+    Ok(list
+        .items
+        .iter()
+        .map(|event| {
+            let data = &event.data;
+            serde_json::json!({
+                "type": data.get("type"),
+                "reason": data.get("reason"),
+                "message": data.get("message"),
+                "count": data.get("count"),
+                "last_timestamp": data.get("lastTimestamp").or_else(|| data.get("eventTime")),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::mock_http::{MockHttpServer, MockResponse};
+
+    /// Serve a canned JSON body for every request
+    async fn mock_api_server(body: serde_json::Value) -> (Client, MockHttpServer) {
+        let server = MockHttpServer::always(MockResponse::json(body)).await;
+        let config = kube::Config::new(server.url().parse().unwrap());
+        (Client::try_from(config).unwrap(), server)
+    }
+
+    fn pod_list() -> serde_json::Value {
+        serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "PodList",
+            "metadata": { "resourceVersion": "100" },
+            "items": [
+                {
+                    "apiVersion": "v1",
+                    "kind": "Pod",
+                    "metadata": {
+                        "name": "web-0",
+                        "namespace": "prod",
+                        "labels": { "app": "web" },
+                        "managedFields": [{ "manager": "kubectl" }]
+                    },
+                    "status": { "phase": "Running" }
+                },
+                {
+                    "apiVersion": "v1",
+                    "kind": "Pod",
+                    "metadata": { "name": "web-1", "namespace": "prod", "labels": { "app": "web" } },
+                    "status": { "phase": "Pending" }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_k8s_api_config() {
+        let tool = K8sApiTool::new();
+        assert_eq!(tool.config().name, "k8s_api");
+        assert_eq!(tool.config().parameters["required"], serde_json::json!(["kind"]));
+    }
+
+    #[test]
+    fn test_kind_aliases() {
+        assert_eq!(KnownKind::find("po").unwrap().kind, "Pod");
+        assert_eq!(KnownKind::find("Deployments").unwrap().kind, "Deployment");
+        assert_eq!(KnownKind::find("hpa").unwrap().api_resource().api_version, "autoscaling/v2");
+        assert_eq!(KnownKind::find("nodes").unwrap().api_resource().api_version, "v1");
+        assert!(KnownKind::find("widgets").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_pods_against_mock_api_server() {
+        let (client, server) = mock_api_server(pod_list()).await;
+        let tool = K8sApiTool::new().with_client(client);
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "action": "list",
+                "kind": "pods",
+                "namespace": "prod",
+                "label_selector": "app=web"
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["kind"], "Pod");
+        assert_eq!(result.data["namespace"], "prod");
+        assert_eq!(result.data["count"], 2);
+        assert_eq!(result.data["items"][0]["metadata"]["name"], "web-0");
+        assert_eq!(result.data["items"][1]["status"]["phase"], "Pending");
+        assert!(result.data["items"][0]["metadata"].get("managedFields").is_none());
+
+        let request_line = server.requests()[0].request_line();
+        assert!(request_line.starts_with("GET /api/v1/namespaces/prod/pods?"), "{}", request_line);
+        assert!(request_line.contains("labelSelector=app%3Dweb"), "{}", request_line);
+    }
+
+    #[tokio::test]
+    async fn test_list_all_namespaces_uses_cluster_path() {
+        let (client, server) = mock_api_server(pod_list()).await;
+        let tool = K8sApiTool::new().with_client(client);
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "kind": "po",
+                "all_namespaces": true
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.data["namespace"].is_null());
+        let request_line = server.requests()[0].request_line();
+        assert!(request_line.starts_with("GET /api/v1/pods"), "{}", request_line);
+    }
+
+    #[tokio::test]
+    async fn test_get_requires_name_and_known_kind() {
+        let tool = K8sApiTool::new();
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"action": "get", "kind": "pods"})))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Missing required parameter: name"));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"kind": "widgets"})))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unsupported kind 'widgets'"));
+    }
+}
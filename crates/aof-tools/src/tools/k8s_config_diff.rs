@@ -0,0 +1,499 @@
+//! Kubernetes Config Diff Tool
+//!
+//! Tool for fetching a ConfigMap or Secret and comparing it to an expected
+//! version, to catch config drift.
+//!
+//! ## Features
+//!
+//! - Expected version from an inline manifest or prior snapshot, a manifest file,
+//!   or a file at a git ref (`git show <ref>:<path>`)
+//! - Per-key diff: added, removed and modified keys
+//! - ConfigMap values shown in the diff
+//! - Secret values never returned, not even decoded: only which keys changed
+//!
+//! ## Prerequisites
+//!
+//! - Requires `kubectl` feature flag
+//! - kubectl must be installed and in PATH (git as well for `git_ref`)
+//! - Valid kubeconfig with cluster access
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use base64::Engine;
+use serde::Serialize;
+use tracing::debug;
+
+use super::common::{create_schema, tool_config_with_timeout, CommandRunner, ProcessRunner};
+
+/// Placeholder shown instead of Secret values
+const MASK: &str = "********";
+
+/// Kind of config object being compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigKind {
+    ConfigMap,
+    Secret,
+}
+
+impl ConfigKind {
+    fn parse(kind: &str) -> Option<Self> {
+        match kind.to_lowercase().as_str() {
+            "configmap" | "configmaps" | "cm" => Some(Self::ConfigMap),
+            "secret" | "secrets" => Some(Self::Secret),
+            _ => None,
+        }
+    }
+
+    fn resource(&self) -> &'static str {
+        match self {
+            Self::ConfigMap => "configmap",
+            Self::Secret => "secret",
+        }
+    }
+}
+
+/// How a key differs between expected and live
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Difference for a single key
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigKeyDiff {
+    pub key: String,
+    pub change: KeyChange,
+    /// Expected value (masked for Secrets)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// Live value (masked for Secrets)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<String>,
+}
+
+/// Kubernetes ConfigMap/Secret diff tool
+pub struct K8sConfigDiffTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl K8sConfigDiffTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "kind": {
+                    "type": "string",
+                    "description": "configmap or secret",
+                    "enum": ["configmap", "secret"]
+                },
+                "name": {
+                    "type": "string",
+                    "description": "Object name"
+                },
+                "namespace": {
+                    "type": "string",
+                    "description": "Kubernetes namespace (default: current context namespace)"
+                },
+                "expected": {
+                    "description": "Expected version: a manifest or prior snapshot (YAML/JSON string or object), or a plain key/value map (Secret values as plain text)"
+                },
+                "expected_path": {
+                    "type": "string",
+                    "description": "Path to a manifest holding the expected version"
+                },
+                "git_ref": {
+                    "type": "string",
+                    "description": "Read expected_path at this git ref instead of the working tree (e.g. 'main', 'HEAD~1')"
+                },
+                "repo_path": {
+                    "type": "string",
+                    "description": "Repository for git_ref (default: current directory)"
+                }
+            }),
+            vec!["kind", "name"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "k8s_config_diff",
+                "Fetch a Kubernetes ConfigMap or Secret and diff it against an expected version (inline manifest, snapshot, file or git ref). Reports added, removed and modified keys. Secret values are always masked; only changed key names are returned.",
+                parameters,
+                60,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Fetch the live object as JSON
+    async fn fetch_live(
+        &self,
+        kind: ConfigKind,
+        name: &str,
+        namespace: Option<&str>,
+    ) -> Result<serde_json::Value, String> {
+        let mut args = vec!["get".to_string(), kind.resource().to_string(), name.to_string()];
+        if let Some(ns) = namespace {
+            args.push(format!("-n={}", ns));
+        }
+        args.push("-o=json".to_string());
+
+        let output = self
+            .runner
+            .run("kubectl", &args, None, self.config.timeout_secs)
+            .await?;
+        if !output.success {
+            return Err(output.stderr.trim().to_string());
+        }
+        serde_json::from_str(&output.stdout)
+            .map_err(|e| format!("Failed to parse {} '{}': {}", kind.resource(), name, e))
+    }
+
+    /// Resolve the expected version from the tool input
+    async fn load_expected(&self, input: &ToolInput) -> Result<serde_json::Value, String> {
+        if let Some(expected) = input.arguments.get("expected") {
+            return match expected {
+                serde_json::Value::String(text) => parse_manifest(text),
+                other => Ok(other.clone()),
+            };
+        }
+
+        let path: String = input
+            .get_arg("expected_path")
+            .map_err(|_| "Either 'expected' or 'expected_path' is required".to_string())?;
+
+        let text = match input.get_arg::<String>("git_ref") {
+            Ok(git_ref) => {
+                let repo: String = input.get_arg("repo_path").unwrap_or_else(|_| ".".to_string());
+                let args = vec![
+                    "-C".to_string(),
+                    repo,
+                    "show".to_string(),
+                    format!("{}:{}", git_ref, path),
+                ];
+                let output = self
+                    .runner
+                    .run("git", &args, None, self.config.timeout_secs)
+                    .await?;
+                if !output.success {
+                    return Err(format!(
+                        "Failed to read {} at {}: {}",
+                        path,
+                        git_ref,
+                        output.stderr.trim()
+                    ));
+                }
+                output.stdout
+            }
+            Err(_) => tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| format!("Failed to read {}: {}", path, e))?,
+        };
+        parse_manifest(&text)
+    }
+}
+
+impl Default for K8sConfigDiffTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for K8sConfigDiffTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let kind_arg: String = input.get_arg("kind")?;
+        let name: String = input.get_arg("name")?;
+        let namespace: Option<String> = input.get_arg("namespace").ok();
+
+        let Some(kind) = ConfigKind::parse(&kind_arg) else {
+            return Ok(ToolResult::error(format!(
+                "Unsupported kind '{}': expected configmap or secret",
+                kind_arg
+            )));
+        };
+
+        let expected = match self.load_expected(&input).await {
+            Ok(expected) => config_data(kind, &expected),
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        debug!(kind = kind.resource(), name = %name, "Diffing config against expected version");
+
+        let live = match self.fetch_live(kind, &name, namespace.as_deref()).await {
+            Ok(live) => config_data(kind, &live),
+            Err(e) => {
+                return Ok(ToolResult::error(format!(
+                    "Failed to get {} '{}': {}",
+                    kind.resource(),
+                    name,
+                    e
+                )))
+            }
+        };
+
+        let diff = diff_data(&expected, &live, kind == ConfigKind::Secret);
+        let keys_with = |change: KeyChange| -> Vec<&str> {
+            diff.iter()
+                .filter(|d| d.change == change)
+                .map(|d| d.key.as_str())
+                .collect()
+        };
+
+        Ok(ToolResult::success(serde_json::json!({
+            "kind": kind.resource(),
+            "name": name,
+            "namespace": namespace,
+            "in_sync": diff.is_empty(),
+            "added": keys_with(KeyChange::Added),
+            "removed": keys_with(KeyChange::Removed),
+            "modified": keys_with(KeyChange::Modified),
+            "unchanged": expected.keys().filter(|k| live.get(*k) == expected.get(*k)).count(),
+            "values_masked": kind == ConfigKind::Secret,
+            "diff": diff,
+        })))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Parse a YAML or JSON manifest
+fn parse_manifest(text: &str) -> Result<serde_json::Value, String> {
+    serde_json::from_str(text)
+        .or_else(|_| serde_yaml::from_str(text))
+        .map_err(|e| format!("Failed to parse expected manifest: {}", e))
+}
+
+/// Key/value data of a ConfigMap or Secret manifest
+///
+/// Accepts a full manifest or a bare key/value map. Secret values are kept
+/// base64-encoded (`stringData` is encoded) so they can be compared without
+/// ever being decoded.
+fn config_data(kind: ConfigKind, manifest: &serde_json::Value) -> BTreeMap<String, String> {
+    let is_manifest = manifest.get("kind").is_some()
+        || manifest.get("data").is_some()
+        || manifest.get("stringData").is_some()
+        || manifest.get("binaryData").is_some();
+
+    let plain = |value: &serde_json::Value| -> Vec<(String, String)> {
+        value
+            .as_object()
+            .map(|map| {
+                map.iter()
+                    .map(|(k, v)| (k.clone(), v.as_str().map(String::from).unwrap_or_else(|| v.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let encode = |(k, v): (String, String)| (k, base64::engine::general_purpose::STANDARD.encode(v));
+
+    let mut data = BTreeMap::new();
+    match (kind, is_manifest) {
+        (ConfigKind::ConfigMap, true) => {
+            data.extend(plain(&manifest["data"]));
+            data.extend(plain(&manifest["binaryData"]));
+        }
+        (ConfigKind::Secret, true) => {
+            data.extend(plain(&manifest["data"]));
+            data.extend(plain(&manifest["stringData"]).into_iter().map(encode));
+        }
+        // A bare map holds plain values
+        (ConfigKind::ConfigMap, false) => data.extend(plain(manifest)),
+        (ConfigKind::Secret, false) => data.extend(plain(manifest).into_iter().map(encode)),
+    }
+    data
+}
+
+/// Per-key differences; values are replaced by a mask when `mask` is set
+fn diff_data(
+    expected: &BTreeMap<String, String>,
+    actual: &BTreeMap<String, String>,
+    mask: bool,
+) -> Vec<ConfigKeyDiff> {
+    let show = |value: &String| Some(if mask { MASK.to_string() } else { value.clone() });
+    let mut diff = Vec::new();
+
+    for (key, expected_value) in expected {
+        match actual.get(key) {
+            None => diff.push(ConfigKeyDiff {
+                key: key.clone(),
+                change: KeyChange::Removed,
+                expected: show(expected_value),
+                actual: None,
+            }),
+            Some(actual_value) if actual_value != expected_value => diff.push(ConfigKeyDiff {
+                key: key.clone(),
+                change: KeyChange::Modified,
+                expected: show(expected_value),
+                actual: show(actual_value),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, actual_value) in actual {
+        if !expected.contains_key(key) {
+            diff.push(ConfigKeyDiff {
+                key: key.clone(),
+                change: KeyChange::Added,
+                expected: None,
+                actual: show(actual_value),
+            });
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::{CommandOutput, FakeRunner};
+
+    const LIVE_CONFIGMAP: &str = r#"{
+        "apiVersion": "v1",
+        "kind": "ConfigMap",
+        "metadata": {"name": "api-config", "namespace": "prod"},
+        "data": {"LOG_LEVEL": "debug", "TIMEOUT": "30s", "FEATURE_X": "on"}
+    }"#;
+
+    // "hunter2" and "admin" base64-encoded
+    const LIVE_SECRET: &str = r#"{
+        "apiVersion": "v1",
+        "kind": "Secret",
+        "metadata": {"name": "db-creds", "namespace": "prod"},
+        "data": {"password": "aHVudGVyMg==", "username": "YWRtaW4=", "extra": "eA=="}
+    }"#;
+
+    fn fake_cluster() -> Arc<FakeRunner> {
+        Arc::new(FakeRunner::new(|program, args| match (program, args[1].as_str()) {
+            ("kubectl", "configmap") => CommandOutput::ok(LIVE_CONFIGMAP),
+            ("kubectl", "secret") => CommandOutput::ok(LIVE_SECRET),
+            ("git", _) => CommandOutput::ok(
+                "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: api-config\ndata:\n  LOG_LEVEL: info\n  TIMEOUT: 30s\n",
+            ),
+            _ => CommandOutput::failed(1, "unexpected command"),
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_configmap_diff_shows_changed_keys() {
+        let runner = fake_cluster();
+        let tool = K8sConfigDiffTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "kind": "configmap",
+                "name": "api-config",
+                "namespace": "prod",
+                "expected": "data:\n  LOG_LEVEL: info\n  TIMEOUT: 30s\n  CACHE: redis\n"
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["in_sync"], false);
+        assert_eq!(result.data["modified"], serde_json::json!(["LOG_LEVEL"]));
+        assert_eq!(result.data["removed"], serde_json::json!(["CACHE"]));
+        assert_eq!(result.data["added"], serde_json::json!(["FEATURE_X"]));
+        assert_eq!(result.data["unchanged"], 1);
+
+        let modified = &result.data["diff"][1];
+        assert_eq!(modified["key"], "LOG_LEVEL");
+        assert_eq!(modified["expected"], "info");
+        assert_eq!(modified["actual"], "debug");
+
+        assert_eq!(
+            runner.calls()[0],
+            ["kubectl", "get", "configmap", "api-config", "-n=prod", "-o=json"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_configmap_expected_from_git_ref() {
+        let runner = fake_cluster();
+        let tool = K8sConfigDiffTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "kind": "cm",
+                "name": "api-config",
+                "expected_path": "k8s/api-config.yaml",
+                "git_ref": "main",
+                "repo_path": "/repo"
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["modified"], serde_json::json!(["LOG_LEVEL"]));
+        assert_eq!(runner.calls()[0], ["git", "-C", "/repo", "show", "main:k8s/api-config.yaml"]);
+    }
+
+    #[tokio::test]
+    async fn test_secret_diff_masks_values() {
+        let tool = K8sConfigDiffTool::new().with_runner(fake_cluster());
+
+        // Expected password differs, username matches via stringData
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "kind": "secret",
+                "name": "db-creds",
+                "expected": {
+                    "kind": "Secret",
+                    "data": {"password": "b2xkcGFzcw=="},
+                    "stringData": {"username": "admin", "token": "s3cr3t-token"}
+                }
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["values_masked"], true);
+        assert_eq!(result.data["modified"], serde_json::json!(["password"]));
+        assert_eq!(result.data["removed"], serde_json::json!(["token"]));
+        assert_eq!(result.data["added"], serde_json::json!(["extra"]));
+
+        for entry in result.data["diff"].as_array().unwrap() {
+            for field in ["expected", "actual"] {
+                if let Some(value) = entry.get(field) {
+                    assert_eq!(value, MASK);
+                }
+            }
+        }
+
+        // Neither encoded nor decoded values appear anywhere in the output
+        let output = result.data.to_string();
+        for leaked in ["hunter2", "aHVudGVyMg==", "admin", "YWRtaW4=", "s3cr3t-token", "oldpass", "b2xkcGFzcw=="] {
+            assert!(!output.contains(leaked), "leaked {}", leaked);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_expected_is_an_error() {
+        let tool = K8sConfigDiffTool::new().with_runner(fake_cluster());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "kind": "configmap",
+                "name": "api-config"
+            })))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("'expected' or 'expected_path'"));
+    }
+}
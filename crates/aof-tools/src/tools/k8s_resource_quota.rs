@@ -0,0 +1,662 @@
+//! Kubernetes Resource Quota Tool
+//!
+//! Tool for explaining scheduling failures caused by quotas and capacity:
+//! "why can't this pod schedule?"
+//!
+//! ## Features
+//!
+//! - ResourceQuotas in a namespace with used vs hard, what is left and utilization
+//! - LimitRanges (defaults, min and max) that shape what pods request
+//! - Node allocatable vs requested CPU, memory and pod slots
+//! - Warnings for quotas and nodes that are exhausted or nearly so
+//! - Node access errors (e.g. no RBAC to list nodes) reported without failing
+//!
+//! ## Prerequisites
+//!
+//! - Requires `kubectl` feature flag
+//! - kubectl must be installed and in PATH
+//! - Valid kubeconfig with cluster access
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use super::common::{create_schema, tool_config_with_timeout, CommandRunner, ProcessRunner};
+
+/// Utilization at which a quota or node is flagged as nearly full
+const NEAR_LIMIT_PERCENT: f64 = 90.0;
+
+/// Node resources compared against allocatable
+const NODE_RESOURCES: [&str; 3] = ["cpu", "memory", "pods"];
+
+/// Used vs hard limit for one resource in a quota
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// Resource name (e.g. `requests.cpu`, `pods`)
+    pub resource: String,
+    /// Hard limit as written in the quota
+    pub hard: String,
+    /// Amount in use as reported by the quota
+    pub used: String,
+    /// What is left before the quota is exhausted
+    pub available: String,
+    /// Used as a percentage of hard (None when hard is zero or unparseable)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utilization_percent: Option<f64>,
+    /// Whether nothing is left
+    pub exhausted: bool,
+}
+
+/// A ResourceQuota and the usage of each resource it limits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuotaSummary {
+    /// Quota name
+    pub name: String,
+    /// Quota scopes (e.g. `BestEffort`); empty when the quota applies to all pods
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    /// Usage per limited resource
+    pub resources: Vec<ResourceUsage>,
+}
+
+/// Allocatable vs requested capacity of a node
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeCapacity {
+    /// Node name
+    pub name: String,
+    /// Whether the node accepts new pods
+    pub schedulable: bool,
+    /// Allocatable cpu, memory and pods
+    pub allocatable: BTreeMap<String, String>,
+    /// Sum of requests of pods running on the node
+    pub requested: BTreeMap<String, String>,
+    /// Allocatable minus requested
+    pub available: BTreeMap<String, String>,
+    /// Requested as a percentage of allocatable
+    pub utilization_percent: BTreeMap<String, f64>,
+}
+
+/// Kubernetes resource quota and capacity tool
+pub struct ResourceQuotaTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl ResourceQuotaTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "namespace": {
+                    "type": "string",
+                    "description": "Kubernetes namespace (default: current context namespace)"
+                },
+                "include_nodes": {
+                    "type": "boolean",
+                    "description": "Also report node allocatable vs requested capacity",
+                    "default": true
+                }
+            }),
+            vec![],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "k8s_resource_quota",
+                "Summarize ResourceQuotas and LimitRanges in a namespace (used vs hard, what is left, utilization) and node allocatable vs requested CPU, memory and pods. Use it to explain why pods can't be created or scheduled.",
+                parameters,
+                60,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Run `kubectl get <args> -o=json` and return the `items` list
+    async fn get_items(&self, args: &[&str]) -> Result<Vec<serde_json::Value>, String> {
+        let mut args: Vec<String> = std::iter::once("get")
+            .chain(args.iter().copied())
+            .map(String::from)
+            .collect();
+        args.push("-o=json".to_string());
+
+        let output = self
+            .runner
+            .run("kubectl", &args, None, self.config.timeout_secs)
+            .await?;
+        if !output.success {
+            return Err(output.stderr.trim().to_string());
+        }
+        let list: serde_json::Value = serde_json::from_str(&output.stdout)
+            .map_err(|e| format!("Failed to parse kubectl {} output: {}", args[1], e))?;
+        Ok(list["items"].as_array().cloned().unwrap_or_default())
+    }
+
+    /// Node capacity from nodes and the pods scheduled on them
+    async fn node_capacity(&self) -> Result<Vec<NodeCapacity>, String> {
+        let nodes = self.get_items(&["nodes"]).await?;
+        let pods = self
+            .get_items(&[
+                "pods",
+                "--all-namespaces",
+                "--field-selector=status.phase!=Succeeded,status.phase!=Failed",
+            ])
+            .await?;
+        Ok(node_capacity(&nodes, &pods))
+    }
+}
+
+impl Default for ResourceQuotaTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ResourceQuotaTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let namespace: Option<String> = input.get_arg("namespace").ok();
+        let include_nodes: bool = input.get_arg("include_nodes").unwrap_or(true);
+
+        let ns_arg = namespace.as_ref().map(|ns| format!("-n={}", ns));
+
+        debug!(namespace = ?namespace, include_nodes, "Fetching resource quotas");
+
+        let mut quota_args = vec!["resourcequota"];
+        quota_args.extend(ns_arg.as_deref());
+        let quotas = match self.get_items(&quota_args).await {
+            Ok(items) => items.iter().map(quota_summary).collect::<Vec<_>>(),
+            Err(e) => return Ok(ToolResult::error(format!("Failed to get resource quotas: {}", e))),
+        };
+
+        let mut range_args = vec!["limitrange"];
+        range_args.extend(ns_arg.as_deref());
+        let limit_ranges = match self.get_items(&range_args).await {
+            Ok(items) => items.iter().map(limit_range_summary).collect::<Vec<_>>(),
+            Err(e) => return Ok(ToolResult::error(format!("Failed to get limit ranges: {}", e))),
+        };
+
+        let mut warnings: Vec<String> = quotas
+            .iter()
+            .flat_map(|quota| quota.resources.iter().filter_map(move |usage| quota_warning(&quota.name, usage)))
+            .collect();
+
+        let mut result = serde_json::json!({
+            "namespace": namespace,
+            "quotas": quotas,
+            "limit_ranges": limit_ranges,
+        });
+
+        if include_nodes {
+            match self.node_capacity().await {
+                Ok(nodes) => {
+                    warnings.extend(nodes.iter().flat_map(node_warnings));
+                    result["nodes"] = serde_json::json!(nodes);
+                }
+                // Namespace-scoped credentials often can't list nodes; quotas still help
+                Err(e) => result["errors"] = serde_json::json!({ "nodes": e }),
+            }
+        }
+
+        result["warnings"] = serde_json::json!(warnings);
+        Ok(ToolResult::success(result))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Parse a Kubernetes quantity (`500m`, `2Gi`, `1.5`, `1e3`) into a number
+///
+/// CPU comes back in cores and memory in bytes.
+pub(crate) fn parse_quantity(quantity: &str) -> Option<f64> {
+    const SUFFIXES: [(&str, f64); 15] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Ei", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("n", 1e-9),
+        ("u", 1e-6),
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+    ];
+
+    let quantity = quantity.trim();
+    if let Ok(value) = quantity.parse::<f64>() {
+        return Some(value);
+    }
+    SUFFIXES.iter().find_map(|(suffix, factor)| {
+        quantity
+            .strip_suffix(suffix)
+            .and_then(|number| number.parse::<f64>().ok())
+            .map(|number| number * factor)
+    })
+}
+
+/// Format a number as a quantity suited to the resource
+fn format_quantity(resource: &str, value: f64) -> String {
+    if resource.ends_with("cpu") {
+        let millis = (value * 1000.0).round();
+        return if millis % 1000.0 == 0.0 {
+            format!("{}", millis / 1000.0)
+        } else {
+            format!("{}m", millis)
+        };
+    }
+    if resource.contains("memory") || resource.contains("storage") {
+        const UNITS: [(&str, f64); 4] = [
+            ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+            ("Gi", 1024.0 * 1024.0 * 1024.0),
+            ("Mi", 1024.0 * 1024.0),
+            ("Ki", 1024.0),
+        ];
+        if let Some((suffix, size)) = UNITS.iter().find(|(_, size)| value.abs() >= *size) {
+            return format!("{}{}", trim_number(value / size), suffix);
+        }
+        return format!("{}", value.round());
+    }
+    trim_number(value)
+}
+
+/// Number with at most two decimals and no trailing zeros
+fn trim_number(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Quantity in a JSON value (quantities are usually strings, sometimes numbers)
+fn quantity_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Used vs hard for one quota resource
+fn resource_usage(resource: &str, hard: &str, used: &str) -> ResourceUsage {
+    let (hard_value, used_value) = (parse_quantity(hard), parse_quantity(used));
+    let (available, utilization_percent, exhausted) = match (hard_value, used_value) {
+        (Some(h), Some(u)) => (
+            format_quantity(resource, (h - u).max(0.0)),
+            (h > 0.0).then(|| (u / h * 1000.0).round() / 10.0),
+            u >= h,
+        ),
+        _ => ("unknown".to_string(), None, false),
+    };
+    ResourceUsage {
+        resource: resource.to_string(),
+        hard: hard.to_string(),
+        used: used.to_string(),
+        available,
+        utilization_percent,
+        exhausted,
+    }
+}
+
+/// Summary of a ResourceQuota object
+fn quota_summary(quota: &serde_json::Value) -> QuotaSummary {
+    let status = &quota["status"];
+    // Status is filled by the quota controller; fall back to the spec for new quotas
+    let hard = if status["hard"].is_object() { &status["hard"] } else { &quota["spec"]["hard"] };
+
+    let resources: Vec<ResourceUsage> = hard
+        .as_object()
+        .map(|hard| {
+            hard.iter()
+                .filter_map(|(resource, limit)| {
+                    let used = quantity_text(&status["used"][resource]).unwrap_or_else(|| "0".to_string());
+                    Some(resource_usage(resource, &quantity_text(limit)?, &used))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    QuotaSummary {
+        name: quota["metadata"]["name"].as_str().unwrap_or_default().to_string(),
+        scopes: quota["spec"]["scopes"]
+            .as_array()
+            .map(|scopes| scopes.iter().filter_map(|s| s.as_str().map(String::from)).collect())
+            .unwrap_or_default(),
+        resources,
+    }
+}
+
+/// Summary of a LimitRange object
+fn limit_range_summary(range: &serde_json::Value) -> serde_json::Value {
+    let limits: Vec<serde_json::Value> = range["spec"]["limits"]
+        .as_array()
+        .map(|limits| {
+            limits
+                .iter()
+                .map(|limit| {
+                    let mut entry = serde_json::json!({ "type": limit["type"] });
+                    for (field, key) in [
+                        ("default", "default"),
+                        ("defaultRequest", "default_request"),
+                        ("min", "min"),
+                        ("max", "max"),
+                        ("maxLimitRequestRatio", "max_limit_request_ratio"),
+                    ] {
+                        if limit[field].is_object() {
+                            entry[key] = limit[field].clone();
+                        }
+                    }
+                    entry
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    serde_json::json!({
+        "name": range["metadata"]["name"],
+        "limits": limits,
+    })
+}
+
+/// Warning for a quota resource that is exhausted or nearly so
+fn quota_warning(quota: &str, usage: &ResourceUsage) -> Option<String> {
+    if usage.exhausted {
+        return Some(format!(
+            "Quota '{}' is exhausted for {} ({} of {} used)",
+            quota, usage.resource, usage.used, usage.hard
+        ));
+    }
+    let percent = usage.utilization_percent?;
+    (percent >= NEAR_LIMIT_PERCENT).then(|| {
+        format!(
+            "Quota '{}' is {}% used for {} ({} left)",
+            quota, percent, usage.resource, usage.available
+        )
+    })
+}
+
+/// Warnings for node resources that are nearly fully requested
+fn node_warnings(node: &NodeCapacity) -> Vec<String> {
+    node.utilization_percent
+        .iter()
+        .filter(|(_, percent)| **percent >= NEAR_LIMIT_PERCENT)
+        .map(|(resource, percent)| {
+            format!(
+                "Node '{}' has {}% of allocatable {} requested ({} left)",
+                node.name,
+                percent,
+                resource,
+                node.available.get(resource).map(String::as_str).unwrap_or("0")
+            )
+        })
+        .collect()
+}
+
+/// Effective cpu and memory requests of a pod
+///
+/// As in the scheduler: the sum over containers, or the largest init
+/// container request if that is higher, plus pod overhead.
+fn pod_requests(pod: &serde_json::Value) -> BTreeMap<&'static str, f64> {
+    let request = |container: &serde_json::Value, resource: &str| -> f64 {
+        quantity_text(&container["resources"]["requests"][resource])
+            .and_then(|q| parse_quantity(&q))
+            .unwrap_or(0.0)
+    };
+    let containers = |field: &str| pod["spec"][field].as_array().cloned().unwrap_or_default();
+
+    let mut requests = BTreeMap::new();
+    for resource in ["cpu", "memory"] {
+        let total: f64 = containers("containers").iter().map(|c| request(c, resource)).sum();
+        let init = containers("initContainers")
+            .iter()
+            .map(|c| request(c, resource))
+            .fold(0.0, f64::max);
+        let overhead = quantity_text(&pod["spec"]["overhead"][resource])
+            .and_then(|q| parse_quantity(&q))
+            .unwrap_or(0.0);
+        requests.insert(resource, total.max(init) + overhead);
+    }
+    requests
+}
+
+/// Allocatable vs requested for each node
+fn node_capacity(nodes: &[serde_json::Value], pods: &[serde_json::Value]) -> Vec<NodeCapacity> {
+    // Requested totals per node, counting pods for the `pods` resource
+    let mut requested: BTreeMap<&str, BTreeMap<&str, f64>> = BTreeMap::new();
+    for pod in pods {
+        let Some(node) = pod["spec"]["nodeName"].as_str() else {
+            continue;
+        };
+        if matches!(pod["status"]["phase"].as_str(), Some("Succeeded" | "Failed")) {
+            continue;
+        }
+        let totals = requested.entry(node).or_default();
+        for (resource, value) in pod_requests(pod) {
+            *totals.entry(resource).or_default() += value;
+        }
+        *totals.entry("pods").or_default() += 1.0;
+    }
+
+    nodes
+        .iter()
+        .map(|node| {
+            let name = node["metadata"]["name"].as_str().unwrap_or_default();
+            let totals = requested.get(name);
+            let mut capacity = NodeCapacity {
+                name: name.to_string(),
+                schedulable: !node["spec"]["unschedulable"].as_bool().unwrap_or(false),
+                allocatable: BTreeMap::new(),
+                requested: BTreeMap::new(),
+                available: BTreeMap::new(),
+                utilization_percent: BTreeMap::new(),
+            };
+
+            for resource in NODE_RESOURCES {
+                let Some(allocatable) = quantity_text(&node["status"]["allocatable"][resource])
+                    .and_then(|q| parse_quantity(&q))
+                else {
+                    continue;
+                };
+                let used = totals.and_then(|t| t.get(resource)).copied().unwrap_or(0.0);
+                capacity.allocatable.insert(resource.to_string(), format_quantity(resource, allocatable));
+                capacity.requested.insert(resource.to_string(), format_quantity(resource, used));
+                capacity
+                    .available
+                    .insert(resource.to_string(), format_quantity(resource, (allocatable - used).max(0.0)));
+                if allocatable > 0.0 {
+                    capacity
+                        .utilization_percent
+                        .insert(resource.to_string(), (used / allocatable * 1000.0).round() / 10.0);
+                }
+            }
+            capacity
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::{CommandOutput, FakeRunner};
+
+    const QUOTAS: &str = r#"{"items": [{
+        "metadata": {"name": "compute", "namespace": "checkout"},
+        "spec": {"hard": {"requests.cpu": "4", "requests.memory": "8Gi", "pods": "10"}},
+        "status": {
+            "hard": {"requests.cpu": "4", "requests.memory": "8Gi", "pods": "10"},
+            "used": {"requests.cpu": "3500m", "requests.memory": "6Gi", "pods": "10"}
+        }
+    }]}"#;
+
+    const LIMIT_RANGES: &str = r#"{"items": [{
+        "metadata": {"name": "defaults"},
+        "spec": {"limits": [{
+            "type": "Container",
+            "default": {"cpu": "500m", "memory": "512Mi"},
+            "defaultRequest": {"cpu": "250m", "memory": "256Mi"},
+            "max": {"cpu": "2"}
+        }]}
+    }]}"#;
+
+    const NODES: &str = r#"{"items": [
+        {"metadata": {"name": "node-a"}, "spec": {},
+         "status": {"allocatable": {"cpu": "3920m", "memory": "15Gi", "pods": "110"}}},
+        {"metadata": {"name": "node-b"}, "spec": {"unschedulable": true},
+         "status": {"allocatable": {"cpu": "2", "memory": "4Gi", "pods": "110"}}}
+    ]}"#;
+
+    const PODS: &str = r#"{"items": [
+        {"spec": {"nodeName": "node-a",
+                  "containers": [{"resources": {"requests": {"cpu": "1", "memory": "2Gi"}}},
+                                 {"resources": {"requests": {"cpu": "500m", "memory": "1Gi"}}}]},
+         "status": {"phase": "Running"}},
+        {"spec": {"nodeName": "node-a",
+                  "initContainers": [{"resources": {"requests": {"cpu": "2", "memory": "512Mi"}}}],
+                  "containers": [{"resources": {"requests": {"cpu": "100m", "memory": "1Gi"}}}]},
+         "status": {"phase": "Pending"}},
+        {"spec": {"nodeName": "node-a",
+                  "containers": [{"resources": {"requests": {"cpu": "4"}}}]},
+         "status": {"phase": "Succeeded"}},
+        {"spec": {"containers": [{"resources": {"requests": {"cpu": "8"}}}]},
+         "status": {"phase": "Pending"}}
+    ]}"#;
+
+    fn fake_cluster(nodes_allowed: bool) -> Arc<FakeRunner> {
+        Arc::new(FakeRunner::new(move |program, args| match (program, args[1].as_str()) {
+            ("kubectl", "resourcequota") => CommandOutput::ok(QUOTAS),
+            ("kubectl", "limitrange") => CommandOutput::ok(LIMIT_RANGES),
+            ("kubectl", "nodes") if nodes_allowed => CommandOutput::ok(NODES),
+            ("kubectl", "nodes") => CommandOutput::failed(
+                1,
+                "Error from server (Forbidden): nodes is forbidden: User \"dev\" cannot list resource \"nodes\"",
+            ),
+            ("kubectl", "pods") => CommandOutput::ok(PODS),
+            _ => CommandOutput::failed(1, "unexpected command"),
+        }))
+    }
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity("500m"), Some(0.5));
+        assert_eq!(parse_quantity("2"), Some(2.0));
+        assert_eq!(parse_quantity("1.5"), Some(1.5));
+        assert_eq!(parse_quantity("2Gi"), Some(2.0 * 1024.0 * 1024.0 * 1024.0));
+        assert_eq!(parse_quantity("128974848"), Some(128974848.0));
+        assert_eq!(parse_quantity("129M"), Some(129e6));
+        assert_eq!(parse_quantity("1e3"), Some(1000.0));
+        assert_eq!(parse_quantity("lots"), None);
+
+        assert_eq!(format_quantity("requests.cpu", 0.5), "500m");
+        assert_eq!(format_quantity("cpu", 3.0), "3");
+        assert_eq!(format_quantity("requests.memory", 1.5 * 1024.0 * 1024.0 * 1024.0), "1.5Gi");
+        assert_eq!(format_quantity("pods", 7.0), "7");
+    }
+
+    #[tokio::test]
+    async fn test_quota_utilization() {
+        let runner = fake_cluster(true);
+        let tool = ResourceQuotaTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"namespace": "checkout"})))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        let quotas: Vec<QuotaSummary> = serde_json::from_value(result.data["quotas"].clone()).unwrap();
+        assert_eq!(quotas[0].name, "compute");
+
+        let usage: BTreeMap<&str, &ResourceUsage> =
+            quotas[0].resources.iter().map(|u| (u.resource.as_str(), u)).collect();
+        assert_eq!(usage["requests.cpu"].available, "500m");
+        assert_eq!(usage["requests.cpu"].utilization_percent, Some(87.5));
+        assert!(!usage["requests.cpu"].exhausted);
+        assert_eq!(usage["requests.memory"].available, "2Gi");
+        assert_eq!(usage["requests.memory"].utilization_percent, Some(75.0));
+        assert!(usage["pods"].exhausted);
+        assert_eq!(usage["pods"].available, "0");
+
+        let warnings = result.data["warnings"].as_array().unwrap();
+        assert!(warnings.iter().any(|w| w == "Quota 'compute' is exhausted for pods (10 of 10 used)"));
+
+        let limits = &result.data["limit_ranges"][0]["limits"][0];
+        assert_eq!(limits["type"], "Container");
+        assert_eq!(limits["default_request"]["cpu"], "250m");
+        assert_eq!(limits["max"]["cpu"], "2");
+
+        let calls = runner.calls();
+        assert_eq!(calls[0], ["kubectl", "get", "resourcequota", "-n=checkout", "-o=json"]);
+        assert_eq!(calls[1], ["kubectl", "get", "limitrange", "-n=checkout", "-o=json"]);
+    }
+
+    #[tokio::test]
+    async fn test_node_allocatable_vs_requested() {
+        let tool = ResourceQuotaTool::new().with_runner(fake_cluster(true));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"namespace": "checkout"})))
+            .await
+            .unwrap();
+
+        let nodes: Vec<NodeCapacity> = serde_json::from_value(result.data["nodes"].clone()).unwrap();
+
+        // 1.5 cores from the running pod + 2 from the pending pod's init container;
+        // finished and unscheduled pods don't count
+        let node_a = &nodes[0];
+        assert!(node_a.schedulable);
+        assert_eq!(node_a.requested["cpu"], "3500m");
+        assert_eq!(node_a.available["cpu"], "420m");
+        assert_eq!(node_a.utilization_percent["cpu"], 89.3);
+        assert_eq!(node_a.requested["memory"], "4Gi");
+        assert_eq!(node_a.available["memory"], "11Gi");
+        assert_eq!(node_a.requested["pods"], "2");
+
+        let node_b = &nodes[1];
+        assert!(!node_b.schedulable);
+        assert_eq!(node_b.requested["cpu"], "0");
+        assert_eq!(node_b.utilization_percent["cpu"], 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_node_access_denied_still_reports_quotas() {
+        let tool = ResourceQuotaTool::new().with_runner(fake_cluster(false));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"namespace": "checkout"})))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.data["quotas"][0]["resources"].as_array().unwrap().len(), 3);
+        assert!(result.data.get("nodes").is_none());
+        assert!(result.data["errors"]["nodes"].as_str().unwrap().contains("forbidden"));
+    }
+
+    #[tokio::test]
+    async fn test_quota_fetch_failure() {
+        let runner = Arc::new(FakeRunner::new(|_, _| {
+            CommandOutput::failed(1, "error: the server doesn't have a resource type \"resourcequota\"")
+        }));
+        let tool = ResourceQuotaTool::new().with_runner(runner);
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"include_nodes": false})))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().starts_with("Failed to get resource quotas"));
+    }
+}
@@ -5,7 +5,7 @@
 //! ## Available Tools
 //!
 //! - `kubectl_get` - Get resources (pods, deployments, services, etc.)
-//! - `kubectl_apply` - Apply manifests
+//! - `kubectl_apply` - Apply manifests (with per-resource preview for multi-document YAML)
 //! - `kubectl_delete` - Delete resources
 //! - `kubectl_logs` - Get pod logs
 //! - `kubectl_exec` - Execute commands in containers
@@ -32,6 +32,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{execute_command, create_schema, tool_config_with_timeout};
+pub use super::manifests::{split_manifest, ManifestResource};
 
 /// Collection of all kubectl tools
 pub struct KubectlTools;
@@ -233,6 +234,11 @@ impl KubectlApplyTool {
                     "type": "boolean",
                     "description": "Force apply (delete and recreate)",
                     "default": false
+                },
+                "preview": {
+                    "type": "boolean",
+                    "description": "Preview only: split the manifest and report per-resource changes via kubectl diff, without applying",
+                    "default": false
                 }
             }),
             vec![],
@@ -241,7 +247,7 @@ impl KubectlApplyTool {
         Self {
             config: tool_config_with_timeout(
                 "kubectl_apply",
-                "Apply a Kubernetes manifest. Creates or updates resources.",
+                "Apply a Kubernetes manifest. Creates or updates resources. Set preview=true to see what would change per resource.",
                 parameters,
                 120,
             ),
@@ -263,22 +269,41 @@ impl Tool for KubectlApplyTool {
         let namespace: Option<String> = input.get_arg("namespace").ok();
         let dry_run: String = input.get_arg("dry_run").unwrap_or_else(|_| "none".to_string());
         let force: bool = input.get_arg("force").unwrap_or(false);
-
-        if manifest.is_none() && file.is_none() {
-            return Ok(ToolResult::error("Either 'manifest' or 'file' is required"));
+        let preview: bool = input.get_arg("preview").unwrap_or(false);
+
+        if preview {
+            let content = match (manifest, &file) {
+                (Some(m), _) => m,
+                (None, Some(f)) => match tokio::fs::read_to_string(f).await {
+                    Ok(c) => c,
+                    Err(e) => {
+                        return Ok(ToolResult::error(format!("Failed to read {}: {}", f, e)))
+                    }
+                },
+                (None, None) => {
+                    return Ok(ToolResult::error("Either 'manifest' or 'file' is required"))
+                }
+            };
+            return Ok(preview_apply(&content, namespace.as_deref()).await);
         }
 
         let mut args = vec!["apply".to_string()];
 
-        if let Some(ref f) = file {
-            args.push(format!("-f={}", f));
-        } else if let Some(ref m) = manifest {
-            // Write manifest to temp file
-            let temp_path = "/tmp/kubectl-apply-manifest.yaml";
-            if let Err(e) = tokio::fs::write(temp_path, m).await {
-                return Ok(ToolResult::error(format!("Failed to write temp manifest: {}", e)));
+        // Kept alive until kubectl has read it; removed on drop
+        let mut _temp_manifest = None;
+        match (&file, &manifest) {
+            (Some(f), _) => args.push(format!("-f={}", f)),
+            (None, Some(m)) => {
+                let temp = match write_temp_manifest("kubectl-apply-", m) {
+                    Ok(t) => t,
+                    Err(e) => return Ok(ToolResult::error(e)),
+                };
+                args.push(format!("-f={}", temp.path().display()));
+                _temp_manifest = Some(temp);
+            }
+            (None, None) => {
+                return Ok(ToolResult::error("Either 'manifest' or 'file' is required"))
             }
-            args.push(format!("-f={}", temp_path));
         }
 
         if let Some(ref ns) = namespace {
@@ -326,6 +351,136 @@ impl Tool for KubectlApplyTool {
     }
 }
 
+// ============================================================================
+// Apply Preview (multi-document manifests)
+// ============================================================================
+
+/// What applying a resource would do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewAction {
+    /// Resource does not exist yet
+    Create,
+    /// Resource exists and would be modified
+    Configure,
+    /// Resource exists and matches the manifest
+    Unchanged,
+    /// The diff could not be computed
+    Error,
+}
+
+/// Per-resource preview of a `kubectl apply`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResourcePreview {
+    #[serde(flatten)]
+    pub resource: ManifestResource,
+    pub action: PreviewAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ResourcePreview {
+    /// Build a preview from the result of `kubectl diff` for one resource.
+    ///
+    /// `kubectl diff` exits 0 when there are no differences, 1 when there are,
+    /// and >1 on failure. The resource would be created when the live side of
+    /// the diff is empty, i.e. every hunk starts at `@@ -0,0`; a diff that only
+    /// adds fields to an existing object is still a configure.
+    pub fn from_diff(resource: ManifestResource, exit_code: i32, stdout: &str, stderr: &str) -> Self {
+        let (action, diff, error) = match exit_code {
+            0 => (PreviewAction::Unchanged, None, None),
+            1 => {
+                let mut hunks = stdout.lines().filter(|l| l.starts_with("@@ ")).peekable();
+                let live_is_empty =
+                    hunks.peek().is_some() && hunks.all(|h| h.starts_with("@@ -0,0 "));
+                let action = if live_is_empty {
+                    PreviewAction::Create
+                } else {
+                    PreviewAction::Configure
+                };
+                (action, Some(stdout.to_string()), None)
+            }
+            _ => (PreviewAction::Error, None, Some(stderr.trim().to_string())),
+        };
+
+        Self {
+            resource,
+            action,
+            diff,
+            error,
+        }
+    }
+}
+
+/// Summarize previews by action
+pub fn summarize_previews(previews: &[ResourcePreview]) -> serde_json::Value {
+    let count = |action: PreviewAction| previews.iter().filter(|p| p.action == action).count();
+    serde_json::json!({
+        "total": previews.len(),
+        "create": count(PreviewAction::Create),
+        "configure": count(PreviewAction::Configure),
+        "unchanged": count(PreviewAction::Unchanged),
+        "error": count(PreviewAction::Error),
+    })
+}
+
+/// Write a manifest to a fresh, uniquely named temp file readable only by us
+fn write_temp_manifest(prefix: &str, content: &str) -> Result<tempfile::NamedTempFile, String> {
+    use std::io::Write;
+
+    let mut temp = tempfile::Builder::new()
+        .prefix(prefix)
+        .suffix(".yaml")
+        .tempfile()
+        .map_err(|e| format!("Failed to create temp manifest: {}", e))?;
+    temp.write_all(content.as_bytes())
+        .and_then(|_| temp.flush())
+        .map_err(|e| format!("Failed to write temp manifest: {}", e))?;
+    Ok(temp)
+}
+
+/// Preview a manifest by running `kubectl diff` on each resource separately
+async fn preview_apply(manifest: &str, namespace: Option<&str>) -> ToolResult {
+    let resources = match split_manifest(manifest) {
+        Ok(r) if r.is_empty() => return ToolResult::error("Manifest contains no resources"),
+        Ok(r) => r,
+        Err(e) => return ToolResult::error(e),
+    };
+
+    let mut previews = Vec::with_capacity(resources.len());
+    for resource in resources {
+        let temp = match write_temp_manifest("kubectl-preview-", &resource.document) {
+            Ok(t) => t,
+            Err(e) => return ToolResult::error(e),
+        };
+
+        let mut args = vec!["diff".to_string(), format!("-f={}", temp.path().display())];
+        if let Some(ns) = namespace {
+            args.push(format!("-n={}", ns));
+        }
+
+        debug!(args = ?args, resource = %resource.id(), "Executing kubectl diff");
+
+        let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        let preview = match execute_command("kubectl", &args_str, None, 60).await {
+            Ok(output) => {
+                ResourcePreview::from_diff(resource, output.exit_code, &output.stdout, &output.stderr)
+            }
+            Err(e) => ResourcePreview::from_diff(resource, -1, "", &e),
+        };
+        drop(temp);
+        previews.push(preview);
+    }
+
+    ToolResult::success(serde_json::json!({
+        "preview": true,
+        "summary": summarize_previews(&previews),
+        "resources": previews
+    }))
+}
+
 // ============================================================================
 // Kubectl Delete Tool
 // ============================================================================
@@ -766,3 +921,128 @@ impl Tool for KubectlDescribeTool {
         &self.config
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THREE_DOC_MANIFEST: &str = r#"
+apiVersion: v1
+kind: Namespace
+metadata:
+  name: shop
+---
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  namespace: shop
+spec:
+  replicas: 3
+---
+apiVersion: v1
+kind: Service
+metadata:
+  name: web
+  namespace: shop
+spec:
+  ports:
+    - port: 80
+---
+"#;
+
+    #[test]
+    fn test_split_manifest_three_documents() {
+        let resources = split_manifest(THREE_DOC_MANIFEST).unwrap();
+        assert_eq!(resources.len(), 3);
+
+        let ids: Vec<String> = resources.iter().map(|r| r.id()).collect();
+        assert_eq!(ids, vec!["Namespace/shop", "Deployment/web", "Service/web"]);
+        assert_eq!(resources[1].namespace.as_deref(), Some("shop"));
+        assert!(resources[1].document.contains("replicas: 3"));
+        assert!(!resources[1].document.contains("kind: Service"));
+    }
+
+    #[test]
+    fn test_split_manifest_expands_list() {
+        let manifest = r#"
+apiVersion: v1
+kind: List
+items:
+  - apiVersion: v1
+    kind: ConfigMap
+    metadata:
+      name: a
+  - apiVersion: v1
+    kind: ConfigMap
+    metadata:
+      name: b
+"#;
+        let resources = split_manifest(manifest).unwrap();
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[1].id(), "ConfigMap/b");
+    }
+
+    #[test]
+    fn test_split_manifest_missing_kind() {
+        let err = split_manifest("metadata:\n  name: x\n").unwrap_err();
+        assert!(err.contains("missing 'kind'"));
+    }
+
+    #[test]
+    fn test_per_resource_previews() {
+        let mut resources = split_manifest(THREE_DOC_MANIFEST).unwrap().into_iter();
+
+        let create_diff = "--- /tmp/LIVE/v1.Namespace..shop\n+++ /tmp/MERGED/v1.Namespace..shop\n@@ -0,0 +1,4 @@\n+apiVersion: v1\n+kind: Namespace\n";
+        let configure_diff = "--- /tmp/LIVE/apps.v1.Deployment.shop.web\n+++ /tmp/MERGED/apps.v1.Deployment.shop.web\n@@ -6 +6 @@\n-  replicas: 2\n+  replicas: 3\n";
+
+        let previews = vec![
+            ResourcePreview::from_diff(resources.next().unwrap(), 1, create_diff, ""),
+            ResourcePreview::from_diff(resources.next().unwrap(), 1, configure_diff, ""),
+            ResourcePreview::from_diff(resources.next().unwrap(), 0, "", ""),
+        ];
+
+        assert_eq!(previews[0].action, PreviewAction::Create);
+        assert_eq!(previews[1].action, PreviewAction::Configure);
+        assert!(previews[1].diff.as_ref().unwrap().contains("replicas: 3"));
+        assert_eq!(previews[2].action, PreviewAction::Unchanged);
+
+        let summary = summarize_previews(&previews);
+        assert_eq!(summary["total"], 3);
+        assert_eq!(summary["create"], 1);
+        assert_eq!(summary["configure"], 1);
+        assert_eq!(summary["unchanged"], 1);
+
+        let json = serde_json::to_value(&previews[1]).unwrap();
+        assert_eq!(json["kind"], "Deployment");
+        assert_eq!(json["action"], "configure");
+    }
+
+    #[test]
+    fn test_additive_diff_is_configure() {
+        let resource = split_manifest(THREE_DOC_MANIFEST).unwrap().remove(1);
+        let add_label = "--- /tmp/LIVE/apps.v1.Deployment.shop.web\n+++ /tmp/MERGED/apps.v1.Deployment.shop.web\n@@ -4,0 +5 @@\n+    team: payments\n";
+        let preview = ResourcePreview::from_diff(resource, 1, add_label, "");
+        assert_eq!(preview.action, PreviewAction::Configure);
+    }
+
+    #[test]
+    fn test_temp_manifest_is_unique_and_removed() {
+        let a = write_temp_manifest("kubectl-preview-", "kind: A\n").unwrap();
+        let b = write_temp_manifest("kubectl-preview-", "kind: B\n").unwrap();
+        assert_ne!(a.path(), b.path());
+        assert_eq!(std::fs::read_to_string(a.path()).unwrap(), "kind: A\n");
+
+        let path = a.path().to_path_buf();
+        drop(a);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_preview_diff_failure() {
+        let resource = split_manifest(THREE_DOC_MANIFEST).unwrap().remove(0);
+        let preview = ResourcePreview::from_diff(resource, 2, "", "error: connection refused\n");
+        assert_eq!(preview.action, PreviewAction::Error);
+        assert_eq!(preview.error.as_deref(), Some("error: connection refused"));
+    }
+}
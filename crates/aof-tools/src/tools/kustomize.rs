@@ -0,0 +1,259 @@
+//! Kustomize Build Tool
+//!
+//! Renders a Kustomize directory (base or overlay) locally so the output can be
+//! reviewed before it is applied.
+//!
+//! ## Features
+//!
+//! - `kustomize build <dir>`, or `kubectl kustomize <dir>` when the standalone
+//!   binary is not installed
+//! - Rendered output as one manifest stream, or split per resource
+//!
+//! ## Prerequisites
+//!
+//! - Requires `kubectl` feature flag
+//! - kustomize or kubectl must be installed and in PATH (no cluster access needed)
+
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use tracing::debug;
+
+use super::common::{
+    create_schema, tool_config_with_timeout, CommandOutput, CommandRunner, ProcessRunner,
+};
+use super::manifests::split_rendered;
+
+/// Kustomize build tool
+pub struct KustomizeBuildTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl KustomizeBuildTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "path": {
+                    "type": "string",
+                    "description": "Directory containing a kustomization.yaml (base or overlay)"
+                },
+                "binary": {
+                    "type": "string",
+                    "enum": ["auto", "kustomize", "kubectl"],
+                    "description": "Renderer to use. auto tries kustomize, then kubectl kustomize (default: auto)"
+                },
+                "enable_helm": {
+                    "type": "boolean",
+                    "description": "Allow helmCharts in the kustomization (--enable-helm)",
+                    "default": false
+                },
+                "split": {
+                    "type": "boolean",
+                    "description": "Return each rendered resource separately",
+                    "default": false
+                }
+            }),
+            vec!["path"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "kustomize_build",
+                "Render a Kustomize base or overlay locally with kustomize build (or kubectl kustomize) and return the manifests, optionally split per resource. Use to review an overlay before applying it.",
+                parameters,
+                120,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Run the build with one renderer; Err means the renderer could not be run
+    async fn build(
+        &self,
+        renderer: &str,
+        path: &str,
+        enable_helm: bool,
+    ) -> Result<CommandOutput, String> {
+        let (program, mut args) = match renderer {
+            "kubectl" => ("kubectl", vec!["kustomize".to_string()]),
+            _ => ("kustomize", vec!["build".to_string()]),
+        };
+        args.push(path.to_string());
+        if enable_helm {
+            args.push("--enable-helm".to_string());
+        }
+
+        self.runner
+            .run(program, &args, None, self.config.timeout_secs)
+            .await
+    }
+}
+
+impl Default for KustomizeBuildTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for KustomizeBuildTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let path: String = input.get_arg("path")?;
+        let binary: String = input.get_arg("binary").unwrap_or_else(|_| "auto".to_string());
+        let enable_helm: bool = input.get_arg("enable_helm").unwrap_or(false);
+        let split: bool = input.get_arg("split").unwrap_or(false);
+
+        let renderers: &[&str] = match binary.as_str() {
+            "auto" => &["kustomize", "kubectl"],
+            "kustomize" => &["kustomize"],
+            "kubectl" => &["kubectl"],
+            other => {
+                return Ok(ToolResult::error(format!(
+                    "Invalid binary '{}': use auto, kustomize, or kubectl",
+                    other
+                )))
+            }
+        };
+
+        let mut unavailable = Vec::new();
+        let mut rendered = None;
+        for renderer in renderers {
+            debug!(path = %path, renderer = %renderer, "Building kustomization");
+            match self.build(renderer, &path, enable_helm).await {
+                Ok(output) => {
+                    rendered = Some((*renderer, output));
+                    break;
+                }
+                Err(e) => unavailable.push(e),
+            }
+        }
+
+        let Some((renderer, output)) = rendered else {
+            return Ok(ToolResult::error(format!(
+                "Kustomize is not available: install kustomize or kubectl ({})",
+                unavailable.join("; ")
+            )));
+        };
+        let renderer = match renderer {
+            "kubectl" => "kubectl kustomize",
+            _ => "kustomize build",
+        };
+
+        if !output.success {
+            return Ok(ToolResult::error(format!(
+                "{} failed for '{}': {}",
+                renderer,
+                path,
+                output.stderr.trim()
+            )));
+        }
+
+        let resources = match split_rendered(&output.stdout) {
+            Ok(resources) => resources,
+            Err(e) => return Ok(ToolResult::error(format!("{} output for '{}' is not valid: {}", renderer, path, e))),
+        };
+        let mut result = serde_json::json!({
+            "path": path,
+            "renderer": renderer,
+            "resource_count": resources.len(),
+        });
+        if split {
+            result["resources"] = serde_json::json!(resources);
+        } else {
+            result["manifests"] = serde_json::json!(output.stdout);
+        }
+
+        Ok(ToolResult::success(result))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::FakeRunner;
+
+    const OVERLAY: &str = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: prod-web-config\n  namespace: prod\ndata:\n  LOG_LEVEL: warn\n---\napiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: prod-web\n  namespace: prod\nspec:\n  replicas: 5\n";
+
+    fn fake_kustomize() -> FakeRunner {
+        FakeRunner::new(|_, args| match args.last().map(String::as_str) {
+            Some("overlays/prod") => CommandOutput::ok(OVERLAY),
+            _ => CommandOutput::failed(1, "Error: unable to find one of 'kustomization.yaml'"),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_build_overlay_split_per_resource() {
+        let runner = Arc::new(fake_kustomize());
+        let tool = KustomizeBuildTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "path": "overlays/prod",
+                "split": true
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["renderer"], "kustomize build");
+        assert_eq!(result.data["resource_count"], 2);
+        let resources = result.data["resources"].as_array().unwrap();
+        assert_eq!(resources[0]["kind"], "ConfigMap");
+        assert_eq!(resources[1]["name"], "prod-web");
+        assert_eq!(resources[1]["namespace"], "prod");
+        assert_eq!(runner.calls(), vec![vec!["kustomize", "build", "overlays/prod"]]);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_kubectl_kustomize() {
+        let runner = Arc::new(fake_kustomize().with_missing("kustomize"));
+        let tool = KustomizeBuildTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({ "path": "overlays/prod" })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["renderer"], "kubectl kustomize");
+        assert!(result.data["manifests"].as_str().unwrap().contains("replicas: 5"));
+        assert_eq!(runner.calls()[1], vec!["kubectl", "kustomize", "overlays/prod"]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_kustomize_is_reported() {
+        let runner = fake_kustomize().with_missing("kustomize").with_missing("kubectl");
+        let tool = KustomizeBuildTool::new().with_runner(Arc::new(runner));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({ "path": "overlays/prod" })))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Kustomize is not available"));
+    }
+
+    #[tokio::test]
+    async fn test_build_failure_is_an_error() {
+        let tool = KustomizeBuildTool::new().with_runner(Arc::new(fake_kustomize()));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({ "path": "overlays/missing" })))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("kustomization.yaml"));
+    }
+}
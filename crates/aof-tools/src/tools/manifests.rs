@@ -0,0 +1,177 @@
+//! Manifest Helpers
+//!
+//! Split multi-document YAML into one entry per resource so each object can be
+//! previewed, approved or reviewed on its own. Used by `kubectl_apply` previews
+//! and by the tools that render manifests locally (Helm, Kustomize).
+
+use serde::Serialize;
+
+/// A single resource document from a (possibly multi-document) manifest
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestResource {
+    /// Position of the document in the manifest (0-based, empty documents skipped)
+    pub index: usize,
+    pub api_version: String,
+    pub kind: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Template the resource came from (Helm's `# Source:` comment)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The resource's YAML document on its own
+    #[serde(skip)]
+    pub document: String,
+}
+
+impl ManifestResource {
+    /// Display identifier, e.g. `Deployment/web`
+    pub fn id(&self) -> String {
+        format!("{}/{}", self.kind, self.name)
+    }
+}
+
+/// A rendered resource as returned to the agent for review
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenderedResource {
+    /// Resource kind (e.g. Deployment)
+    pub kind: String,
+    /// `metadata.name`
+    pub name: String,
+    /// `metadata.namespace`, when set in the manifest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Template the resource came from (Helm's `# Source:` comment)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The resource's YAML document
+    pub manifest: String,
+}
+
+impl From<ManifestResource> for RenderedResource {
+    fn from(resource: ManifestResource) -> Self {
+        Self {
+            kind: resource.kind,
+            name: resource.name,
+            namespace: resource.namespace,
+            source: resource.source,
+            manifest: resource.document,
+        }
+    }
+}
+
+/// Split a multi-document YAML manifest into individual resources.
+///
+/// Empty documents are skipped and `kind: List` documents are expanded into
+/// their items so every resource can be previewed and approved on its own.
+/// Other documents are kept as written, comments included.
+pub fn split_manifest(manifest: &str) -> Result<Vec<ManifestResource>, String> {
+    let mut resources = Vec::new();
+    for (doc_index, raw) in split_documents(manifest).into_iter().enumerate() {
+        let value: serde_yaml::Value = serde_yaml::from_str(&raw)
+            .map_err(|e| format!("Invalid YAML in document {}: {}", doc_index + 1, e))?;
+        if value.is_null() {
+            continue;
+        }
+
+        let source = raw
+            .lines()
+            .find_map(|l| l.trim().strip_prefix("# Source:"))
+            .map(|s| s.trim().to_string());
+
+        let items = if value.get("kind").and_then(|k| k.as_str()) == Some("List") {
+            value
+                .get("items")
+                .and_then(|i| i.as_sequence())
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| {
+                    let document = serde_yaml::to_string(&item)
+                        .map_err(|e| format!("Failed to serialize document {}: {}", doc_index + 1, e))?;
+                    Ok((item, document))
+                })
+                .collect::<Result<Vec<_>, String>>()?
+        } else {
+            vec![(value, raw.trim().to_string())]
+        };
+
+        for (item, document) in items {
+            let field = |path: &[&str]| -> Option<String> {
+                let mut current = &item;
+                for key in path {
+                    current = current.get(*key)?;
+                }
+                current.as_str().map(|s| s.to_string())
+            };
+
+            let kind = field(&["kind"]).ok_or_else(|| {
+                format!("Document {} is missing 'kind'", doc_index + 1)
+            })?;
+
+            resources.push(ManifestResource {
+                index: resources.len(),
+                api_version: field(&["apiVersion"]).unwrap_or_default(),
+                name: field(&["metadata", "name"])
+                    .or_else(|| field(&["metadata", "generateName"]))
+                    .unwrap_or_else(|| "<unnamed>".to_string()),
+                namespace: field(&["metadata", "namespace"]),
+                source: source.clone(),
+                kind,
+                document,
+            });
+        }
+    }
+
+    Ok(resources)
+}
+
+/// Split rendered manifests into resources for review
+pub fn split_rendered(text: &str) -> Result<Vec<RenderedResource>, String> {
+    Ok(split_manifest(text)?.into_iter().map(RenderedResource::from).collect())
+}
+
+// Helper: Split YAML text on `---` document markers
+fn split_documents(text: &str) -> Vec<String> {
+    let mut documents = vec![String::new()];
+    for line in text.lines() {
+        if line.trim_end() == "---" || line.starts_with("--- ") {
+            documents.push(String::new());
+        } else {
+            let doc = documents.last_mut().expect("at least one document");
+            doc.push_str(line);
+            doc.push('\n');
+        }
+    }
+    documents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_rendered_keeps_helm_sources() {
+        let text = "---\n# Source: web/templates/service.yaml\napiVersion: v1\nkind: Service\nmetadata:\n  name: web\n---\n# Source: web/templates/empty.yaml\n---\napiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: web\n  namespace: prod\n";
+
+        let resources = split_rendered(text).unwrap();
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[0].kind, "Service");
+        assert_eq!(resources[0].source.as_deref(), Some("web/templates/service.yaml"));
+        assert!(resources[0].manifest.starts_with("# Source:"));
+        assert_eq!(resources[1].kind, "Deployment");
+        assert_eq!(resources[1].namespace.as_deref(), Some("prod"));
+        assert!(resources[1].source.is_none());
+    }
+
+    #[test]
+    fn test_split_rendered_expands_list() {
+        let text = "apiVersion: v1\nkind: List\nitems:\n  - apiVersion: v1\n    kind: ConfigMap\n    metadata:\n      name: a\n  - apiVersion: v1\n    kind: ConfigMap\n    metadata:\n      name: b\n";
+
+        let resources = split_rendered(text).unwrap();
+        let names: Vec<&str> = resources.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert!(resources[1].manifest.contains("name: b"));
+        assert!(!resources[1].manifest.contains("name: a"));
+    }
+}
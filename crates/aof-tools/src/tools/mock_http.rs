@@ -0,0 +1,192 @@
+//! Local HTTP server for testing API clients
+//!
+//! `MockHttpServer` listens on a random local port, answers each connection
+//! with a canned response and records the requests it received.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Canned reply served by `MockHttpServer`
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl MockResponse {
+    /// 200 response with a JSON body (`Value::Null` sends an empty body)
+    pub fn json(body: serde_json::Value) -> Self {
+        let body = if body.is_null() { String::new() } else { body.to_string() };
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// Use a different status code
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Add a response header
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    fn to_http(&self) -> String {
+        let mut reply = format!("HTTP/1.1 {} Mock\r\ncontent-type: application/json\r\n", self.status);
+        for (name, value) in &self.headers {
+            reply.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        reply.push_str(&format!(
+            "content-length: {}\r\nconnection: close\r\n\r\n{}",
+            self.body.len(),
+            self.body
+        ));
+        reply
+    }
+}
+
+/// A request received by `MockHttpServer`
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// e.g. `POST`
+    pub method: String,
+    /// Path including the query string
+    pub path: String,
+    /// Headers in the order they were sent
+    pub headers: Vec<(String, String)>,
+    /// Raw request body
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// `METHOD path HTTP/1.1`
+    pub fn request_line(&self) -> String {
+        format!("{} {} HTTP/1.1", self.method, self.path)
+    }
+
+    /// Value of a header, matched case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Body as text
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+
+    /// Body parsed as JSON; panics if it isn't
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("request body is not JSON")
+    }
+}
+
+/// Local HTTP server answering one canned response per connection
+pub struct MockHttpServer {
+    url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockHttpServer {
+    /// Serve `responses` in order, one per connection, then stop accepting
+    pub async fn start(responses: Vec<MockResponse>) -> Self {
+        Self::serve(responses.into(), None).await
+    }
+
+    /// Serve the same response to every connection
+    pub async fn always(response: MockResponse) -> Self {
+        Self::serve(VecDeque::new(), Some(response)).await
+    }
+
+    async fn serve(mut responses: VecDeque<MockResponse>, fallback: Option<MockResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        tokio::spawn(async move {
+            while let Some(response) = responses.pop_front().or_else(|| fallback.clone()) {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+                if let Some(request) = read_request(&mut socket).await {
+                    recorded.lock().unwrap().push(request);
+                }
+                let _ = socket.write_all(response.to_http().as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// Base URL, e.g. `http://127.0.0.1:40123`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Requests received so far, in order
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Read one request: headers, then `content-length` bytes of body
+async fn read_request(socket: &mut TcpStream) -> Option<RecordedRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = socket.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some((name.trim().to_string(), value.trim().to_string()))
+        })
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    while buf.len() < header_end + content_length {
+        let n = socket.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    let body_end = buf.len().min(header_end + content_length);
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body: buf[header_end..body_end].to_vec(),
+    })
+}
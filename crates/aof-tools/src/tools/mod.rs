@@ -23,22 +23,65 @@ pub mod shell;
 // Unified CLI tools (recommended)
 pub mod cli;
 
+// Output format negotiation (json, text, table)
+pub mod output;
+
+// Context connectivity probes (aofctl contexts health, /contexts health)
+pub mod context_health;
+
 // Legacy per-operation tools (still available for backward compatibility)
 #[cfg(feature = "kubectl")]
 pub mod kubectl;
 
+#[cfg(feature = "kubectl")]
+pub mod pod_logs;
+
+#[cfg(feature = "kubectl")]
+pub mod k8s_config_diff;
+
+#[cfg(feature = "kubectl")]
+pub mod k8s_resource_quota;
+
+#[cfg(feature = "kubectl")]
+pub mod manifests;
+
+#[cfg(feature = "kubectl")]
+pub mod helm;
+
+#[cfg(feature = "kubectl")]
+pub mod kustomize;
+
 #[cfg(feature = "docker")]
 pub mod docker;
 
 #[cfg(feature = "git")]
 pub mod git;
 
+#[cfg(feature = "git")]
+pub mod commit_cache;
+
+#[cfg(feature = "git")]
+pub mod git_diff_summary;
+
+#[cfg(feature = "git")]
+pub mod git_changelog;
+
 #[cfg(feature = "terraform")]
 pub mod terraform;
 
+#[cfg(feature = "terraform")]
+pub mod terraform_state;
+
 #[cfg(feature = "http")]
 pub mod http;
 
+#[cfg(feature = "reqwest")]
+pub mod rate_limit;
+
+// Local HTTP server for API client tests
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock_http;
+
 #[cfg(feature = "observability")]
 pub mod observability;
 
@@ -98,6 +141,25 @@ pub mod azure;
 #[cfg(feature = "cloud")]
 pub mod gcp;
 
+// Network diagnostic tools
+#[cfg(feature = "network")]
+pub mod netdiag;
+
+#[cfg(feature = "network")]
+pub mod cert;
+
+// Data transform tools
+#[cfg(feature = "transform")]
+pub mod transform;
+
+// Kubernetes API tools (no kubectl binary)
+#[cfg(feature = "k8s-api")]
+pub mod k8s_api;
+
+// Feature flag management
+#[cfg(feature = "featureflags")]
+pub mod feature_flags;
+
 /// Common utilities for tool implementations
 pub mod common {
     use aof_core::ToolConfig;
@@ -187,6 +249,107 @@ pub mod common {
         })
     }
 
+    /// How a tool turns its single `command` string into a process
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum CommandPolicy {
+        /// Split with shell quoting rules and run the binary directly; shell
+        /// metacharacters (`;`, `|`, `&`, redirects, `$()`, backticks) are rejected
+        #[default]
+        Strict,
+        /// Run through `sh -c`, so pipes, chaining and substitution work
+        Shell,
+    }
+
+    /// First shell metacharacter that would be interpreted by a shell, if any
+    ///
+    /// Anything inside single quotes is literal. Inside double quotes only command
+    /// substitution (`$()` and backticks) is still active.
+    pub fn find_shell_metachar(command: &str) -> Option<&'static str> {
+        let mut chars = command.chars().peekable();
+        let mut in_single = false;
+        let mut in_double = false;
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if !in_single => {
+                    chars.next();
+                }
+                '\'' if !in_double => in_single = !in_single,
+                '"' if !in_single => in_double = !in_double,
+                _ if in_single => {}
+                '`' => return Some("`"),
+                '$' if chars.peek() == Some(&'(') => return Some("$()"),
+                _ if in_double => {}
+                ';' => return Some(";"),
+                '|' => return Some("|"),
+                '&' => return Some("&"),
+                '>' => return Some(">"),
+                '<' => return Some("<"),
+                '\n' | '\r' => return Some("newline"),
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Split a tool's `command` string into arguments under the given policy
+    pub fn parse_command_args(
+        program: &str,
+        command: &str,
+        policy: CommandPolicy,
+    ) -> Result<Vec<String>, String> {
+        if policy == CommandPolicy::Strict {
+            if let Some(meta) = find_shell_metachar(command) {
+                return Err(format!(
+                    "Invalid command: shell metacharacter '{}' is not allowed for {} (arguments are passed directly, not through a shell). Run one command per call.",
+                    meta, program
+                ));
+            }
+        }
+
+        let args = shell_words::split(command)
+            .map_err(|e| format!("Invalid command: failed to parse arguments: {}", e))?;
+        if args.is_empty() {
+            return Err("Empty command provided".to_string());
+        }
+
+        Ok(args)
+    }
+
+    /// Runs external commands for a tool
+    ///
+    /// Tools that shell out take a runner so tests can substitute canned output
+    /// for the real binary.
+    #[async_trait::async_trait]
+    pub trait CommandRunner: Send + Sync {
+        async fn run(
+            &self,
+            program: &str,
+            args: &[String],
+            working_dir: Option<&str>,
+            timeout_secs: u64,
+        ) -> Result<CommandOutput, String>;
+    }
+
+    /// Runs commands as child processes
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct ProcessRunner;
+
+    #[async_trait::async_trait]
+    impl CommandRunner for ProcessRunner {
+        async fn run(
+            &self,
+            program: &str,
+            args: &[String],
+            working_dir: Option<&str>,
+            timeout_secs: u64,
+        ) -> Result<CommandOutput, String> {
+            let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+            execute_command(program, &args, working_dir, timeout_secs).await
+        }
+    }
+
     /// Command execution output
     #[derive(Debug, Clone, serde::Serialize)]
     pub struct CommandOutput {
@@ -200,5 +363,80 @@ pub mod common {
         pub fn to_json(&self) -> serde_json::Value {
             serde_json::to_value(self).unwrap_or_default()
         }
+
+        /// Successful output with the given stdout
+        pub fn ok(stdout: impl Into<String>) -> Self {
+            Self {
+                exit_code: 0,
+                stdout: stdout.into(),
+                stderr: String::new(),
+                success: true,
+            }
+        }
+
+        /// Failed output with the given stderr
+        pub fn failed(exit_code: i32, stderr: impl Into<String>) -> Self {
+            Self {
+                exit_code,
+                stdout: String::new(),
+                stderr: stderr.into(),
+                success: false,
+            }
+        }
+    }
+
+    /// Answers a `(program, args)` invocation for `FakeRunner`
+    #[cfg(any(test, feature = "test-util"))]
+    type FakeHandler = Box<dyn Fn(&str, &[String]) -> CommandOutput + Send + Sync>;
+
+    /// Test runner that records invocations and answers from a handler
+    #[cfg(any(test, feature = "test-util"))]
+    pub struct FakeRunner {
+        handler: FakeHandler,
+        calls: std::sync::Mutex<Vec<Vec<String>>>,
+        missing: Vec<String>,
+    }
+
+    #[cfg(any(test, feature = "test-util"))]
+    impl FakeRunner {
+        /// Answer every command with `handler(program, args)`
+        pub fn new(handler: impl Fn(&str, &[String]) -> CommandOutput + Send + Sync + 'static) -> Self {
+            Self {
+                handler: Box::new(handler),
+                calls: std::sync::Mutex::new(Vec::new()),
+                missing: Vec::new(),
+            }
+        }
+
+        /// Fail to start `program`, as if it were not installed
+        pub fn with_missing(mut self, program: &str) -> Self {
+            self.missing.push(program.to_string());
+            self
+        }
+
+        /// Every invocation so far, as `[program, args...]`
+        pub fn calls(&self) -> Vec<Vec<String>> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[cfg(any(test, feature = "test-util"))]
+    #[async_trait::async_trait]
+    impl CommandRunner for FakeRunner {
+        async fn run(
+            &self,
+            program: &str,
+            args: &[String],
+            _working_dir: Option<&str>,
+            _timeout_secs: u64,
+        ) -> Result<CommandOutput, String> {
+            let mut call = vec![program.to_string()];
+            call.extend(args.iter().cloned());
+            self.calls.lock().unwrap().push(call);
+            if self.missing.iter().any(|m| m == program) {
+                return Err(format!("Failed to spawn {}: No such file or directory", program));
+            }
+            Ok((self.handler)(program, args))
+        }
     }
 }
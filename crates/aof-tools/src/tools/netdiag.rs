@@ -0,0 +1,463 @@
+//! Network Diagnostic Tool
+//!
+//! Tool for answering "can this service resolve and reach that host" without
+//! shelling out to `dig`, `nc` or `openssl s_client`.
+//!
+//! ## Features
+//!
+//! - DNS resolution (A/AAAA) with timing
+//! - TCP connect checks with latency
+//! - TLS certificate inspection (subject, issuer, days until expiry)
+//!
+//! ## Prerequisites
+//!
+//! - Requires `network` feature flag
+//!
+//! ## Usage
+//!
+//! All checks use tokio sockets and rustls directly, so results are structured
+//! and consistent across platforms regardless of which CLI utilities are installed.
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::net::TcpStream;
+use tracing::debug;
+
+use super::common::{create_schema, tool_config_with_timeout};
+
+/// Network diagnostic tool (DNS, TCP connect, TLS certificate)
+pub struct NetDiagTool {
+    config: ToolConfig,
+}
+
+impl NetDiagTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "host": {
+                    "type": "string",
+                    "description": "Hostname or IP address to diagnose"
+                },
+                "port": {
+                    "type": "integer",
+                    "description": "Port for TCP/TLS checks (required for tcp and tls checks)"
+                },
+                "checks": {
+                    "type": "array",
+                    "description": "Checks to run. Defaults to dns, plus tcp when a port is given",
+                    "items": {
+                        "type": "string",
+                        "enum": ["dns", "tcp", "tls"]
+                    }
+                },
+                "timeout_secs": {
+                    "type": "integer",
+                    "description": "Timeout for each individual check in seconds",
+                    "default": 5
+                }
+            }),
+            vec!["host"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "net_diag",
+                "Diagnose network reachability of a host: DNS resolution, TCP connect latency, and TLS certificate expiry. Returns structured results per check.",
+                parameters,
+                60,
+            ),
+        }
+    }
+}
+
+impl Default for NetDiagTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for NetDiagTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let host: String = input.get_arg("host")?;
+        let port: Option<u16> = input.get_arg("port").ok();
+        let timeout_secs: u64 = input.get_arg("timeout_secs").unwrap_or(5);
+        let checks: Vec<String> = input.get_arg("checks").unwrap_or_else(|_| {
+            let mut defaults = vec!["dns".to_string()];
+            if port.is_some() {
+                defaults.push("tcp".to_string());
+            }
+            defaults
+        });
+        let timeout = Duration::from_secs(timeout_secs);
+
+        debug!(host = %host, port = ?port, checks = ?checks, "Running network diagnostics");
+
+        let mut result = serde_json::json!({
+            "host": host,
+            "port": port,
+        });
+        let mut all_ok = true;
+
+        for check in &checks {
+            let outcome = match check.as_str() {
+                "dns" => {
+                    let dns = resolve_host(&host, timeout).await;
+                    all_ok &= dns.resolved;
+                    serde_json::to_value(dns).unwrap_or_default()
+                }
+                "tcp" | "tls" => {
+                    let Some(port) = port else {
+                        return Ok(ToolResult::error(format!(
+                            "Check '{}' requires a port",
+                            check
+                        )));
+                    };
+                    if check == "tcp" {
+                        let tcp = tcp_connect(&host, port, timeout).await;
+                        all_ok &= tcp.reachable;
+                        serde_json::to_value(tcp).unwrap_or_default()
+                    } else {
+                        match fetch_peer_certificate(&host, port, timeout).await {
+                            Ok(der) => match parse_certificate(&der) {
+                                Ok(info) => serde_json::json!({
+                                    "subject": info.subject,
+                                    "issuer": info.issuer,
+                                    "sans": info.sans,
+                                    "not_before": info.not_before,
+                                    "not_after": info.not_after,
+                                    "days_until_expiry": info.days_until_expiry(SystemTime::now()),
+                                }),
+                                Err(e) => {
+                                    all_ok = false;
+                                    serde_json::json!({ "error": e })
+                                }
+                            },
+                            Err(e) => {
+                                all_ok = false;
+                                serde_json::json!({ "error": e })
+                            }
+                        }
+                    }
+                }
+                other => {
+                    return Ok(ToolResult::error(format!(
+                        "Unknown check '{}'. Valid checks: dns, tcp, tls",
+                        other
+                    )));
+                }
+            };
+            result[check.as_str()] = outcome;
+        }
+
+        result["healthy"] = serde_json::json!(all_ok);
+        Ok(ToolResult::success(result))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Result of a DNS resolution check
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DnsCheck {
+    pub resolved: bool,
+    pub addresses: Vec<String>,
+    pub elapsed_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of a TCP connect check
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TcpCheck {
+    pub reachable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Resolve a hostname to its IP addresses using the system resolver
+pub async fn resolve_host(host: &str, timeout: Duration) -> DnsCheck {
+    let start = Instant::now();
+    // lookup_host requires a port; it is discarded from the output
+    let lookup = tokio::time::timeout(timeout, tokio::net::lookup_host((host, 0))).await;
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+
+    match lookup {
+        Ok(Ok(addrs)) => {
+            let mut addresses: Vec<String> = addrs.map(|a| a.ip().to_string()).collect();
+            addresses.sort();
+            addresses.dedup();
+            DnsCheck {
+                resolved: !addresses.is_empty(),
+                addresses,
+                elapsed_ms,
+                error: None,
+            }
+        }
+        Ok(Err(e)) => DnsCheck {
+            resolved: false,
+            addresses: vec![],
+            elapsed_ms,
+            error: Some(format!("DNS resolution failed: {}", e)),
+        },
+        Err(_) => DnsCheck {
+            resolved: false,
+            addresses: vec![],
+            elapsed_ms,
+            error: Some(format!("DNS resolution timed out after {}s", timeout.as_secs())),
+        },
+    }
+}
+
+/// Attempt a TCP connection and measure connect latency
+pub async fn tcp_connect(host: &str, port: u16, timeout: Duration) -> TcpCheck {
+    let start = Instant::now();
+    let connect = tokio::time::timeout(timeout, TcpStream::connect((host, port))).await;
+    let latency_ms = start.elapsed().as_millis() as u64;
+
+    match connect {
+        Ok(Ok(stream)) => TcpCheck {
+            reachable: true,
+            address: stream.peer_addr().ok().map(|a| a.to_string()),
+            latency_ms,
+            error: None,
+        },
+        Ok(Err(e)) => TcpCheck {
+            reachable: false,
+            address: None,
+            latency_ms,
+            error: Some(format!("Connection failed: {}", e)),
+        },
+        Err(_) => TcpCheck {
+            reachable: false,
+            address: None,
+            latency_ms,
+            error: Some(format!("Connection timed out after {}s", timeout.as_secs())),
+        },
+    }
+}
+
+// ============================================================================
+// TLS certificate helpers
+// ============================================================================
+
+/// Parsed details of an X.509 certificate
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    /// Subject alternative names (DNS names, IPs, emails, URIs)
+    pub sans: Vec<String>,
+    pub not_before: String,
+    pub not_after: String,
+    /// Expiry as a unix timestamp (seconds)
+    pub not_after_unix: i64,
+}
+
+impl CertificateInfo {
+    /// Whole days remaining until expiry relative to `now` (negative once expired)
+    pub fn days_until_expiry(&self, now: SystemTime) -> i64 {
+        let now_unix = match now.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(d) => d.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64),
+        };
+        (self.not_after_unix - now_unix).div_euclid(86_400)
+    }
+}
+
+/// Parse a DER-encoded certificate
+pub fn parse_certificate(der: &[u8]) -> Result<CertificateInfo, String> {
+    let (_, cert) = x509_parser::parse_x509_certificate(der)
+        .map_err(|e| format!("Failed to parse certificate: {}", e))?;
+    let validity = cert.validity();
+    let sans = match cert.subject_alternative_name() {
+        Ok(Some(ext)) => ext
+            .value
+            .general_names
+            .iter()
+            .filter_map(format_general_name)
+            .collect(),
+        _ => vec![],
+    };
+
+    Ok(CertificateInfo {
+        subject: cert.subject().to_string(),
+        issuer: cert.issuer().to_string(),
+        sans,
+        not_before: format_timestamp(validity.not_before.timestamp()),
+        not_after: format_timestamp(validity.not_after.timestamp()),
+        not_after_unix: validity.not_after.timestamp(),
+    })
+}
+
+fn format_general_name(name: &x509_parser::extensions::GeneralName) -> Option<String> {
+    use x509_parser::extensions::GeneralName;
+    match name {
+        GeneralName::DNSName(s) | GeneralName::RFC822Name(s) | GeneralName::URI(s) => {
+            Some(s.to_string())
+        }
+        GeneralName::IPAddress(bytes) => match bytes.len() {
+            4 => <[u8; 4]>::try_from(*bytes)
+                .ok()
+                .map(|o| std::net::Ipv4Addr::from(o).to_string()),
+            16 => <[u8; 16]>::try_from(*bytes)
+                .ok()
+                .map(|o| std::net::Ipv6Addr::from(o).to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn format_timestamp(ts: i64) -> String {
+    use chrono::TimeZone;
+    chrono::Utc
+        .timestamp_opt(ts, 0)
+        .single()
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_else(|| ts.to_string())
+}
+
+/// Complete a TLS handshake with `host:port` and return the DER-encoded leaf certificate.
+///
+/// Chain validation is intentionally skipped so expired or self-signed
+/// certificates can still be inspected.
+pub async fn fetch_peer_certificate(
+    host: &str,
+    port: u16,
+    timeout: Duration,
+) -> Result<Vec<u8>, String> {
+    let config = rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_custom_certificate_verifier(Arc::new(InspectOnlyVerifier))
+        .with_no_client_auth();
+    let connector = tokio_rustls::TlsConnector::from(Arc::new(config));
+
+    let server_name = rustls::ServerName::try_from(host)
+        .map_err(|e| format!("Invalid TLS server name '{}': {}", host, e))?;
+
+    let handshake = async {
+        let tcp = TcpStream::connect((host, port))
+            .await
+            .map_err(|e| format!("Connection failed: {}", e))?;
+        connector
+            .connect(server_name, tcp)
+            .await
+            .map_err(|e| format!("TLS handshake failed: {}", e))
+    };
+
+    let stream = tokio::time::timeout(timeout, handshake)
+        .await
+        .map_err(|_| format!("TLS handshake timed out after {}s", timeout.as_secs()))??;
+
+    let (_, session) = stream.get_ref();
+    session
+        .peer_certificates()
+        .and_then(|certs| certs.first())
+        .map(|cert| cert.0.clone())
+        .ok_or_else(|| "Server did not present a certificate".to_string())
+}
+
+/// Certificate verifier that accepts any certificate; used only for inspection
+struct InspectOnlyVerifier;
+
+impl rustls::client::ServerCertVerifier for InspectOnlyVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &rustls::Certificate,
+        _intermediates: &[rustls::Certificate],
+        _server_name: &rustls::ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<rustls::client::ServerCertVerified, rustls::Error> {
+        Ok(rustls::client::ServerCertVerified::assertion())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_diag_config() {
+        let tool = NetDiagTool::new();
+        let config = tool.config();
+
+        assert_eq!(config.name, "net_diag");
+        assert!(config.description.contains("DNS"));
+        assert_eq!(config.timeout_secs, 60);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_localhost() {
+        let dns = resolve_host("localhost", Duration::from_secs(5)).await;
+
+        assert!(dns.resolved);
+        assert!(dns
+            .addresses
+            .iter()
+            .any(|a| a == "127.0.0.1" || a == "::1"));
+    }
+
+    #[tokio::test]
+    async fn test_tcp_connect_local_listener() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let tcp = tcp_connect("127.0.0.1", port, Duration::from_secs(5)).await;
+        assert!(tcp.reachable);
+        assert_eq!(tcp.address.as_deref(), Some(format!("127.0.0.1:{}", port).as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_tcp_connect_refused() {
+        // Bind then drop a listener so the port is known to be closed
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let tcp = tcp_connect("127.0.0.1", port, Duration::from_secs(5)).await;
+        assert!(!tcp.reachable);
+        assert!(tcp.error.unwrap().contains("Connection failed"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_reports_unhealthy_on_refused() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let tool = NetDiagTool::new();
+        let input = ToolInput::new(serde_json::json!({
+            "host": "127.0.0.1",
+            "port": port
+        }));
+
+        let result = tool.execute(input).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.data["dns"]["resolved"], true);
+        assert_eq!(result.data["tcp"]["reachable"], false);
+        assert_eq!(result.data["healthy"], false);
+    }
+
+    #[tokio::test]
+    async fn test_tls_check_requires_port() {
+        let tool = NetDiagTool::new();
+        let input = ToolInput::new(serde_json::json!({
+            "host": "localhost",
+            "checks": ["tls"]
+        }));
+
+        let result = tool.execute(input).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("requires a port"));
+    }
+}
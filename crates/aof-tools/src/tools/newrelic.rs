@@ -34,6 +34,7 @@ use serde_json::json;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit;
 
 /// Collection of all New Relic tools
 pub struct NewRelicTools;
@@ -98,10 +99,7 @@ async fn execute_graphql(
         "variables": variables
     });
 
-    let response = client
-        .post(endpoint)
-        .json(&body)
-        .send()
+    let (response, quota) = rate_limit::send(client.post(endpoint).json(&body))
         .await
         .map_err(|e| aof_core::AofError::tool(format!("GraphQL request failed: {}", e)))?;
 
@@ -128,12 +126,12 @@ async fn execute_graphql(
     }
 
     if status >= 400 {
-        return Ok(ToolResult::error(format!("HTTP {}: {:?}", status, body)));
+        return Ok(quota.annotate(ToolResult::error(format!("HTTP {}: {:?}", status, body))));
     }
 
-    Ok(ToolResult::success(
+    Ok(quota.annotate(ToolResult::success(
         body.get("data").cloned().unwrap_or(body),
-    ))
+    )))
 }
 
 // ============================================================================
@@ -24,6 +24,7 @@ use std::collections::HashMap;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all observability tools
 pub struct ObservabilityTools;
@@ -145,7 +146,7 @@ impl Tool for PrometheusQueryTool {
             (url, params)
         };
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Prometheus query failed: {}", e)));
@@ -273,7 +274,7 @@ impl Tool for LokiQueryTool {
             params.push(("end", e));
         }
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Loki query failed: {}", e)));
@@ -442,7 +443,7 @@ impl Tool for ElasticsearchQueryTool {
             }
         }
 
-        let response = match request.send().await {
+        let response = match request.send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Elasticsearch query failed: {}", e)));
@@ -580,7 +581,7 @@ impl Tool for VictoriaMetricsQueryTool {
             (url, params)
         };
 
-        let response = match client.get(&url).query(&params).send().await {
+        let response = match client.get(&url).query(&params).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("VictoriaMetrics query failed: {}", e)));
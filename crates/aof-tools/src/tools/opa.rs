@@ -28,6 +28,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all OPA tools
 pub struct OpaTools;
@@ -166,7 +167,7 @@ impl Tool for OpaEvalTool {
             "input": input_data
         });
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -278,7 +279,7 @@ impl Tool for OpaQueryTool {
             payload["input"] = data;
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("OPA request failed: {}", e)));
@@ -368,7 +369,7 @@ impl Tool for OpaDataGetTool {
             path.trim_start_matches('/')
         );
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("OPA request failed: {}", e)));
@@ -463,7 +464,7 @@ impl Tool for OpaDataPutTool {
             path.trim_start_matches('/')
         );
 
-        let response = match client.put(&url).json(&data).send().await {
+        let response = match client.put(&url).json(&data).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("OPA request failed: {}", e)));
@@ -538,7 +539,7 @@ impl Tool for OpaPolicyListTool {
 
         let url = format!("{}/v1/policies", endpoint.trim_end_matches('/'));
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("OPA request failed: {}", e)));
@@ -649,7 +650,7 @@ impl Tool for OpaPolicyPutTool {
             .put(&url)
             .header("Content-Type", "text/plain")
             .body(policy)
-            .send()
+            .send_tracked()
             .await
         {
             Ok(r) => r,
@@ -748,7 +749,7 @@ impl Tool for OpaHealthTool {
             url = format!("{}?{}", url, query_params.join("&"));
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("OPA request failed: {}", e)));
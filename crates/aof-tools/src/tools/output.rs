@@ -0,0 +1,190 @@
+//! Output Format Negotiation
+//!
+//! Tools whose results are rows (log lines, changed files, ...) accept an
+//! optional `format` argument so each consumer gets the shape it reads best:
+//!
+//! - `json` - the structured result, which models handle best
+//! - `text` - one plain line per row
+//! - `table` - aligned columns rendered with the `aof-viz` table renderer,
+//!   with every row and full cell text
+//!
+//! Without `format` a tool returns its natural format. Text and table results
+//! are returned as a single string.
+
+use aof_core::ToolInput;
+use aof_viz::{RenderConfig, ToolRenderer};
+
+/// Shape of a tool's result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Text,
+    Table,
+}
+
+impl OutputFormat {
+    /// Parse a format name
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "text" => Ok(Self::Text),
+            "table" => Ok(Self::Table),
+            other => Err(format!(
+                "Invalid format '{}': use json, text, or table",
+                other
+            )),
+        }
+    }
+
+    /// Format requested by the `format` argument, or `natural` when absent
+    pub fn from_input(input: &ToolInput, natural: Self) -> Result<Self, String> {
+        match input.arguments.get("format").and_then(|f| f.as_str()) {
+            Some(name) => Self::parse(name),
+            None => Ok(natural),
+        }
+    }
+
+    /// Schema of the `format` parameter, for a tool's parameter list
+    pub fn schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "string",
+            "enum": ["json", "text", "table"],
+            "description": "Result format: json (structured), text (one line per row), or table (aligned columns for humans)"
+        })
+    }
+}
+
+/// Which rows and columns of a result make up its text and table forms
+#[derive(Debug, Clone, Copy)]
+pub struct RowView<'a> {
+    /// Field of the result holding an array of row objects
+    pub rows: &'a str,
+    /// Row fields to show, in order
+    pub columns: &'a [&'a str],
+}
+
+/// Shape a JSON result into the requested format
+///
+/// Results without the view's rows fall back to one line (or table row) per
+/// top-level scalar field.
+pub fn shape(data: serde_json::Value, format: OutputFormat, view: &RowView) -> serde_json::Value {
+    if format == OutputFormat::Json {
+        return data;
+    }
+
+    let (headers, rows) = match data.get(view.rows).and_then(|r| r.as_array()) {
+        Some(rows) => (
+            view.columns.iter().map(|c| c.to_uppercase()).collect::<Vec<_>>(),
+            rows.iter()
+                .map(|row| view.columns.iter().map(|c| cell(&row[*c])).collect())
+                .collect::<Vec<Vec<String>>>(),
+        ),
+        None => (
+            vec!["FIELD".to_string(), "VALUE".to_string()],
+            data.as_object()
+                .map(|fields| {
+                    fields
+                        .iter()
+                        .filter(|(_, v)| !v.is_array() && !v.is_object())
+                        .map(|(k, v)| vec![k.clone(), cell(v)])
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ),
+    };
+
+    let rendered = match format {
+        OutputFormat::Table => {
+            let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+            let rows: Vec<Vec<&str>> = rows
+                .iter()
+                .map(|row| row.iter().map(String::as_str).collect())
+                .collect();
+            ToolRenderer::new(table_config()).render_full_table(&headers, &rows)
+        }
+        _ => rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter(|c| !c.is_empty())
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join("  ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+
+    serde_json::Value::String(rendered)
+}
+
+// Helper: Render settings for tables read in a terminal or chat
+fn table_config() -> RenderConfig {
+    RenderConfig {
+        use_colors: false,
+        ..RenderConfig::terminal()
+    }
+}
+
+// Helper: A JSON value as table cell text
+fn cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEW: RowView = RowView {
+        rows: "files",
+        columns: &["path", "insertions"],
+    };
+
+    fn result() -> serde_json::Value {
+        serde_json::json!({
+            "files_changed": 2,
+            "files": [
+                { "path": "src/lib.rs", "insertions": 12 },
+                { "path": "README.md", "insertions": 3 }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(OutputFormat::parse("TABLE").unwrap(), OutputFormat::Table);
+        assert!(OutputFormat::parse("xml").is_err());
+
+        let input = ToolInput::new(serde_json::json!({}));
+        assert_eq!(OutputFormat::from_input(&input, OutputFormat::Text).unwrap(), OutputFormat::Text);
+    }
+
+    #[test]
+    fn test_text_and_fallback() {
+        let text = shape(result(), OutputFormat::Text, &VIEW);
+        assert_eq!(text, "src/lib.rs  12\nREADME.md  3");
+
+        let fallback = shape(serde_json::json!({ "in_sync": true }), OutputFormat::Table, &VIEW);
+        let fallback = fallback.as_str().unwrap();
+        assert!(fallback.starts_with("FIELD"));
+        assert!(fallback.contains("in_sync"));
+    }
+
+    #[test]
+    fn test_table_is_not_truncated() {
+        let message = "a commit message that runs well past twenty characters";
+        let files: Vec<_> = (0..20)
+            .map(|i| serde_json::json!({ "path": format!("src/file_{}.rs", i), "insertions": message }))
+            .collect();
+        let table = shape(serde_json::json!({ "files": files }), OutputFormat::Table, &VIEW);
+        let table = table.as_str().unwrap();
+
+        assert!((0..20).all(|i| table.contains(&format!("src/file_{}.rs", i))));
+        assert!(table.contains(message));
+        assert!(!table.contains("more rows"));
+    }
+}
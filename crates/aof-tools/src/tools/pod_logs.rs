@@ -0,0 +1,452 @@
+//! Pod Logs Tool
+//!
+//! Tool for fetching pod logs as structured lines, including multi-container pods.
+//!
+//! ## Features
+//!
+//! - `container`, `since`, `tail` and `previous` (crashed container) options
+//! - Fetch every container of a pod at once, each line labelled with its container
+//! - Lines split into timestamp and message, merged in time order
+//! - Per-container errors reported without failing the whole fetch
+//! - `format: text|table` for reading the lines directly instead of as JSON
+//!
+//! ## Prerequisites
+//!
+//! - Requires `kubectl` feature flag
+//! - kubectl must be installed and in PATH
+//! - Valid kubeconfig with cluster access
+
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::debug;
+
+use super::common::{
+    create_schema, tool_config_with_timeout, CommandOutput, CommandRunner, ProcessRunner,
+};
+use super::output::{self, OutputFormat, RowView};
+
+/// Text and table form of the result: one row per log line
+const LINES_VIEW: RowView = RowView {
+    rows: "lines",
+    columns: &["timestamp", "container", "message"],
+};
+
+/// A single log line
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogLine {
+    /// Container that emitted the line
+    pub container: String,
+    /// RFC 3339 timestamp added by the kubelet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    /// Log message
+    pub message: String,
+}
+
+/// Pod logs tool
+pub struct PodLogsTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl PodLogsTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "pod": {
+                    "type": "string",
+                    "description": "Pod name"
+                },
+                "namespace": {
+                    "type": "string",
+                    "description": "Kubernetes namespace (default: current context namespace)"
+                },
+                "container": {
+                    "type": "string",
+                    "description": "Container to read. Omit to read every container in the pod"
+                },
+                "since": {
+                    "type": "string",
+                    "description": "Only return logs newer than a duration (e.g., '5m', '1h')"
+                },
+                "tail": {
+                    "type": "integer",
+                    "description": "Number of most recent lines per container",
+                    "default": 100
+                },
+                "previous": {
+                    "type": "boolean",
+                    "description": "Read the previous (crashed) instance of the container",
+                    "default": false
+                },
+                "include_init_containers": {
+                    "type": "boolean",
+                    "description": "Also read init containers when no container is given",
+                    "default": false
+                },
+                "format": OutputFormat::schema()
+            }),
+            vec!["pod"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "pod_logs",
+                "Fetch logs from a Kubernetes pod as structured lines. Reads one container or all containers at once (each line labelled with its container), with since/tail limits and previous-instance logs for crashed containers.",
+                parameters,
+                60,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    async fn kubectl(&self, args: Vec<String>) -> Result<CommandOutput, String> {
+        self.runner
+            .run("kubectl", &args, None, self.config.timeout_secs)
+            .await
+    }
+
+    /// Container names of a pod, from its spec
+    async fn container_names(
+        &self,
+        pod: &str,
+        namespace: Option<&str>,
+        include_init: bool,
+    ) -> Result<Vec<String>, String> {
+        let mut args = vec!["get".to_string(), "pod".to_string(), pod.to_string()];
+        if let Some(ns) = namespace {
+            args.push(format!("-n={}", ns));
+        }
+        args.push("-o=json".to_string());
+
+        let output = self.kubectl(args).await?;
+        if !output.success {
+            return Err(output.stderr.trim().to_string());
+        }
+
+        let spec: serde_json::Value = serde_json::from_str(&output.stdout)
+            .map_err(|e| format!("Failed to parse pod spec: {}", e))?;
+        let names = |field: &str| -> Vec<String> {
+            spec["spec"][field]
+                .as_array()
+                .map(|list| {
+                    list.iter()
+                        .filter_map(|c| c["name"].as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let mut containers = Vec::new();
+        if include_init {
+            containers.extend(names("initContainers"));
+        }
+        containers.extend(names("containers"));
+        Ok(containers)
+    }
+}
+
+impl Default for PodLogsTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for PodLogsTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let pod: String = input.get_arg("pod")?;
+        let namespace: Option<String> = input.get_arg("namespace").ok();
+        let container: Option<String> = input.get_arg("container").ok();
+        let since: Option<String> = input.get_arg("since").ok();
+        let tail: i64 = input.get_arg("tail").unwrap_or(100);
+        let previous: bool = input.get_arg("previous").unwrap_or(false);
+        let include_init: bool = input.get_arg("include_init_containers").unwrap_or(false);
+        let format = match OutputFormat::from_input(&input, OutputFormat::Json) {
+            Ok(format) => format,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        let containers = match container {
+            Some(c) => vec![c],
+            None => match self
+                .container_names(&pod, namespace.as_deref(), include_init)
+                .await
+            {
+                Ok(names) if !names.is_empty() => names,
+                Ok(_) => return Ok(ToolResult::error(format!("Pod '{}' has no containers", pod))),
+                Err(e) => return Ok(ToolResult::error(format!("Failed to get pod '{}': {}", pod, e))),
+            },
+        };
+
+        debug!(pod = %pod, containers = ?containers, previous, "Fetching pod logs");
+
+        let mut lines = Vec::new();
+        let mut errors = serde_json::Map::new();
+
+        for name in &containers {
+            let mut args = vec!["logs".to_string(), pod.clone(), format!("-c={}", name)];
+            if let Some(ref ns) = namespace {
+                args.push(format!("-n={}", ns));
+            }
+            args.push("--timestamps".to_string());
+            args.push(format!("--tail={}", tail));
+            if let Some(ref s) = since {
+                args.push(format!("--since={}", s));
+            }
+            if previous {
+                args.push("--previous".to_string());
+            }
+
+            match self.kubectl(args).await {
+                Ok(output) if output.success => {
+                    lines.extend(
+                        output
+                            .stdout
+                            .lines()
+                            .filter(|l| !l.is_empty())
+                            .map(|l| parse_line(name, l)),
+                    );
+                }
+                Ok(output) => {
+                    errors.insert(name.clone(), serde_json::json!(output.stderr.trim()));
+                }
+                Err(e) => {
+                    errors.insert(name.clone(), serde_json::json!(e));
+                }
+            }
+        }
+
+        if errors.len() == containers.len() {
+            let details: Vec<String> = errors
+                .iter()
+                .map(|(c, e)| format!("{}: {}", c, e.as_str().unwrap_or_default()))
+                .collect();
+            return Ok(ToolResult::error(format!(
+                "Failed to fetch logs for pod '{}': {}",
+                pod,
+                details.join("; ")
+            )));
+        }
+
+        // Interleave containers in time order (stable, so each container keeps its own order)
+        if containers.len() > 1 {
+            lines.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+        }
+
+        let mut result = serde_json::json!({
+            "pod": pod,
+            "namespace": namespace,
+            "containers": containers,
+            "previous": previous,
+            "line_count": lines.len(),
+            "lines": lines,
+        });
+        if !errors.is_empty() {
+            result["errors"] = serde_json::Value::Object(errors);
+        }
+
+        Ok(ToolResult::success(output::shape(result, format, &LINES_VIEW)))
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Split a `kubectl logs --timestamps` line into timestamp and message
+fn parse_line(container: &str, line: &str) -> LogLine {
+    let (timestamp, message) = match line.split_once(' ') {
+        Some((ts, rest)) if looks_like_timestamp(ts) => (Some(ts.to_string()), rest),
+        _ => (None, line),
+    };
+
+    LogLine {
+        container: container.to_string(),
+        timestamp,
+        message: message.to_string(),
+    }
+}
+
+fn looks_like_timestamp(s: &str) -> bool {
+    s.len() >= 20 && s.as_bytes()[0].is_ascii_digit() && s.contains('T') && s.ends_with('Z')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::FakeRunner;
+
+    const POD_SPEC: &str = r#"{
+        "spec": {
+            "initContainers": [{"name": "migrate"}],
+            "containers": [{"name": "app"}, {"name": "proxy"}]
+        }
+    }"#;
+
+    fn container_arg(args: &[String]) -> Option<&str> {
+        args.iter().find_map(|a| a.strip_prefix("-c="))
+    }
+
+    fn fake_cluster() -> Arc<FakeRunner> {
+        Arc::new(FakeRunner::new(|_, args| match args[0].as_str() {
+            "get" => CommandOutput::ok(POD_SPEC),
+            "logs" => match container_arg(args) {
+                Some("app") => CommandOutput::ok(
+                    "2024-05-01T10:00:00.000000001Z starting server\n2024-05-01T10:00:02.000000000Z listening on :8080\n",
+                ),
+                Some("proxy") => CommandOutput::ok("2024-05-01T10:00:01.000000000Z proxy ready\n"),
+                _ => CommandOutput::failed(1, "container not found"),
+            },
+            _ => CommandOutput::failed(1, "unexpected command"),
+        }))
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = parse_line("app", "2024-05-01T10:00:00.123Z GET /healthz 200");
+        assert_eq!(line.timestamp.as_deref(), Some("2024-05-01T10:00:00.123Z"));
+        assert_eq!(line.message, "GET /healthz 200");
+
+        let line = parse_line("app", "panic: runtime error");
+        assert!(line.timestamp.is_none());
+        assert_eq!(line.message, "panic: runtime error");
+    }
+
+    #[tokio::test]
+    async fn test_all_containers_labelled_and_merged() {
+        let runner = fake_cluster();
+        let tool = PodLogsTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "pod": "web-0",
+                "namespace": "prod",
+                "tail": 50
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["containers"], serde_json::json!(["app", "proxy"]));
+        assert_eq!(result.data["line_count"], 3);
+
+        let lines = result.data["lines"].as_array().unwrap();
+        let labelled: Vec<(&str, &str)> = lines
+            .iter()
+            .map(|l| (l["container"].as_str().unwrap(), l["message"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            labelled,
+            vec![
+                ("app", "starting server"),
+                ("proxy", "proxy ready"),
+                ("app", "listening on :8080"),
+            ]
+        );
+
+        let calls = runner.calls();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0][..4], ["kubectl", "get", "pod", "web-0"]);
+        assert!(calls[1].contains(&"--tail=50".to_string()));
+        assert!(calls[1].contains(&"-n=prod".to_string()));
+        assert!(!calls[1].contains(&"--previous".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_json_and_table_formats() {
+        let tool = PodLogsTool::new().with_runner(fake_cluster());
+
+        let json = tool
+            .execute(ToolInput::new(serde_json::json!({"pod": "web-0", "format": "json"})))
+            .await
+            .unwrap();
+        assert!(json.success, "{:?}", json.error);
+        assert_eq!(json.data["lines"][0]["message"], "starting server");
+
+        let table = tool
+            .execute(ToolInput::new(serde_json::json!({"pod": "web-0", "format": "table"})))
+            .await
+            .unwrap();
+        assert!(table.success, "{:?}", table.error);
+        let table = table.data.as_str().unwrap();
+        assert!(table.contains("CONTAINER"));
+        assert!(table.contains("MESSAGE"));
+        assert!(table.contains("proxy ready"));
+
+        let invalid = tool
+            .execute(ToolInput::new(serde_json::json!({"pod": "web-0", "format": "xml"})))
+            .await
+            .unwrap();
+        assert!(!invalid.success);
+    }
+
+    #[tokio::test]
+    async fn test_previous_flag_for_single_container() {
+        let runner = fake_cluster();
+        let tool = PodLogsTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "pod": "web-0",
+                "container": "app",
+                "previous": true,
+                "since": "10m"
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["previous"], true);
+        assert_eq!(result.data["containers"], serde_json::json!(["app"]));
+
+        // A named container skips the pod spec lookup
+        let calls = runner.calls();
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].contains(&"--previous".to_string()));
+        assert!(calls[0].contains(&"--since=10m".to_string()));
+        assert!(calls[0].contains(&"-c=app".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_partial_container_failure_is_reported() {
+        let tool = PodLogsTool::new().with_runner(fake_cluster());
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({
+                "pod": "web-0",
+                "include_init_containers": true
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.data["containers"], serde_json::json!(["migrate", "app", "proxy"]));
+        assert_eq!(result.data["errors"]["migrate"], "container not found");
+        assert_eq!(result.data["line_count"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_all_containers_failing_is_an_error() {
+        let tool = PodLogsTool::new().with_runner(Arc::new(FakeRunner::new(|_, _| {
+            CommandOutput::failed(1, "Error from server (NotFound): pods \"web-9\" not found")
+        })));
+
+        let result = tool
+            .execute(ToolInput::new(serde_json::json!({"pod": "web-9", "container": "app"})))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not found"));
+    }
+}
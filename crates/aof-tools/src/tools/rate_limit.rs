@@ -0,0 +1,461 @@
+//! Rate-Limit Aware HTTP Requests
+//!
+//! Vendor APIs (Grafana, Datadog, New Relic, ...) report their quota in
+//! response headers. Requests sent through [`send`] read those headers so a
+//! tool can:
+//!
+//! - report the remaining quota in its result metadata (`rate_limit`)
+//! - back off proactively when the quota for a host is nearly used up,
+//!   instead of running into 429s
+//! - retry once after `Retry-After` when it does get a 429
+//!
+//! Recognized headers: `X-RateLimit-Limit`, `X-RateLimit-Remaining`,
+//! `X-RateLimit-Reset` (seconds until reset, or a Unix timestamp), their
+//! unprefixed `RateLimit-*` forms, and `Retry-After` (seconds).
+//!
+//! Quota is tracked per host and shared by every tool in the process.
+//!
+//! Tools that send a single request can take the [`QuotaReport`] from [`send`]
+//! and annotate their result. Tools with many requests call
+//! [`TrackedSend::send_tracked`] instead of `RequestBuilder::send`; the
+//! registry runs every tool call in [`track_call`], which annotates the result
+//! with the quota of the last request the call sent.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aof_core::{AofResult, ToolResult};
+use reqwest::header::HeaderMap;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use tracing::{debug, warn};
+
+/// Values above this in a reset header are Unix timestamps, not a delay
+const EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Quota reported by one response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests allowed per window
+    pub limit: Option<u64>,
+    /// Requests left in the current window
+    pub remaining: Option<u64>,
+    /// Time until the window resets
+    pub reset_after: Option<Duration>,
+    /// Time the server asked us to wait (`Retry-After`)
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimit {
+    /// Read rate-limit headers; None if the response has none
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| headers.get(*name))
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        let reset_after = number(&["x-ratelimit-reset", "ratelimit-reset"]).map(|reset| {
+            if reset > EPOCH_THRESHOLD {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                Duration::from_secs(reset.saturating_sub(now))
+            } else {
+                Duration::from_secs(reset)
+            }
+        });
+
+        let rate_limit = Self {
+            limit: number(&["x-ratelimit-limit", "ratelimit-limit"]),
+            remaining: number(&["x-ratelimit-remaining", "ratelimit-remaining"]),
+            reset_after,
+            retry_after: number(&["retry-after"]).map(Duration::from_secs),
+        };
+
+        (rate_limit != Self::default()).then_some(rate_limit)
+    }
+
+    /// Whether the remaining quota is at or below `low_watermark` of the limit
+    pub fn is_low(&self, low_watermark: f64) -> bool {
+        match (self.remaining, self.limit) {
+            (Some(0), _) => true,
+            (Some(remaining), Some(limit)) if limit > 0 => {
+                (remaining as f64) <= (limit as f64) * low_watermark
+            }
+            _ => false,
+        }
+    }
+
+    /// JSON form for tool result metadata
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "limit": self.limit,
+            "remaining": self.remaining,
+            "reset_secs": self.reset_after.map(|d| d.as_secs()),
+            "retry_after_secs": self.retry_after.map(|d| d.as_secs()),
+        })
+    }
+}
+
+/// When to slow down
+#[derive(Debug, Clone)]
+pub struct BackoffPolicy {
+    /// Fraction of the limit below which requests are spaced out
+    pub low_watermark: f64,
+    /// Longest a request waits, proactively or after a 429
+    pub max_wait: Duration,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            low_watermark: 0.1,
+            max_wait: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Observation {
+    rate_limit: RateLimit,
+    observed_at: Instant,
+}
+
+/// Last known quota per host
+#[derive(Debug, Default)]
+pub struct QuotaTracker {
+    hosts: Mutex<HashMap<String, Observation>>,
+}
+
+impl QuotaTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracker shared by every tool in the process
+    pub fn global() -> Arc<QuotaTracker> {
+        static GLOBAL: OnceLock<Arc<QuotaTracker>> = OnceLock::new();
+        GLOBAL.get_or_init(|| Arc::new(QuotaTracker::new())).clone()
+    }
+
+    /// Record the quota a response reported for a host
+    pub fn observe(&self, host: &str, rate_limit: &RateLimit) {
+        self.observe_at(host, rate_limit, Instant::now())
+    }
+
+    /// How long the next request to a host should wait, reserving one request
+    ///
+    /// Exhausted quota waits for the reset (or `Retry-After`); low quota spreads
+    /// the remaining requests over the rest of the window.
+    pub fn delay_for(&self, host: &str, policy: &BackoffPolicy) -> Duration {
+        self.delay_at(host, policy, Instant::now())
+    }
+
+    fn observe_at(&self, host: &str, rate_limit: &RateLimit, now: Instant) {
+        self.hosts.lock().unwrap().insert(
+            host.to_string(),
+            Observation {
+                rate_limit: rate_limit.clone(),
+                observed_at: now,
+            },
+        );
+    }
+
+    fn delay_at(&self, host: &str, policy: &BackoffPolicy, now: Instant) -> Duration {
+        let mut hosts = self.hosts.lock().unwrap();
+        let Some(observation) = hosts.get_mut(host) else {
+            return Duration::ZERO;
+        };
+
+        let elapsed = now.saturating_duration_since(observation.observed_at);
+        let until = |d: Duration| d.saturating_sub(elapsed);
+        let rate_limit = &mut observation.rate_limit;
+
+        let delay = match (rate_limit.remaining, rate_limit.reset_after) {
+            // Window has reset since we last heard
+            (_, Some(reset)) if elapsed >= reset => Duration::ZERO,
+            (Some(0), reset) => rate_limit
+                .retry_after
+                .or(reset)
+                .map(until)
+                .unwrap_or(Duration::ZERO),
+            (Some(remaining), Some(reset)) if rate_limit.is_low(policy.low_watermark) => {
+                until(reset) / (remaining as u32 + 1)
+            }
+            _ => Duration::ZERO,
+        };
+
+        if let Some(remaining) = rate_limit.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+        }
+        delay.min(policy.max_wait)
+    }
+}
+
+/// Quota information gathered while sending a request
+#[derive(Debug, Clone, Default)]
+pub struct QuotaReport {
+    /// Quota reported by the final response
+    pub rate_limit: Option<RateLimit>,
+    /// Time spent waiting before sending (proactive backoff and 429 retry)
+    pub waited: Duration,
+}
+
+impl QuotaReport {
+    /// Fold in a later request's report: its quota wins, waits add up
+    fn merge(&mut self, later: &QuotaReport) {
+        if later.rate_limit.is_some() {
+            self.rate_limit = later.rate_limit.clone();
+        }
+        self.waited += later.waited;
+    }
+
+    /// Add the quota to a tool result's metadata as `rate_limit`
+    pub fn annotate(&self, result: ToolResult) -> ToolResult {
+        let Some(ref rate_limit) = self.rate_limit else {
+            return result;
+        };
+        let mut value = rate_limit.to_json();
+        value["backoff_ms"] = serde_json::json!(self.waited.as_millis() as u64);
+        result.with_metadata("rate_limit", value)
+    }
+}
+
+tokio::task_local! {
+    /// Quota gathered by the requests of the tool call being run
+    static CALL_QUOTA: RefCell<QuotaReport>;
+}
+
+/// Run a tool call and annotate its result with the quota its requests saw
+pub async fn track_call<F>(call: F) -> AofResult<ToolResult>
+where
+    F: Future<Output = AofResult<ToolResult>>,
+{
+    CALL_QUOTA
+        .scope(RefCell::new(QuotaReport::default()), async {
+            let result = call.await;
+            let quota = CALL_QUOTA.with(|quota| quota.take());
+            result.map(|result| quota.annotate(result))
+        })
+        .await
+}
+
+/// Send a request with rate-limit tracking, using the shared tracker
+///
+/// Inside [`track_call`] the report is also added to the call's quota.
+pub async fn send(request: RequestBuilder) -> Result<(Response, QuotaReport), reqwest::Error> {
+    let (response, report) = send_with(&QuotaTracker::global(), &BackoffPolicy::default(), request).await?;
+    let _ = CALL_QUOTA.try_with(|quota| quota.borrow_mut().merge(&report));
+    Ok((response, report))
+}
+
+/// `RequestBuilder::send` with rate-limit tracking
+pub trait TrackedSend {
+    /// Send through [`send`], returning just the response
+    fn send_tracked(self) -> impl Future<Output = Result<Response, reqwest::Error>> + Send;
+}
+
+impl TrackedSend for RequestBuilder {
+    async fn send_tracked(self) -> Result<Response, reqwest::Error> {
+        send(self).await.map(|(response, _)| response)
+    }
+}
+
+/// Send a request with rate-limit tracking
+pub async fn send_with(
+    tracker: &QuotaTracker,
+    policy: &BackoffPolicy,
+    request: RequestBuilder,
+) -> Result<(Response, QuotaReport), reqwest::Error> {
+    let (client, request) = request.build_split();
+    let request = request?;
+    let host = match request.url().port_or_known_default() {
+        Some(port) => format!("{}:{}", request.url().host_str().unwrap_or_default(), port),
+        None => request.url().host_str().unwrap_or_default().to_string(),
+    };
+    let mut report = QuotaReport::default();
+
+    let delay = tracker.delay_for(&host, policy);
+    if !delay.is_zero() {
+        debug!(host = %host, delay_ms = delay.as_millis() as u64, "Rate limit low, backing off");
+        tokio::time::sleep(delay).await;
+        report.waited += delay;
+    }
+
+    let retry = request.try_clone();
+    let mut response = client.execute(request).await?;
+    report.rate_limit = RateLimit::from_headers(response.headers());
+
+    if response.status() == StatusCode::TOO_MANY_REQUESTS {
+        let wait = report.rate_limit.as_ref().and_then(|r| r.retry_after);
+        match (wait, retry) {
+            (Some(wait), Some(retry)) if wait <= policy.max_wait => {
+                warn!(host = %host, wait_secs = wait.as_secs(), "Rate limited, retrying after Retry-After");
+                tokio::time::sleep(wait).await;
+                report.waited += wait;
+                response = client.execute(retry).await?;
+                report.rate_limit = RateLimit::from_headers(response.headers());
+            }
+            _ => warn!(host = %host, "Rate limited"),
+        }
+    }
+
+    if let Some(ref rate_limit) = report.rate_limit {
+        tracker.observe(&host, rate_limit);
+    }
+    Ok((response, report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::mock_http::{MockHttpServer, MockResponse};
+
+    /// Serve one canned response per connection, in order
+    async fn mock_server(replies: Vec<(u16, Vec<(&'static str, String)>)>) -> String {
+        let responses = replies
+            .into_iter()
+            .map(|(status, headers)| {
+                headers.into_iter().fold(
+                    MockResponse::json(serde_json::json!({ "status": "ok" })).with_status(status),
+                    |response, (name, value)| response.with_header(name, value),
+                )
+            })
+            .collect();
+        MockHttpServer::start(responses).await.url().to_string()
+    }
+
+    fn quota(limit: u64, remaining: u64, reset: u64) -> Vec<(&'static str, String)> {
+        vec![
+            ("X-RateLimit-Limit", limit.to_string()),
+            ("X-RateLimit-Remaining", remaining.to_string()),
+            ("X-RateLimit-Reset", reset.to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_parse_headers() {
+        let mut headers = HeaderMap::new();
+        assert!(RateLimit::from_headers(&headers).is_none());
+
+        headers.insert("x-ratelimit-limit", "100".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "5".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "60".parse().unwrap());
+        let rate_limit = RateLimit::from_headers(&headers).unwrap();
+        assert_eq!(rate_limit.remaining, Some(5));
+        assert_eq!(rate_limit.reset_after, Some(Duration::from_secs(60)));
+        assert!(rate_limit.is_low(0.1));
+        assert!(!rate_limit.is_low(0.01));
+
+        // Unix timestamp resets become a delay
+        let soon = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 30;
+        headers.insert("x-ratelimit-reset", soon.to_string().parse().unwrap());
+        let reset = RateLimit::from_headers(&headers).unwrap().reset_after.unwrap();
+        assert!(reset <= Duration::from_secs(30) && reset >= Duration::from_secs(28));
+    }
+
+    fn observed(limit: u64, remaining: u64, reset_secs: u64) -> RateLimit {
+        RateLimit {
+            limit: Some(limit),
+            remaining: Some(remaining),
+            reset_after: Some(Duration::from_secs(reset_secs)),
+            retry_after: None,
+        }
+    }
+
+    #[test]
+    fn test_delay_paces_low_and_waits_out_exhausted_quota() {
+        let tracker = QuotaTracker::new();
+        let policy = BackoffPolicy::default();
+        let now = Instant::now();
+
+        tracker.observe_at("api", &observed(100, 50, 60), now);
+        assert_eq!(tracker.delay_at("api", &policy, now), Duration::ZERO);
+
+        // 3 left of 100 with 20s to go: spread them out
+        tracker.observe_at("api", &observed(100, 3, 20), now);
+        assert_eq!(tracker.delay_at("api", &policy, now), Duration::from_secs(5));
+
+        // Exhausted: wait for the reset, capped by the policy
+        tracker.observe_at("api", &observed(100, 0, 120), now);
+        assert_eq!(tracker.delay_at("api", &policy, now), Duration::from_secs(30));
+
+        // Once the window has passed there is nothing to wait for
+        assert_eq!(
+            tracker.delay_at("api", &policy, now + Duration::from_secs(121)),
+            Duration::ZERO
+        );
+        assert_eq!(tracker.delay_at("unknown", &policy, now), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_send_reports_quota_and_backs_off() {
+        let endpoint = mock_server(vec![(200, quota(100, 0, 1)), (200, quota(100, 99, 60))]).await;
+        let tracker = QuotaTracker::new();
+        let policy = BackoffPolicy {
+            max_wait: Duration::from_millis(50),
+            ..Default::default()
+        };
+        let client = reqwest::Client::new();
+
+        let (response, report) = send_with(&tracker, &policy, client.get(&endpoint)).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(report.waited, Duration::ZERO);
+        let result = report.annotate(ToolResult::success(serde_json::json!({})));
+        assert_eq!(result.metadata["rate_limit"]["remaining"], 0);
+        assert_eq!(result.metadata["rate_limit"]["limit"], 100);
+
+        // Quota exhausted: the next request waits (capped at max_wait)
+        let (_, report) = send_with(&tracker, &policy, client.get(&endpoint)).await.unwrap();
+        assert_eq!(report.waited, Duration::from_millis(50));
+        assert_eq!(report.rate_limit.unwrap().remaining, Some(99));
+    }
+
+    #[tokio::test]
+    async fn test_tracked_call_reports_last_quota() {
+        let endpoint = mock_server(vec![(200, quota(50, 40, 60)), (200, quota(50, 39, 60))]).await;
+        let client = reqwest::Client::new();
+
+        let result = track_call(async {
+            client.get(&endpoint).send_tracked().await.unwrap();
+            client.get(&endpoint).send_tracked().await.unwrap();
+            Ok(ToolResult::success(serde_json::json!({})))
+        })
+        .await
+        .unwrap();
+        assert_eq!(result.metadata["rate_limit"]["remaining"], 39);
+        assert_eq!(result.metadata["rate_limit"]["limit"], 50);
+
+        // Calls that send nothing are left alone
+        let result = track_call(async { Ok(ToolResult::success(serde_json::json!({}))) })
+            .await
+            .unwrap();
+        assert!(!result.metadata.contains_key("rate_limit"));
+    }
+
+    #[tokio::test]
+    async fn test_send_retries_after_429() {
+        let endpoint = mock_server(vec![
+            (429, vec![("Retry-After", "0".to_string())]),
+            (200, quota(10, 9, 60)),
+        ])
+        .await;
+
+        let (response, report) = send_with(
+            &QuotaTracker::new(),
+            &BackoffPolicy::default(),
+            reqwest::Client::new().get(&endpoint),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(report.rate_limit.unwrap().remaining, Some(9));
+    }
+}
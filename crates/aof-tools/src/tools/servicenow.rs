@@ -37,6 +37,7 @@ use serde_json::json;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all ServiceNow tools
 pub struct ServiceNowTools;
@@ -272,7 +273,7 @@ impl Tool for ServiceNowIncidentCreateTool {
         let response = client
             .post(&url)
             .json(&body)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Request failed: {}", e)))?;
 
@@ -382,7 +383,7 @@ impl Tool for ServiceNowIncidentQueryTool {
         let response = client
             .get(&url)
             .query(&params)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Query failed: {}", e)))?;
 
@@ -469,7 +470,7 @@ impl Tool for ServiceNowIncidentUpdateTool {
         let response = client
             .patch(&url)
             .json(&fields)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Update failed: {}", e)))?;
 
@@ -562,7 +563,7 @@ impl Tool for ServiceNowIncidentGetTool {
 
         let response = client
             .get(&url)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Get failed: {}", e)))?;
 
@@ -669,7 +670,7 @@ impl Tool for ServiceNowCmdbQueryTool {
         let response = client
             .get(&url)
             .query(&params)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("CMDB query failed: {}", e)))?;
 
@@ -823,7 +824,7 @@ impl Tool for ServiceNowChangeCreateTool {
         let response = client
             .post(&url)
             .json(&body)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Request failed: {}", e)))?;
 
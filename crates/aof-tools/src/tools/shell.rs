@@ -247,6 +247,21 @@ mod tests {
         assert!(!result.data["success"].as_bool().unwrap());
     }
 
+    #[tokio::test]
+    async fn test_shell_allows_metacharacters() {
+        // The shell tool is explicitly a shell: chaining and pipes are its job
+        let tool = ShellTool::new();
+        let input = ToolInput::new(serde_json::json!({
+            "command": "echo one; echo two | tr a-z A-Z"
+        }));
+
+        let result = tool.execute(input).await.unwrap();
+        assert!(result.success);
+        let stdout = result.data["stdout"].as_str().unwrap();
+        assert!(stdout.contains("one"));
+        assert!(stdout.contains("TWO"));
+    }
+
     #[tokio::test]
     async fn test_shell_blocked_command() {
         let tool = ShellTool::new();
@@ -26,6 +26,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, execute_command, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 const SNYK_API_BASE: &str = "https://api.snyk.io/v1";
 
@@ -625,7 +626,7 @@ impl Tool for SnykIssuesListTool {
             url.push_str(&query_params.join("&"));
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -762,7 +763,7 @@ impl Tool for SnykIssueIgnoreTool {
             payload["expires"] = serde_json::json!(exp);
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Snyk API request failed: {}", e)));
@@ -868,7 +869,7 @@ impl Tool for SnykFixPrTool {
             payload["issueId"] = serde_json::json!(iid);
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Snyk API request failed: {}", e)));
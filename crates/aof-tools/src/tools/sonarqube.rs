@@ -26,6 +26,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all SonarQube tools
 pub struct SonarQubeTools;
@@ -163,7 +164,7 @@ impl Tool for SonarProjectStatusTool {
             url.push_str(&format!("&branch={}", urlencoding::encode(&b)));
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -340,7 +341,7 @@ impl Tool for SonarIssuesSearchTool {
             url.push_str(&format!("&ps={}", ps));
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("SonarQube request failed: {}", e)));
@@ -484,7 +485,7 @@ impl Tool for SonarHotspotsSearchTool {
             url.push_str(&format!("&branch={}", urlencoding::encode(&b)));
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("SonarQube request failed: {}", e)));
@@ -612,7 +613,7 @@ impl Tool for SonarMeasuresComponentTool {
             url.push_str(&format!("&branch={}", urlencoding::encode(&b)));
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("SonarQube request failed: {}", e)));
@@ -739,7 +740,7 @@ impl Tool for SonarIssueTransitionTool {
             url.push_str(&format!("&comment={}", urlencoding::encode(&c)));
         }
 
-        let response = match client.post(&url).send().await {
+        let response = match client.post(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("SonarQube request failed: {}", e)));
@@ -871,7 +872,7 @@ impl Tool for SonarProjectAnalysesTool {
             url.push_str(&format!("&to={}", urlencoding::encode(&t)));
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("SonarQube request failed: {}", e)));
@@ -35,6 +35,7 @@ use serde_json::json;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all Splunk tools
 pub struct SplunkTools;
@@ -212,7 +213,7 @@ impl Tool for SplunkSearchTool {
         let create_response = client
             .post(&create_url)
             .form(&form_data)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Failed to create search job: {}", e)))?;
 
@@ -245,7 +246,7 @@ impl Tool for SplunkSearchTool {
             let status_response = client
                 .get(&status_url)
                 .query(&[("output_mode", "json")])
-                .send()
+                .send_tracked()
                 .await
                 .map_err(|e| aof_core::AofError::tool(format!("Failed to check job status: {}", e)))?;
 
@@ -274,7 +275,7 @@ impl Tool for SplunkSearchTool {
                 ("output_mode", "json"),
                 ("count", &max_count.to_string()),
             ])
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Failed to retrieve results: {}", e)))?;
 
@@ -351,7 +352,7 @@ impl Tool for SplunkAlertsListTool {
         let response = client
             .get(&url)
             .query(&[("output_mode", "json"), ("count", &count.to_string())])
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Failed to list alerts: {}", e)))?;
 
@@ -442,7 +443,7 @@ impl Tool for SplunkSavedSearchesTool {
         let response = client
             .get(&url)
             .query(&params)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Failed to list saved searches: {}", e)))?;
 
@@ -530,7 +531,7 @@ impl Tool for SplunkSavedSearchRunTool {
         let response = client
             .post(&url)
             .form(&form_data)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Failed to run saved search: {}", e)))?;
 
@@ -651,7 +652,7 @@ impl Tool for SplunkHecSendTool {
             .header("Authorization", format!("Splunk {}", hec_token))
             .header("Content-Type", "application/json")
             .json(&payload)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("HEC request failed: {}", e)))?;
 
@@ -729,7 +730,7 @@ impl Tool for SplunkIndexesListTool {
         let response = client
             .get(&url)
             .query(&[("output_mode", "json")])
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| aof_core::AofError::tool(format!("Failed to list indexes: {}", e)))?;
 
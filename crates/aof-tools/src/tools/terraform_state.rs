@@ -0,0 +1,489 @@
+//! Terraform State Tool
+//!
+//! Read-only view of what Terraform thinks is deployed, for incident
+//! investigation. Never modifies state.
+//!
+//! ## Actions
+//!
+//! - `list` - `terraform state list`, parsed into resource addresses
+//! - `show` - One resource's attributes
+//! - `current` - The whole current state (`terraform show -json`)
+//!
+//! `show` and `current` read `terraform show -json` rather than the text output
+//! of `terraform state show`, because only the JSON form says which attributes
+//! are sensitive. Sensitive attributes (per the state's `sensitive_values`,
+//! sensitive outputs, and attribute names such as `password` or `private_key`)
+//! are replaced with the shared `[REDACTED]` marker; their paths are listed in
+//! `sensitive_attributes`.
+//!
+//! ## Prerequisites
+//!
+//! - Requires `terraform` feature flag
+//! - terraform must be installed and in PATH, with access to the state backend
+
+use std::sync::Arc;
+
+use aof_core::guardrail::REDACTED;
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tracing::debug;
+
+use super::common::{
+    create_schema, tool_config_with_timeout, CommandOutput, CommandRunner, ProcessRunner,
+};
+
+/// Attribute names treated as sensitive even when the provider doesn't say so
+const SENSITIVE_NAMES: &[&str] = &[
+    "password",
+    "secret",
+    "private_key",
+    "access_key",
+    "token",
+    "credentials",
+];
+
+/// A resource address from `terraform state list`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StateAddress {
+    /// Full address (e.g. `module.db.aws_db_instance.main`)
+    pub address: String,
+    /// Module path, if not in the root module (e.g. `module.db`)
+    pub module: Option<String>,
+    /// `managed` or `data`
+    pub mode: String,
+    /// Resource type (e.g. `aws_db_instance`)
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    /// Resource name, including any index (e.g. `web[0]`)
+    pub name: String,
+}
+
+impl StateAddress {
+    /// Parse a resource address
+    pub fn parse(address: &str) -> Option<Self> {
+        let mut rest = address.trim();
+        let mut module = Vec::new();
+        while let Some(after) = rest.strip_prefix("module.") {
+            let (name, tail) = split_segment(after)?;
+            module.push(format!("module.{}", name));
+            rest = tail;
+        }
+
+        let (mode, rest) = match rest.strip_prefix("data.") {
+            Some(rest) => ("data", rest),
+            None => ("managed", rest),
+        };
+        let (resource_type, name) = rest.split_once('.')?;
+        if resource_type.is_empty() || name.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            address: address.trim().to_string(),
+            module: (!module.is_empty()).then(|| module.join(".")),
+            mode: mode.to_string(),
+            resource_type: resource_type.to_string(),
+            name: name.to_string(),
+        })
+    }
+}
+
+// Helper: Split `name[index].rest` into (`name[index]`, `rest`)
+fn split_segment(s: &str) -> Option<(&str, &str)> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '.' if depth == 0 => return Some((&s[..i], &s[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Terraform state inspection tool
+pub struct TerraformStateTool {
+    config: ToolConfig,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl TerraformStateTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            json!({
+                "action": {
+                    "type": "string",
+                    "description": "list: resource addresses; show: one resource's attributes; current: the whole state",
+                    "enum": ["list", "show", "current"],
+                    "default": "list"
+                },
+                "address": {
+                    "type": "string",
+                    "description": "Resource address (required for show; filters list, e.g. 'module.db')"
+                },
+                "working_dir": {
+                    "type": "string",
+                    "description": "Terraform working directory"
+                }
+            }),
+            vec![],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "terraform_state",
+                "Inspect Terraform state (read-only): list resource addresses, show one resource's attributes, or return the current state. Sensitive attributes are redacted.",
+                parameters,
+                120,
+            ),
+            runner: Arc::new(ProcessRunner),
+        }
+    }
+
+    /// Use a custom command runner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    async fn terraform(&self, args: &[&str], working_dir: Option<&str>) -> Result<CommandOutput, String> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        let output = self
+            .runner
+            .run("terraform", &args, working_dir, self.config.timeout_secs)
+            .await
+            .map_err(|e| format!("Failed to run terraform: {}", e))?;
+        if !output.success {
+            return Err(format!(
+                "terraform {} failed: {}",
+                args.join(" "),
+                output.stderr.trim()
+            ));
+        }
+        Ok(output)
+    }
+
+    /// `terraform show -json`, parsed
+    async fn state_json(&self, working_dir: Option<&str>) -> Result<Value, String> {
+        let output = self.terraform(&["show", "-json", "-no-color"], working_dir).await?;
+        serde_json::from_str(&output.stdout)
+            .map_err(|e| format!("Failed to parse terraform show output: {}", e))
+    }
+}
+
+impl Default for TerraformStateTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for TerraformStateTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let action: String = input.get_arg("action").unwrap_or_else(|_| "list".to_string());
+        let address: Option<String> = input.get_arg("address").ok();
+        let working_dir: Option<String> = input.get_arg("working_dir").ok();
+        let working_dir = working_dir.as_deref();
+
+        debug!(action = %action, address = ?address, "Inspecting terraform state");
+
+        let result = match action.as_str() {
+            "list" => {
+                let mut args = vec!["state", "list"];
+                if let Some(ref address) = address {
+                    args.push(address);
+                }
+                self.terraform(&args, working_dir).await.map(|output| {
+                    let resources: Vec<StateAddress> = output
+                        .stdout
+                        .lines()
+                        .filter_map(StateAddress::parse)
+                        .collect();
+                    json!({ "count": resources.len(), "resources": resources })
+                })
+            }
+            "show" => match address {
+                Some(address) => self.state_json(working_dir).await.and_then(|state| {
+                    resources(&state)
+                        .into_iter()
+                        .find(|r| r.get("address").and_then(|a| a.as_str()) == Some(address.as_str()))
+                        .map(redact_resource)
+                        .ok_or_else(|| format!("Resource '{}' not found in state", address))
+                }),
+                None => Err("Missing required parameter: address (needed for show)".to_string()),
+            },
+            "current" => self.state_json(working_dir).await.map(|state| {
+                let resources: Vec<Value> = resources(&state).into_iter().map(redact_resource).collect();
+                json!({
+                    "terraform_version": state.get("terraform_version"),
+                    "resource_count": resources.len(),
+                    "resources": resources,
+                    "outputs": redact_outputs(state.pointer("/values/outputs")),
+                })
+            }),
+            other => Err(format!(
+                "Unknown action '{}'. Valid actions: list, show, current",
+                other
+            )),
+        };
+
+        Ok(match result {
+            Ok(value) => ToolResult::success(value),
+            Err(e) => ToolResult::error(e),
+        })
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// All resources in the state, including child modules
+fn resources(state: &Value) -> Vec<&Value> {
+    fn collect<'a>(module: &'a Value, out: &mut Vec<&'a Value>) {
+        if let Some(resources) = module.get("resources").and_then(|r| r.as_array()) {
+            out.extend(resources);
+        }
+        if let Some(children) = module.get("child_modules").and_then(|c| c.as_array()) {
+            for child in children {
+                collect(child, out);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    if let Some(root) = state.pointer("/values/root_module") {
+        collect(root, &mut out);
+    }
+    out
+}
+
+/// A resource with its sensitive attributes redacted
+fn redact_resource(resource: &Value) -> Value {
+    let mut values = resource.get("values").cloned().unwrap_or(Value::Null);
+    let sensitive = resource.get("sensitive_values").cloned().unwrap_or(Value::Null);
+    let mut redacted = Vec::new();
+    redact(&mut values, &sensitive, "", &mut redacted);
+    redacted.sort();
+
+    json!({
+        "address": resource.get("address"),
+        "mode": resource.get("mode"),
+        "type": resource.get("type"),
+        "name": resource.get("name"),
+        "provider": resource.get("provider_name"),
+        "values": values,
+        "sensitive_attributes": redacted,
+    })
+}
+
+/// Outputs, with sensitive ones redacted
+fn redact_outputs(outputs: Option<&Value>) -> Value {
+    let Some(outputs) = outputs.and_then(|o| o.as_object()) else {
+        return json!({});
+    };
+    outputs
+        .iter()
+        .map(|(name, output)| {
+            let sensitive = output.get("sensitive").and_then(|s| s.as_bool()).unwrap_or(false)
+                || is_sensitive_name(name);
+            let value = if sensitive {
+                json!(REDACTED)
+            } else {
+                output.get("value").cloned().unwrap_or(Value::Null)
+            };
+            (name.clone(), json!({ "value": value, "sensitive": sensitive }))
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+/// Replace sensitive values in place, recording their paths
+///
+/// `sensitive` mirrors the shape of `value`, with `true` at sensitive leaves.
+fn redact(value: &mut Value, sensitive: &Value, path: &str, redacted: &mut Vec<String>) {
+    if sensitive.as_bool() == Some(true) {
+        if !value.is_null() {
+            *value = json!(REDACTED);
+            redacted.push(path.to_string());
+        }
+        return;
+    }
+
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                let path = child(key);
+                if is_sensitive_name(key) && !v.is_null() && !v.is_boolean() {
+                    *v = json!(REDACTED);
+                    redacted.push(path);
+                } else {
+                    redact(v, sensitive.get(key).unwrap_or(&Value::Null), &path, redacted);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter_mut().enumerate() {
+                let path = format!("{}[{}]", path, i);
+                redact(v, sensitive.get(i).unwrap_or(&Value::Null), &path, redacted);
+            }
+        }
+        _ => {}
+    }
+}
+
+// Helper: Whether an attribute name suggests a secret
+fn is_sensitive_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+    SENSITIVE_NAMES.iter().any(|s| name.contains(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::common::FakeRunner;
+
+    const STATE_LIST: &str = "aws_security_group.web\ndata.aws_ami.ubuntu\nmodule.db.aws_db_instance.main\nmodule.app.module.lb[\"east\"].aws_lb.this[0]\n";
+
+    fn state() -> Value {
+        json!({
+            "format_version": "1.0",
+            "terraform_version": "1.7.5",
+            "values": {
+                "outputs": {
+                    "db_endpoint": { "sensitive": false, "value": "db.internal:5432" },
+                    "db_password": { "sensitive": true, "value": "hunter2" }
+                },
+                "root_module": {
+                    "resources": [{
+                        "address": "aws_security_group.web",
+                        "mode": "managed",
+                        "type": "aws_security_group",
+                        "name": "web",
+                        "provider_name": "registry.terraform.io/hashicorp/aws",
+                        "values": { "name": "web", "ingress": [{ "from_port": 443 }] },
+                        "sensitive_values": { "ingress": [{}] }
+                    }],
+                    "child_modules": [{
+                        "address": "module.db",
+                        "resources": [{
+                            "address": "module.db.aws_db_instance.main",
+                            "mode": "managed",
+                            "type": "aws_db_instance",
+                            "name": "main",
+                            "provider_name": "registry.terraform.io/hashicorp/aws",
+                            "values": {
+                                "engine": "postgres",
+                                "username": "admin",
+                                "master_password": "hunter2",
+                                "connection": { "uri": "postgres://admin:hunter2@db", "port": 5432 },
+                                "manage_master_user_password": false
+                            },
+                            "sensitive_values": { "connection": { "uri": true } }
+                        }]
+                    }]
+                }
+            }
+        })
+    }
+
+    fn fake_terraform() -> Arc<FakeRunner> {
+        Arc::new(FakeRunner::new(|_, args| match args[0].as_str() {
+            "state" => CommandOutput::ok(STATE_LIST),
+            "show" => CommandOutput::ok(state().to_string()),
+            _ => CommandOutput::failed(1, "unexpected command"),
+        }))
+    }
+
+    #[test]
+    fn test_parse_addresses() {
+        let nested = StateAddress::parse("module.app.module.lb[\"east\"].aws_lb.this[0]").unwrap();
+        assert_eq!(nested.module.as_deref(), Some("module.app.module.lb[\"east\"]"));
+        assert_eq!(nested.resource_type, "aws_lb");
+        assert_eq!(nested.name, "this[0]");
+
+        let data = StateAddress::parse("data.aws_ami.ubuntu").unwrap();
+        assert_eq!(data.mode, "data");
+        assert_eq!(data.module, None);
+
+        assert!(StateAddress::parse("").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_state_list() {
+        let runner = fake_terraform();
+        let tool = TerraformStateTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(json!({ "action": "list", "address": "module.db" })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["count"], 4);
+        assert_eq!(result.data["resources"][2]["module"], "module.db");
+        assert_eq!(result.data["resources"][2]["type"], "aws_db_instance");
+        assert_eq!(runner.calls()[0], vec!["terraform", "state", "list", "module.db"]);
+    }
+
+    #[tokio::test]
+    async fn test_show_redacts_sensitive_attributes() {
+        let runner = fake_terraform();
+        let tool = TerraformStateTool::new().with_runner(runner.clone());
+
+        let result = tool
+            .execute(ToolInput::new(json!({
+                "action": "show",
+                "address": "module.db.aws_db_instance.main"
+            })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        let values = &result.data["values"];
+        assert_eq!(values["engine"], "postgres");
+        assert_eq!(values["master_password"], REDACTED);
+        assert_eq!(values["connection"]["uri"], REDACTED);
+        assert_eq!(values["connection"]["port"], 5432);
+        assert_eq!(values["manage_master_user_password"], false);
+        assert_eq!(
+            result.data["sensitive_attributes"],
+            json!(["connection.uri", "master_password"])
+        );
+        assert!(!result.data.to_string().contains("hunter2"));
+        assert_eq!(runner.calls()[0], vec!["terraform", "show", "-json", "-no-color"]);
+
+        let missing = tool
+            .execute(ToolInput::new(json!({ "action": "show", "address": "aws_s3_bucket.logs" })))
+            .await
+            .unwrap();
+        assert!(missing.error.unwrap().contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn test_current_state_redacts_outputs() {
+        let tool = TerraformStateTool::new().with_runner(fake_terraform());
+
+        let result = tool
+            .execute(ToolInput::new(json!({ "action": "current" })))
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.data["resource_count"], 2);
+        assert_eq!(result.data["outputs"]["db_endpoint"]["value"], "db.internal:5432");
+        assert_eq!(result.data["outputs"]["db_password"]["value"], REDACTED);
+        assert!(!result.data.to_string().contains("hunter2"));
+    }
+}
@@ -0,0 +1,405 @@
+//! Data Transform Tool
+//!
+//! Tool for reshaping JSON/YAML data with jq expressions, in-process.
+//!
+//! ## Features
+//!
+//! - jq filter language (via the pure-Rust `jaq` implementation)
+//! - JSON and YAML input, JSON or YAML output
+//! - Multiple filter outputs are returned as a list
+//! - Bounded: at most `MAX_OUTPUTS` outputs, and evaluation runs on a blocking
+//!   thread under the tool timeout, so the call returns when the timeout passes
+//!
+//! jaq cannot be interrupted mid-step. Evaluation stops at the first output
+//! produced after the deadline, but an expression that computes without ever
+//! producing output (e.g. `last(repeat(1))`) keeps its thread busy until it
+//! ends. At most `MAX_CONCURRENT_EVALUATIONS` evaluations run at once, so such
+//! expressions cannot pile up threads; further calls are refused meanwhile.
+//!
+//! ## Prerequisites
+//!
+//! - Requires `transform` feature flag
+//!
+//! ## Usage
+//!
+//! Useful in flows for extracting or reshaping the output of one node before it is
+//! passed to the next, without shelling out to `jq` or `yq`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use aof_core::{AofResult, Tool, ToolConfig, ToolInput, ToolResult};
+use async_trait::async_trait;
+use jaq_interpret::{Ctx, FilterT, ParseCtx, RcIter, Val};
+use tracing::debug;
+
+use super::common::{create_schema, tool_config_with_timeout};
+
+/// Most outputs one expression may produce (e.g. `range(1e9)` is refused)
+pub const MAX_OUTPUTS: usize = 10_000;
+
+/// Most jq evaluations that may run at once
+pub const MAX_CONCURRENT_EVALUATIONS: usize = 4;
+
+/// Evaluations currently running on blocking threads
+static RUNNING_EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// A claim on one of the `MAX_CONCURRENT_EVALUATIONS` slots, released on drop
+struct EvaluationSlot;
+
+impl EvaluationSlot {
+    fn acquire() -> Option<Self> {
+        RUNNING_EVALUATIONS
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < MAX_CONCURRENT_EVALUATIONS).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Self)
+    }
+}
+
+impl Drop for EvaluationSlot {
+    fn drop(&mut self) {
+        RUNNING_EVALUATIONS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// jq-style data transform tool
+pub struct DataTransformTool {
+    config: ToolConfig,
+}
+
+impl DataTransformTool {
+    pub fn new() -> Self {
+        let parameters = create_schema(
+            serde_json::json!({
+                "expression": {
+                    "type": "string",
+                    "description": "jq expression, e.g. '.items[] | select(.status == \"Running\") | .name'"
+                },
+                "input": {
+                    "description": "Input document: a JSON/YAML string, or an already-structured value"
+                },
+                "input_format": {
+                    "type": "string",
+                    "description": "Format of a string input",
+                    "enum": ["auto", "json", "yaml"],
+                    "default": "auto"
+                },
+                "output_format": {
+                    "type": "string",
+                    "description": "Format of the returned result",
+                    "enum": ["json", "yaml"],
+                    "default": "json"
+                }
+            }),
+            vec!["expression", "input"],
+        );
+
+        Self {
+            config: tool_config_with_timeout(
+                "data_transform",
+                "Transform JSON or YAML data with a jq expression. Returns the filter output(s) without shelling out to jq/yq.",
+                parameters,
+                10,
+            ),
+        }
+    }
+}
+
+impl Default for DataTransformTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for DataTransformTool {
+    async fn execute(&self, input: ToolInput) -> AofResult<ToolResult> {
+        let expression: String = input.get_arg("expression")?;
+        let raw_input: serde_json::Value = input.get_arg("input")?;
+        let input_format: String = input
+            .get_arg("input_format")
+            .unwrap_or_else(|_| "auto".to_string());
+        let output_format: String = input
+            .get_arg("output_format")
+            .unwrap_or_else(|_| "json".to_string());
+
+        debug!(expression = %expression, input_format = %input_format, "Transforming data");
+
+        let document = match raw_input {
+            serde_json::Value::String(text) => match parse_document(&text, &input_format) {
+                Ok(doc) => doc,
+                Err(e) => return Ok(ToolResult::error(e)),
+            },
+            value => value,
+        };
+
+        // jaq evaluation is synchronous and may not terminate, so keep it off
+        // the async workers, stop it at the next output after the deadline and
+        // stop waiting for it at the timeout
+        let Some(slot) = EvaluationSlot::acquire() else {
+            return Ok(ToolResult::error(format!(
+                "{} jq evaluations are still running; try again once they finish",
+                MAX_CONCURRENT_EVALUATIONS
+            )));
+        };
+        let timeout = Duration::from_secs(self.config.timeout_secs);
+        let deadline = Instant::now() + timeout;
+        let task = tokio::task::spawn_blocking(move || {
+            let _slot = slot;
+            apply_jq_until(&expression, document, Some(deadline))
+        });
+        let outputs = match tokio::time::timeout(timeout, task).await {
+            Ok(Ok(Ok(outputs))) => outputs,
+            Ok(Ok(Err(e))) => return Ok(ToolResult::error(e)),
+            Ok(Err(e)) => return Ok(ToolResult::error(format!("jq evaluation failed: {}", e))),
+            Err(_) => {
+                return Ok(ToolResult::error(format!(
+                    "jq expression did not finish within {}s",
+                    timeout.as_secs()
+                )))
+            }
+        };
+
+        let result = match outputs.as_slice() {
+            [single] => single.clone(),
+            _ => serde_json::Value::Array(outputs.clone()),
+        };
+
+        match output_format.as_str() {
+            "yaml" => match serde_yaml::to_string(&result) {
+                Ok(yaml) => Ok(ToolResult::success(serde_json::json!({
+                    "result": yaml,
+                    "count": outputs.len(),
+                    "format": "yaml"
+                }))),
+                Err(e) => Ok(ToolResult::error(format!("Failed to render YAML: {}", e))),
+            },
+            _ => Ok(ToolResult::success(serde_json::json!({
+                "result": result,
+                "count": outputs.len(),
+                "format": "json"
+            }))),
+        }
+    }
+
+    fn config(&self) -> &ToolConfig {
+        &self.config
+    }
+}
+
+/// Parse a JSON or YAML string; `auto` tries JSON first, then YAML
+///
+/// YAML is a superset of JSON, so text that is neither (e.g. an unclosed JSON
+/// object) reports both parse errors.
+fn parse_document(text: &str, format: &str) -> Result<serde_json::Value, String> {
+    match format {
+        "json" => serde_json::from_str(text).map_err(|e| format!("Invalid JSON input: {}", e)),
+        "yaml" => serde_yaml::from_str(text).map_err(|e| format!("Invalid YAML input: {}", e)),
+        "auto" => {
+            let json_err = match serde_json::from_str(text) {
+                Ok(doc) => return Ok(doc),
+                Err(e) => e,
+            };
+            serde_yaml::from_str(text).map_err(|yaml_err| {
+                format!(
+                    "Input is neither valid JSON ({}) nor valid YAML ({})",
+                    json_err, yaml_err
+                )
+            })
+        }
+        other => Err(format!(
+            "Unknown input_format '{}'. Valid formats: auto, json, yaml",
+            other
+        )),
+    }
+}
+
+/// Compile and run a jq expression against a document, collecting every output
+///
+/// Fails if the expression produces more than `MAX_OUTPUTS` outputs.
+pub fn apply_jq(
+    expression: &str,
+    document: serde_json::Value,
+) -> Result<Vec<serde_json::Value>, String> {
+    apply_jq_until(expression, document, None)
+}
+
+/// Like `apply_jq`, but fails at the first output produced after `deadline`
+pub fn apply_jq_until(
+    expression: &str,
+    document: serde_json::Value,
+    deadline: Option<Instant>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let mut defs = ParseCtx::new(Vec::new());
+    defs.insert_natives(jaq_core::core());
+    defs.insert_defs(jaq_std::std());
+
+    let (filter, errs) = jaq_parse::parse(expression, jaq_parse::main());
+    if !errs.is_empty() {
+        let details: Vec<String> = errs.iter().map(|e| e.to_string()).collect();
+        return Err(format!(
+            "Invalid jq expression '{}': {}",
+            expression,
+            details.join("; ")
+        ));
+    }
+    let filter = filter.ok_or_else(|| format!("Invalid jq expression '{}'", expression))?;
+
+    let filter = defs.compile(filter);
+    if !defs.errs.is_empty() {
+        let details: Vec<String> = defs.errs.iter().map(|(e, _)| format!("{:?}", e)).collect();
+        return Err(format!(
+            "Failed to compile jq expression '{}': {}",
+            expression,
+            details.join("; ")
+        ));
+    }
+
+    let inputs = RcIter::new(core::iter::empty());
+    let mut outputs = Vec::new();
+    for out in filter.run((Ctx::new([], &inputs), Val::from(document))) {
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Err(format!("jq expression '{}' did not finish in time", expression));
+        }
+        if outputs.len() == MAX_OUTPUTS {
+            return Err(format!(
+                "jq expression '{}' produced more than {} outputs; narrow it or wrap it in limit()",
+                expression, MAX_OUTPUTS
+            ));
+        }
+        outputs.push(
+            out.map(serde_json::Value::from)
+                .map_err(|e| format!("jq evaluation error: {}", e))?,
+        );
+    }
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pods() -> serde_json::Value {
+        serde_json::json!({
+            "items": [
+                { "name": "api-1", "status": "Running", "restarts": 0 },
+                { "name": "api-2", "status": "CrashLoopBackOff", "restarts": 12 },
+                { "name": "worker-1", "status": "Running", "restarts": 1 }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_data_transform_config() {
+        let tool = DataTransformTool::new();
+        assert_eq!(tool.config().name, "data_transform");
+        assert!(tool.config().description.contains("jq"));
+    }
+
+    #[test]
+    fn test_apply_jq_select() {
+        let out = apply_jq(
+            r#".items[] | select(.status == "Running") | .name"#,
+            pods(),
+        )
+        .unwrap();
+        assert_eq!(out, vec![serde_json::json!("api-1"), serde_json::json!("worker-1")]);
+    }
+
+    #[test]
+    fn test_apply_jq_reshape() {
+        let out = apply_jq(
+            "{unhealthy: [.items[] | select(.restarts > 5) | .name], total: (.items | length)}",
+            pods(),
+        )
+        .unwrap();
+        assert_eq!(out, vec![serde_json::json!({ "unhealthy": ["api-2"], "total": 3 })]);
+    }
+
+    #[test]
+    fn test_apply_jq_invalid_expression() {
+        let err = apply_jq(".items[", pods()).unwrap_err();
+        assert!(err.contains("Invalid jq expression"));
+    }
+
+    #[test]
+    fn test_apply_jq_caps_outputs() {
+        let err = apply_jq("range(1e9)", serde_json::Value::Null).unwrap_err();
+        assert!(err.contains("more than 10000 outputs"));
+
+        let outputs = apply_jq("limit(3; range(1e9))", serde_json::Value::Null).unwrap();
+        assert_eq!(outputs.len(), 3);
+    }
+
+    #[test]
+    fn test_apply_jq_stops_at_deadline() {
+        let err = apply_jq_until("range(1e9)", serde_json::Value::Null, Some(Instant::now())).unwrap_err();
+        assert!(err.contains("did not finish in time"));
+    }
+
+    #[test]
+    fn test_parse_document_yaml() {
+        let doc = parse_document("replicas: 3\nimage: nginx\n", "auto").unwrap();
+        assert_eq!(doc["replicas"], 3);
+        assert_eq!(doc["image"], "nginx");
+    }
+
+    #[test]
+    fn test_parse_document_auto_rejects_malformed_json() {
+        let err = parse_document(r#"{"replicas": 3"#, "auto").unwrap_err();
+        assert!(err.contains("neither valid JSON"));
+        assert!(err.contains("nor valid YAML"));
+    }
+
+    #[test]
+    fn test_parse_document_auto_accepts_yaml_scalars() {
+        assert_eq!(parse_document("Running", "auto").unwrap(), "Running");
+        assert_eq!(parse_document("'Running'", "auto").unwrap(), "Running");
+        assert_eq!(parse_document("true", "auto").unwrap(), true);
+        assert_eq!(parse_document("nginx", "yaml").unwrap(), "nginx");
+    }
+
+    #[tokio::test]
+    async fn test_execute_on_yaml_string() {
+        let tool = DataTransformTool::new();
+        let input = ToolInput::new(serde_json::json!({
+            "expression": ".spec.replicas",
+            "input": "apiVersion: apps/v1\nkind: Deployment\nspec:\n  replicas: 4\n",
+            "input_format": "yaml"
+        }));
+
+        let result = tool.execute(input).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.data["result"], 4);
+        assert_eq!(result.data["count"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_times_out_slow_expression() {
+        let mut tool = DataTransformTool::new();
+        tool.config.timeout_secs = 0;
+        let input = ToolInput::new(serde_json::json!({
+            "expression": "last(range(1e6))",
+            "input": {}
+        }));
+
+        let result = tool.execute(input).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("did not finish"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_invalid_expression_is_tool_error() {
+        let tool = DataTransformTool::new();
+        let input = ToolInput::new(serde_json::json!({
+            "expression": "map(",
+            "input": pods()
+        }));
+
+        let result = tool.execute(input).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("jq expression"));
+    }
+}
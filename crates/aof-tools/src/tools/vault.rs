@@ -28,6 +28,7 @@ use async_trait::async_trait;
 use tracing::debug;
 
 use super::common::{create_schema, tool_config_with_timeout};
+use super::rate_limit::TrackedSend;
 
 /// Collection of all Vault tools
 pub struct VaultTools;
@@ -174,7 +175,7 @@ impl Tool for VaultKvGetTool {
             url = format!("{}?version={}", url, v);
         }
 
-        let response = match client.get(&url).send().await {
+        let response = match client.get(&url).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 if e.is_timeout() {
@@ -305,7 +306,7 @@ impl Tool for VaultKvPutTool {
             });
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Vault request failed: {}", e)));
@@ -409,7 +410,7 @@ impl Tool for VaultKvListTool {
         // LIST method is a GET with a query parameter
         let response = match client
             .request(reqwest::Method::from_bytes(b"LIST").unwrap_or(reqwest::Method::GET), &url)
-            .send()
+            .send_tracked()
             .await
         {
             Ok(r) => r,
@@ -524,11 +525,11 @@ impl Tool for VaultKvDeleteTool {
             let payload = serde_json::json!({
                 "versions": vers
             });
-            client.post(&url).json(&payload).send().await
+            client.post(&url).json(&payload).send_tracked().await
         } else {
             // Delete latest version
             let url = format!("{}/v1/{}", endpoint.trim_end_matches('/'), path);
-            client.delete(&url).send().await
+            client.delete(&url).send_tracked().await
         };
 
         let response = match response {
@@ -636,9 +637,9 @@ impl Tool for VaultTokenLookupTool {
         };
 
         let response = if method == "POST" {
-            client.post(&url).json(&payload.unwrap()).send().await
+            client.post(&url).json(&payload.unwrap()).send_tracked().await
         } else {
-            client.get(&url).send().await
+            client.get(&url).send_tracked().await
         };
 
         let response = match response {
@@ -786,7 +787,7 @@ impl Tool for VaultTransitEncryptTool {
             payload["key_version"] = serde_json::json!(ver);
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Vault request failed: {}", e)));
@@ -921,7 +922,7 @@ impl Tool for VaultTransitDecryptTool {
             payload["context"] = serde_json::json!(ctx);
         }
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Vault request failed: {}", e)));
@@ -1060,7 +1061,7 @@ impl Tool for VaultAppRoleLoginTool {
             "secret_id": secret_id
         });
 
-        let response = match client.post(&url).json(&payload).send().await {
+        let response = match client.post(&url).json(&payload).send_tracked().await {
             Ok(r) => r,
             Err(e) => {
                 return Ok(ToolResult::error(format!("Vault request failed: {}", e)));
@@ -9,6 +9,33 @@ use thiserror::Error;
 
 use crate::platforms::TriggerMessage;
 
+/// Prefix commands start with unless configured otherwise
+pub const DEFAULT_COMMAND_PREFIX: &str = "/";
+
+/// Strip the first matching command prefix from `text`
+///
+/// Longer prefixes are tried first, so `!aof` wins over `!`. A prefix ending
+/// in a letter or digit must be followed by whitespace (`!aof help`, not
+/// `!aofhelp`). Returns the text after the prefix, or `None` if no prefix matches.
+pub fn strip_command_prefix<'a, S: AsRef<str>>(text: &'a str, prefixes: &[S]) -> Option<&'a str> {
+    let text = text.trim();
+    let mut prefixes: Vec<&str> = prefixes
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|p| !p.is_empty())
+        .collect();
+    prefixes.sort_by_key(|p| std::cmp::Reverse(p.len()));
+
+    prefixes.into_iter().find_map(|prefix| {
+        let rest = text.strip_prefix(prefix)?;
+        let needs_separator = prefix.ends_with(|c: char| c.is_alphanumeric());
+        if needs_separator && !(rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+            return None;
+        }
+        Some(rest.trim_start())
+    })
+}
+
 /// Command parsing errors
 #[derive(Debug, Error)]
 pub enum CommandError {
@@ -61,6 +88,12 @@ pub enum CommandType {
     /// Switch or show fleet (team of agents)
     /// /fleet command for switching between configured fleets
     Fleet,
+
+    /// Show or switch the global safe mode that blocks all writes
+    SafeMode,
+
+    /// Show or set how much detail replies contain
+    Verbosity,
 }
 
 impl CommandType {
@@ -75,9 +108,11 @@ impl CommandType {
             "help" | "h" => Ok(Self::Help),
             "flows" => Ok(Self::Flows),
             // /agent command for switching agents (DEPRECATED: use /fleet)
-            "agent" | "agents" | "context" | "ctx" => Ok(Self::Agent),
+            "agent" | "agents" | "context" | "contexts" | "ctx" => Ok(Self::Agent),
             // /fleet command for switching fleets
             "fleet" | "fleets" | "team" => Ok(Self::Fleet),
+            "safemode" | "safe-mode" | "safe_mode" | "killswitch" => Ok(Self::SafeMode),
+            "verbosity" | "detail" => Ok(Self::Verbosity),
             _ => Err(CommandError::UnknownCommand(s.to_string())),
         }
     }
@@ -95,12 +130,14 @@ impl CommandType {
             Self::Flows => "Show available flows for selection",
             Self::Agent => "Switch or show agent (deprecated: use /fleet)",
             Self::Fleet => "Switch or show fleet (team of agents)",
+            Self::SafeMode => "Show or switch safe mode (blocks all writes)",
+            Self::Verbosity => "Show or set reply detail (terse, normal, verbose)",
         }
     }
 
     /// Check if this command type requires no target argument
     pub fn is_targetless(&self) -> bool {
-        matches!(self, Self::Help | Self::Flows | Self::Agent | Self::Fleet)
+        matches!(self, Self::Help | Self::Flows | Self::Agent | Self::Fleet | Self::SafeMode | Self::Verbosity)
     }
 }
 
@@ -238,16 +275,23 @@ impl TriggerCommand {
     /// - `/list agents`
     /// - `/help`
     pub fn parse(msg: &TriggerMessage) -> Result<Self, CommandError> {
-        let text = msg.text.trim();
+        Self::parse_with_prefixes(msg, &[DEFAULT_COMMAND_PREFIX])
+    }
 
-        // Must start with /
-        if !text.starts_with('/') {
-            return Err(CommandError::InvalidFormat(
-                "Command must start with /".to_string(),
-            ));
-        }
+    /// Parse command from message text accepting any of the given prefixes
+    ///
+    /// With prefixes `["/", "!aof"]`, both `/status task t-1` and
+    /// `!aof status task t-1` parse to the same command.
+    pub fn parse_with_prefixes<S: AsRef<str>>(msg: &TriggerMessage, prefixes: &[S]) -> Result<Self, CommandError> {
+        let Some(body) = strip_command_prefix(&msg.text, prefixes) else {
+            let accepted: Vec<&str> = prefixes.iter().map(AsRef::as_ref).collect();
+            return Err(CommandError::InvalidFormat(format!(
+                "Command must start with {}",
+                accepted.join(" or ")
+            )));
+        };
 
-        let parts: Vec<&str> = text[1..].split_whitespace().collect();
+        let parts: Vec<&str> = body.split_whitespace().collect();
 
         if parts.is_empty() {
             return Err(CommandError::InvalidFormat("Empty command".to_string()));
@@ -356,11 +400,38 @@ mod tests {
         assert!(TriggerCommand::parse(&msg).is_err());
     }
 
+    #[test]
+    fn test_parse_custom_prefixes() {
+        let prefixes = ["/", "!aof"];
+
+        let cmd = TriggerCommand::parse_with_prefixes(&create_test_message("!aof status task t-1"), &prefixes).unwrap();
+        assert_eq!(cmd.command_type, CommandType::Status);
+        assert_eq!(cmd.get_arg(0).unwrap(), "t-1");
+
+        let cmd = TriggerCommand::parse_with_prefixes(&create_test_message("/help"), &prefixes).unwrap();
+        assert_eq!(cmd.command_type, CommandType::Help);
+
+        // Word-like prefixes need a separator
+        assert!(TriggerCommand::parse_with_prefixes(&create_test_message("!aofhelp"), &prefixes).is_err());
+        // Only configured prefixes are accepted
+        assert!(TriggerCommand::parse_with_prefixes(&create_test_message("/help"), &["!aof"]).is_err());
+    }
+
+    #[test]
+    fn test_strip_command_prefix() {
+        assert_eq!(strip_command_prefix("  /help", &["/"]), Some("help"));
+        assert_eq!(strip_command_prefix("!! deploy", &["!", "!!"]), Some("deploy"));
+        assert_eq!(strip_command_prefix("!aof", &["!aof"]), Some(""));
+        assert_eq!(strip_command_prefix("hello", &["/", "!aof"]), None);
+    }
+
     #[test]
     fn test_command_type_from_str() {
         assert_eq!(CommandType::from_str("run").unwrap(), CommandType::Run);
         assert_eq!(CommandType::from_str("execute").unwrap(), CommandType::Run);
         assert_eq!(CommandType::from_str("list").unwrap(), CommandType::List);
+        assert_eq!(CommandType::from_str("safe-mode").unwrap(), CommandType::SafeMode);
+        assert_eq!(CommandType::from_str("verbosity").unwrap(), CommandType::Verbosity);
         assert!(CommandType::from_str("invalid").is_err());
     }
 
@@ -0,0 +1,245 @@
+//! Circuit breaker for failing agents and flows
+//!
+//! When an agent or flow fails every invocation (a bad deploy, a revoked
+//! credential), retrying each incoming message only spams the channel with
+//! errors. The breaker tracks consecutive failures per target:
+//!
+//! - **Closed**: invocations run normally
+//! - **Open**: after `failure_threshold` consecutive failures, invocations are
+//!   rejected with a "temporarily unavailable" reply until `cooldown` passes
+//! - **Half-open**: after the cooldown one trial invocation is let through;
+//!   success closes the breaker, failure opens it for another cooldown
+//!
+//! Targets are keyed as `agent:<name>` or `flow:<name>`.
+
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+/// Circuit breaker settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the breaker (0 disables it)
+    pub failure_threshold: u32,
+    /// How long an open breaker rejects invocations before a trial
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(60),
+        }
+    }
+}
+
+/// State of one target's breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Invocations run normally
+    Closed,
+    /// Invocations are rejected
+    Open,
+    /// One trial invocation is in flight
+    HalfOpen,
+}
+
+/// Whether an invocation may run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Admission {
+    /// Run the invocation (and record its outcome)
+    Allow,
+    /// Reply "temporarily unavailable" instead of running
+    Reject {
+        /// Time until the next trial is allowed
+        retry_after: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Circuit {
+    state: BreakerState,
+    consecutive_failures: u32,
+    /// When the breaker opened, or when the half-open trial started
+    since: Instant,
+}
+
+/// Per-target circuit breakers
+#[derive(Debug, Default)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: DashMap<String, Circuit>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: DashMap::new(),
+        }
+    }
+
+    /// Key for an agent
+    pub fn agent_key(name: &str) -> String {
+        format!("agent:{}", name)
+    }
+
+    /// Key for a flow
+    pub fn flow_key(name: &str) -> String {
+        format!("flow:{}", name)
+    }
+
+    /// Decide whether an invocation of `key` may run
+    pub fn check(&self, key: &str) -> Admission {
+        self.check_at(key, Instant::now())
+    }
+
+    /// Record a successful invocation, closing the breaker
+    pub fn record_success(&self, key: &str) {
+        self.circuits.remove(key);
+    }
+
+    /// Record a failed invocation
+    pub fn record_failure(&self, key: &str) {
+        self.record_failure_at(key, Instant::now())
+    }
+
+    /// Current state of a target's breaker
+    pub fn state(&self, key: &str) -> BreakerState {
+        self.circuits
+            .get(key)
+            .map(|c| c.state)
+            .unwrap_or(BreakerState::Closed)
+    }
+
+    fn check_at(&self, key: &str, now: Instant) -> Admission {
+        if self.config.failure_threshold == 0 {
+            return Admission::Allow;
+        }
+        let Some(mut circuit) = self.circuits.get_mut(key) else {
+            return Admission::Allow;
+        };
+
+        match circuit.state {
+            BreakerState::Closed => Admission::Allow,
+            // A trial that never reported back (or an open cooldown that has
+            // passed) lets the next invocation through as a new trial
+            BreakerState::Open | BreakerState::HalfOpen => {
+                let elapsed = now.saturating_duration_since(circuit.since);
+                if elapsed >= self.config.cooldown {
+                    circuit.state = BreakerState::HalfOpen;
+                    circuit.since = now;
+                    Admission::Allow
+                } else {
+                    Admission::Reject {
+                        retry_after: self.config.cooldown - elapsed,
+                    }
+                }
+            }
+        }
+    }
+
+    fn record_failure_at(&self, key: &str, now: Instant) {
+        if self.config.failure_threshold == 0 {
+            return;
+        }
+        let mut circuit = self.circuits.entry(key.to_string()).or_insert(Circuit {
+            state: BreakerState::Closed,
+            consecutive_failures: 0,
+            since: now,
+        });
+
+        circuit.consecutive_failures += 1;
+        let trip = circuit.state == BreakerState::HalfOpen
+            || circuit.consecutive_failures >= self.config.failure_threshold;
+        if trip {
+            circuit.state = BreakerState::Open;
+            circuit.since = now;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 3,
+            cooldown: Duration::from_secs(30),
+        })
+    }
+
+    #[test]
+    fn test_consecutive_failures_open_breaker() {
+        let breaker = breaker();
+        let key = CircuitBreaker::agent_key("k8s-ops");
+        let start = Instant::now();
+
+        breaker.record_failure_at(&key, start);
+        breaker.record_failure_at(&key, start);
+        assert_eq!(breaker.check_at(&key, start), Admission::Allow);
+
+        // A success resets the count
+        breaker.record_success(&key);
+        breaker.record_failure_at(&key, start);
+        breaker.record_failure_at(&key, start);
+        assert_eq!(breaker.state(&key), BreakerState::Closed);
+
+        breaker.record_failure_at(&key, start);
+        assert_eq!(breaker.state(&key), BreakerState::Open);
+        assert_eq!(
+            breaker.check_at(&key, start + Duration::from_secs(10)),
+            Admission::Reject {
+                retry_after: Duration::from_secs(20)
+            }
+        );
+
+        // Other targets are unaffected
+        assert_eq!(breaker.check_at(&CircuitBreaker::flow_key("k8s-ops"), start), Admission::Allow);
+    }
+
+    #[test]
+    fn test_cooldown_half_opens_breaker() {
+        let breaker = breaker();
+        let key = CircuitBreaker::flow_key("deploy");
+        let start = Instant::now();
+        for _ in 0..3 {
+            breaker.record_failure_at(&key, start);
+        }
+
+        // After the cooldown one trial is allowed; others wait for its outcome
+        let after = start + Duration::from_secs(30);
+        assert_eq!(breaker.check_at(&key, after), Admission::Allow);
+        assert_eq!(breaker.state(&key), BreakerState::HalfOpen);
+        assert!(matches!(breaker.check_at(&key, after), Admission::Reject { .. }));
+
+        // A failed trial opens it again for a full cooldown
+        breaker.record_failure_at(&key, after);
+        assert_eq!(breaker.state(&key), BreakerState::Open);
+        assert!(matches!(
+            breaker.check_at(&key, after + Duration::from_secs(29)),
+            Admission::Reject { .. }
+        ));
+
+        // A successful trial closes it
+        let later = after + Duration::from_secs(30);
+        assert_eq!(breaker.check_at(&key, later), Admission::Allow);
+        breaker.record_success(&key);
+        assert_eq!(breaker.state(&key), BreakerState::Closed);
+        assert_eq!(breaker.check_at(&key, later), Admission::Allow);
+    }
+
+    #[test]
+    fn test_zero_threshold_disables_breaker() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 0,
+            ..Default::default()
+        });
+        for _ in 0..10 {
+            breaker.record_failure("agent:k8s-ops");
+        }
+        assert_eq!(breaker.check("agent:k8s-ops"), Admission::Allow);
+    }
+}
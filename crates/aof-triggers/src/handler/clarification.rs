@@ -0,0 +1,174 @@
+//! Clarification requests from agents
+//!
+//! An agent that needs more information before it can act ("which
+//! namespace?") ends its output with a clarification block instead of
+//! guessing:
+//!
+//! ```yaml
+//! needs_clarification: true
+//! question: Which namespace should I check?
+//! options: default, prod, staging
+//! ```
+//!
+//! The handler posts the question, waits for the same user's next message in
+//! the same conversation, and re-runs the agent with the original request, the
+//! question and the answer. Unanswered questions expire after
+//! `clarification_timeout_secs`.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// A question an agent asked before it could continue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClarificationRequest {
+    /// Question for the user
+    pub question: String,
+    /// Suggested answers (may be empty)
+    pub options: Vec<String>,
+    /// Agent output before the clarification block
+    pub preamble: String,
+}
+
+impl ClarificationRequest {
+    /// Parse a clarification request from agent output
+    ///
+    /// Returns None unless the output sets `needs_clarification: true` and
+    /// gives a `question`.
+    pub fn parse(output: &str) -> Option<Self> {
+        let mut needs_clarification = false;
+        let mut question = None;
+        let mut options = Vec::new();
+        let mut preamble = Vec::new();
+
+        for line in output.lines() {
+            let trimmed = line.trim();
+            if let Some(value) = field(trimmed, "needs_clarification") {
+                needs_clarification = value == "true";
+            } else if let Some(value) = field(trimmed, "question") {
+                question = Some(value.to_string()).filter(|q| !q.is_empty());
+            } else if let Some(value) = field(trimmed, "options") {
+                options = value
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .split(',')
+                    .map(|o| unquote(o.trim()).to_string())
+                    .filter(|o| !o.is_empty())
+                    .collect();
+            } else {
+                preamble.push(line);
+            }
+        }
+
+        if !needs_clarification {
+            return None;
+        }
+        Some(Self {
+            question: question?,
+            options,
+            preamble: preamble.join("\n").trim().to_string(),
+        })
+    }
+
+    /// Text sent to the user
+    pub fn prompt(&self) -> String {
+        let mut text = String::new();
+        if !self.preamble.is_empty() {
+            text.push_str(&self.preamble);
+            text.push_str("\n\n");
+        }
+        text.push_str("❓ ");
+        text.push_str(&self.question);
+        if !self.options.is_empty() {
+            text.push_str(&format!("\nOptions: {}", self.options.join(", ")));
+        }
+        text
+    }
+}
+
+/// A clarification question awaiting the user's reply
+#[derive(Debug, Clone)]
+pub struct PendingClarification {
+    /// Agent to resume with the answer
+    pub agent_name: String,
+    /// User who must answer
+    pub user_id: String,
+    /// Request the agent was working on
+    pub original_input: String,
+    /// Question the agent asked
+    pub question: String,
+    /// When the question was posted
+    pub asked_at: DateTime<Utc>,
+}
+
+impl PendingClarification {
+    /// Whether the question has gone unanswered for longer than `timeout_secs`
+    pub fn is_expired(&self, timeout_secs: u64, now: DateTime<Utc>) -> bool {
+        now.signed_duration_since(self.asked_at) > Duration::seconds(timeout_secs as i64)
+    }
+
+    /// Agent input that resumes the original request with the user's answer
+    pub fn resume_input(&self, answer: &str) -> String {
+        format!(
+            "{}\n\nYou asked: {}\nUser answered: {}",
+            self.original_input,
+            self.question,
+            answer.trim()
+        )
+    }
+}
+
+// Helper: Value of a `key: value` line
+fn field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    line.strip_prefix(key)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .map(|value| unquote(value.trim()))
+}
+
+// Helper: Strip one pair of surrounding quotes
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clarification() {
+        let output = "I can check the pods.\nneeds_clarification: true\nquestion: \"Which namespace?\"\noptions: [default, \"prod\", staging]";
+        let request = ClarificationRequest::parse(output).unwrap();
+
+        assert_eq!(request.question, "Which namespace?");
+        assert_eq!(request.options, vec!["default", "prod", "staging"]);
+        assert_eq!(request.preamble, "I can check the pods.");
+        assert_eq!(
+            request.prompt(),
+            "I can check the pods.\n\n❓ Which namespace?\nOptions: default, prod, staging"
+        );
+
+        assert!(ClarificationRequest::parse("3 pods running").is_none());
+        assert!(ClarificationRequest::parse("needs_clarification: false\nquestion: Which?").is_none());
+        assert!(ClarificationRequest::parse("needs_clarification: true").is_none());
+    }
+
+    #[test]
+    fn test_pending_expiry_and_resume() {
+        let pending = PendingClarification {
+            agent_name: "k8s-ops".to_string(),
+            user_id: "U_DEV".to_string(),
+            original_input: "how many pods are running?".to_string(),
+            question: "Which namespace?".to_string(),
+            asked_at: Utc::now() - Duration::seconds(120),
+        };
+
+        assert!(!pending.is_expired(300, Utc::now()));
+        assert!(pending.is_expired(60, Utc::now()));
+        assert_eq!(
+            pending.resume_input(" prod "),
+            "how many pods are running?\n\nYou asked: Which namespace?\nUser answered: prod"
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! Localizable bot messages
+//!
+//! User-facing strings are looked up by message id in a `MessageCatalog`.
+//! Operators can override any message per locale; anything missing falls back
+//! to the built-in English text.
+//!
+//! Templates use `{name}` placeholders, filled from the arguments passed to
+//! `render`. Unknown placeholders are left as-is.
+//!
+//! ```yaml
+//! es:
+//!   processing: "Procesando tu solicitud..."
+//!   write_blocked: "Operación de escritura bloqueada\n\n{platform} es de solo lectura."
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Locale used when nothing else is configured, and for fallbacks
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Message ids and their built-in English templates
+const ENGLISH: &[(&str, &str)] = &[
+    ("processing", "Processing your request..."),
+    ("thinking", "🤔 Thinking..."),
+    ("command_failed", "Command failed: {error}"),
+    ("agent_error", "❌ Sorry, I encountered an error: {error}"),
+    ("agent_not_found", "❌ Agent not found: `{agent}`"),
+    ("ai_init_failed", "❌ Failed to initialize AI: {error}"),
+    (
+        "no_api_key",
+        "❌ No API key configured. Please set GOOGLE_API_KEY, ANTHROPIC_API_KEY, or OPENAI_API_KEY.",
+    ),
+    (
+        "greeting",
+        "Hi! I'm your DevOps assistant.\n\n\
+        Current agent: {agent}\n\
+        Tools: {tools}\n\n\
+        Just type your question naturally.\n\n\
+        Use /help to switch agents.",
+    ),
+    ("tools_standard", "standard"),
+    ("tools_none", "none"),
+    (
+        "help",
+        "AOF Bot - DevOps from mobile\n\n\
+        Current fleet: {fleet}\n\n\
+        Commands:\n\
+        /fleet - Switch fleet (recommended)\n\
+        /agent - Switch agent (legacy)\n\
+        /contexts health - Check context connectivity\n\
+        /context <name> --check - Switch after a connectivity check\n\
+        /run agent <name> --background <msg> - Run as a task\n\
+        /safemode on|off - Block all writes everywhere\n\
+        /verbosity terse|normal|verbose - Reply detail\n\
+        /help - Show this help\n\n\
+        Just type naturally after selecting a fleet.\n\n\
+        Select fleet:",
+    ),
+    (
+        "system_info",
+        "**AOF System Info**\n\n\
+        **Version:** {version}\n\
+        **Runtime Stats:**\n\
+        • Max Concurrent: {max_concurrent}\n\
+        • Available Permits: {available}\n\
+        • Active Tasks: {active}\n\
+        • Pending: {pending}\n\
+        • Running: {running}\n\
+        • Completed: {completed}\n\
+        • Failed: {failed}\n\n\
+        **Platforms:** {platforms}",
+    ),
+    ("invalid_command_format", "Invalid command format: {error}\n\nUse `/help` for usage."),
+    ("unknown_command", "Unknown command: {command}\n\nUse `/help` for available commands."),
+    ("missing_argument", "Missing required argument: {argument}\n\nUse `/help` for usage."),
+    ("invalid_target", "Invalid target: {target}\n\nValid targets: agent, task, fleet, flow"),
+    ("create_not_implemented", "Create command not yet implemented"),
+    ("run_usage", "Usage: `/run agent {agent} [--background] <your message>`"),
+    ("run_not_supported", "Run command not supported for {target}"),
+    ("status_not_supported", "Status not supported for {target}"),
+    ("cancel_not_supported", "Cancel not supported for {target}"),
+    ("list_not_supported", "List not supported for {target}"),
+    (
+        "too_many_tasks",
+        "You have too many active tasks ({count}). Please wait for some to complete.",
+    ),
+    (
+        "task_started",
+        "⏳ Started task `{task}` with agent {agent}.\n\n\
+        Check progress with `/status task {task}`. I'll post here when it finishes.",
+    ),
+    ("task_completed_notice", "✅ Task `{task}` ({agent}) completed in {seconds}s"),
+    ("task_failed_notice", "❌ Task `{task}` ({agent}) failed after {seconds}s"),
+    ("task_cancelled_notice", "🚫 Task `{task}` ({agent}) was cancelled"),
+    (
+        "task_status",
+        "{icon} **Task Status**\n\n**ID:** `{task}`\n**Name:** {name}\n**Agent:** {agent}\n**Status:** {status}",
+    ),
+    ("task_priority", "**Priority:** {priority}"),
+    ("task_metadata", "**Metadata:**"),
+    ("task_input", "**Input:** {input}"),
+    ("task_progress", "**Progress:** iteration {iteration}, {tool_calls} tool call(s)"),
+    (
+        "task_progress_running",
+        "**Progress:** iteration {iteration}, {tool_calls} tool call(s), running for {seconds}s",
+    ),
+    ("task_running_tools", "**Running tools:** {tools}"),
+    ("task_recent_output", "**Recent output:**"),
+    ("task_result", "**Result** ({seconds}s):\n{output}"),
+    ("task_error", "**Error:** {error}"),
+    ("task_not_found", "❌ Task not found: `{task}`"),
+    ("task_cancelled", "✓ Task cancelled: {task}"),
+    ("task_cancel_failed", "Failed to cancel task: {error}"),
+    (
+        "task_overview",
+        "📋 **Task Overview**\n\n\
+        **Statistics:**\n\
+        ⏳ Pending: {pending}\n\
+        ▶️ Running: {running}\n\
+        ✅ Completed: {completed}\n\
+        ❌ Failed: {failed}\n\
+        🚫 Cancelled: {cancelled}\n\n\
+        **Capacity:**\n\
+        • Max Concurrent: {max_concurrent}\n\
+        • Available Slots: {available}",
+    ),
+    ("task_overview_active", "**Active Tasks ({count}):**"),
+    ("task_overview_more", "...and {count} more tasks"),
+    ("task_overview_empty", "_No active tasks_"),
+    ("agent_select", "Select Agent\n\nCurrent: {current}\n\nTap to switch:"),
+    ("agent_info", "Current: {agent}\n\nTools: {tools}\n\n{description}\n\nUse /agent to switch."),
+    ("agent_info_not_found", "Agent '{agent}' not found."),
+    ("unknown_agent", "Unknown agent: '{agent}'\n\nAvailable: {available}"),
+    ("context_switched", "Switched to {context}\n\nTools: {tools}\n\n{description}"),
+    ("context_not_found", "Context not found: {context}"),
+    ("context_health", "Context Health\n\n{report}"),
+    ("fleet_select", "Select Fleet\n\nCurrent: {current}\n\nTap to switch:"),
+    (
+        "fleet_info",
+        "{fleet}\n\n{description}\n\nAgents:\n{agents}\n\nRouter: {router}\n\nUse /fleet to switch.",
+    ),
+    ("fleet_info_not_found", "Fleet '{fleet}' not found."),
+    ("fleet_switched", "Switched to {fleet}\n\nAgents: {agents}\n\n{description}"),
+    ("unknown_fleet", "Unknown fleet: '{fleet}'\n\nAvailable: {available}"),
+    ("fleet_not_found", "Fleet not found: {fleet}"),
+    ("fleet_running", "{emoji} Running {fleet} fleet..."),
+    ("fleet_no_agents", "Fleet '{fleet}' has no agents configured."),
+    ("fleet_binding_not_found", "Fleet '{fleet}' not found. Check fleets configuration."),
+    ("no_flows", "No flows available. Add flows to the flows directory."),
+    ("flows_unavailable", "No flows available."),
+    ("flow_select", "**Select a Flow**\n\nTap to run:"),
+    ("flow_select_more", "_...and {count} more flows._"),
+    ("flow_running", "Running flow: *{flow}*..."),
+    ("flow_not_found", "Flow not found: {flow}"),
+    ("flow_binding_running", "🔄 Running {flow} flow..."),
+    ("flow_binding_not_found", "Flow '{flow}' not found. Check flows configuration."),
+    ("flow_greeting", "Hi! How can I help you? Just ask me anything."),
+    ("flow_processing", "🔄 Processing with flow `{flow}`..."),
+    ("flow_completed", "✅ Flow `{flow}` completed successfully."),
+    ("flow_completed_empty", "✅ Flow `{flow}` completed."),
+    ("flow_failed", "❌ Flow `{flow}` failed: {error}"),
+    ("invalid_selection", "Invalid selection format. Please try again.\nReceived: {data}"),
+    ("unknown_selection", "Unknown selection type: {kind}"),
+    (
+        "approval_required",
+        "{output}\n\n⚠️ *This action requires approval*\n`{command}`\n\n\
+        React with ✅ to approve or ❌ to deny.",
+    ),
+    ("approval_request_failed", "❌ Failed to request approval: {error}"),
+    ("approval_denied", "❌ *Action denied by <@{user}>*\n```{command}```"),
+    ("approval_executing", "⚡ *Executing approved command...*\n```{command}```"),
+    ("command_no_output", "Command completed successfully (no output)"),
+    ("command_exit_code", "Command failed with exit code: {code}"),
+    ("command_spawn_failed", "Failed to execute command: {error}"),
+    (
+        "approved_command_succeeded",
+        "✅ *Command completed successfully*\n```{output}```\n*Approved by:* <@{user}>",
+    ),
+    ("approved_command_failed", "❌ *Command failed*\n```{output}```\n*Approved by:* <@{user}>"),
+    (
+        "temporarily_unavailable",
+        "⏸️ {target} is temporarily unavailable after repeated failures. Try again in {retry_after}s.",
+    ),
+    (
+        "write_blocked",
+        "Write operation blocked\n\n\
+        {platform} is read-only for safety.\n\n\
+        What you can do:\n\
+        - Read-only commands (get, list, describe, logs)\n\
+        - Use Slack or CLI for write operations",
+    ),
+    (
+        "not_context_approver",
+        "⚠️ <@{user}> is not an approver for the {emoji} *{context}* context. Approvers: {approvers}",
+    ),
+    (
+        "self_approval_denied",
+        "⚠️ <@{user}>, you cannot approve your own command. Ask another approver, or react with ❌ to cancel it.",
+    ),
+    (
+        "not_authorized_approver",
+        "⚠️ <@{user}> is not authorized to approve commands. Please contact an admin.",
+    ),
+    (
+        "safe_mode_blocked",
+        "🛑 Safe mode is on - write operations are blocked on every platform.\n\n\
+        {details}\n\n\
+        Read-only commands (get, list, describe, logs) still work.",
+    ),
+    (
+        "freeze_blocked",
+        "🚫 Write operations are blocked in the *{context}* context.\n\n\
+        {details}\n\n\
+        Read-only commands (get, list, describe, logs) still work.",
+    ),
+    ("safe_mode_active", "🛑 {details}"),
+    ("safe_mode_inactive", "✅ Safe mode is off."),
+    (
+        "safe_mode_enabled",
+        "🛑 Safe mode enabled. Write, delete and dangerous operations are blocked on every platform until /safemode off.",
+    ),
+    ("safe_mode_disabled", "✅ Safe mode disabled. Normal policies apply again."),
+    ("safe_mode_usage", "Usage: /safemode [status | on [reason] | off]"),
+    (
+        "not_safe_mode_admin",
+        "⚠️ <@{user}> is not allowed to turn safe mode off. Please contact an admin.",
+    ),
+    (
+        "safe_mode_off_no_admins",
+        "⚠️ Safe mode can't be turned off from chat because no admin users are configured. \
+        Send SIGUSR1 to the server or restart it without AOF_SAFE_MODE.",
+    ),
+    ("verbosity_current", "Reply verbosity: {level}"),
+    ("verbosity_set", "✅ Reply verbosity set to {level}."),
+    ("verbosity_usage", "Usage: /verbosity [terse | normal | verbose]"),
+    ("context_reachable", "✅ Connected - {details}"),
+    (
+        "context_unreachable",
+        "⚠️ Could not reach {context} - {details}\n\
+        Switched anyway; commands in this context will fail until the connection is fixed.",
+    ),
+];
+
+/// Per-locale message overrides
+///
+/// Serialized as `locale -> message id -> template`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MessageCatalog {
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl MessageCatalog {
+    /// Catalog with only the built-in English messages
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse overrides from YAML (or JSON)
+    pub fn from_yaml(content: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(content)
+    }
+
+    /// Override one message for a locale
+    pub fn with_message(
+        mut self,
+        locale: impl Into<String>,
+        id: impl Into<String>,
+        template: impl Into<String>,
+    ) -> Self {
+        self.locales
+            .entry(locale.into())
+            .or_default()
+            .insert(id.into(), template.into());
+        self
+    }
+
+    /// Locales with at least one override
+    pub fn locales(&self) -> Vec<&str> {
+        self.locales.keys().map(String::as_str).collect()
+    }
+
+    /// Template for a message id in a locale
+    ///
+    /// Tries the exact locale, then its language (`pt-BR` -> `pt`), then
+    /// English overrides and finally the built-in English text.
+    pub fn template(&self, locale: &str, id: &str) -> Option<&str> {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+        [locale, language, DEFAULT_LOCALE]
+            .into_iter()
+            .find_map(|l| self.locales.get(l).and_then(|messages| messages.get(id)))
+            .map(String::as_str)
+            .or_else(|| ENGLISH.iter().find(|(key, _)| *key == id).map(|(_, text)| *text))
+    }
+
+    /// Render a message, substituting `{name}` placeholders
+    ///
+    /// Unknown message ids render as the id itself so gaps are visible.
+    pub fn render(&self, locale: &str, id: &str, args: &[(&str, &str)]) -> String {
+        let Some(template) = self.template(locale, id) else {
+            return id.to_string();
+        };
+        args.iter().fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback_to_english() {
+        let catalog = MessageCatalog::new().with_message("es", "processing", "Procesando tu solicitud...");
+
+        assert_eq!(catalog.render("es", "processing", &[]), "Procesando tu solicitud...");
+        assert_eq!(catalog.render("es-MX", "processing", &[]), "Procesando tu solicitud...");
+        assert_eq!(catalog.render("fr", "processing", &[]), "Processing your request...");
+        assert_eq!(
+            catalog.render("es", "command_failed", &[("error", "timeout")]),
+            "Command failed: timeout"
+        );
+        assert_eq!(catalog.render("es", "no_such_message", &[]), "no_such_message");
+    }
+
+    #[test]
+    fn test_from_yaml() {
+        let catalog = MessageCatalog::from_yaml(
+            r#"
+de:
+  write_blocked: "Schreibvorgang blockiert: {platform} ist schreibgeschützt."
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            catalog.render("de", "write_blocked", &[("platform", "telegram")]),
+            "Schreibvorgang blockiert: telegram ist schreibgeschützt."
+        );
+        assert_eq!(catalog.locales(), vec!["de"]);
+    }
+}
@@ -0,0 +1,280 @@
+//! Trigger handler metrics
+//!
+//! Counts, errors and handling latency per platform and message kind,
+//! exportable in the Prometheus text format.
+//!
+//! Recording is lock-free: every (platform, kind) series is allocated when the
+//! platform is registered, and updates are plain atomic increments.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::command::CommandType;
+
+/// Upper bounds (seconds) of the handling latency histogram buckets
+const LATENCY_BUCKETS: [f64; 11] = [0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0];
+
+/// What kind of message the handler processed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    /// Built-in command (`/help`, `/status`, ...)
+    Command(CommandType),
+    /// Slash command routed through a command binding
+    Binding,
+    /// Free text routed to an agent
+    NaturalLanguage,
+    /// Inline keyboard callback
+    Callback,
+    /// Approval reaction
+    Reaction,
+    /// Text that was neither a command nor routable to an agent
+    Unparsed,
+}
+
+impl MessageKind {
+    /// Every kind, in export order
+    pub const ALL: [MessageKind; 17] = [
+        Self::Command(CommandType::Run),
+        Self::Command(CommandType::Create),
+        Self::Command(CommandType::Status),
+        Self::Command(CommandType::Cancel),
+        Self::Command(CommandType::List),
+        Self::Command(CommandType::Help),
+        Self::Command(CommandType::Info),
+        Self::Command(CommandType::Flows),
+        Self::Command(CommandType::Agent),
+        Self::Command(CommandType::Fleet),
+        Self::Command(CommandType::SafeMode),
+        Self::Command(CommandType::Verbosity),
+        Self::Binding,
+        Self::NaturalLanguage,
+        Self::Callback,
+        Self::Reaction,
+        Self::Unparsed,
+    ];
+
+    /// Label used in exported metrics
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Command(CommandType::Run) => "run",
+            Self::Command(CommandType::Create) => "create",
+            Self::Command(CommandType::Status) => "status",
+            Self::Command(CommandType::Cancel) => "cancel",
+            Self::Command(CommandType::List) => "list",
+            Self::Command(CommandType::Help) => "help",
+            Self::Command(CommandType::Info) => "info",
+            Self::Command(CommandType::Flows) => "flows",
+            Self::Command(CommandType::Agent) => "agent",
+            Self::Command(CommandType::Fleet) => "fleet",
+            Self::Command(CommandType::SafeMode) => "safemode",
+            Self::Command(CommandType::Verbosity) => "verbosity",
+            Self::Binding => "binding",
+            Self::NaturalLanguage => "natural_language",
+            Self::Callback => "callback",
+            Self::Reaction => "reaction",
+            Self::Unparsed => "unparsed",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|k| k == self).unwrap_or(Self::ALL.len() - 1)
+    }
+}
+
+/// Counters for one (platform, kind) series
+#[derive(Default)]
+struct SeriesMetrics {
+    count: AtomicU64,
+    errors: AtomicU64,
+    latency_micros: AtomicU64,
+    /// Non-cumulative bucket counts; the last slot is +Inf
+    buckets: [AtomicU64; LATENCY_BUCKETS.len() + 1],
+}
+
+/// Point-in-time view of one series
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeriesSnapshot {
+    /// Messages handled
+    pub count: u64,
+    /// Messages whose handling failed or produced an error response
+    pub errors: u64,
+    /// Total handling time
+    pub latency: Duration,
+}
+
+impl SeriesSnapshot {
+    /// Fraction of messages that errored
+    pub fn error_rate(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.errors as f64 / self.count as f64
+        }
+    }
+}
+
+/// Metrics for every registered platform
+#[derive(Default)]
+pub struct HandlerMetrics {
+    platforms: HashMap<String, Vec<SeriesMetrics>>,
+}
+
+impl HandlerMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocate the series for a platform
+    pub fn register_platform(&mut self, platform: &str) {
+        self.platforms.entry(platform.to_string()).or_insert_with(|| {
+            MessageKind::ALL.iter().map(|_| SeriesMetrics::default()).collect()
+        });
+    }
+
+    /// Record one handled message; unregistered platforms are ignored
+    pub fn record(&self, platform: &str, kind: MessageKind, latency: Duration, error: bool) {
+        let Some(series) = self.platforms.get(platform).map(|s| &s[kind.index()]) else {
+            return;
+        };
+
+        series.count.fetch_add(1, Ordering::Relaxed);
+        if error {
+            series.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        series
+            .latency_micros
+            .fetch_add(latency.as_micros() as u64, Ordering::Relaxed);
+
+        let secs = latency.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| secs <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        series.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Current values of one series
+    pub fn snapshot(&self, platform: &str, kind: MessageKind) -> Option<SeriesSnapshot> {
+        let series = &self.platforms.get(platform)?[kind.index()];
+        Some(SeriesSnapshot {
+            count: series.count.load(Ordering::Relaxed),
+            errors: series.errors.load(Ordering::Relaxed),
+            latency: Duration::from_micros(series.latency_micros.load(Ordering::Relaxed)),
+        })
+    }
+
+    /// Export in the Prometheus text exposition format
+    ///
+    /// Series that have not seen any message are omitted.
+    pub fn render_prometheus(&self) -> String {
+        let mut platforms: Vec<&String> = self.platforms.keys().collect();
+        platforms.sort();
+
+        let active: Vec<(&str, MessageKind, &SeriesMetrics)> = platforms
+            .iter()
+            .flat_map(|platform| {
+                let series = &self.platforms[*platform];
+                MessageKind::ALL
+                    .iter()
+                    .zip(series.iter())
+                    .filter(|(_, s)| s.count.load(Ordering::Relaxed) > 0)
+                    .map(|(kind, s)| (platform.as_str(), *kind, s))
+            })
+            .collect();
+
+        let mut out = String::new();
+        let labels = |platform: &str, kind: MessageKind| {
+            format!("platform=\"{}\",command=\"{}\"", platform, kind.label())
+        };
+
+        out.push_str("# HELP aof_trigger_messages_total Messages handled by the trigger handler\n");
+        out.push_str("# TYPE aof_trigger_messages_total counter\n");
+        for (platform, kind, s) in &active {
+            let _ = writeln!(
+                out,
+                "aof_trigger_messages_total{{{}}} {}",
+                labels(platform, *kind),
+                s.count.load(Ordering::Relaxed)
+            );
+        }
+
+        out.push_str("# HELP aof_trigger_errors_total Messages whose handling failed or returned an error\n");
+        out.push_str("# TYPE aof_trigger_errors_total counter\n");
+        for (platform, kind, s) in &active {
+            let _ = writeln!(
+                out,
+                "aof_trigger_errors_total{{{}}} {}",
+                labels(platform, *kind),
+                s.errors.load(Ordering::Relaxed)
+            );
+        }
+
+        out.push_str("# HELP aof_trigger_handling_seconds Time spent handling a message\n");
+        out.push_str("# TYPE aof_trigger_handling_seconds histogram\n");
+        for (platform, kind, s) in &active {
+            let labels = labels(platform, *kind);
+            let mut cumulative = 0;
+            for (i, bound) in LATENCY_BUCKETS.iter().enumerate() {
+                cumulative += s.buckets[i].load(Ordering::Relaxed);
+                let _ = writeln!(
+                    out,
+                    "aof_trigger_handling_seconds_bucket{{{},le=\"{}\"}} {}",
+                    labels, bound, cumulative
+                );
+            }
+            let count = s.count.load(Ordering::Relaxed);
+            let _ = writeln!(
+                out,
+                "aof_trigger_handling_seconds_bucket{{{},le=\"+Inf\"}} {}",
+                labels, count
+            );
+            let _ = writeln!(
+                out,
+                "aof_trigger_handling_seconds_sum{{{}}} {}",
+                labels,
+                s.latency_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0
+            );
+            let _ = writeln!(out, "aof_trigger_handling_seconds_count{{{}}} {}", labels, count);
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prometheus_export() {
+        let mut metrics = HandlerMetrics::new();
+        metrics.register_platform("slack");
+        metrics.register_platform("telegram");
+
+        let help = MessageKind::Command(CommandType::Help);
+        metrics.record("slack", help, Duration::from_millis(20), false);
+        metrics.record("slack", help, Duration::from_millis(300), true);
+        metrics.record("unregistered", help, Duration::from_millis(1), false);
+
+        let snapshot = metrics.snapshot("slack", help).unwrap();
+        assert_eq!(snapshot.count, 2);
+        assert_eq!(snapshot.errors, 1);
+        assert_eq!(snapshot.error_rate(), 0.5);
+        assert_eq!(snapshot.latency, Duration::from_millis(320));
+        assert!(metrics.snapshot("unregistered", help).is_none());
+
+        let text = metrics.render_prometheus();
+        assert!(text.contains("aof_trigger_messages_total{platform=\"slack\",command=\"help\"} 2"));
+        assert!(text.contains("aof_trigger_errors_total{platform=\"slack\",command=\"help\"} 1"));
+        assert!(text.contains("aof_trigger_handling_seconds_bucket{platform=\"slack\",command=\"help\",le=\"0.05\"} 1"));
+        assert!(text.contains("aof_trigger_handling_seconds_bucket{platform=\"slack\",command=\"help\",le=\"0.25\"} 1"));
+        assert!(text.contains("aof_trigger_handling_seconds_bucket{platform=\"slack\",command=\"help\",le=\"0.5\"} 2"));
+        assert!(text.contains("aof_trigger_handling_seconds_count{platform=\"slack\",command=\"help\"} 2"));
+        assert!(text.contains("aof_trigger_handling_seconds_sum{platform=\"slack\",command=\"help\"} 0.32"));
+
+        // Idle series are not exported
+        assert!(!text.contains("telegram"));
+    }
+}
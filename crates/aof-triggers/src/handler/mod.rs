@@ -5,18 +5,47 @@
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use dashmap::DashMap;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
-use crate::command::{CommandError, CommandType, TriggerCommand, TriggerTarget};
+use crate::command::{strip_command_prefix, CommandError, CommandType, TriggerCommand, TriggerTarget, DEFAULT_COMMAND_PREFIX};
 use crate::flow::{FlowRegistry, FlowRouter, FlowMatch};
 use crate::platforms::{TriggerMessage, TriggerPlatform, TriggerUser};
 use crate::response::{Action, ActionStyle, TriggerResponse, TriggerResponseBuilder};
-use aof_core::{AgentContext, AofError, AofResult};
-use aof_runtime::{Runtime, RuntimeOrchestrator, Task, TaskStatus, AgentFlowExecutor};
+use crate::safety::{
+    active_freeze, ApprovalAuditLog, ApprovalDecision, ApprovalOutcome, ApprovalResultStatus,
+    GuardedToolExecutor, PolicyEngine, SafeMode, SafetyContext, SafetySettings,
+};
+use aof_core::{AgentContext, AofError, AofResult, ApprovalConfig, AuditConfig};
+use aof_tools::{
+    CommandRunner, ContextHealth, ContextProbe, HealthReport, HealthStatus, ProbeTarget, ProcessRunner,
+    DEFAULT_PROBE_TIMEOUT_SECS,
+};
+use aof_runtime::{Runtime, RuntimeOrchestrator, StreamEvent, Task, TaskResult, TaskStatus, AgentFlowExecutor};
+
+mod breaker;
+mod clarification;
+mod messages;
+mod metrics;
+mod reply;
+mod routing;
+mod verbosity;
+
+pub use breaker::{Admission, BreakerState, CircuitBreaker, CircuitBreakerConfig};
+pub use clarification::{ClarificationRequest, PendingClarification};
+pub use messages::{MessageCatalog, DEFAULT_LOCALE};
+pub use metrics::{HandlerMetrics, MessageKind, SeriesSnapshot};
+pub use routing::{
+    bound_command_in_text, explain_route, resolve_command_binding, BindingRoute, BindingTarget, RouteExplanation,
+    DEFAULT_BINDING,
+};
+pub use verbosity::Verbosity;
+
+use reply::RunReply;
 
 /// Pending approval request for human-in-the-loop workflow
 #[derive(Debug, Clone)]
@@ -35,6 +64,8 @@ pub struct PendingApproval {
     pub agent_name: String,
     /// Original user message for context
     pub original_message: String,
+    /// Context the requester had active (its approver allowlist applies)
+    pub context_name: String,
 }
 
 /// Parse agent output for approval-related fields
@@ -64,6 +95,31 @@ fn parse_approval_output(output: &str) -> (bool, Option<String>, String) {
     (requires_approval, command, clean_output)
 }
 
+/// Append a message to a conversation, keeping the last 20
+fn push_conversation_entry(
+    memory: &DashMap<String, Vec<ConversationEntry>>,
+    key: String,
+    role: &str,
+    content: &str,
+) {
+    let entry = ConversationEntry {
+        content: content.to_string(),
+        role: role.to_string(),
+        timestamp: chrono::Utc::now(),
+    };
+
+    memory
+        .entry(key)
+        .and_modify(|messages| {
+            // Keep last 20 messages to avoid memory bloat
+            if messages.len() >= 20 {
+                messages.remove(0);
+            }
+            messages.push(entry.clone());
+        })
+        .or_insert_with(|| vec![entry]);
+}
+
 /// Helper trait to convert CommandError to AofError
 trait CommandErrorExt<T> {
     fn map_cmd_err(self) -> AofResult<T>;
@@ -88,6 +144,17 @@ pub struct CommandBinding {
     pub description: String,
 }
 
+impl From<&aof_core::CommandBinding> for CommandBinding {
+    fn from(binding: &aof_core::CommandBinding) -> Self {
+        Self {
+            agent: binding.agent.clone(),
+            fleet: binding.fleet.clone(),
+            flow: binding.flow.clone(),
+            description: binding.description.clone(),
+        }
+    }
+}
+
 /// Handler configuration
 #[derive(Debug, Clone)]
 pub struct TriggerHandlerConfig {
@@ -115,6 +182,42 @@ pub struct TriggerHandlerConfig {
     /// from platforms like Telegram when the daemon was down.
     /// Default: 60 seconds. Set to 0 to disable.
     pub max_message_age_secs: u64,
+
+    /// Locale for users and contexts without one (e.g. "en", "es", "pt-BR")
+    pub default_locale: String,
+
+    /// Localized overrides for bot messages (English built in)
+    pub messages: MessageCatalog,
+
+    /// Verbosity for users and contexts without one
+    pub default_verbosity: Verbosity,
+
+    /// Accepted command prefixes (e.g. "/", "!aof"); default: "/"
+    /// Use a non-slash prefix where `/` clashes with native slash commands.
+    pub command_prefixes: Vec<String>,
+
+    /// When to stop invoking an agent or flow that keeps failing
+    pub circuit_breaker: CircuitBreakerConfig,
+
+    /// How long an agent's clarification question waits for an answer (seconds)
+    pub clarification_timeout_secs: u64,
+
+    /// Users who may turn safe mode off from chat (empty: nobody; use
+    /// SIGUSR1 or restart without `AOF_SAFE_MODE`)
+    /// Anyone can turn safe mode on; it is an emergency brake.
+    pub admin_users: Vec<String>,
+
+    /// Probe a context's connections before every switch (`/context <name>`)
+    /// Without this, probes run only for `/context <name> --check`.
+    pub probe_context_switch: bool,
+
+    /// Who may approve writes in contexts without their own `approval` rules
+    /// (`allowed_users` empty: fall back to the platform's approval check)
+    pub approval: ApprovalConfig,
+
+    /// Where approve/deny decisions are audited in contexts without their own
+    /// `audit` config (None: kept in memory only)
+    pub audit: Option<AuditConfig>,
 }
 
 impl Default for TriggerHandlerConfig {
@@ -127,10 +230,25 @@ impl Default for TriggerHandlerConfig {
             default_agent: None,
             command_bindings: HashMap::new(),
             max_message_age_secs: 60, // Drop messages older than 1 minute
+            default_locale: DEFAULT_LOCALE.to_string(),
+            messages: MessageCatalog::new(),
+            default_verbosity: Verbosity::Normal,
+            command_prefixes: vec![DEFAULT_COMMAND_PREFIX.to_string()],
+            circuit_breaker: CircuitBreakerConfig::default(),
+            clarification_timeout_secs: 300, // 5 minutes
+            admin_users: Vec::new(),
+            probe_context_switch: false,
+            approval: ApprovalConfig::default(),
+            audit: None,
         }
     }
 }
 
+/// Timeout for the connectivity probe run before a context switch
+///
+/// Shorter than `DEFAULT_PROBE_TIMEOUT_SECS`: the user is waiting on the reply.
+pub const CONTEXT_SWITCH_PROBE_TIMEOUT_SECS: u64 = 5;
+
 /// Conversation memory entry for maintaining context across messages
 #[derive(Debug, Clone)]
 pub struct ConversationEntry {
@@ -171,6 +289,18 @@ pub struct TriggerHandler {
     /// Pending approvals (message_ts -> PendingApproval)
     pending_approvals: Arc<DashMap<String, PendingApproval>>,
 
+    /// Structured record of approve/deny decisions
+    approval_audit: Arc<ApprovalAuditLog>,
+
+    /// Questions awaiting the user's answer (conversation key -> PendingClarification)
+    pending_clarifications: Arc<DashMap<String, PendingClarification>>,
+
+    /// Per-platform, per-command counters and latency
+    metrics: HandlerMetrics,
+
+    /// Consecutive-failure breakers per agent and flow
+    breakers: Arc<CircuitBreaker>,
+
     /// Conversation memory per channel/thread (channel_id:thread_id -> messages)
     /// Maintains conversation context for natural language interactions
     conversation_memory: Arc<DashMap<String, Vec<ConversationEntry>>>,
@@ -190,9 +320,21 @@ pub struct TriggerHandler {
     /// Tracks which fleet each user has selected
     user_fleet_sessions: Arc<DashMap<String, String>>,
 
+    /// User locale preferences (user_id -> locale)
+    user_locales: Arc<DashMap<String, String>>,
+
+    /// User verbosity preferences (user_id -> verbosity)
+    user_verbosity: Arc<DashMap<String, Verbosity>>,
+
     /// Available fleets (name -> config)
     /// Fleet = team of single-purpose agents with LLM-based routing
     available_fleets: Arc<DashMap<String, FleetConfig>>,
+
+    /// Global kill switch that blocks all write operations
+    safe_mode: Arc<SafeMode>,
+
+    /// Runs context connectivity probes (kubectl / aws)
+    probe_runner: Arc<dyn CommandRunner>,
 }
 
 /// Context configuration bundling agent + connection + environment
@@ -232,6 +374,57 @@ pub struct ContextConfig {
     /// Read-only mode - blocks write/delete/dangerous operations
     /// Default: true for mobile platforms (Telegram, WhatsApp), false for CLI/Slack
     pub read_only: bool,
+
+    /// Who may approve writes proposed in this context (None: the handler's `approval`)
+    pub approval: Option<ApprovalConfig>,
+
+    /// Where approve/deny decisions in this context are audited (None: the handler's `audit`)
+    pub audit: Option<AuditConfig>,
+
+    /// Locale for bot messages in this context (None: handler default)
+    pub locale: Option<String>,
+
+    /// Reply verbosity in this context (None: handler default)
+    pub verbosity: Option<Verbosity>,
+
+    /// Safety settings, including change freeze windows during which write
+    /// operations are blocked - for messages and agent tool calls alike
+    pub safety: SafetySettings,
+}
+
+impl ContextConfig {
+    /// Whether this context defines its own approver allowlist
+    pub fn has_approvers(&self) -> bool {
+        self.approval.as_ref().is_some_and(|approval| !approval.allowed_users.is_empty())
+    }
+
+    /// Safety context enforcing this context's settings and `safe_mode`
+    pub fn safety_context(&self, name: &str, safe_mode: Arc<SafeMode>) -> SafetyContext {
+        SafetyContext::new(name)
+            .with_settings(self.safety.clone())
+            .with_safe_mode(safe_mode)
+    }
+
+    /// Connectivity probes for the connections this context configures
+    pub fn health_probe(&self, name: &str) -> ContextProbe {
+        let mut targets = Vec::new();
+        if self.kubeconfig.is_some() || self.kubecontext.is_some() {
+            targets.push(ProbeTarget::Kubernetes {
+                kubeconfig: self.kubeconfig.clone(),
+                context: self.kubecontext.clone(),
+            });
+        }
+        if self.aws_profile.is_some() || self.aws_region.is_some() {
+            targets.push(ProbeTarget::Aws {
+                profile: self.aws_profile.clone(),
+                region: self.aws_region.clone(),
+            });
+        }
+        ContextProbe {
+            context: name.to_string(),
+            targets,
+        }
+    }
 }
 
 /// Fleet configuration - team of agents for a purpose
@@ -357,14 +550,23 @@ impl TriggerHandler {
             runtime: Arc::new(RwLock::new(Runtime::new())),
             agents_dir: None,
             pending_approvals: Arc::new(DashMap::new()),
+            approval_audit: Arc::new(ApprovalAuditLog::new()),
+            pending_clarifications: Arc::new(DashMap::new()),
+            metrics: HandlerMetrics::new(),
+            breakers: Arc::new(CircuitBreaker::default()),
             conversation_memory: Arc::new(DashMap::new()),
             user_context_sessions: Arc::new(DashMap::new()),
             available_contexts: Arc::new(DashMap::new()),
             user_fleet_sessions: Arc::new(DashMap::new()),
+            user_locales: Arc::new(DashMap::new()),
+            user_verbosity: Arc::new(DashMap::new()),
             available_fleets: Arc::new(DashMap::new()),
+            safe_mode: SafeMode::global(),
+            probe_runner: Arc::new(ProcessRunner),
         };
         handler.init_default_contexts();
         handler.init_default_fleets();
+        handler.install_tool_guard();
         handler
     }
 
@@ -373,20 +575,29 @@ impl TriggerHandler {
         let handler = Self {
             orchestrator,
             platforms: HashMap::new(),
+            breakers: Arc::new(CircuitBreaker::new(config.circuit_breaker.clone())),
             config,
             user_tasks: Arc::new(DashMap::new()),
             flow_router: None,
             runtime: Arc::new(RwLock::new(Runtime::new())),
             agents_dir: None,
             pending_approvals: Arc::new(DashMap::new()),
+            approval_audit: Arc::new(ApprovalAuditLog::new()),
+            pending_clarifications: Arc::new(DashMap::new()),
+            metrics: HandlerMetrics::new(),
             conversation_memory: Arc::new(DashMap::new()),
             user_context_sessions: Arc::new(DashMap::new()),
             available_contexts: Arc::new(DashMap::new()),
             user_fleet_sessions: Arc::new(DashMap::new()),
+            user_locales: Arc::new(DashMap::new()),
+            user_verbosity: Arc::new(DashMap::new()),
             available_fleets: Arc::new(DashMap::new()),
+            safe_mode: SafeMode::global(),
+            probe_runner: Arc::new(ProcessRunner),
         };
         handler.init_default_contexts();
         handler.init_default_fleets();
+        handler.install_tool_guard();
         handler
     }
 
@@ -408,6 +619,11 @@ impl TriggerHandler {
             tools: vec!["kubectl".to_string(), "helm".to_string()],
             env: std::collections::HashMap::new(),
             read_only: true,
+            approval: None,
+            audit: None,
+            locale: None,
+            verbosity: None,
+            safety: SafetySettings::default(),
         });
 
         // AWS Agent
@@ -425,6 +641,11 @@ impl TriggerHandler {
             tools: vec!["aws".to_string()],
             env: std::collections::HashMap::new(),
             read_only: true,
+            approval: None,
+            audit: None,
+            locale: None,
+            verbosity: None,
+            safety: SafetySettings::default(),
         });
 
         // Docker Agent
@@ -442,6 +663,11 @@ impl TriggerHandler {
             tools: vec!["docker".to_string(), "shell".to_string()],
             env: std::collections::HashMap::new(),
             read_only: true,
+            approval: None,
+            audit: None,
+            locale: None,
+            verbosity: None,
+            safety: SafetySettings::default(),
         });
 
         // DevOps Agent (full stack)
@@ -466,6 +692,11 @@ impl TriggerHandler {
             ],
             env: std::collections::HashMap::new(),
             read_only: true,
+            approval: None,
+            audit: None,
+            locale: None,
+            verbosity: None,
+            safety: SafetySettings::default(),
         });
     }
 
@@ -635,6 +866,73 @@ impl TriggerHandler {
         self.pending_approvals.clone()
     }
 
+    /// Get pending clarification questions (conversation key -> PendingClarification)
+    pub fn pending_clarifications(&self) -> Arc<DashMap<String, PendingClarification>> {
+        self.pending_clarifications.clone()
+    }
+
+    /// Get the approval audit log (for reporting on past decisions)
+    pub fn approval_audit(&self) -> Arc<ApprovalAuditLog> {
+        self.approval_audit.clone()
+    }
+
+    /// Use a shared approval audit log (e.g. one backed by a file sink)
+    pub fn with_approval_audit(mut self, audit: Arc<ApprovalAuditLog>) -> Self {
+        self.approval_audit = audit;
+        self
+    }
+
+    /// Get the safe-mode switch (shared with every handler by default)
+    pub fn safe_mode(&self) -> Arc<SafeMode> {
+        self.safe_mode.clone()
+    }
+
+    /// Use a specific safe-mode switch instead of the process-wide one
+    pub fn with_safe_mode(mut self, safe_mode: Arc<SafeMode>) -> Self {
+        self.set_safe_mode(safe_mode);
+        self
+    }
+
+    /// Use a specific safe-mode switch instead of the process-wide one (mutable)
+    pub fn set_safe_mode(&mut self, safe_mode: Arc<SafeMode>) {
+        self.safe_mode = safe_mode;
+        self.install_tool_guard();
+    }
+
+    /// Check every agent tool call against safe mode and change freezes
+    ///
+    /// Messages are checked before they reach an agent, but a harmless request
+    /// can still lead the agent to call a write tool; the guard refuses those
+    /// calls at execution time. An agent's calls are subject to the freeze
+    /// windows of every context that uses it.
+    fn install_tool_guard(&self) {
+        let contexts = self.available_contexts.clone();
+        let safe_mode = self.safe_mode.clone();
+        let default_agent = self.config.default_agent.clone();
+        let layer = GuardedToolExecutor::layer(self.safe_mode.clone(), move |agent| {
+            contexts
+                .iter()
+                .filter(|entry| entry.agent_ref.as_ref().or(default_agent.as_ref()).is_some_and(|a| a == agent))
+                .map(|entry| entry.safety_context(entry.key(), safe_mode.clone()))
+                .collect()
+        });
+        match self.runtime.try_write() {
+            Ok(mut runtime) => runtime.set_tool_layer(layer),
+            Err(_) => warn!("Runtime is busy; agent tool guards keep their previous safe mode and freeze windows"),
+        }
+    }
+
+    /// Run context probes through a custom runner (e.g. canned output in tests)
+    pub fn with_probe_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.probe_runner = runner;
+        self
+    }
+
+    /// Run context probes through a custom runner (mutable)
+    pub fn set_probe_runner(&mut self, runner: Arc<dyn CommandRunner>) {
+        self.probe_runner = runner;
+    }
+
     /// Get conversation key for a channel/thread combination
     fn get_conversation_key(channel_id: &str, thread_id: Option<&str>) -> String {
         match thread_id {
@@ -646,22 +944,7 @@ impl TriggerHandler {
     /// Add a message to conversation memory
     fn add_to_conversation(&self, channel_id: &str, thread_id: Option<&str>, role: &str, content: &str) {
         let key = Self::get_conversation_key(channel_id, thread_id);
-        let entry = ConversationEntry {
-            content: content.to_string(),
-            role: role.to_string(),
-            timestamp: chrono::Utc::now(),
-        };
-
-        self.conversation_memory
-            .entry(key)
-            .and_modify(|messages| {
-                // Keep last 20 messages to avoid memory bloat
-                if messages.len() >= 20 {
-                    messages.remove(0);
-                }
-                messages.push(entry.clone());
-            })
-            .or_insert_with(|| vec![entry]);
+        push_conversation_entry(&self.conversation_memory, key, role, content);
     }
 
     /// Get conversation history for context
@@ -726,6 +1009,7 @@ impl TriggerHandler {
     /// Set runtime for agent execution
     pub fn set_runtime(&mut self, runtime: Arc<RwLock<Runtime>>) {
         self.runtime = runtime;
+        self.install_tool_guard();
     }
 
     /// Load flows from a directory and set up the router
@@ -822,9 +1106,20 @@ impl TriggerHandler {
     pub fn register_platform(&mut self, platform: Arc<dyn TriggerPlatform>) {
         let name = platform.platform_name();
         info!("Registering platform: {}", name);
+        self.metrics.register_platform(name);
         self.platforms.insert(name.to_string(), platform);
     }
 
+    /// Handler metrics (commands, errors, latency)
+    pub fn metrics(&self) -> &HandlerMetrics {
+        &self.metrics
+    }
+
+    /// Circuit breakers for agents and flows
+    pub fn circuit_breaker(&self) -> &CircuitBreaker {
+        &self.breakers
+    }
+
     /// Get registered platform
     pub fn get_platform(&self, name: &str) -> Option<&Arc<dyn TriggerPlatform>> {
         self.platforms.get(name)
@@ -867,6 +1162,51 @@ impl TriggerHandler {
             .get(platform)
             .ok_or_else(|| aof_core::AofError::agent(format!("Unknown platform: {}", platform)))?;
 
+        // Route the message, noting whether any error response was sent
+        let started = std::time::Instant::now();
+        let kind = self.message_kind(&message);
+        let observer = Arc::new(ErrorObserver::new(platform_impl.clone()));
+        let observed: Arc<dyn TriggerPlatform> = observer.clone();
+
+        let result = self.route_message(message, &observed).await;
+
+        self.metrics.record(
+            platform,
+            kind,
+            started.elapsed(),
+            result.is_err() || observer.saw_error(),
+        );
+        result
+    }
+
+    /// Classify a message the way `route_message` will route it
+    fn message_kind(&self, message: &TriggerMessage) -> MessageKind {
+        if message.metadata.get("event_type").and_then(|v| v.as_str()) == Some("reaction_added") {
+            return MessageKind::Reaction;
+        }
+        if message.text.starts_with("callback:") {
+            return MessageKind::Callback;
+        }
+
+        if let (Some(cmd_name), _) = self.extract_command_binding(message) {
+            if resolve_command_binding(&self.config.command_bindings, &cmd_name).is_some() {
+                return MessageKind::Binding;
+            }
+        }
+
+        match TriggerCommand::parse_with_prefixes(message, &self.config.command_prefixes) {
+            Ok(cmd) => MessageKind::Command(cmd.command_type),
+            Err(_) if self.get_user_agent(&message.user.id).is_some() => MessageKind::NaturalLanguage,
+            Err(_) => MessageKind::Unparsed,
+        }
+    }
+
+    /// Route a message to the reaction, callback, binding, command or agent path
+    async fn route_message(
+        &self,
+        message: TriggerMessage,
+        platform_impl: &Arc<dyn TriggerPlatform>,
+    ) -> AofResult<()> {
         // Check for reaction events (for approval workflow)
         if let Some(event_type) = message.metadata.get("event_type") {
             info!("Detected event_type in metadata: {:?}", event_type);
@@ -880,10 +1220,7 @@ impl TriggerHandler {
         if let Some(count) = self.user_tasks.get(&message.user.id) {
             if *count >= self.config.max_tasks_per_user {
                 let response = TriggerResponseBuilder::new()
-                    .text(format!(
-                        "You have too many active tasks ({}). Please wait for some to complete.",
-                        *count
-                    ))
+                    .text(self.message(&message.user.id, "too_many_tasks", &[("count", &(*count).to_string())]))
                     .error()
                     .build();
 
@@ -898,98 +1235,80 @@ impl TriggerHandler {
             return self.handle_callback(&message, platform_impl).await;
         }
 
+        // An answer to an agent's clarification question resumes that agent
+        if let Some(pending) = self.take_clarification(&message) {
+            info!("Resuming agent '{}' with clarification answer from {}", pending.agent_name, message.user.id);
+            let mut resumed = message.clone();
+            resumed.text = pending.resume_input(&message.text);
+            return self.handle_natural_language(&resumed, platform_impl, &pending.agent_name).await;
+        }
+
         // Check for command bindings (works across all platforms)
         // - Slack/Discord: metadata.event_type = "slash_command", metadata.command = "/aof"
         // - Telegram/WhatsApp: message.text starts with "/command"
         let (command_name, command_text) = self.extract_command_binding(&message);
 
         if let Some(cmd_name) = command_name {
-            // Check if we have a binding for this command
-            if let Some(binding) = self.config.command_bindings.get(&cmd_name) {
-                // Check for builtin handler - skip binding and use built-in command handler
-                if binding.agent.as_deref() == Some("builtin") {
-                    info!("Command '{}' uses builtin handler, falling through to built-in command parser", cmd_name);
-                    // Fall through to TriggerCommand::parse below which handles built-ins
-                } else {
-                info!("Command '{}' matched binding: {:?}", cmd_name, binding);
-
-                // Create modified message with context from metadata if command text is empty
-                let mut routed_message = message.clone();
-                let cmd_text = command_text.clone().unwrap_or_default();
-
-                // If command text is empty, construct context from metadata (for PR/issue commands)
-                if cmd_text.trim().is_empty() {
-                    // Build context from metadata for commands like /review
-                    let mut context_parts = Vec::new();
-
-                    if let Some(pr_url) = message.metadata.get("pr_html_url").and_then(|v| v.as_str()) {
-                        context_parts.push(format!("Review the PR at: {}", pr_url));
-                    } else if let Some(issue_url) = message.metadata.get("issue_html_url").and_then(|v| v.as_str()) {
-                        context_parts.push(format!("Review the PR/issue at: {}", issue_url));
-                    }
+            match resolve_command_binding(&self.config.command_bindings, &cmd_name) {
+                Some(route) if route.is_default() => {
+                    let mut routed_message = message.clone();
+                    routed_message.text = command_text.unwrap_or_else(|| message.text.clone());
+                    info!("Routing command '{}' to default {}", cmd_name, route.target);
+                    return self.dispatch_binding(&routed_message, platform_impl, &route.target).await;
+                }
+                Some(route) => {
+                    info!("Command '{}' matched binding: {}", cmd_name, route.target);
+
+                    // Create modified message with context from metadata if command text is empty
+                    let mut routed_message = message.clone();
+                    let cmd_text = command_text.clone().unwrap_or_default();
+
+                    // If command text is empty, construct context from metadata (for PR/issue commands)
+                    if cmd_text.trim().is_empty() {
+                        // Build context from metadata for commands like /review
+                        let mut context_parts = Vec::new();
+
+                        if let Some(pr_url) = message.metadata.get("pr_html_url").and_then(|v| v.as_str()) {
+                            context_parts.push(format!("Review the PR at: {}", pr_url));
+                        } else if let Some(issue_url) = message.metadata.get("issue_html_url").and_then(|v| v.as_str()) {
+                            context_parts.push(format!("Review the PR/issue at: {}", issue_url));
+                        }
 
-                    if let Some(pr_title) = message.metadata.get("pr_title").and_then(|v| v.as_str()) {
-                        context_parts.push(format!("Title: {}", pr_title));
-                    } else if let Some(issue_title) = message.metadata.get("issue_title").and_then(|v| v.as_str()) {
-                        context_parts.push(format!("Title: {}", issue_title));
-                    }
+                        if let Some(pr_title) = message.metadata.get("pr_title").and_then(|v| v.as_str()) {
+                            context_parts.push(format!("Title: {}", pr_title));
+                        } else if let Some(issue_title) = message.metadata.get("issue_title").and_then(|v| v.as_str()) {
+                            context_parts.push(format!("Title: {}", issue_title));
+                        }
 
-                    if let Some(comment_body) = message.metadata.get("comment_body").and_then(|v| v.as_str()) {
-                        if !comment_body.starts_with('/') {
-                            context_parts.push(format!("Additional context: {}", comment_body));
+                        if let Some(comment_body) = message.metadata.get("comment_body").and_then(|v| v.as_str()) {
+                            if strip_command_prefix(comment_body, &self.config.command_prefixes).is_none() {
+                                context_parts.push(format!("Additional context: {}", comment_body));
+                            }
                         }
-                    }
 
-                    routed_message.text = if context_parts.is_empty() {
-                        format!("Execute {} command", cmd_name)
+                        routed_message.text = if context_parts.is_empty() {
+                            format!("Execute {} command", cmd_name)
+                        } else {
+                            context_parts.join("\n")
+                        };
+                        info!("Constructed context for command '{}': {}", cmd_name, routed_message.text);
                     } else {
-                        context_parts.join("\n")
-                    };
-                    info!("Constructed context for command '{}': {}", cmd_name, routed_message.text);
-                } else {
-                    routed_message.text = cmd_text;
-                }
-
-                // Route to flow if specified (highest priority - complex workflows)
-                if let Some(ref flow_name) = binding.flow {
-                    info!("Routing command '{}' to flow '{}'", cmd_name, flow_name);
-                    return self.handle_flow_execution(&routed_message, platform_impl, flow_name).await;
-                }
-
-                // Route to fleet if specified (multi-agent teams)
-                if let Some(ref fleet_name) = binding.fleet {
-                    info!("Routing command '{}' to fleet '{}'", cmd_name, fleet_name);
-                    return self.handle_fleet_execution(&routed_message, platform_impl, fleet_name).await;
-                }
-
-                // Route to agent if specified (single agent)
-                if let Some(ref agent_name) = binding.agent {
-                    info!("Routing command '{}' to agent '{}'", cmd_name, agent_name);
-                    return self.handle_natural_language(&routed_message, platform_impl, agent_name).await;
-                }
-                } // end else (non-builtin handler)
-            }
-
-            // Check for default binding (for any unbound slash command)
-            if let Some(binding) = self.config.command_bindings.get("default") {
-                let mut routed_message = message.clone();
-                routed_message.text = command_text.unwrap_or_else(|| message.text.clone());
+                        routed_message.text = cmd_text;
+                    }
 
-                if let Some(ref agent_name) = binding.agent {
-                    info!("Routing command '{}' to default agent '{}'", cmd_name, agent_name);
-                    return self.handle_natural_language(&routed_message, platform_impl, agent_name).await;
+                    info!("Routing command '{}' to {}", cmd_name, route.target);
+                    return self.dispatch_binding(&routed_message, platform_impl, &route.target).await;
                 }
+                // Builtin and unbound commands fall through to TriggerCommand::parse below
+                None => info!("No binding for command '{}', falling through to built-in command parser", cmd_name),
             }
-
-            // Fall through to default agent handling below
-            info!("No binding for command '{}', using default agent", cmd_name);
         }
 
         // Note: Flow routing now happens through Trigger command bindings, not flow-embedded triggers.
         // The FlowRouter provides simple lookup by name for explicit flow references.
 
         // Parse command - if it fails and we have a default agent, route to it
-        let cmd = match TriggerCommand::parse(&message) {
+        let cmd = match TriggerCommand::parse_with_prefixes(&message, &self.config.command_prefixes) {
             Ok(cmd) => cmd,
             Err(e) => {
                 // Get user's session agent or fall back to default
@@ -1001,7 +1320,10 @@ impl TriggerHandler {
                 }
 
                 warn!("Failed to parse command: {}", e);
-                let response = self.handle_parse_error(&message, e).await;
+                let response = self
+                    .handle_parse_error(&message, e)
+                    .await
+                    .with_ephemeral_user(message.user.id.clone());
                 let _ = platform_impl.send_response(&message.channel_id, response).await;
                 return Ok(());
             }
@@ -1015,7 +1337,7 @@ impl TriggerHandler {
         );
         if self.config.auto_ack && !is_git_platform {
             let ack = TriggerResponseBuilder::new()
-                .text("Processing your request...")
+                .text(self.message(&message.user.id, "processing", &[]))
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, ack).await;
         }
@@ -1026,8 +1348,9 @@ impl TriggerHandler {
             Err(e) => {
                 error!("Command execution failed: {}", e);
                 TriggerResponseBuilder::new()
-                    .text(format!("Command failed: {}", e))
+                    .text(self.message(&message.user.id, "command_failed", &[("error", &e.to_string())]))
                     .error()
+                    .ephemeral(&message.user.id)
                     .build()
             }
         };
@@ -1058,6 +1381,8 @@ impl TriggerHandler {
             CommandType::Flows => Ok(self.handle_flows_command(cmd).await),
             CommandType::Agent => Ok(self.handle_agent_command(cmd).await),
             CommandType::Fleet => Ok(self.handle_fleet_command(cmd).await),
+            CommandType::SafeMode => Ok(self.handle_safe_mode_command(cmd)),
+            CommandType::Verbosity => Ok(self.handle_verbosity_command(cmd)),
         }
     }
 
@@ -1073,11 +1398,15 @@ impl TriggerHandler {
 
                 if input.is_empty() {
                     return Ok(TriggerResponseBuilder::new()
-                        .text(format!("Usage: `/run agent {} <your message>`", agent_name))
+                        .text(self.message(&cmd.context.user_id, "run_usage", &[("agent", agent_name)]))
                         .error()
                         .build());
                 }
 
+                if cmd.get_param("background").is_some() || cmd.get_param("bg").is_some() {
+                    return self.start_background_task(&cmd, agent_name, input).await;
+                }
+
                 // Get platform for response
                 let platform_impl = self
                     .platforms
@@ -1112,16 +1441,141 @@ impl TriggerHandler {
                 Ok(TriggerResponseBuilder::new().build())
             }
             _ => Ok(TriggerResponseBuilder::new()
-                .text(format!("Run command not supported for {:?}", cmd.target))
+                .text(self.message(&cmd.context.user_id, "run_not_supported", &[("target", &format!("{:?}", cmd.target))]))
                 .error()
                 .build()),
         }
     }
 
+    /// Start an agent run as a background task and return its id immediately
+    ///
+    /// Progress (iteration, tool calls, recent tool output) is recorded on the
+    /// orchestrator's task handle for `/status task <id>`. When the task
+    /// finishes, its result is delivered to the channel it was started from
+    /// like an interactive run's, under a completion notice.
+    async fn start_background_task(
+        &self,
+        cmd: &TriggerCommand,
+        agent_name: &str,
+        input: String,
+    ) -> AofResult<TriggerResponse> {
+        let ctx = &cmd.context;
+        let platform_impl = self
+            .platforms
+            .get(&ctx.platform)
+            .cloned()
+            .ok_or_else(|| AofError::agent(format!("Unknown platform: {}", ctx.platform)))?;
+
+        // Background runs get the same write checks as interactive ones
+        if is_write_operation(&input) {
+            if let Some(text) = self.write_block_notice(&ctx.user_id) {
+                return Ok(TriggerResponseBuilder::new()
+                    .text(text)
+                    .error()
+                    .ephemeral(&ctx.user_id)
+                    .build());
+            }
+            if matches!(ctx.platform.as_str(), "telegram" | "whatsapp") {
+                return Ok(TriggerResponseBuilder::new()
+                    .text(self.message(&ctx.user_id, "write_blocked", &[("platform", &ctx.platform)]))
+                    .error()
+                    .ephemeral(&ctx.user_id)
+                    .build());
+            }
+        }
+
+        if !self.runtime.read().await.has_agent(agent_name) {
+            return Ok(TriggerResponseBuilder::new()
+                .text(self.message(&ctx.user_id, "agent_not_found", &[("agent", agent_name)]))
+                .error()
+                .build());
+        }
+
+        let breaker_key = CircuitBreaker::agent_key(agent_name);
+        if let Some(text) = self.circuit_open_notice(&breaker_key, agent_name, &ctx.user_id) {
+            return Ok(TriggerResponseBuilder::new().text(text).error().build());
+        }
+
+        let reply = self.run_reply(agent_name, &ctx.user_id, &ctx.channel_id, ctx.thread_id.as_deref(), &input);
+
+        let task_id = format!("task-{}", &uuid::Uuid::new_v4().simple().to_string()[..8]);
+        let task = Task::new(
+            task_id.clone(),
+            format!("Background run of {}", agent_name),
+            agent_name.to_string(),
+            input,
+        )
+        .with_metadata("user_id".to_string(), serde_json::json!(ctx.user_id))
+        .with_metadata("channel_id".to_string(), serde_json::json!(ctx.channel_id))
+        .with_metadata("platform".to_string(), serde_json::json!(ctx.platform));
+        let handle = self.orchestrator.submit_task(task);
+
+        // Run the agent with streaming so tool activity lands in the task's progress
+        let runtime = self.runtime.clone();
+        let verbosity = self.get_user_verbosity(&ctx.user_id);
+        let recorder_handle = handle.clone();
+        self.orchestrator
+            .execute_task(&task_id, move |task| async move {
+                let (stream_tx, mut stream_rx) = tokio::sync::mpsc::channel::<StreamEvent>(64);
+                let recorder = tokio::spawn(async move {
+                    while let Some(event) = stream_rx.recv().await {
+                        recorder_handle.record_progress(&event).await;
+                    }
+                });
+                let result = runtime
+                    .read()
+                    .await
+                    .execute_streaming(&task.agent_name, &verbosity.apply_to_prompt(&task.input), stream_tx)
+                    .await;
+                let _ = recorder.await;
+                result
+            })
+            .await?;
+
+        info!("Started background task {} for agent {} (user: {})", task_id, agent_name, ctx.user_id);
+
+        // Deliver the result once the task finishes, the same way an
+        // interactive run is delivered (breaker, clarification, approval)
+        let notify_id = task_id.clone();
+        tokio::spawn(async move {
+            let Ok(result) = handle.wait().await else {
+                return;
+            };
+
+            let status = handle.status().await;
+            let header = background_task_notification(&reply, &notify_id, status, &result);
+            if status == TaskStatus::Cancelled {
+                let mut builder = TriggerResponseBuilder::new()
+                    .text(header)
+                    .metadata("task_id", serde_json::json!(notify_id))
+                    .error();
+                if let Some(thread_id) = reply.thread_id.clone() {
+                    builder = builder.thread_id(thread_id);
+                }
+                if let Err(e) = platform_impl.send_response(&reply.channel_id, builder.build()).await {
+                    error!("Failed to post cancellation of task {}: {:?}", notify_id, e);
+                }
+                return;
+            }
+
+            let outcome = if result.success {
+                Ok(result.output)
+            } else {
+                Err(result.error.unwrap_or_else(|| "unknown error".to_string()))
+            };
+            reply.for_task(&notify_id, header).deliver(&platform_impl, outcome).await;
+        });
+
+        Ok(TriggerResponseBuilder::new()
+            .text(self.message(&ctx.user_id, "task_started", &[("task", &task_id), ("agent", agent_name)]))
+            .metadata("task_id", serde_json::json!(task_id))
+            .build())
+    }
+
     /// Handle create command
     async fn handle_create_command(&self, cmd: TriggerCommand) -> AofResult<TriggerResponse> {
         Ok(TriggerResponseBuilder::new()
-            .text("Create command not yet implemented")
+            .text(self.message(&cmd.context.user_id, "create_not_implemented", &[]))
             .build())
     }
 
@@ -1130,6 +1584,7 @@ impl TriggerHandler {
         match cmd.target {
             TriggerTarget::Task => {
                 let task_id = cmd.get_arg(0).map_cmd_err()?;
+                let user_id = &cmd.context.user_id;
 
                 if let Some(handle) = self.orchestrator.get_task(task_id) {
                     let task = handle.task().await;
@@ -1144,19 +1599,27 @@ impl TriggerHandler {
                         TaskStatus::Cancelled => "🚫",
                     };
 
-                    let mut text = format!(
-                        "{} **Task Status**\n\n**ID:** `{}`\n**Name:** {}\n**Agent:** {}\n**Status:** {:?}",
-                        status_icon, task.id, task.name, task.agent_name, status
+                    let mut text = self.message(
+                        user_id,
+                        "task_status",
+                        &[
+                            ("icon", status_icon),
+                            ("task", &task.id),
+                            ("name", &task.name),
+                            ("agent", &task.agent_name),
+                            ("status", &format!("{:?}", status)),
+                        ],
                     );
 
                     // Add priority if set
                     if task.priority > 0 {
-                        text.push_str(&format!("\n**Priority:** {}", task.priority));
+                        let line = self.message(user_id, "task_priority", &[("priority", &task.priority.to_string())]);
+                        text.push_str(&format!("\n{}", line));
                     }
 
                     // Add metadata if present
                     if !task.metadata.is_empty() {
-                        text.push_str("\n\n**Metadata:**");
+                        text.push_str(&format!("\n\n{}", self.message(user_id, "task_metadata", &[])));
                         for (key, value) in &task.metadata {
                             text.push_str(&format!("\n• {}: {}", key, value));
                         }
@@ -1168,20 +1631,75 @@ impl TriggerHandler {
                     } else {
                         task.input.clone()
                     };
-                    text.push_str(&format!("\n\n**Input:** {}", input_preview));
+                    let line = self.message(user_id, "task_input", &[("input", &input_preview)]);
+                    text.push_str(&format!("\n\n{}", line));
+
+                    // Progress streamed from the agent (background tasks)
+                    let progress = handle.progress().await;
+                    if let Some(started_at) = progress.started_at {
+                        let iteration = progress.iteration.to_string();
+                        let tool_calls = progress.tool_calls.to_string();
+                        let line = if status == TaskStatus::Running {
+                            let elapsed = (chrono::Utc::now() - started_at).num_seconds().max(0);
+                            self.message(
+                                user_id,
+                                "task_progress_running",
+                                &[
+                                    ("iteration", &iteration),
+                                    ("tool_calls", &tool_calls),
+                                    ("seconds", &elapsed.to_string()),
+                                ],
+                            )
+                        } else {
+                            self.message(
+                                user_id,
+                                "task_progress",
+                                &[("iteration", &iteration), ("tool_calls", &tool_calls)],
+                            )
+                        };
+                        text.push_str(&format!("\n\n{}", line));
+                        if !progress.running_tools.is_empty() {
+                            let tools = progress.running_tools.join(", ");
+                            let line = self.message(user_id, "task_running_tools", &[("tools", &tools)]);
+                            text.push_str(&format!("\n{}", line));
+                        }
+                        let verbosity = self.get_user_verbosity(user_id);
+                        if !progress.output.is_empty() && verbosity.tool_output_lines() > 0 {
+                            text.push_str(&format!("\n\n{}", self.message(user_id, "task_recent_output", &[])));
+                            let skip = progress.output.len().saturating_sub(verbosity.tool_output_lines());
+                            for line in progress.output.iter().skip(skip) {
+                                text.push_str(&format!("\n{}", truncate_output(line, verbosity.tool_output_chars())));
+                            }
+                        }
+                    }
+
+                    if let Some(result) = handle.result().await {
+                        let line = match &result.error {
+                            None => self.message(
+                                user_id,
+                                "task_result",
+                                &[
+                                    ("seconds", &format!("{:.1}", result.execution_time_ms as f64 / 1000.0)),
+                                    ("output", &truncate_output(&result.output, 1000)),
+                                ],
+                            ),
+                            Some(error) => self.message(user_id, "task_error", &[("error", error)]),
+                        };
+                        text.push_str(&format!("\n\n{}", line));
+                    }
 
                     Ok(TriggerResponseBuilder::new()
                         .text(text)
                         .build())
                 } else {
                     Ok(TriggerResponseBuilder::new()
-                        .text(format!("❌ Task not found: `{}`", task_id))
+                        .text(self.message(user_id, "task_not_found", &[("task", task_id)]))
                         .error()
                         .build())
                 }
             }
             _ => Ok(TriggerResponseBuilder::new()
-                .text(format!("Status not supported for {:?}", cmd.target))
+                .text(self.message(&cmd.context.user_id, "status_not_supported", &[("target", &format!("{:?}", cmd.target))]))
                 .error()
                 .build()),
         }
@@ -1195,17 +1713,17 @@ impl TriggerHandler {
 
                 match self.orchestrator.cancel_task(task_id).await {
                     Ok(_) => Ok(TriggerResponseBuilder::new()
-                        .text(format!("✓ Task cancelled: {}", task_id))
+                        .text(self.message(&cmd.context.user_id, "task_cancelled", &[("task", task_id)]))
                         .success()
                         .build()),
                     Err(e) => Ok(TriggerResponseBuilder::new()
-                        .text(format!("Failed to cancel task: {}", e))
+                        .text(self.message(&cmd.context.user_id, "task_cancel_failed", &[("error", &e.to_string())]))
                         .error()
                         .build()),
                 }
             }
             _ => Ok(TriggerResponseBuilder::new()
-                .text(format!("Cancel not supported for {:?}", cmd.target))
+                .text(self.message(&cmd.context.user_id, "cancel_not_supported", &[("target", &format!("{:?}", cmd.target))]))
                 .error()
                 .build()),
         }
@@ -1215,22 +1733,27 @@ impl TriggerHandler {
     async fn handle_list_command(&self, cmd: TriggerCommand) -> AofResult<TriggerResponse> {
         match cmd.target {
             TriggerTarget::Task => {
+                let user_id = &cmd.context.user_id;
                 let task_ids = self.orchestrator.list_tasks();
                 let stats = self.orchestrator.stats().await;
 
-                let mut text = format!(
-                    "📋 **Task Overview**\n\n**Statistics:**\n⏳ Pending: {}\n▶️ Running: {}\n✅ Completed: {}\n❌ Failed: {}\n🚫 Cancelled: {}\n\n**Capacity:**\n• Max Concurrent: {}\n• Available Slots: {}",
-                    stats.pending,
-                    stats.running,
-                    stats.completed,
-                    stats.failed,
-                    stats.cancelled,
-                    stats.max_concurrent,
-                    stats.available_permits
+                let mut text = self.message(
+                    user_id,
+                    "task_overview",
+                    &[
+                        ("pending", &stats.pending.to_string()),
+                        ("running", &stats.running.to_string()),
+                        ("completed", &stats.completed.to_string()),
+                        ("failed", &stats.failed.to_string()),
+                        ("cancelled", &stats.cancelled.to_string()),
+                        ("max_concurrent", &stats.max_concurrent.to_string()),
+                        ("available", &stats.available_permits.to_string()),
+                    ],
                 );
 
                 if !task_ids.is_empty() {
-                    text.push_str(&format!("\n\n**Active Tasks ({}):**", task_ids.len()));
+                    let line = self.message(user_id, "task_overview_active", &[("count", &task_ids.len().to_string())]);
+                    text.push_str(&format!("\n\n{}", line));
 
                     // Show first 10 tasks with status
                     let display_limit = 10;
@@ -1251,16 +1774,18 @@ impl TriggerHandler {
                     }
 
                     if task_ids.len() > display_limit {
-                        text.push_str(&format!("\n\n...and {} more tasks", task_ids.len() - display_limit));
+                        let more = (task_ids.len() - display_limit).to_string();
+                        let line = self.message(user_id, "task_overview_more", &[("count", &more)]);
+                        text.push_str(&format!("\n\n{}", line));
                     }
                 } else {
-                    text.push_str("\n\n_No active tasks_");
+                    text.push_str(&format!("\n\n{}", self.message(user_id, "task_overview_empty", &[])));
                 }
 
                 Ok(TriggerResponseBuilder::new().text(text).build())
             }
             _ => Ok(TriggerResponseBuilder::new()
-                .text(format!("List not supported for {:?}", cmd.target))
+                .text(self.message(&cmd.context.user_id, "list_not_supported", &[("target", &format!("{:?}", cmd.target))]))
                 .error()
                 .build()),
         }
@@ -1282,17 +1807,7 @@ impl TriggerHandler {
             .map(|f| format!("{} {}", f.emoji, f.display_name))
             .unwrap_or_else(|| current_fleet.clone());
 
-        let help_text = format!(
-            "AOF Bot - DevOps from mobile\n\n\
-            Current fleet: {}\n\n\
-            Commands:\n\
-            /fleet - Switch fleet (recommended)\n\
-            /agent - Switch agent (legacy)\n\
-            /help - Show this help\n\n\
-            Just type naturally after selecting a fleet.\n\n\
-            Select fleet:",
-            fleet_display
-        );
+        let help_text = self.message(&cmd.context.user_id, "help", &[("fleet", &fleet_display)]);
 
         let mut builder = TriggerResponseBuilder::new()
             .text(help_text);
@@ -1320,39 +1835,109 @@ impl TriggerHandler {
         builder.build()
     }
 
+    /// Handle safe mode command - show, enable or disable the global kill switch
+    ///
+    /// Anyone can turn safe mode on; turning it off is limited to `admin_users`,
+    /// and refused from chat when no admins are configured.
+    fn handle_safe_mode_command(&self, cmd: TriggerCommand) -> TriggerResponse {
+        let user_id = &cmd.context.user_id;
+        let action = cmd.args.first().map(|a| a.to_lowercase());
+
+        let text = match action.as_deref() {
+            None | Some("status") => match self.safe_mode.status() {
+                Some(state) => self.message(user_id, "safe_mode_active", &[("details", &state.message())]),
+                None => self.message(user_id, "safe_mode_inactive", &[]),
+            },
+            Some("on") | Some("enable") => {
+                let reason = cmd.args[1..].join(" ");
+                let enabled_by = format!("{} on {}", user_id, cmd.context.platform);
+                if self.safe_mode.enable(&enabled_by, (!reason.is_empty()).then_some(reason)) {
+                    self.message(user_id, "safe_mode_enabled", &[])
+                } else {
+                    let details = self.safe_mode.status().map(|s| s.message()).unwrap_or_default();
+                    self.message(user_id, "safe_mode_active", &[("details", &details)])
+                }
+            }
+            Some("off") | Some("disable") => {
+                let refusal = if self.config.admin_users.is_empty() {
+                    Some(self.message(user_id, "safe_mode_off_no_admins", &[]))
+                } else if !self.config.admin_users.iter().any(|u| u == user_id) {
+                    Some(self.message(user_id, "not_safe_mode_admin", &[("user", user_id)]))
+                } else {
+                    None
+                };
+                if let Some(refusal) = refusal {
+                    return TriggerResponseBuilder::new()
+                        .text(refusal)
+                        .error()
+                        .ephemeral(user_id)
+                        .build();
+                }
+                self.safe_mode.disable();
+                self.message(user_id, "safe_mode_disabled", &[])
+            }
+            Some(_) => {
+                return TriggerResponseBuilder::new()
+                    .text(self.message(user_id, "safe_mode_usage", &[]))
+                    .error()
+                    .build();
+            }
+        };
+
+        TriggerResponseBuilder::new().text(text).build()
+    }
+
+    /// Handle verbosity command
+    ///
+    /// `/verbosity` shows the user's level; `/verbosity <terse|normal|verbose>` sets it.
+    fn handle_verbosity_command(&self, cmd: TriggerCommand) -> TriggerResponse {
+        let user_id = &cmd.context.user_id;
+
+        let Some(level) = cmd.args.first() else {
+            let current = self.get_user_verbosity(user_id);
+            return TriggerResponseBuilder::new()
+                .text(self.message(user_id, "verbosity_current", &[("level", current.as_str())]))
+                .build();
+        };
+
+        match level.parse::<Verbosity>() {
+            Ok(verbosity) => {
+                self.set_user_verbosity(user_id, verbosity);
+                TriggerResponseBuilder::new()
+                    .text(self.message(user_id, "verbosity_set", &[("level", verbosity.as_str())]))
+                    .success()
+                    .build()
+            }
+            Err(_) => TriggerResponseBuilder::new()
+                .text(self.message(user_id, "verbosity_usage", &[]))
+                .error()
+                .build(),
+        }
+    }
+
     /// Handle info command
-    async fn handle_info_command(&self, _cmd: TriggerCommand) -> TriggerResponse {
+    async fn handle_info_command(&self, cmd: TriggerCommand) -> TriggerResponse {
         let stats = self.orchestrator.stats().await;
-
-        let info_text = format!(
-            r#"
-**AOF System Info**
-
-**Version:** {}
-**Runtime Stats:**
-• Max Concurrent: {}
-• Available Permits: {}
-• Active Tasks: {}
-• Pending: {}
-• Running: {}
-• Completed: {}
-• Failed: {}
-
-**Platforms:** {}
-            "#,
-            crate::VERSION,
-            stats.max_concurrent,
-            stats.available_permits,
-            stats.pending + stats.running,
-            stats.pending,
-            stats.running,
-            stats.completed,
-            stats.failed,
-            self.platforms.keys().map(|k| k.as_str()).collect::<Vec<_>>().join(", ")
+        let platforms = self.platforms.keys().map(|k| k.as_str()).collect::<Vec<_>>().join(", ");
+
+        let info_text = self.message(
+            &cmd.context.user_id,
+            "system_info",
+            &[
+                ("version", crate::VERSION),
+                ("max_concurrent", &stats.max_concurrent.to_string()),
+                ("available", &stats.available_permits.to_string()),
+                ("active", &(stats.pending + stats.running).to_string()),
+                ("pending", &stats.pending.to_string()),
+                ("running", &stats.running.to_string()),
+                ("completed", &stats.completed.to_string()),
+                ("failed", &stats.failed.to_string()),
+                ("platforms", &platforms),
+            ],
         );
 
         TriggerResponseBuilder::new()
-            .text(info_text.trim())
+            .text(info_text)
             .build()
     }
 
@@ -1362,9 +1947,12 @@ impl TriggerHandler {
     /// - `/agent` - List available agents with inline selection
     /// - `/agent <name>` - Switch to the specified agent
     /// - `/agent info` - Show detailed current agent info
+    /// - `/contexts health` - Probe every context's connectivity
+    /// - `/context <name> --check` - Probe the context's connections, then switch
     async fn handle_agent_command(&self, cmd: TriggerCommand) -> TriggerResponse {
         // Check if user wants to switch or just list
-        let context_arg = cmd.args.first().map(|s| s.as_str());
+        let context_arg = cmd.args.iter().map(|s| s.as_str()).find(|a| !a.starts_with("--"));
+        let check = cmd.args.iter().any(|a| a == "--check");
 
         // Get current context for this user
         let current_context = self.get_user_context(&cmd.context.user_id);
@@ -1379,9 +1967,10 @@ impl TriggerHandler {
                     .map(|c| format!("{} {}", c.emoji, c.display_name))
                     .unwrap_or_else(|| current_context.clone());
 
-                builder = builder.text(format!(
-                    "Select Agent\n\nCurrent: {}\n\nTap to switch:",
-                    current_display
+                builder = builder.text(self.message(
+                    &cmd.context.user_id,
+                    "agent_select",
+                    &[("current", &current_display)],
                 ));
 
                 // Add agent buttons
@@ -1406,56 +1995,55 @@ impl TriggerHandler {
 
                 builder.build()
             }
+            Some("health") => {
+                let report = self.probe_contexts().await;
+                let config = match cmd.context.platform.as_str() {
+                    "telegram" | "whatsapp" => aof_viz::RenderConfig::telegram(),
+                    "slack" => aof_viz::RenderConfig::slack(),
+                    _ => aof_viz::RenderConfig::default(),
+                };
+                let text = self.message(&cmd.context.user_id, "context_health", &[("report", &report.render(config))]);
+
+                let builder = TriggerResponseBuilder::new().text(text);
+                if report.has_failures() {
+                    builder.error().build()
+                } else {
+                    builder.success().build()
+                }
+            }
             Some("info") => {
                 // Show detailed info about current agent
                 if let Some(ctx_config) = self.available_contexts.get(&current_context) {
                     let tools_display = if ctx_config.tools.is_empty() {
-                        "none".to_string()
+                        self.message(&cmd.context.user_id, "tools_none", &[])
                     } else {
                         ctx_config.tools.join(", ")
                     };
 
-                    let info_text = format!(
-                        "Current: {} {}\n\nTools: {}\n\n{}\n\nUse /agent to switch.",
-                        ctx_config.emoji,
-                        ctx_config.display_name,
-                        tools_display,
-                        ctx_config.description
+                    let info_text = self.message(
+                        &cmd.context.user_id,
+                        "agent_info",
+                        &[
+                            ("agent", &format!("{} {}", ctx_config.emoji, ctx_config.display_name)),
+                            ("tools", &tools_display),
+                            ("description", &ctx_config.description),
+                        ],
                     );
                     TriggerResponseBuilder::new()
                         .text(info_text)
                         .build()
                 } else {
                     TriggerResponseBuilder::new()
-                        .text(format!("Agent '{}' not found.", current_context))
+                        .text(self.message(&cmd.context.user_id, "agent_info_not_found", &[("agent", &current_context)]))
                         .error()
                         .build()
                 }
             }
             Some(agent_name) => {
                 // Switch to the specified agent
-                if self.available_contexts.contains_key(agent_name) {
-                    self.set_user_context(&cmd.context.user_id, agent_name);
-
-                    let ctx_config = self.available_contexts.get(agent_name).unwrap();
-                    let tools_display = if ctx_config.tools.is_empty() {
-                        "standard".to_string()
-                    } else {
-                        ctx_config.tools.join(", ")
-                    };
-
-                    let response_text = format!(
-                        "Switched to {} {}\n\nTools: {}\n\n{}",
-                        ctx_config.emoji,
-                        ctx_config.display_name,
-                        tools_display,
-                        ctx_config.description
-                    );
-
-                    TriggerResponseBuilder::new()
-                        .text(response_text)
-                        .success()
-                        .build()
+                let probe = check || self.config.probe_context_switch;
+                if let Some(response) = self.switch_context(&cmd.context.user_id, agent_name, probe).await {
+                    response
                 } else {
                     // Unknown agent
                     let available: Vec<String> = self.available_contexts
@@ -1464,10 +2052,10 @@ impl TriggerHandler {
                         .collect();
 
                     TriggerResponseBuilder::new()
-                        .text(format!(
-                            "Unknown agent: '{}'\n\nAvailable: {}",
-                            agent_name,
-                            available.join(", ")
+                        .text(self.message(
+                            &cmd.context.user_id,
+                            "unknown_agent",
+                            &[("agent", agent_name), ("available", &available.join(", "))],
                         ))
                         .error()
                         .build()
@@ -1476,6 +2064,91 @@ impl TriggerHandler {
         }
     }
 
+    /// Probe every configured context, sorted by name
+    pub async fn probe_contexts(&self) -> HealthReport {
+        let mut probes: Vec<ContextProbe> = self
+            .available_contexts
+            .iter()
+            .map(|entry| entry.value().health_probe(entry.key()))
+            .collect();
+        probes.sort_by(|a, b| a.context.cmp(&b.context));
+        HealthReport::probe(self.probe_runner.clone(), probes, DEFAULT_PROBE_TIMEOUT_SECS).await
+    }
+
+    /// Probe one context's connections (None: unknown context)
+    pub async fn probe_context(&self, name: &str) -> Option<ContextHealth> {
+        let probe = self.available_contexts.get(name)?.health_probe(name);
+        HealthReport::probe(self.probe_runner.clone(), vec![probe], CONTEXT_SWITCH_PROBE_TIMEOUT_SECS)
+            .await
+            .contexts
+            .pop()
+    }
+
+    /// Switch a user to a context and describe it (None: unknown context)
+    ///
+    /// With `probe`, the context's connections are checked first. A failed
+    /// probe still switches - the user may be about to fix the credentials -
+    /// but the reply warns that commands will fail until it is reachable.
+    async fn switch_context(&self, user_id: &str, ctx_name: &str, probe: bool) -> Option<TriggerResponse> {
+        let health = if probe { self.probe_context(ctx_name).await } else { None };
+        let ctx_config = self.available_contexts.get(ctx_name)?.clone();
+        self.set_user_context(user_id, ctx_name);
+
+        let tools_display = if ctx_config.tools.is_empty() {
+            self.message(user_id, "tools_standard", &[])
+        } else {
+            ctx_config.tools.join(", ")
+        };
+
+        // Simple, clean response - text only, no markdown for mobile
+        let mut response_text = self.message(
+            user_id,
+            "context_switched",
+            &[
+                ("context", &format!("{} {}", ctx_config.emoji, ctx_config.display_name)),
+                ("tools", &tools_display),
+                ("description", &ctx_config.description),
+            ],
+        );
+
+        let mut builder = TriggerResponseBuilder::new();
+        match health {
+            Some(health) => {
+                let details = |reachable: bool| {
+                    health
+                        .probes
+                        .iter()
+                        .filter(|p| p.reachable == reachable)
+                        .map(|p| format!("{}: {}", p.kind, p.detail))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                };
+                builder = match health.status {
+                    HealthStatus::Reachable => {
+                        let line = self.message(user_id, "context_reachable", &[("details", &details(true))]);
+                        response_text = format!("{}\n\n{}", response_text, line);
+                        builder.success()
+                    }
+                    HealthStatus::Degraded | HealthStatus::Unreachable => {
+                        warn!("Context '{}' is unreachable: {}", ctx_name, details(false));
+                        let line = self.message(
+                            user_id,
+                            "context_unreachable",
+                            &[("context", &ctx_config.display_name), ("details", &details(false))],
+                        );
+                        response_text = format!("{}\n\n{}", response_text, line);
+                        builder.warning()
+                    }
+                    HealthStatus::Unconfigured => builder.success(),
+                };
+                builder = builder.metadata("context_health", serde_json::json!(health.status));
+            }
+            None => builder = builder.success(),
+        }
+
+        Some(builder.text(response_text).build())
+    }
+
     /// Handle /fleet command - show or switch fleets
     ///
     /// Usage:
@@ -1498,9 +2171,10 @@ impl TriggerHandler {
                     .map(|f| format!("{} {}", f.emoji, f.display_name))
                     .unwrap_or_else(|| current_fleet.clone());
 
-                builder = builder.text(format!(
-                    "Select Fleet\n\nCurrent: {}\n\nTap to switch:",
-                    current_display
+                builder = builder.text(self.message(
+                    &cmd.context.user_id,
+                    "fleet_select",
+                    &[("current", &current_display)],
                 ));
 
                 // Add fleet buttons
@@ -1533,20 +2207,22 @@ impl TriggerHandler {
                         .map(|a| format!("• {}: {}", a.name, a.description))
                         .collect();
 
-                    let info_text = format!(
-                        "{} {}\n\n{}\n\nAgents:\n{}\n\nRouter: {}\n\nUse /fleet to switch.",
-                        fleet_config.emoji,
-                        fleet_config.display_name,
-                        fleet_config.description,
-                        agents_display.join("\n"),
-                        fleet_config.router_model
+                    let info_text = self.message(
+                        &cmd.context.user_id,
+                        "fleet_info",
+                        &[
+                            ("fleet", &format!("{} {}", fleet_config.emoji, fleet_config.display_name)),
+                            ("description", &fleet_config.description),
+                            ("agents", &agents_display.join("\n")),
+                            ("router", &fleet_config.router_model),
+                        ],
                     );
                     TriggerResponseBuilder::new()
                         .text(info_text)
                         .build()
                 } else {
                     TriggerResponseBuilder::new()
-                        .text(format!("Fleet '{}' not found.", current_fleet))
+                        .text(self.message(&cmd.context.user_id, "fleet_info_not_found", &[("fleet", &current_fleet)]))
                         .error()
                         .build()
                 }
@@ -1556,19 +2232,7 @@ impl TriggerHandler {
                 if self.available_fleets.contains_key(fleet_name) {
                     self.set_user_fleet(&cmd.context.user_id, fleet_name);
 
-                    let fleet_config = self.available_fleets.get(fleet_name).unwrap();
-                    let agents_list: Vec<String> = fleet_config.agents
-                        .iter()
-                        .map(|a| a.name.clone())
-                        .collect();
-
-                    let response_text = format!(
-                        "Switched to {} {}\n\nAgents: {}\n\n{}",
-                        fleet_config.emoji,
-                        fleet_config.display_name,
-                        agents_list.join(", "),
-                        fleet_config.description
-                    );
+                    let response_text = self.fleet_switched_text(&cmd.context.user_id, fleet_name);
 
                     TriggerResponseBuilder::new()
                         .text(response_text)
@@ -1582,10 +2246,10 @@ impl TriggerHandler {
                         .collect();
 
                     TriggerResponseBuilder::new()
-                        .text(format!(
-                            "Unknown fleet: '{}'\n\nAvailable: {}",
-                            fleet_name,
-                            available.join(", ")
+                        .text(self.message(
+                            &cmd.context.user_id,
+                            "unknown_fleet",
+                            &[("fleet", fleet_name), ("available", &available.join(", "))],
                         ))
                         .error()
                         .build()
@@ -1598,7 +2262,7 @@ impl TriggerHandler {
     ///
     /// Returns a response with action buttons for each available flow.
     /// Users can click to trigger a flow execution.
-    async fn handle_flows_command(&self, _cmd: TriggerCommand) -> TriggerResponse {
+    async fn handle_flows_command(&self, cmd: TriggerCommand) -> TriggerResponse {
         // Get flows from the router if available
         let flows: Vec<String> = if let Some(ref router) = self.flow_router {
             router.list_flows()
@@ -1608,14 +2272,20 @@ impl TriggerHandler {
 
         if flows.is_empty() {
             return TriggerResponseBuilder::new()
-                .text("No flows available. Add flows to the flows directory.")
+                .text(self.message(&cmd.context.user_id, "no_flows", &[]))
                 .warning()
                 .build();
         }
 
+        let mut text = self.message(&cmd.context.user_id, "flow_select", &[]);
+        if flows.len() > 8 {
+            let more = (flows.len() - 8).to_string();
+            let line = self.message(&cmd.context.user_id, "flow_select_more", &[("count", &more)]);
+            text = format!("{}\n\n{}", text, line);
+        }
+
         // Build action buttons for each flow
-        let mut builder = TriggerResponseBuilder::new()
-            .text("**Select a Flow**\n\nTap to run:");
+        let mut builder = TriggerResponseBuilder::new().text(text);
 
         for flow_name in flows.iter().take(8) { // Limit to 8 flows for UI
             builder = builder.action(Action {
@@ -1626,16 +2296,43 @@ impl TriggerHandler {
             });
         }
 
-        if flows.len() > 8 {
-            builder = builder.text(format!(
-                "\n\n_...and {} more flows._",
-                flows.len() - 8
-            ));
-        }
-
         builder.build()
     }
 
+    /// Reply confirming a switch to a fleet
+    fn fleet_switched_text(&self, user_id: &str, fleet_name: &str) -> String {
+        let Some(fleet_config) = self.available_fleets.get(fleet_name) else {
+            return self.message(user_id, "fleet_not_found", &[("fleet", fleet_name)]);
+        };
+        let agents_list: Vec<String> = fleet_config.agents
+            .iter()
+            .map(|a| a.name.clone())
+            .collect();
+
+        self.message(
+            user_id,
+            "fleet_switched",
+            &[
+                ("fleet", &format!("{} {}", fleet_config.emoji, fleet_config.display_name)),
+                ("agents", &agents_list.join(", ")),
+                ("description", &fleet_config.description),
+            ],
+        )
+    }
+
+    /// Configuration of an available context
+    pub fn get_context(&self, name: &str) -> Option<ContextConfig> {
+        self.available_contexts.get(name).map(|ctx| ctx.clone())
+    }
+
+    /// Register (or replace) an available context
+    ///
+    /// Re-applies the tool guard so agents pick up the context's freeze windows.
+    pub fn register_context(&self, name: impl Into<String>, config: ContextConfig) {
+        self.available_contexts.insert(name.into(), config);
+        self.install_tool_guard();
+    }
+
     /// Set the active context for a user session
     /// Context = Agent + Connection Parameters
     pub fn set_user_context(&self, user_id: &str, ctx_name: &str) {
@@ -1643,6 +2340,45 @@ impl TriggerHandler {
         info!("Set context '{}' for user '{}'", ctx_name, user_id);
     }
 
+    /// Set the locale bot messages are rendered in for a user
+    pub fn set_user_locale(&self, user_id: &str, locale: &str) {
+        self.user_locales.insert(user_id.to_string(), locale.to_string());
+    }
+
+    /// Locale for a user: their own preference, then their context's, then the default
+    pub fn get_user_locale(&self, user_id: &str) -> String {
+        if let Some(locale) = self.user_locales.get(user_id) {
+            return locale.clone();
+        }
+        self.available_contexts
+            .get(&self.get_user_context(user_id))
+            .and_then(|ctx| ctx.locale.clone())
+            .unwrap_or_else(|| self.config.default_locale.clone())
+    }
+
+    /// Set how much detail replies to a user contain
+    pub fn set_user_verbosity(&self, user_id: &str, verbosity: Verbosity) {
+        self.user_verbosity.insert(user_id.to_string(), verbosity);
+    }
+
+    /// Verbosity for a user: their own preference, then their context's, then the default
+    pub fn get_user_verbosity(&self, user_id: &str) -> Verbosity {
+        if let Some(verbosity) = self.user_verbosity.get(user_id) {
+            return *verbosity;
+        }
+        self.available_contexts
+            .get(&self.get_user_context(user_id))
+            .and_then(|ctx| ctx.verbosity)
+            .unwrap_or(self.config.default_verbosity)
+    }
+
+    /// Render a bot message in the user's locale
+    fn message(&self, user_id: &str, id: &str, args: &[(&str, &str)]) -> String {
+        self.config
+            .messages
+            .render(&self.get_user_locale(user_id), id, args)
+    }
+
     /// Get the active context for a user session
     /// Returns the context name (defaults to config.default_agent or "devops")
     pub fn get_user_context(&self, user_id: &str) -> String {
@@ -1720,7 +2456,7 @@ impl TriggerHandler {
         } else {
             warn!("Invalid callback format: '{}' (parts: {:?})", callback_data, parts);
             let response = TriggerResponseBuilder::new()
-                .text(format!("Invalid selection format. Please try again.\nReceived: {}", callback_data))
+                .text(self.message(&message.user.id, "invalid_selection", &[("data", callback_data)]))
                 .error()
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -1732,57 +2468,25 @@ impl TriggerHandler {
         match callback_type {
             "context" => {
                 // Switch to the selected agent
-                if self.available_contexts.contains_key(callback_value) {
-                    self.set_user_context(&message.user.id, callback_value);
-
-                    let ctx_config = self.available_contexts.get(callback_value).unwrap();
-                    let tools_display = if ctx_config.tools.is_empty() {
-                        "standard".to_string()
-                    } else {
-                        ctx_config.tools.join(", ")
-                    };
-
-                    // Simple, clean response - text only, no markdown for mobile
-                    let response_text = format!(
-                        "Switched to {} {}\n\nTools: {}\n\n{}",
-                        ctx_config.emoji,
-                        ctx_config.display_name,
-                        tools_display,
-                        ctx_config.description
-                    );
-
-                    let response = TriggerResponseBuilder::new()
-                        .text(response_text)
-                        .success()
-                        .build();
-                    let _ = platform_impl.send_response(&message.channel_id, response).await;
-                } else {
-                    let response = TriggerResponseBuilder::new()
-                        .text(format!("Context not found: {}", callback_value))
+                let response = match self
+                    .switch_context(&message.user.id, callback_value, self.config.probe_context_switch)
+                    .await
+                {
+                    Some(response) => response,
+                    None => TriggerResponseBuilder::new()
+                        .text(self.message(&message.user.id, "context_not_found", &[("context", callback_value)]))
                         .error()
-                        .build();
-                    let _ = platform_impl.send_response(&message.channel_id, response).await;
-                }
+                        .build(),
+                };
+                let _ = platform_impl.send_response(&message.channel_id, response).await;
             }
             "fleet" => {
                 // Switch to the selected fleet
                 if self.available_fleets.contains_key(callback_value) {
                     self.set_user_fleet(&message.user.id, callback_value);
 
-                    let fleet_config = self.available_fleets.get(callback_value).unwrap();
-                    let agents_list: Vec<String> = fleet_config.agents
-                        .iter()
-                        .map(|a| a.name.clone())
-                        .collect();
-
                     // Simple, clean response - text only for mobile
-                    let response_text = format!(
-                        "Switched to {} {}\n\nAgents: {}\n\n{}",
-                        fleet_config.emoji,
-                        fleet_config.display_name,
-                        agents_list.join(", "),
-                        fleet_config.description
-                    );
+                    let response_text = self.fleet_switched_text(&message.user.id, callback_value);
 
                     let response = TriggerResponseBuilder::new()
                         .text(response_text)
@@ -1791,7 +2495,7 @@ impl TriggerHandler {
                     let _ = platform_impl.send_response(&message.channel_id, response).await;
                 } else {
                     let response = TriggerResponseBuilder::new()
-                        .text(format!("Fleet not found: {}", callback_value))
+                        .text(self.message(&message.user.id, "fleet_not_found", &[("fleet", callback_value)]))
                         .error()
                         .build();
                     let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -1808,7 +2512,7 @@ impl TriggerHandler {
                 );
                 if !is_git_platform {
                     let ack = TriggerResponseBuilder::new()
-                        .text(format!("Running flow: *{}*...", callback_value))
+                        .text(self.message(&message.user.id, "flow_running", &[("flow", callback_value)]))
                         .build();
                     let _ = platform_impl.send_response(&message.channel_id, ack).await;
                 }
@@ -1838,14 +2542,14 @@ impl TriggerHandler {
                         return self.execute_agentflow(platform_impl, &synthetic_msg, flow_match).await;
                     } else {
                         let response = TriggerResponseBuilder::new()
-                            .text(format!("Flow not found: {}", callback_value))
+                            .text(self.message(&message.user.id, "flow_not_found", &[("flow", callback_value)]))
                             .error()
                             .build();
                         let _ = platform_impl.send_response(&message.channel_id, response).await;
                     }
                 } else {
                     let response = TriggerResponseBuilder::new()
-                        .text("No flows available.")
+                        .text(self.message(&message.user.id, "flows_unavailable", &[]))
                         .error()
                         .build();
                     let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -1854,7 +2558,7 @@ impl TriggerHandler {
             _ => {
                 warn!("Unknown callback type: {}", callback_type);
                 let response = TriggerResponseBuilder::new()
-                    .text(format!("Unknown selection type: {}", callback_type))
+                    .text(self.message(&message.user.id, "unknown_selection", &[("kind", callback_type)]))
                     .error()
                     .build();
                 let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -1867,22 +2571,15 @@ impl TriggerHandler {
     /// Handle parse error
     async fn handle_parse_error(
         &self,
-        _message: &TriggerMessage,
+        message: &TriggerMessage,
         error: CommandError,
     ) -> TriggerResponse {
+        let user_id = &message.user.id;
         let text = match error {
-            CommandError::InvalidFormat(msg) => {
-                format!("Invalid command format: {}\n\nUse `/help` for usage.", msg)
-            }
-            CommandError::UnknownCommand(cmd) => {
-                format!("Unknown command: {}\n\nUse `/help` for available commands.", cmd)
-            }
-            CommandError::MissingArgument(arg) => {
-                format!("Missing required argument: {}\n\nUse `/help` for usage.", arg)
-            }
-            CommandError::InvalidTarget(target) => {
-                format!("Invalid target: {}\n\nValid targets: agent, task, fleet, flow", target)
-            }
+            CommandError::InvalidFormat(msg) => self.message(user_id, "invalid_command_format", &[("error", &msg)]),
+            CommandError::UnknownCommand(cmd) => self.message(user_id, "unknown_command", &[("command", &cmd)]),
+            CommandError::MissingArgument(arg) => self.message(user_id, "missing_argument", &[("argument", &arg)]),
+            CommandError::InvalidTarget(target) => self.message(user_id, "invalid_target", &[("target", &target)]),
         };
 
         TriggerResponseBuilder::new().text(text).error().build()
@@ -1923,20 +2620,26 @@ impl TriggerHandler {
             }
         }
 
-        // Check Telegram/WhatsApp/CLI style: message starts with /command
-        // Only check if we have bindings configured (avoid false positives for /help, /agent, etc.)
-        if message.text.starts_with('/') && !self.config.command_bindings.is_empty() {
-            let parts: Vec<&str> = message.text.splitn(2, char::is_whitespace).collect();
-            let cmd_name = parts[0].trim_start_matches('/');
-
-            // Only return if this command has a binding (not built-in commands)
-            if self.config.command_bindings.contains_key(cmd_name) {
-                let remaining_text = parts.get(1).map(|s| s.to_string());
-                return (Some(cmd_name.to_string()), remaining_text);
-            }
+        // Check Telegram/WhatsApp/CLI style: message starts with a command prefix
+        // Only bound commands count (avoid false positives for /help, /agent, etc.)
+        match bound_command_in_text(&self.config.command_bindings, &message.text, &self.config.command_prefixes) {
+            Some((cmd_name, remaining_text)) => (Some(cmd_name), remaining_text),
+            None => (None, None),
         }
+    }
 
-        (None, None)
+    /// Send a bound command to the flow, fleet or agent its binding resolved to
+    async fn dispatch_binding(
+        &self,
+        message: &TriggerMessage,
+        platform_impl: &Arc<dyn TriggerPlatform>,
+        target: &BindingTarget,
+    ) -> AofResult<()> {
+        match target {
+            BindingTarget::Flow(name) => self.handle_flow_execution(message, platform_impl, name).await,
+            BindingTarget::Fleet(name) => self.handle_fleet_execution(message, platform_impl, name).await,
+            BindingTarget::Agent(name) => self.handle_natural_language(message, platform_impl, name).await,
+        }
     }
 
     /// Handle flow execution for a bound command
@@ -1958,7 +2661,7 @@ impl TriggerHandler {
                 );
                 if !is_git_platform {
                     let ack = TriggerResponseBuilder::new()
-                        .text(format!("🔄 Running {} flow...", flow_name))
+                        .text(self.message(&message.user.id, "flow_binding_running", &[("flow", flow_name)]))
                         .build();
                     let _ = platform_impl.send_response(&message.channel_id, ack).await;
                 }
@@ -1976,7 +2679,7 @@ impl TriggerHandler {
 
         // Flow not found
         let response = TriggerResponseBuilder::new()
-            .text(format!("Flow '{}' not found. Check flows configuration.", flow_name))
+            .text(self.message(&message.user.id, "flow_binding_not_found", &[("flow", flow_name)]))
             .error()
             .build();
         let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -2001,7 +2704,11 @@ impl TriggerHandler {
             );
             if !is_git_platform {
                 let ack = TriggerResponseBuilder::new()
-                    .text(format!("{} Running {} fleet...", fleet_config.emoji, fleet_config.display_name))
+                    .text(self.message(
+                        &message.user.id,
+                        "fleet_running",
+                        &[("emoji", &fleet_config.emoji), ("fleet", &fleet_config.display_name)],
+                    ))
                     .build();
                 let _ = platform_impl.send_response(&message.channel_id, ack).await;
             }
@@ -2016,7 +2723,7 @@ impl TriggerHandler {
             }
 
             let response = TriggerResponseBuilder::new()
-                .text(format!("Fleet '{}' has no agents configured.", fleet_name))
+                .text(self.message(&message.user.id, "fleet_no_agents", &[("fleet", fleet_name)]))
                 .error()
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -2025,7 +2732,7 @@ impl TriggerHandler {
 
         // Fleet not found
         let response = TriggerResponseBuilder::new()
-            .text(format!("Fleet '{}' not found. Check fleets configuration.", fleet_name))
+            .text(self.message(&message.user.id, "fleet_binding_not_found", &[("fleet", fleet_name)]))
             .error()
             .build();
         let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -2069,19 +2776,19 @@ impl TriggerHandler {
             let (agent_display, tools_display) = self.available_contexts
                 .get(&ctx_name)
                 .map(|c| {
-                    let tools = if c.tools.is_empty() { "standard".to_string() } else { c.tools.join(", ") };
+                    let tools = if c.tools.is_empty() {
+                        self.message(&message.user.id, "tools_standard", &[])
+                    } else {
+                        c.tools.join(", ")
+                    };
                     (format!("{} {}", c.emoji, c.display_name), tools)
                 })
-                .unwrap_or_else(|| (ctx_name.clone(), "standard".to_string()));
-
-            let greeting_text = format!(
-                "Hi! I'm your DevOps assistant.\n\n\
-                Current agent: {}\n\
-                Tools: {}\n\n\
-                Just type your question naturally.\n\n\
-                Use /help to switch agents.",
-                agent_display,
-                tools_display
+                .unwrap_or_else(|| (ctx_name.clone(), self.message(&message.user.id, "tools_standard", &[])));
+
+            let greeting_text = self.message(
+                &message.user.id,
+                "greeting",
+                &[("agent", &agent_display), ("tools", &tools_display)],
             );
 
             let response = TriggerResponseBuilder::new()
@@ -2093,6 +2800,11 @@ impl TriggerHandler {
 
         info!("Processing natural language input for agent {}: {}", agent_name, input);
 
+        // Safe mode and change freezes block write operations ahead of any platform policy
+        if self.reject_if_writes_blocked(&input, message, platform_impl).await {
+            return Ok(());
+        }
+
         // MVP Safety Layer: Block write operations on mobile platforms (Telegram, WhatsApp)
         // Platform hierarchy: CLI (full access) > Slack (approval for writes) > Telegram/WhatsApp (read-only)
         let is_mobile_platform = matches!(message.platform.as_str(), "telegram" | "whatsapp");
@@ -2102,20 +2814,19 @@ impl TriggerHandler {
 
             // Plain text response for mobile (no markdown)
             let response = TriggerResponseBuilder::new()
-                .text(format!(
-                    "Write operation blocked\n\n\
-                    {} is read-only for safety.\n\n\
-                    What you can do:\n\
-                    - Read-only commands (get, list, describe, logs)\n\
-                    - Use Slack or CLI for write operations",
-                    message.platform
-                ))
+                .text(self.message(&message.user.id, "write_blocked", &[("platform", &message.platform)]))
                 .error()
+                .ephemeral(&message.user.id)
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, response).await;
             return Ok(());
         }
 
+        let breaker_key = CircuitBreaker::agent_key(agent_name);
+        if self.reject_if_circuit_open(&breaker_key, agent_name, message, platform_impl).await {
+            return Ok(());
+        }
+
         let thread_id = message.thread_id.as_deref();
 
         // Get conversation history for context BEFORE adding the current message
@@ -2135,7 +2846,7 @@ impl TriggerHandler {
         );
         if !is_git_platform {
             let ack = TriggerResponseBuilder::new()
-                .text("🤔 Thinking...")
+                .text(self.message(&message.user.id, "thinking", &[]))
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, ack).await;
         }
@@ -2146,6 +2857,7 @@ impl TriggerHandler {
         } else {
             format!("{}\n\nCurrent message: {}", conversation_context, input)
         };
+        let input_with_context = self.get_user_verbosity(&message.user.id).apply_to_prompt(&input_with_context);
 
         // Check if agent is pre-loaded in the runtime (indexed by metadata.name)
         let runtime = self.runtime.read().await;
@@ -2156,99 +2868,11 @@ impl TriggerHandler {
             // Use pre-loaded agent from runtime
             info!("Using pre-loaded agent: {}", agent_name);
 
-            let runtime = self.runtime.read().await;
-            match runtime.execute(agent_name, &input_with_context).await {
-                Ok(output) => {
-                    info!("Agent '{}' executed successfully", agent_name);
-
-                    // Parse output for approval requirements
-                    let (requires_approval, command, clean_output) = parse_approval_output(&output);
-
-                    if requires_approval {
-                        if let Some(cmd) = command {
-                            info!("Command requires approval: {}", cmd);
-
-                            // Send approval request message
-                            let approval_text = format!(
-                                "{}\n\n⚠️ *This action requires approval*\n`{}`\n\nReact with ✅ to approve or ❌ to deny.",
-                                clean_output,
-                                cmd
-                            );
-
-                            // Try to use SlackPlatform directly for approval flow
-                            if let Some(slack) = platform_impl.as_any().downcast_ref::<crate::platforms::SlackPlatform>() {
-                                let thread_ts = message.thread_id.as_deref();
-                                match slack.post_message_with_ts(&message.channel_id, &approval_text, thread_ts).await {
-                                    Ok((channel, msg_ts)) => {
-                                        // Add reactions for approve/deny
-                                        let _ = slack.add_reaction(&channel, &msg_ts, "white_check_mark").await;
-                                        let _ = slack.add_reaction(&channel, &msg_ts, "x").await;
-
-                                        // Store pending approval
-                                        let approval = PendingApproval {
-                                            command: cmd.clone(),
-                                            user_id: message.user.id.clone(),
-                                            channel_id: channel.clone(),
-                                            message_ts: msg_ts.clone(),
-                                            requested_at: chrono::Utc::now(),
-                                            agent_name: agent_name.to_string(),
-                                            original_message: input.clone(),
-                                        };
-                                        self.pending_approvals.insert(msg_ts.clone(), approval);
-                                        info!("Stored pending approval for message {}", msg_ts);
-                                    }
-                                    Err(e) => {
-                                        error!("Failed to post approval message: {}", e);
-                                        let response = TriggerResponseBuilder::new()
-                                            .text(format!("❌ Failed to request approval: {}", e))
-                                            .error()
-                                            .build();
-                                        let _ = platform_impl.send_response(&message.channel_id, response).await;
-                                    }
-                                }
-                            } else {
-                                // Fallback for non-Slack platforms
-                                let response = TriggerResponseBuilder::new()
-                                    .text(approval_text)
-                                    .build();
-                                let _ = platform_impl.send_response(&message.channel_id, response).await;
-                            }
-                        } else {
-                            // requires_approval but no command - just send the output
-                            let response = TriggerResponseBuilder::new()
-                                .text(clean_output)
-                                .success()
-                                .build();
-                            let _ = platform_impl.send_response(&message.channel_id, response).await;
-                        }
-                    } else {
-                        // Normal response without approval
-                        // Store assistant response in conversation memory
-                        self.add_to_conversation(&message.channel_id, thread_id, "assistant", &output);
-
-                        let response = TriggerResponseBuilder::new()
-                            .text(output)
-                            .success()
-                            .build();
-                        let _ = platform_impl.send_response(&message.channel_id, response).await;
-                    }
-
-                    return Ok(());
-                }
-                Err(e) => {
-                    error!("Agent execution failed: {}", e);
-                    let error_msg = format!("❌ Sorry, I encountered an error: {}", e);
-                    // Store error in conversation memory too
-                    self.add_to_conversation(&message.channel_id, thread_id, "assistant", &error_msg);
-
-                    let response = TriggerResponseBuilder::new()
-                        .text(error_msg)
-                        .error()
-                        .build();
-                    let _ = platform_impl.send_response(&message.channel_id, response).await;
-                    return Ok(());
-                }
-            }
+            let result = self.runtime.read().await.execute(agent_name, &input_with_context).await;
+            self.run_reply(agent_name, &message.user.id, &message.channel_id, thread_id, &input)
+                .deliver(platform_impl, result.map_err(|e| e.to_string()))
+                .await;
+            return Ok(());
         }
 
         // Fallback: Create a simple agent without tools
@@ -2263,7 +2887,7 @@ impl TriggerHandler {
             ("gpt-4o".to_string(), ModelProvider::OpenAI)
         } else {
             let response = TriggerResponseBuilder::new()
-                .text("❌ No API key configured. Please set GOOGLE_API_KEY, ANTHROPIC_API_KEY, or OPENAI_API_KEY.")
+                .text(self.message(&message.user.id, "no_api_key", &[]))
                 .error()
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -2289,7 +2913,15 @@ impl TriggerHandler {
             max_iterations: 5,
             temperature: 0.7,
             max_tokens: Some(2000),
+            stop: Vec::new(),
+            seed: None,
             output_schema: None,
+            guardrails: vec![],
+            max_tool_calls_per_turn: None,
+            max_tool_calls_per_run: None,
+            max_continuations: None,
+            tool_result_page_size: None,
+            tool_discovery: false,
             extra: std::collections::HashMap::new(),
         };
 
@@ -2302,6 +2934,8 @@ impl TriggerHandler {
             temperature: 0.7,
             max_tokens: Some(2000),
             timeout_secs: 60,
+            stop: vec![],
+            seed: None,
             headers: std::collections::HashMap::new(),
             extra: std::collections::HashMap::new(),
         };
@@ -2311,8 +2945,9 @@ impl TriggerHandler {
             Ok(m) => m,
             Err(e) => {
                 error!("Failed to create model: {}", e);
+                self.breakers.record_failure(&breaker_key);
                 let response = TriggerResponseBuilder::new()
-                    .text(format!("❌ Failed to initialize AI: {}", e))
+                    .text(self.message(&message.user.id, "ai_init_failed", &[("error", &e.to_string())]))
                     .error()
                     .build();
                 let _ = platform_impl.send_response(&message.channel_id, response).await;
@@ -2325,42 +2960,30 @@ impl TriggerHandler {
         let memory = std::sync::Arc::new(SimpleMemory::new(std::sync::Arc::new(memory_backend)));
 
         // Create executor
-        let executor = AgentExecutor::new(
+        let executor = match AgentExecutor::new(
             config,
             model,
             None, // No tool executor in fallback mode
             Some(memory),
-        );
-
-        // Execute with conversation context
-        let mut context = AgentContext::new(&input_with_context);
-        let result = executor.execute(&mut context).await;
-
-        // Send response and store in conversation memory
-        let response = match result {
-            Ok(output) => {
-                // Store assistant response in conversation memory
-                self.add_to_conversation(&message.channel_id, thread_id, "assistant", &output);
-
-                TriggerResponseBuilder::new()
-                    .text(output)
-                    .success()
-                    .build()
-            }
+        ) {
+            Ok(executor) => executor,
             Err(e) => {
-                error!("Agent execution failed: {}", e);
-                let error_msg = format!("❌ Sorry, I encountered an error: {}", e);
-                // Store error in conversation memory
-                self.add_to_conversation(&message.channel_id, thread_id, "assistant", &error_msg);
-
-                TriggerResponseBuilder::new()
-                    .text(error_msg)
+                error!("Failed to create agent {}: {}", agent_name, e);
+                let response = TriggerResponseBuilder::new()
+                    .text(self.message(&message.user.id, "ai_init_failed", &[("error", &e.to_string())]))
                     .error()
-                    .build()
+                    .build();
+                let _ = platform_impl.send_response(&message.channel_id, response).await;
+                return Ok(());
             }
         };
 
-        let _ = platform_impl.send_response(&message.channel_id, response).await;
+        // Execute with conversation context
+        let mut context = AgentContext::new(&input_with_context);
+        let result = executor.execute(&mut context).await;
+        self.run_reply(agent_name, &message.user.id, &message.channel_id, thread_id, &input)
+            .deliver(platform_impl, result.map_err(|e| e.to_string()))
+            .await;
         Ok(())
     }
 
@@ -2394,12 +3017,21 @@ impl TriggerHandler {
 
         if input.is_empty() {
             let response = TriggerResponseBuilder::new()
-                .text("Hi! How can I help you? Just ask me anything.")
+                .text(self.message(&message.user.id, "flow_greeting", &[]))
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, response).await;
             return Ok(());
         }
 
+        if self.reject_if_writes_blocked(&input, message, platform_impl).await {
+            return Ok(());
+        }
+
+        let breaker_key = CircuitBreaker::flow_key(flow_name);
+        if self.reject_if_circuit_open(&breaker_key, flow_name, message, platform_impl).await {
+            return Ok(());
+        }
+
         info!("Executing AgentFlow '{}' with input: {}", flow_name, input);
 
         // Send typing indicator / acknowledgment (skip for Git platforms - they create new comments)
@@ -2409,7 +3041,7 @@ impl TriggerHandler {
         );
         if !is_git_platform {
             let ack = TriggerResponseBuilder::new()
-                .text(format!("🔄 Processing with flow `{}`...", flow_name))
+                .text(self.message(&message.user.id, "flow_processing", &[("flow", flow_name)]))
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, ack).await;
         }
@@ -2451,6 +3083,7 @@ impl TriggerHandler {
         // Send response based on execution result
         let response = match result {
             Ok(state) => {
+                self.breakers.record_success(&breaker_key);
                 // Extract output from the final node or state
                 let output = if let Some(last_result) = state.node_results.values().last() {
                     if let Some(ref output) = last_result.output {
@@ -2466,10 +3099,10 @@ impl TriggerHandler {
                                     .unwrap_or_else(|| serde_json::to_string_pretty(output).unwrap_or_default())
                             })
                     } else {
-                        format!("✅ Flow `{}` completed successfully.", flow_name)
+                        self.message(&message.user.id, "flow_completed", &[("flow", flow_name)])
                     }
                 } else {
-                    format!("✅ Flow `{}` completed.", flow_name)
+                    self.message(&message.user.id, "flow_completed_empty", &[("flow", flow_name)])
                 };
 
                 TriggerResponseBuilder::new()
@@ -2479,8 +3112,13 @@ impl TriggerHandler {
             }
             Err(e) => {
                 error!("AgentFlow '{}' execution failed: {}", flow_name, e);
+                self.breakers.record_failure(&breaker_key);
                 TriggerResponseBuilder::new()
-                    .text(format!("❌ Flow `{}` failed: {}", flow_name, e))
+                    .text(self.message(
+                        &message.user.id,
+                        "flow_failed",
+                        &[("flow", flow_name), ("error", &e.to_string())],
+                    ))
                     .error()
                     .build()
             }
@@ -2490,6 +3128,139 @@ impl TriggerHandler {
         Ok(())
     }
 
+    /// Delivery of an agent run's result to a user's conversation
+    fn run_reply(
+        &self,
+        agent_name: &str,
+        user_id: &str,
+        channel_id: &str,
+        thread_id: Option<&str>,
+        input: &str,
+    ) -> RunReply {
+        RunReply {
+            agent_name: agent_name.to_string(),
+            breaker_key: CircuitBreaker::agent_key(agent_name),
+            user_id: user_id.to_string(),
+            channel_id: channel_id.to_string(),
+            thread_id: thread_id.map(str::to_string),
+            input: input.to_string(),
+            context_name: self.get_user_context(user_id),
+            locale: self.get_user_locale(user_id),
+            header: None,
+            task_id: None,
+            messages: self.config.messages.clone(),
+            breakers: self.breakers.clone(),
+            pending_approvals: self.pending_approvals.clone(),
+            pending_clarifications: self.pending_clarifications.clone(),
+            conversation_memory: self.conversation_memory.clone(),
+        }
+    }
+
+    /// Take the clarification this message answers, if any
+    ///
+    /// Only a plain (non-command) reply from the user who was asked, in the same
+    /// conversation, answers a question. Expired questions are discarded.
+    fn take_clarification(&self, message: &TriggerMessage) -> Option<PendingClarification> {
+        if strip_command_prefix(&message.text, &self.config.command_prefixes).is_some() {
+            return None;
+        }
+
+        let key = Self::get_conversation_key(&message.channel_id, message.thread_id.as_deref());
+        let (_, pending) = self
+            .pending_clarifications
+            .remove_if(&key, |_, pending| pending.user_id == message.user.id)?;
+
+        if pending.is_expired(self.config.clarification_timeout_secs, chrono::Utc::now()) {
+            info!("Clarification from agent '{}' expired unanswered", pending.agent_name);
+            return None;
+        }
+        Some(pending)
+    }
+
+    /// Reply "temporarily unavailable" when a target's circuit breaker is open
+    ///
+    /// Returns true if the invocation was rejected and must not run.
+    async fn reject_if_circuit_open(
+        &self,
+        key: &str,
+        target: &str,
+        message: &TriggerMessage,
+        platform_impl: &Arc<dyn TriggerPlatform>,
+    ) -> bool {
+        let Some(text) = self.circuit_open_notice(key, target, &message.user.id) else {
+            return false;
+        };
+
+        let response = TriggerResponseBuilder::new().text(text).error().build();
+        let _ = platform_impl.send_response(&message.channel_id, response).await;
+        true
+    }
+
+    /// "Temporarily unavailable" notice when a target's circuit breaker is open
+    fn circuit_open_notice(&self, key: &str, target: &str, user_id: &str) -> Option<String> {
+        let Admission::Reject { retry_after } = self.breakers.check(key) else {
+            return None;
+        };
+
+        warn!("Circuit open for {}, rejecting message from {}", key, user_id);
+        let retry_after = retry_after.as_secs().max(1).to_string();
+        Some(self.message(
+            user_id,
+            "temporarily_unavailable",
+            &[("target", target), ("retry_after", &retry_after)],
+        ))
+    }
+
+    /// Notice explaining why write operations are blocked for a user right now
+    ///
+    /// Safe mode blocks writes everywhere; a change freeze blocks them in the
+    /// context that declares it (here, the user's current context).
+    fn write_block_notice(&self, user_id: &str) -> Option<String> {
+        self.write_block_notice_in(user_id, &self.get_user_context(user_id))
+    }
+
+    /// Notice explaining why write operations are blocked in a context right now
+    fn write_block_notice_in(&self, user_id: &str, context_name: &str) -> Option<String> {
+        if let Some(state) = self.safe_mode.status() {
+            return Some(self.message(user_id, "safe_mode_blocked", &[("details", &state.message())]));
+        }
+        let freeze = self
+            .available_contexts
+            .get(context_name)
+            .and_then(|ctx| active_freeze(&ctx.safety.freeze_windows, chrono::Utc::now()))?;
+        Some(self.message(
+            user_id,
+            "freeze_blocked",
+            &[("context", context_name), ("details", &freeze.message())],
+        ))
+    }
+
+    /// Reply with the safe-mode or change-freeze notice when writes are blocked
+    ///
+    /// Returns true when the message was rejected.
+    async fn reject_if_writes_blocked(
+        &self,
+        input: &str,
+        message: &TriggerMessage,
+        platform_impl: &Arc<dyn TriggerPlatform>,
+    ) -> bool {
+        if !is_write_operation(input) {
+            return false;
+        }
+        let Some(text) = self.write_block_notice(&message.user.id) else {
+            return false;
+        };
+
+        warn!("Writes blocked, rejected write operation from {} on {}: {}", message.user.id, message.platform, input);
+        let response = TriggerResponseBuilder::new()
+            .text(text)
+            .error()
+            .ephemeral(&message.user.id)
+            .build();
+        let _ = platform_impl.send_response(&message.channel_id, response).await;
+        true
+    }
+
     /// Format error for specific platform
     ///
     /// Provides platform-specific error formatting to enhance user experience
@@ -2590,56 +3361,74 @@ impl TriggerHandler {
         );
 
         // Check if user has permission to approve
-        let can_approve = message.metadata.get("can_approve")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(true); // Default to true for backward compatibility
-
-        if !can_approve {
+        if let Err(reason) = self.check_approver(&approval, message, is_deny) {
             info!(
-                "User {} is not authorized to approve commands",
-                message.user.id
+                "User {} is not authorized to approve commands: {}",
+                message.user.id, reason
             );
 
             // Re-insert the pending approval (it wasn't consumed)
             self.pending_approvals.insert(item_ts.to_string(), approval);
 
-            // Send unauthorized message
+            // Send unauthorized message (only the would-be approver sees it)
             let response = TriggerResponseBuilder::new()
-                .text(format!(
-                    "⚠️ <@{}> is not authorized to approve commands. Please contact an admin.",
-                    message.user.id
-                ))
+                .text(reason)
                 .thread_id(message.thread_id.clone().unwrap_or_default())
+                .ephemeral(&message.user.id)
                 .build();
             let _ = platform_impl.send_response(&message.channel_id, response).await;
             return Ok(());
         }
 
         if is_deny {
+            let outcome = self.record_approval_outcome(
+                &approval,
+                message,
+                ApprovalDecision::Denied,
+                ApprovalResultStatus::NotExecuted,
+            );
+
             // Send denial message
-            let denial_text = format!(
-                "❌ *Action denied by <@{}>*\n```{}```",
-                message.user.id,
-                approval.command
+            let denial_text = self.message(
+                &message.user.id,
+                "approval_denied",
+                &[("user", &message.user.id), ("command", &approval.command)],
             );
 
             let response = TriggerResponseBuilder::new()
                 .text(denial_text)
                 .thread_id(approval.message_ts.clone())
+                .metadata("approval_outcome", outcome)
                 .build();
             let _ = platform_impl.send_response(&approval.channel_id, response).await;
 
             return Ok(());
         }
 
+        // Safe mode and change freezes also stop commands approved before they began
+        if let Some(text) = self.write_block_notice_in(&message.user.id, &approval.context_name) {
+            warn!("Writes are blocked, not executing approved command: {}", approval.command);
+            let outcome = self.record_approval_outcome(
+                &approval,
+                message,
+                ApprovalDecision::Approved,
+                ApprovalResultStatus::NotExecuted,
+            );
+            let response = TriggerResponseBuilder::new()
+                .text(text)
+                .error()
+                .thread_id(approval.message_ts.clone())
+                .metadata("approval_outcome", outcome)
+                .build();
+            let _ = platform_impl.send_response(&approval.channel_id, response).await;
+            return Ok(());
+        }
+
         // Approve - execute the command
         info!("Executing approved command: {}", approval.command);
 
         // Send "executing" message
-        let executing_text = format!(
-            "⚡ *Executing approved command...*\n```{}```",
-            approval.command
-        );
+        let executing_text = self.message(&message.user.id, "approval_executing", &[("command", &approval.command)]);
         let response = TriggerResponseBuilder::new()
             .text(executing_text)
             .thread_id(approval.message_ts.clone())
@@ -2659,47 +3448,200 @@ impl TriggerHandler {
 
                 if output.status.success() {
                     let result = if stdout.is_empty() {
-                        "Command completed successfully (no output)".to_string()
+                        self.message(&message.user.id, "command_no_output", &[])
                     } else {
                         stdout.to_string()
                     };
                     (true, result)
                 } else {
                     let error = if stderr.is_empty() {
-                        format!("Command failed with exit code: {:?}", output.status.code())
+                        let code = format!("{:?}", output.status.code());
+                        self.message(&message.user.id, "command_exit_code", &[("code", &code)])
                     } else {
                         stderr.to_string()
                     };
                     (false, error)
                 }
             }
-            Err(e) => (false, format!("Failed to execute command: {}", e)),
+            Err(e) => (false, self.message(&message.user.id, "command_spawn_failed", &[("error", &e.to_string())])),
         };
 
         // Send result back to Slack
         let (success, result_text) = output;
-        let result_message = if success {
-            format!(
-                "✅ *Command completed successfully*\n```{}```\n*Approved by:* <@{}>",
-                truncate_output(&result_text, 2500),
-                message.user.id
-            )
-        } else {
-            format!(
-                "❌ *Command failed*\n```{}```\n*Approved by:* <@{}>",
-                truncate_output(&result_text, 2500),
-                message.user.id
-            )
-        };
+        let outcome = self.record_approval_outcome(
+            &approval,
+            message,
+            ApprovalDecision::Approved,
+            if success { ApprovalResultStatus::Succeeded } else { ApprovalResultStatus::Failed },
+        );
+        let output_chars = self.get_user_verbosity(&approval.user_id).tool_output_chars();
+        let result_message = self.message(
+            &message.user.id,
+            if success { "approved_command_succeeded" } else { "approved_command_failed" },
+            &[("output", &truncate_output(&result_text, output_chars)), ("user", &message.user.id)],
+        );
 
         let response = TriggerResponseBuilder::new()
             .text(result_message)
             .thread_id(approval.message_ts)
+            .metadata("approval_outcome", outcome)
             .build();
         let _ = platform_impl.send_response(&approval.channel_id, response).await;
 
         Ok(())
     }
+
+    /// Check whether the sender of `message` may decide on `approval`
+    ///
+    /// The approval rules of the approval's context apply, falling back to the
+    /// handler's. Requesters may deny (cancel) their own commands but only
+    /// approve them when `allow_self_approval` is set. With an `allowed_users`
+    /// list only those users may decide; otherwise the platform's
+    /// `can_approve` metadata is used (defaulting to allowed).
+    /// Returns the message to show a non-approver.
+    fn check_approver(&self, approval: &PendingApproval, message: &TriggerMessage, is_deny: bool) -> Result<(), String> {
+        let user_id = &message.user.id;
+        let context = self.available_contexts.get(&approval.context_name).map(|ctx| ctx.clone());
+        let rules = context
+            .as_ref()
+            .and_then(|ctx| ctx.approval.clone())
+            .unwrap_or_else(|| self.config.approval.clone());
+
+        if !is_deny && approval.user_id == *user_id && !rules.allow_self_approval {
+            return Err(self.message(user_id, "self_approval_denied", &[("user", user_id)]));
+        }
+
+        if !rules.allowed_users.is_empty() {
+            let mut policy = PolicyEngine::new();
+            policy.set_approval_users(rules.allowed_users.clone());
+            if policy.can_user_approve(user_id) {
+                return Ok(());
+            }
+            return Err(match context.filter(|ctx| ctx.has_approvers()) {
+                Some(ctx) => self.message(user_id, "not_context_approver", &[
+                    ("user", user_id),
+                    ("emoji", &ctx.emoji),
+                    ("context", &ctx.display_name),
+                    ("approvers", &rules.allowed_users.join(", ")),
+                ]),
+                None => self.message(user_id, "not_authorized_approver", &[("user", user_id)]),
+            });
+        }
+
+        let can_approve = message.metadata.get("can_approve")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true); // Default to true for backward compatibility
+        if can_approve {
+            Ok(())
+        } else {
+            Err(self.message(user_id, "not_authorized_approver", &[("user", user_id)]))
+        }
+    }
+
+    /// Record an approval decision in the audit log
+    ///
+    /// The decision is emitted to the approval context's audit sink, falling
+    /// back to the handler's. Returns the outcome as JSON so it can travel with the chat response.
+    fn record_approval_outcome(
+        &self,
+        approval: &PendingApproval,
+        message: &TriggerMessage,
+        decision: ApprovalDecision,
+        status: ApprovalResultStatus,
+    ) -> serde_json::Value {
+        let outcome = ApprovalOutcome {
+            command: approval.command.clone(),
+            decision,
+            decided_by: message.user.id.clone(),
+            decided_at: chrono::Utc::now(),
+            requested_by: approval.user_id.clone(),
+            requested_at: approval.requested_at,
+            platform: message.platform.clone(),
+            channel_id: approval.channel_id.clone(),
+            agent_name: approval.agent_name.clone(),
+            status,
+        };
+        let value = serde_json::to_value(&outcome).unwrap_or_default();
+        let audit = self
+            .available_contexts
+            .get(&approval.context_name)
+            .and_then(|ctx| ctx.audit.clone())
+            .or_else(|| self.config.audit.clone());
+        self.approval_audit.record(outcome, audit.as_ref());
+        value
+    }
+}
+
+/// Platform wrapper that notes whether an error response was sent
+///
+/// Delegates everything (including `as_any`, so platform downcasts keep
+/// working) to the wrapped platform.
+struct ErrorObserver {
+    inner: Arc<dyn TriggerPlatform>,
+    saw_error: AtomicBool,
+}
+
+impl ErrorObserver {
+    fn new(inner: Arc<dyn TriggerPlatform>) -> Self {
+        Self {
+            inner,
+            saw_error: AtomicBool::new(false),
+        }
+    }
+
+    fn saw_error(&self) -> bool {
+        self.saw_error.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait::async_trait]
+impl TriggerPlatform for ErrorObserver {
+    async fn parse_message(
+        &self,
+        raw: &[u8],
+        headers: &HashMap<String, String>,
+    ) -> Result<TriggerMessage, crate::platforms::PlatformError> {
+        self.inner.parse_message(raw, headers).await
+    }
+
+    async fn send_response(
+        &self,
+        channel: &str,
+        response: TriggerResponse,
+    ) -> Result<(), crate::platforms::PlatformError> {
+        if response.status == crate::response::ResponseStatus::Error {
+            self.saw_error.store(true, Ordering::Relaxed);
+        }
+        self.inner.send_response(channel, response).await
+    }
+
+    fn platform_name(&self) -> &'static str {
+        self.inner.platform_name()
+    }
+
+    async fn verify_signature(&self, payload: &[u8], signature: &str) -> bool {
+        self.inner.verify_signature(payload, signature).await
+    }
+
+    fn bot_name(&self) -> &str {
+        self.inner.bot_name()
+    }
+
+    fn supports_threading(&self) -> bool {
+        self.inner.supports_threading()
+    }
+
+    fn supports_interactive(&self) -> bool {
+        self.inner.supports_interactive()
+    }
+
+    fn supports_files(&self) -> bool {
+        self.inner.supports_files()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self.inner.as_any()
+    }
 }
 
 /// Truncate output to a maximum length, adding ellipsis if needed
@@ -2707,13 +3649,30 @@ fn truncate_output(output: &str, max_len: usize) -> String {
     if output.len() <= max_len {
         output.to_string()
     } else {
-        format!("{}...\n[Output truncated - {} more characters]", &output[..max_len], output.len() - max_len)
+        let mut end = max_len;
+        while !output.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...\n[Output truncated - {} more characters]", &output[..end], output.len() - end)
     }
 }
 
+/// Completion notice for a background task, posted above its result
+fn background_task_notification(reply: &RunReply, task_id: &str, status: TaskStatus, result: &TaskResult) -> String {
+    let elapsed = format!("{:.1}", result.execution_time_ms as f64 / 1000.0);
+    let id = match status {
+        TaskStatus::Cancelled => "task_cancelled_notice",
+        _ if result.success => "task_completed_notice",
+        _ => "task_failed_notice",
+    };
+    reply.message(id, &[("task", task_id), ("agent", &reply.agent_name), ("seconds", &elapsed)])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::response::ResponseStatus;
+    use aof_tools::{CommandOutput, FakeRunner};
 
     #[tokio::test]
     async fn test_handler_creation() {
@@ -2723,4 +3682,495 @@ mod tests {
         assert_eq!(handler.platforms.len(), 0);
         assert!(handler.config.auto_ack);
     }
+
+    /// Platform that records responses instead of sending them
+    #[derive(Default)]
+    struct RecordingPlatform {
+        sent: std::sync::Mutex<Vec<TriggerResponse>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TriggerPlatform for RecordingPlatform {
+        async fn parse_message(
+            &self,
+            _raw: &[u8],
+            _headers: &HashMap<String, String>,
+        ) -> Result<TriggerMessage, crate::platforms::PlatformError> {
+            Err(crate::platforms::PlatformError::UnsupportedMessageType)
+        }
+
+        async fn send_response(
+            &self,
+            _channel: &str,
+            response: TriggerResponse,
+        ) -> Result<(), crate::platforms::PlatformError> {
+            self.sent.lock().unwrap().push(response);
+            Ok(())
+        }
+
+        fn platform_name(&self) -> &'static str {
+            "slack"
+        }
+
+        async fn verify_signature(&self, _payload: &[u8], _signature: &str) -> bool {
+            true
+        }
+
+        fn bot_name(&self) -> &str {
+            "aofbot"
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    fn reaction(user_id: &str, reaction: &str, item_ts: &str) -> TriggerMessage {
+        let user = TriggerUser {
+            id: user_id.to_string(),
+            username: None,
+            display_name: None,
+            is_bot: false,
+        };
+        let mut message = TriggerMessage::new(
+            "evt1".to_string(),
+            "slack".to_string(),
+            "C123".to_string(),
+            user,
+            String::new(),
+        );
+        message.metadata.insert("reaction".to_string(), serde_json::json!(reaction));
+        message.metadata.insert("item_ts".to_string(), serde_json::json!(item_ts));
+        message
+    }
+
+    fn pending(command: &str) -> PendingApproval {
+        PendingApproval {
+            command: command.to_string(),
+            user_id: "U_REQ".to_string(),
+            channel_id: "C123".to_string(),
+            message_ts: "1700000000.000100".to_string(),
+            requested_at: chrono::Utc::now(),
+            agent_name: "k8s-ops".to_string(),
+            original_message: "restart the api".to_string(),
+            context_name: "k8s".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approval_records_outcome() {
+        let handler = TriggerHandler::new(Arc::new(RuntimeOrchestrator::new()));
+        let recorder = Arc::new(RecordingPlatform::default());
+        let platform: Arc<dyn TriggerPlatform> = recorder.clone();
+        handler
+            .pending_approvals
+            .insert("1700000000.000100".to_string(), pending("echo restarted"));
+
+        handler
+            .handle_reaction_event(&reaction("U_APPROVER", "white_check_mark", "1700000000.000100"), &platform)
+            .await
+            .unwrap();
+
+        let outcomes = handler.approval_audit().all();
+        assert_eq!(outcomes.len(), 1);
+        let outcome = &outcomes[0];
+        assert_eq!(outcome.command, "echo restarted");
+        assert_eq!(outcome.decision, ApprovalDecision::Approved);
+        assert_eq!(outcome.decided_by, "U_APPROVER");
+        assert_eq!(outcome.requested_by, "U_REQ");
+        assert_eq!(outcome.status, ApprovalResultStatus::Succeeded);
+        assert!(outcome.decided_at >= outcome.requested_at);
+
+        // The final chat message carries the same record
+        let sent = recorder.sent.lock().unwrap();
+        let last = sent.last().unwrap();
+        assert!(last.text.contains("Command completed successfully"));
+        assert_eq!(last.metadata["approval_outcome"]["decision"], "approved");
+        assert_eq!(last.metadata["approval_outcome"]["status"], "succeeded");
+    }
+
+    #[tokio::test]
+    async fn test_denial_records_outcome() {
+        let handler = TriggerHandler::new(Arc::new(RuntimeOrchestrator::new()));
+        let platform: Arc<dyn TriggerPlatform> = Arc::new(RecordingPlatform::default());
+        handler
+            .pending_approvals
+            .insert("1700000000.000100".to_string(), pending("kubectl delete ns prod"));
+
+        handler
+            .handle_reaction_event(&reaction("U_APPROVER", "x", "1700000000.000100"), &platform)
+            .await
+            .unwrap();
+
+        let outcomes = handler.approval_audit().all();
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].decision, ApprovalDecision::Denied);
+        assert_eq!(outcomes[0].status, ApprovalResultStatus::NotExecuted);
+    }
+
+    #[tokio::test]
+    async fn test_decision_is_emitted_to_context_audit_sink() {
+        let path = std::env::temp_dir().join(format!("aof-approvals-{}.jsonl", uuid::Uuid::new_v4()));
+        let handler = TriggerHandler::new(Arc::new(RuntimeOrchestrator::new()));
+        let mut ctx = handler.available_contexts.get("k8s").unwrap().clone();
+        ctx.audit = Some(AuditConfig {
+            enabled: true,
+            sink: Some(format!("file://{}", path.display())),
+            events: vec![aof_core::AuditEvent::ApprovalDenied],
+            ..Default::default()
+        });
+        handler.register_context("k8s", ctx);
+        let platform: Arc<dyn TriggerPlatform> = Arc::new(RecordingPlatform::default());
+        handler
+            .pending_approvals
+            .insert("1700000000.000100".to_string(), pending("kubectl delete ns prod"));
+
+        handler
+            .handle_reaction_event(&reaction("U_APPROVER", "x", "1700000000.000100"), &platform)
+            .await
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let record: serde_json::Value = serde_json::from_str(contents.trim()).unwrap();
+        assert_eq!(record["event"], "approval_denied");
+        assert_eq!(record["command"], "kubectl delete ns prod");
+        assert_eq!(record["decided_by"], "U_APPROVER");
+    }
+
+    fn guarded_k8s(handler: &TriggerHandler, approvers: &[&str]) {
+        let mut ctx = handler.available_contexts.get("k8s").unwrap().clone();
+        ctx.approval = Some(ApprovalConfig {
+            allowed_users: approvers.iter().map(|a| a.to_string()).collect(),
+            ..Default::default()
+        });
+        handler.register_context("k8s", ctx);
+    }
+
+    #[tokio::test]
+    async fn test_context_approver_allowlist() {
+        let handler = TriggerHandler::new(Arc::new(RuntimeOrchestrator::new()));
+        guarded_k8s(&handler, &["U_LEAD", "U_REQ"]);
+        let recorder = Arc::new(RecordingPlatform::default());
+        let platform: Arc<dyn TriggerPlatform> = recorder.clone();
+        handler
+            .pending_approvals
+            .insert("1700000000.000100".to_string(), pending("echo scaled"));
+
+        // Not on the allowlist: rejected even though the platform would allow it
+        let mut outsider = reaction("U_DEV", "white_check_mark", "1700000000.000100");
+        outsider.metadata.insert("can_approve".to_string(), serde_json::json!(true));
+        handler.handle_reaction_event(&outsider, &platform).await.unwrap();
+
+        assert!(handler.pending_approvals.contains_key("1700000000.000100"));
+        assert!(handler.approval_audit().is_empty());
+        let rejection = recorder.sent.lock().unwrap().last().unwrap().clone();
+        assert!(rejection.text.contains("is not an approver for the"));
+        assert!(rejection.text.contains("Kubernetes"));
+        assert_eq!(rejection.ephemeral_user.as_deref(), Some("U_DEV"));
+
+        // Allowlisted user approves
+        handler
+            .handle_reaction_event(&reaction("U_LEAD", "white_check_mark", "1700000000.000100"), &platform)
+            .await
+            .unwrap();
+
+        assert!(handler.pending_approvals.is_empty());
+        let outcomes = handler.approval_audit().all();
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].decided_by, "U_LEAD");
+    }
+
+    #[tokio::test]
+    async fn test_self_approval_is_refused() {
+        let handler = TriggerHandler::new(Arc::new(RuntimeOrchestrator::new()));
+        guarded_k8s(&handler, &["U_LEAD", "U_REQ"]);
+        let recorder = Arc::new(RecordingPlatform::default());
+        let platform: Arc<dyn TriggerPlatform> = recorder.clone();
+        handler
+            .pending_approvals
+            .insert("1700000000.000100".to_string(), pending("echo scaled"));
+
+        // The requester is an approver, but not for their own command
+        handler
+            .handle_reaction_event(&reaction("U_REQ", "white_check_mark", "1700000000.000100"), &platform)
+            .await
+            .unwrap();
+        assert!(handler.pending_approvals.contains_key("1700000000.000100"));
+        assert!(recorder.sent.lock().unwrap().last().unwrap().text.contains("cannot approve your own"));
+
+        // They can still cancel it
+        handler
+            .handle_reaction_event(&reaction("U_REQ", "x", "1700000000.000100"), &platform)
+            .await
+            .unwrap();
+        assert!(handler.pending_approvals.is_empty());
+        assert_eq!(handler.approval_audit().all()[0].decision, ApprovalDecision::Denied);
+
+        // Contexts can allow it, and contexts without rules defer to the handler's
+        let mut ctx = handler.available_contexts.get("k8s").unwrap().clone();
+        ctx.approval.as_mut().unwrap().allow_self_approval = true;
+        handler.register_context("k8s", ctx);
+        handler
+            .pending_approvals
+            .insert("1700000000.000100".to_string(), pending("echo scaled"));
+        handler
+            .handle_reaction_event(&reaction("U_REQ", "white_check_mark", "1700000000.000100"), &platform)
+            .await
+            .unwrap();
+        assert!(handler.pending_approvals.is_empty());
+        assert!(!handler.available_contexts.get("aws").unwrap().has_approvers());
+    }
+
+    fn handler_with_recorder() -> (TriggerHandler, Arc<RecordingPlatform>) {
+        let mut handler = TriggerHandler::new(Arc::new(RuntimeOrchestrator::new()));
+        let recorder = Arc::new(RecordingPlatform::default());
+        handler.register_platform(recorder.clone());
+        (handler, recorder)
+    }
+
+    fn text_message(text: &str) -> TriggerMessage {
+        let user = TriggerUser {
+            id: "U_DEV".to_string(),
+            username: None,
+            display_name: None,
+            is_bot: false,
+        };
+        TriggerMessage::new("m1".to_string(), "slack".to_string(), "C123".to_string(), user, text.to_string())
+    }
+
+    #[test]
+    fn test_user_locale_falls_back_to_context() {
+        let config = TriggerHandlerConfig {
+            messages: MessageCatalog::new().with_message("de", "processing", "Wird bearbeitet..."),
+            ..Default::default()
+        };
+        let handler = TriggerHandler::with_config(Arc::new(RuntimeOrchestrator::new()), config);
+        let mut ctx = handler.available_contexts.get("k8s").unwrap().clone();
+        ctx.locale = Some("de".to_string());
+        handler.register_context("k8s", ctx);
+
+        assert_eq!(handler.get_user_locale("U_DEV"), DEFAULT_LOCALE);
+        handler.set_user_context("U_DEV", "k8s");
+        assert_eq!(handler.get_user_locale("U_DEV"), "de");
+        assert_eq!(handler.message("U_DEV", "processing", &[]), "Wird bearbeitet...");
+
+        handler.set_user_locale("U_DEV", "en");
+        assert_eq!(handler.message("U_DEV", "processing", &[]), "Processing your request...");
+    }
+
+    #[tokio::test]
+    async fn test_command_replies_use_catalog() {
+        let config = TriggerHandlerConfig {
+            messages: MessageCatalog::new().with_message("de", "task_not_found", "❌ Aufgabe nicht gefunden: `{task}`"),
+            ..Default::default()
+        };
+        let mut handler = TriggerHandler::with_config(Arc::new(RuntimeOrchestrator::new()), config);
+        let recorder = Arc::new(RecordingPlatform::default());
+        handler.register_platform(recorder.clone());
+        handler.set_user_locale("U_DEV", "de");
+
+        handler.handle_message("slack", text_message("/status task task-nope")).await.unwrap();
+        assert_eq!(
+            recorder.sent.lock().unwrap().last().unwrap().text,
+            "❌ Aufgabe nicht gefunden: `task-nope`"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verbosity_command_and_context_fallback() {
+        let (handler, recorder) = handler_with_recorder();
+        let mut ctx = handler.available_contexts.get("k8s").unwrap().clone();
+        ctx.verbosity = Some(Verbosity::Terse);
+        handler.register_context("k8s", ctx);
+
+        assert_eq!(handler.get_user_verbosity("U_DEV"), Verbosity::Normal);
+        handler.set_user_context("U_DEV", "k8s");
+        assert_eq!(handler.get_user_verbosity("U_DEV"), Verbosity::Terse);
+
+        handler.handle_message("slack", text_message("/verbosity verbose")).await.unwrap();
+        assert_eq!(handler.get_user_verbosity("U_DEV"), Verbosity::Verbose);
+        assert_eq!(recorder.sent.lock().unwrap().last().unwrap().text, "✅ Reply verbosity set to verbose.");
+
+        handler.handle_message("slack", text_message("/verbosity loud")).await.unwrap();
+        assert_eq!(handler.get_user_verbosity("U_DEV"), Verbosity::Verbose);
+        assert!(recorder.sent.lock().unwrap().last().unwrap().text.starts_with("Usage: /verbosity"));
+    }
+
+    #[tokio::test]
+    async fn test_context_switch_check_reachable() {
+        let (handler, recorder) = handler_with_recorder();
+        let handler = handler.with_probe_runner(Arc::new(FakeRunner::new(|_, _| {
+            CommandOutput::ok(r#"{"serverVersion":{"gitVersion":"v1.29.2"}}"#)
+        })));
+
+        handler.handle_message("slack", text_message("/context k8s --check")).await.unwrap();
+
+        let reply = recorder.sent.lock().unwrap().last().unwrap().clone();
+        assert_eq!(handler.get_user_context("U_DEV"), "k8s");
+        assert_eq!(reply.status, ResponseStatus::Success);
+        assert!(reply.text.starts_with("Switched to ☸️ Kubernetes"));
+        assert!(reply.text.ends_with("✅ Connected - kubernetes: server v1.29.2"));
+        assert_eq!(reply.metadata["context_health"], "reachable");
+    }
+
+    #[tokio::test]
+    async fn test_context_switch_probe_warns_when_unreachable() {
+        let config = TriggerHandlerConfig {
+            probe_context_switch: true,
+            ..Default::default()
+        };
+        let mut handler = TriggerHandler::with_config(Arc::new(RuntimeOrchestrator::new()), config)
+            .with_probe_runner(Arc::new(FakeRunner::new(|_, _| {
+                CommandOutput::failed(1, "Unable to connect to the server: dial tcp 10.0.0.1:443: i/o timeout\n")
+            })));
+        let recorder = Arc::new(RecordingPlatform::default());
+        handler.register_platform(recorder.clone());
+
+        handler.handle_message("slack", text_message("/context k8s")).await.unwrap();
+
+        // The switch still happens; the reply warns instead
+        let reply = recorder.sent.lock().unwrap().last().unwrap().clone();
+        assert_eq!(handler.get_user_context("U_DEV"), "k8s");
+        assert_eq!(reply.status, ResponseStatus::Warning);
+        assert!(reply.text.starts_with("Switched to ☸️ Kubernetes"));
+        assert!(reply.text.contains(
+            "⚠️ Could not reach Kubernetes - kubernetes: Unable to connect to the server: dial tcp 10.0.0.1:443: i/o timeout"
+        ));
+        assert_eq!(reply.metadata["context_health"], "unreachable");
+    }
+
+    #[tokio::test]
+    async fn test_status_tool_output_follows_verbosity() {
+        let (handler, recorder) = handler_with_recorder();
+        let handle = handler.orchestrator.submit_task(Task::new(
+            "task-1".to_string(),
+            "Background run of k8s-ops".to_string(),
+            "k8s-ops".to_string(),
+            "check pods".to_string(),
+        ));
+        handle.update_status(TaskStatus::Running).await;
+        for i in 0..8 {
+            handle
+                .record_progress(&StreamEvent::ToolCallComplete {
+                    tool_name: "kubectl".to_string(),
+                    tool_id: format!("call-{}", i),
+                    success: true,
+                    execution_time_ms: 10,
+                    error: None,
+                    output: Some(format!("pod-{} Running", i)),
+                })
+                .await;
+        }
+        let status_text = || recorder.sent.lock().unwrap().last().unwrap().text.clone();
+
+        handler.set_user_verbosity("U_DEV", Verbosity::Terse);
+        handler.handle_message("slack", text_message("/status task task-1")).await.unwrap();
+        let terse = status_text();
+        assert!(terse.contains("8 tool call(s)"));
+        assert!(!terse.contains("Recent output"));
+
+        handler.set_user_verbosity("U_DEV", Verbosity::Normal);
+        handler.handle_message("slack", text_message("/status task task-1")).await.unwrap();
+        let normal = status_text();
+        assert!(normal.contains("✓ kubectl: pod-7 Running"));
+        assert!(!normal.contains("pod-2 Running"));
+
+        handler.set_user_verbosity("U_DEV", Verbosity::Verbose);
+        handler.handle_message("slack", text_message("/status task task-1")).await.unwrap();
+        let verbose = status_text();
+        assert!(verbose.contains("✓ kubectl: pod-0 Running"));
+        assert!(verbose.contains("✓ kubectl: pod-7 Running"));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_commands_and_latency() {
+        let (handler, _recorder) = handler_with_recorder();
+        let help = MessageKind::Command(CommandType::Help);
+        let status = MessageKind::Command(CommandType::Status);
+
+        handler.handle_message("slack", text_message("/help")).await.unwrap();
+        handler.handle_message("slack", text_message("/help")).await.unwrap();
+        handler.handle_message("slack", text_message("/status task missing-task")).await.unwrap();
+
+        let help_stats = handler.metrics().snapshot("slack", help).unwrap();
+        assert_eq!(help_stats.count, 2);
+        assert_eq!(help_stats.errors, 0);
+        assert!(help_stats.latency > std::time::Duration::ZERO);
+
+        // Unknown task sends an error response, which counts as an error
+        let status_stats = handler.metrics().snapshot("slack", status).unwrap();
+        assert_eq!(status_stats.count, 1);
+        assert_eq!(status_stats.errors, 1);
+
+        let exported = handler.metrics().render_prometheus();
+        assert!(exported.contains("aof_trigger_messages_total{platform=\"slack\",command=\"help\"} 2"));
+        assert!(exported.contains("aof_trigger_errors_total{platform=\"slack\",command=\"status\"} 1"));
+        assert!(exported.contains("aof_trigger_handling_seconds_count{platform=\"slack\",command=\"help\"} 2"));
+    }
+
+    fn canned_agent(handler: &mut TriggerHandler, output: &str) {
+        let runtime = Runtime::new()
+            .with_canned_responses(HashMap::from([("k8s-ops".to_string(), output.to_string())]));
+        handler.set_runtime(Arc::new(RwLock::new(runtime)));
+    }
+
+    fn user_turns(handler: &TriggerHandler) -> Vec<String> {
+        handler
+            .get_conversation_history("C123", None)
+            .into_iter()
+            .filter(|entry| entry.role == "user")
+            .map(|entry| entry.content)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_clarification_pauses_and_resumes_with_answer() {
+        let (mut handler, recorder) = handler_with_recorder();
+        handler.set_user_context("U_DEV", "k8s");
+        canned_agent(&mut handler, "needs_clarification: true\nquestion: Which namespace?\noptions: default, prod");
+
+        handler.handle_message("slack", text_message("how many pods are running?")).await.unwrap();
+
+        let question = recorder.sent.lock().unwrap().last().unwrap().clone();
+        assert_eq!(question.text, "❓ Which namespace?\nOptions: default, prod");
+        let pending = handler.pending_clarifications().get("C123").unwrap().clone();
+        assert_eq!(pending.original_input, "how many pods are running?");
+        assert_eq!(pending.agent_name, "k8s-ops");
+
+        // The reply resumes the same agent with the question and answer fed back in
+        canned_agent(&mut handler, "3 pods running in prod");
+        handler.handle_message("slack", text_message("prod")).await.unwrap();
+
+        let answer = recorder.sent.lock().unwrap().last().unwrap().clone();
+        assert_eq!(answer.text, "3 pods running in prod");
+        assert!(handler.pending_clarifications().is_empty());
+        assert_eq!(
+            user_turns(&handler).last().unwrap(),
+            "how many pods are running?\n\nYou asked: Which namespace?\nUser answered: prod"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_clarification_is_not_resumed() {
+        let (mut handler, _recorder) = handler_with_recorder();
+        handler.set_user_context("U_DEV", "k8s");
+        canned_agent(&mut handler, "3 pods running");
+        handler.pending_clarifications.insert(
+            "C123".to_string(),
+            PendingClarification {
+                agent_name: "k8s-ops".to_string(),
+                user_id: "U_DEV".to_string(),
+                original_input: "how many pods are running?".to_string(),
+                question: "Which namespace?".to_string(),
+                asked_at: chrono::Utc::now() - chrono::Duration::seconds(600),
+            },
+        );
+
+        handler.handle_message("slack", text_message("prod")).await.unwrap();
+
+        // Treated as a new message rather than an answer
+        assert!(handler.pending_clarifications().is_empty());
+        assert_eq!(user_turns(&handler), vec!["prod"]);
+    }
 }
@@ -0,0 +1,208 @@
+//! Delivering the result of an agent run
+//!
+//! Interactive and background runs finish the same way: the circuit breaker
+//! records the outcome, a clarification question pauses the conversation, a
+//! proposed write becomes a pending approval with approve/deny reactions, and
+//! anything else is posted as the reply.
+//!
+//! `RunReply` owns everything this needs, so a background task can deliver its
+//! result after the command that started it has returned.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use tracing::{error, info};
+
+use super::{
+    parse_approval_output, push_conversation_entry, CircuitBreaker, ClarificationRequest, ConversationEntry,
+    MessageCatalog, PendingApproval, PendingClarification, TriggerHandler,
+};
+use crate::platforms::TriggerPlatform;
+use crate::response::TriggerResponseBuilder;
+
+/// Where and how to deliver the result of one agent run
+#[derive(Clone)]
+pub(super) struct RunReply {
+    pub(super) agent_name: String,
+    pub(super) breaker_key: String,
+    pub(super) user_id: String,
+    pub(super) channel_id: String,
+    pub(super) thread_id: Option<String>,
+    /// The user's request, kept for approvals and clarifications
+    pub(super) input: String,
+    /// Context the user had active when the run started
+    pub(super) context_name: String,
+    pub(super) locale: String,
+    /// Text posted above the result (background task notices)
+    pub(super) header: Option<String>,
+    /// Background task the run belongs to
+    pub(super) task_id: Option<String>,
+    pub(super) messages: MessageCatalog,
+    pub(super) breakers: Arc<CircuitBreaker>,
+    pub(super) pending_approvals: Arc<DashMap<String, PendingApproval>>,
+    pub(super) pending_clarifications: Arc<DashMap<String, PendingClarification>>,
+    pub(super) conversation_memory: Arc<DashMap<String, Vec<ConversationEntry>>>,
+}
+
+impl RunReply {
+    /// Post a background task's notice above the result
+    pub(super) fn for_task(mut self, task_id: &str, header: String) -> Self {
+        self.task_id = Some(task_id.to_string());
+        self.header = Some(header);
+        self
+    }
+
+    /// Record the run's outcome and post its result
+    pub(super) async fn deliver(&self, platform_impl: &Arc<dyn TriggerPlatform>, result: Result<String, String>) {
+        let output = match result {
+            Ok(output) => output,
+            Err(e) => {
+                error!("Agent execution failed: {}", e);
+                self.breakers.record_failure(&self.breaker_key);
+                let error_msg = self.message("agent_error", &[("error", &e)]);
+                // Store error in conversation memory too
+                self.remember(&error_msg);
+                self.send(platform_impl, self.response(error_msg).error()).await;
+                return;
+            }
+        };
+
+        info!("Agent '{}' executed successfully", self.agent_name);
+        self.breakers.record_success(&self.breaker_key);
+
+        if let Some(request) = ClarificationRequest::parse(&output) {
+            self.ask_clarification(platform_impl, request).await;
+            return;
+        }
+
+        // Parse output for approval requirements
+        let (requires_approval, command, clean_output) = parse_approval_output(&output);
+        if !requires_approval {
+            // Normal response without approval
+            self.remember(&output);
+            self.send(platform_impl, self.response(output).success()).await;
+            return;
+        }
+
+        match command {
+            Some(cmd) => self.request_approval(platform_impl, cmd, clean_output).await,
+            // requires_approval but no command - just send the output
+            None => self.send(platform_impl, self.response(clean_output).success()).await,
+        }
+    }
+
+    /// Post an agent's clarification question and wait for the user's answer
+    pub(super) async fn ask_clarification(
+        &self,
+        platform_impl: &Arc<dyn TriggerPlatform>,
+        request: ClarificationRequest,
+    ) {
+        let prompt = request.prompt();
+        info!("Agent '{}' asked for clarification: {}", self.agent_name, request.question);
+
+        self.remember(&prompt);
+        self.pending_clarifications.insert(
+            TriggerHandler::get_conversation_key(&self.channel_id, self.thread_id.as_deref()),
+            PendingClarification {
+                agent_name: self.agent_name.clone(),
+                user_id: self.user_id.clone(),
+                original_input: self.input.clone(),
+                question: request.question,
+                asked_at: chrono::Utc::now(),
+            },
+        );
+
+        self.send(platform_impl, self.response(prompt)).await;
+    }
+
+    /// Ask for approval of a proposed command
+    ///
+    /// On Slack the request gets approve/deny reactions and is stored as a
+    /// pending approval; other platforms only see the request.
+    async fn request_approval(&self, platform_impl: &Arc<dyn TriggerPlatform>, cmd: String, clean_output: String) {
+        info!("Command requires approval: {}", cmd);
+
+        let approval_text = self.with_header(
+            self.message("approval_required", &[("output", &clean_output), ("command", &cmd)]),
+        );
+
+        // Try to use SlackPlatform directly for approval flow
+        let Some(slack) = platform_impl.as_any().downcast_ref::<crate::platforms::SlackPlatform>() else {
+            // Fallback for non-Slack platforms
+            self.send(platform_impl, self.builder().text(approval_text)).await;
+            return;
+        };
+
+        match slack
+            .post_message_with_ts(&self.channel_id, &approval_text, self.thread_id.as_deref())
+            .await
+        {
+            Ok((channel, msg_ts)) => {
+                // Add reactions for approve/deny
+                let _ = slack.add_reaction(&channel, &msg_ts, "white_check_mark").await;
+                let _ = slack.add_reaction(&channel, &msg_ts, "x").await;
+
+                let approval = PendingApproval {
+                    command: cmd,
+                    user_id: self.user_id.clone(),
+                    channel_id: channel,
+                    message_ts: msg_ts.clone(),
+                    requested_at: chrono::Utc::now(),
+                    agent_name: self.agent_name.clone(),
+                    original_message: self.input.clone(),
+                    context_name: self.context_name.clone(),
+                };
+                self.pending_approvals.insert(msg_ts.clone(), approval);
+                info!("Stored pending approval for message {}", msg_ts);
+            }
+            Err(e) => {
+                error!("Failed to post approval message: {}", e);
+                let text = self.message("approval_request_failed", &[("error", &e.to_string())]);
+                self.send(platform_impl, self.builder().text(text).error()).await;
+            }
+        }
+    }
+
+    /// Store an assistant turn in the conversation the run belongs to
+    fn remember(&self, content: &str) {
+        push_conversation_entry(
+            &self.conversation_memory,
+            TriggerHandler::get_conversation_key(&self.channel_id, self.thread_id.as_deref()),
+            "assistant",
+            content,
+        );
+    }
+
+    pub(super) fn message(&self, id: &str, args: &[(&str, &str)]) -> String {
+        self.messages.render(&self.locale, id, args)
+    }
+
+    fn with_header(&self, text: String) -> String {
+        match &self.header {
+            Some(header) => format!("{}\n\n{}", header, text),
+            None => text,
+        }
+    }
+
+    /// Response builder; background notices are threaded and tagged with their task
+    fn builder(&self) -> TriggerResponseBuilder {
+        let mut builder = TriggerResponseBuilder::new();
+        if let Some(task_id) = &self.task_id {
+            builder = builder.metadata("task_id", serde_json::json!(task_id));
+            if let Some(thread_id) = &self.thread_id {
+                builder = builder.thread_id(thread_id.clone());
+            }
+        }
+        builder
+    }
+
+    fn response(&self, text: String) -> TriggerResponseBuilder {
+        self.builder().text(self.with_header(text))
+    }
+
+    async fn send(&self, platform_impl: &Arc<dyn TriggerPlatform>, builder: TriggerResponseBuilder) {
+        if let Err(e) = platform_impl.send_response(&self.channel_id, builder.build()).await {
+            error!("Failed to post result of agent '{}': {:?}", self.agent_name, e);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Command binding routing
+//!
+//! Decides where a bound command sends a message: the command's own binding
+//! (flow, then fleet, then agent) or the `default` binding's agent.
+//! `TriggerHandler::route_message` and `aofctl flows explain` both go through
+//! these functions, so an explanation always matches what the handler does.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use super::CommandBinding;
+use crate::command::strip_command_prefix;
+
+/// Binding used for slash commands without a usable binding of their own
+pub const DEFAULT_BINDING: &str = "default";
+
+/// Where a command binding sends a message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "name", rename_all = "snake_case")]
+pub enum BindingTarget {
+    /// An AgentFlow
+    Flow(String),
+    /// A fleet of agents
+    Fleet(String),
+    /// A single agent
+    Agent(String),
+}
+
+impl BindingTarget {
+    /// Target of a binding; a flow beats a fleet, which beats an agent
+    ///
+    /// `builtin` bindings have no target: the built-in command parser
+    /// handles them.
+    pub fn of(binding: &CommandBinding) -> Option<Self> {
+        if binding.agent.as_deref() == Some("builtin") {
+            return None;
+        }
+        binding
+            .flow
+            .clone()
+            .map(Self::Flow)
+            .or_else(|| binding.fleet.clone().map(Self::Fleet))
+            .or_else(|| binding.agent.clone().map(Self::Agent))
+    }
+}
+
+impl fmt::Display for BindingTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Flow(name) => write!(f, "flow '{}'", name),
+            Self::Fleet(name) => write!(f, "fleet '{}'", name),
+            Self::Agent(name) => write!(f, "agent '{}'", name),
+        }
+    }
+}
+
+/// A command resolved against the command bindings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BindingRoute {
+    /// Command the message invoked (without prefix)
+    pub command: String,
+    /// Binding that applies: the command's own or `default`
+    pub binding: String,
+    /// Where the message goes
+    pub target: BindingTarget,
+}
+
+impl BindingRoute {
+    /// Whether the `default` binding applies rather than the command's own
+    pub fn is_default(&self) -> bool {
+        self.binding != self.command
+    }
+}
+
+/// Resolve a command against the command bindings
+///
+/// The command's own binding applies when it has a target; otherwise only
+/// the `default` binding's agent can take the message. `None` leaves the
+/// message to the built-in command parser.
+pub fn resolve_command_binding(
+    bindings: &HashMap<String, CommandBinding>,
+    command: &str,
+) -> Option<BindingRoute> {
+    if let Some(target) = bindings.get(command).and_then(BindingTarget::of) {
+        return Some(BindingRoute {
+            command: command.to_string(),
+            binding: command.to_string(),
+            target,
+        });
+    }
+
+    let agent = bindings.get(DEFAULT_BINDING)?.agent.clone()?;
+    Some(BindingRoute {
+        command: command.to_string(),
+        binding: DEFAULT_BINDING.to_string(),
+        target: BindingTarget::Agent(agent),
+    })
+}
+
+/// Bound command at the start of a text message, and the text after it
+///
+/// Only commands with a binding count, so built-ins like `/help` are left to
+/// the command parser.
+pub fn bound_command_in_text<S: AsRef<str>>(
+    bindings: &HashMap<String, CommandBinding>,
+    text: &str,
+    prefixes: &[S],
+) -> Option<(String, Option<String>)> {
+    let body = strip_command_prefix(text, prefixes)?;
+    let mut parts = body.splitn(2, char::is_whitespace);
+    let command = parts.next()?;
+    if !bindings.contains_key(command) {
+        return None;
+    }
+    Some((command.to_string(), parts.next().map(str::to_string)))
+}
+
+/// How a text message (Telegram, WhatsApp, CLI style) will be routed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RouteExplanation {
+    /// Binding route, if a bound command starts the message
+    pub route: Option<BindingRoute>,
+    /// Text passed on to the target
+    pub text: Option<String>,
+    /// Human-readable explanation
+    pub detail: String,
+}
+
+/// Explain where a text message goes, using the same resolution as the handler
+pub fn explain_route<S: AsRef<str>>(
+    bindings: &HashMap<String, CommandBinding>,
+    text: &str,
+    prefixes: &[S],
+) -> RouteExplanation {
+    let Some((command, rest)) = bound_command_in_text(bindings, text, prefixes) else {
+        return RouteExplanation {
+            route: None,
+            text: None,
+            detail: "no bound command; the built-in command parser or the user's active agent handles the message"
+                .to_string(),
+        };
+    };
+
+    let route = resolve_command_binding(bindings, &command);
+    let detail = match &route {
+        Some(route) if route.is_default() => format!(
+            "command '{}' has no target of its own; the default binding sends it to {}",
+            command, route.target
+        ),
+        Some(route) => format!("command '{}' is bound to {}", command, route.target),
+        None => format!(
+            "command '{}' is handled by the built-in command parser",
+            command
+        ),
+    };
+    RouteExplanation {
+        route,
+        text: rest,
+        detail,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binding(agent: Option<&str>, fleet: Option<&str>, flow: Option<&str>) -> CommandBinding {
+        CommandBinding {
+            agent: agent.map(str::to_string),
+            fleet: fleet.map(str::to_string),
+            flow: flow.map(str::to_string),
+            description: String::new(),
+        }
+    }
+
+    fn bindings() -> HashMap<String, CommandBinding> {
+        HashMap::from([
+            (
+                "deploy".to_string(),
+                binding(Some("deployer"), Some("release-team"), Some("deploy")),
+            ),
+            (
+                "diagnose".to_string(),
+                binding(Some("k8s-ops"), Some("sre-team"), None),
+            ),
+            ("help".to_string(), binding(Some("builtin"), None, None)),
+            ("notes".to_string(), binding(None, None, None)),
+            ("default".to_string(), binding(Some("devops"), None, None)),
+        ])
+    }
+
+    #[test]
+    fn test_resolve_prefers_flow_then_fleet_then_agent() {
+        let bindings = bindings();
+
+        let deploy = resolve_command_binding(&bindings, "deploy").unwrap();
+        assert_eq!(deploy.target, BindingTarget::Flow("deploy".to_string()));
+        assert!(!deploy.is_default());
+        assert_eq!(
+            resolve_command_binding(&bindings, "diagnose")
+                .unwrap()
+                .target,
+            BindingTarget::Fleet("sre-team".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_default_agent() {
+        let bindings = bindings();
+
+        for command in ["help", "notes", "unbound"] {
+            let route = resolve_command_binding(&bindings, command).unwrap();
+            assert!(route.is_default(), "{}", command);
+            assert_eq!(route.target, BindingTarget::Agent("devops".to_string()));
+        }
+
+        let mut without_default = bindings;
+        without_default.remove(DEFAULT_BINDING);
+        assert!(resolve_command_binding(&without_default, "help").is_none());
+    }
+
+    #[test]
+    fn test_explain_text_message() {
+        let bindings = bindings();
+
+        let explained = explain_route(&bindings, "/deploy api to prod", &["/"]);
+        assert_eq!(
+            explained.route.unwrap().target,
+            BindingTarget::Flow("deploy".to_string())
+        );
+        assert_eq!(explained.text.as_deref(), Some("api to prod"));
+        assert_eq!(
+            explained.detail,
+            "command 'deploy' is bound to flow 'deploy'"
+        );
+
+        // Naming a flow in the text routes nowhere by itself
+        let explained = explain_route(&bindings, "please run deploy", &["/"]);
+        assert!(explained.route.is_none());
+
+        // Unbound text commands never reach the default binding
+        assert!(explain_route(&bindings, "/status", &["/"]).route.is_none());
+    }
+}
@@ -0,0 +1,142 @@
+//! Output verbosity levels
+//!
+//! The same agent serves people on phones who want a one-line answer and
+//! engineers who want to see every command it ran. Verbosity is chosen per
+//! user (`/verbosity terse`), per context, or by the handler default, and
+//! controls two things:
+//!
+//! - an instruction injected into the agent prompt asking for a response style
+//! - how much raw tool output the handler includes in its replies
+//!
+//! `normal` injects nothing, so agents behave exactly as configured.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// How much detail replies should contain
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verbosity {
+    /// Short answers, no raw tool output
+    Terse,
+    /// The agent's own style
+    #[default]
+    Normal,
+    /// Reasoning plus the commands run and their output
+    Verbose,
+}
+
+impl Verbosity {
+    /// Every level, least to most detailed
+    pub const ALL: [Verbosity; 3] = [Self::Terse, Self::Normal, Self::Verbose];
+
+    /// Level name as used in commands and config
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Terse => "terse",
+            Self::Normal => "normal",
+            Self::Verbose => "verbose",
+        }
+    }
+
+    /// Instruction added to the agent prompt (None: leave the prompt alone)
+    pub fn prompt_instruction(&self) -> Option<&'static str> {
+        match self {
+            Self::Terse => Some(
+                "Response style: terse. Answer in at most three short sentences or a short list. \
+                Do not explain your steps or paste raw command output unless asked.",
+            ),
+            Self::Normal => None,
+            Self::Verbose => Some(
+                "Response style: verbose. Explain what you checked and why, list the commands you ran, \
+                and include the relevant raw output from each tool call.",
+            ),
+        }
+    }
+
+    /// Prefix `input` with this level's prompt instruction
+    pub fn apply_to_prompt(&self, input: &str) -> String {
+        match self.prompt_instruction() {
+            Some(instruction) => format!("[{}]\n\n{}", instruction, input),
+            None => input.to_string(),
+        }
+    }
+
+    /// Maximum characters of a single tool result shown in replies
+    pub fn tool_output_chars(&self) -> usize {
+        match self {
+            Self::Terse => 300,
+            Self::Normal => 2500,
+            Self::Verbose => 10000,
+        }
+    }
+
+    /// Lines of recent tool output shown in task progress
+    pub fn tool_output_lines(&self) -> usize {
+        match self {
+            Self::Terse => 0,
+            Self::Normal => 5,
+            Self::Verbose => 20,
+        }
+    }
+}
+
+impl fmt::Display for Verbosity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Verbosity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "terse" | "brief" | "short" => Ok(Self::Terse),
+            "normal" | "default" => Ok(Self::Normal),
+            "verbose" | "debug" | "full" => Ok(Self::Verbose),
+            other => Err(format!("Unknown verbosity '{}' (expected terse, normal or verbose)", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_injection_per_level() {
+        let terse = Verbosity::Terse.apply_to_prompt("why is checkout down?");
+        let verbose = Verbosity::Verbose.apply_to_prompt("why is checkout down?");
+
+        assert!(terse.contains("Response style: terse"));
+        assert!(verbose.contains("Response style: verbose"));
+        assert_ne!(terse, verbose);
+        assert!(terse.ends_with("why is checkout down?"));
+        assert!(verbose.ends_with("why is checkout down?"));
+        // Normal leaves the prompt untouched
+        assert_eq!(Verbosity::Normal.apply_to_prompt("why is checkout down?"), "why is checkout down?");
+    }
+
+    #[test]
+    fn test_tool_output_grows_with_verbosity() {
+        let [terse, normal, verbose] = Verbosity::ALL;
+        assert!(terse.tool_output_chars() < normal.tool_output_chars());
+        assert!(normal.tool_output_chars() < verbose.tool_output_chars());
+        assert_eq!(terse.tool_output_lines(), 0);
+        assert!(normal.tool_output_lines() < verbose.tool_output_lines());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("TERSE".parse::<Verbosity>().unwrap(), Verbosity::Terse);
+        assert_eq!("debug".parse::<Verbosity>().unwrap(), Verbosity::Verbose);
+        assert_eq!(Verbosity::default(), Verbosity::Normal);
+        assert!("loud".parse::<Verbosity>().is_err());
+        for level in Verbosity::ALL {
+            assert_eq!(level.to_string().parse::<Verbosity>().unwrap(), level);
+        }
+    }
+}
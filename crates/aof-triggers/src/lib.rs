@@ -15,12 +15,17 @@ pub mod platforms;
 pub mod response;
 pub mod safety;
 pub mod server;
+#[cfg(feature = "test-util")]
+pub mod testing;
 
 // Re-export main types from command module
 pub use command::{CommandContext, CommandType, TriggerCommand, TriggerTarget};
 
 // Re-export main types from handler module
-pub use handler::{TriggerHandler, TriggerHandlerConfig, CommandBinding};
+pub use handler::{
+    TriggerHandler, TriggerHandlerConfig, CommandBinding, CircuitBreakerConfig, MessageCatalog, Verbosity, DEFAULT_LOCALE,
+    BindingRoute, BindingTarget, RouteExplanation, explain_route, resolve_command_binding, DEFAULT_BINDING,
+};
 
 // Re-export main types from platforms module
 pub use platforms::{Platform, PlatformConfig};
@@ -29,7 +34,10 @@ pub use platforms::{Platform, PlatformConfig};
 pub use response::{ResponseFormat, TriggerResponse, TriggerResponseBuilder};
 
 // Re-export main types from server module
-pub use server::{TriggerServer, TriggerServerBuilder, TriggerServerConfig};
+pub use server::{
+    LogConfig, LogFileConfig, LogFormat, LogGuard, LogRotation, TriggerServer, TriggerServerBuilder,
+    TriggerServerConfig,
+};
 
 // Re-export flow types
 pub use flow::{FlowMatch, FlowRegistry, FlowRouter, MatchReason};
@@ -39,6 +47,7 @@ pub use safety::{
     ActionClass, ClassificationResult, ToolClassifier, ToolClassifications,
     PlatformPolicy, PolicyDecision, PolicyEngine,
     SafetyContext, SafetyConfig,
+    SafeMode, SafeModeState, SAFE_MODE_ENV, GuardedToolExecutor,
 };
 
 // Re-export error types from aof-core
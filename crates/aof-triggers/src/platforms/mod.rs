@@ -118,7 +118,12 @@ impl TriggerMessage {
 
     /// Check if message is a command (starts with /)
     pub fn is_command(&self) -> bool {
-        self.text.trim().starts_with('/')
+        self.is_command_with_prefixes(&[crate::command::DEFAULT_COMMAND_PREFIX])
+    }
+
+    /// Check if message starts with any of the given command prefixes
+    pub fn is_command_with_prefixes<S: AsRef<str>>(&self, prefixes: &[S]) -> bool {
+        crate::command::strip_command_prefix(&self.text, prefixes).is_some()
     }
 
     /// Check if message mentions bot (contains @botname)
@@ -625,6 +630,8 @@ mod tests {
             "Hello world".to_string(),
         );
         assert!(!text_msg.is_command());
+        assert!(!cmd_msg.is_command_with_prefixes(&["!aof"]));
+        assert!(cmd_msg.is_command_with_prefixes(&["!aof", "/"]));
     }
 
     #[test]
@@ -23,8 +23,12 @@ type HmacSha256 = Hmac<Sha256>;
 pub struct SlackPlatform {
     config: SlackConfig,
     client: reqwest::Client,
+    api_url: String,
 }
 
+/// Slack Web API base URL
+const SLACK_API_URL: &str = "https://slack.com/api";
+
 /// Slack configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlackConfig {
@@ -201,7 +205,22 @@ impl SlackPlatform {
             .build()
             .map_err(|e| PlatformError::ApiError(format!("Failed to create HTTP client: {}", e)))?;
 
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            api_url: SLACK_API_URL.to_string(),
+        })
+    }
+
+    /// Override the Web API base URL (e.g. a mock server in tests)
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = api_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    // Helper: Full URL of a Web API method
+    fn method_url(&self, method: &str) -> String {
+        format!("{}/{}", self.api_url, method)
     }
 
     /// Create new Slack platform adapter with auto-detected bot_user_id
@@ -235,7 +254,11 @@ impl SlackPlatform {
             }
         }
 
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            api_url: SLACK_API_URL.to_string(),
+        })
     }
 
     /// Fetch bot user ID from Slack's auth.test API
@@ -393,6 +416,9 @@ impl SlackPlatform {
     }
 
     /// Post message using chat.postMessage API
+    ///
+    /// Ephemeral responses go through chat.postEphemeral and are only shown
+    /// to `response.ephemeral_user`.
     async fn post_message(
         &self,
         channel: &str,
@@ -410,9 +436,17 @@ impl SlackPlatform {
             payload["thread_ts"] = serde_json::json!(thread_ts);
         }
 
+        let method = match response.ephemeral_user {
+            Some(ref user) => {
+                payload["user"] = serde_json::json!(user);
+                "chat.postEphemeral"
+            }
+            None => "chat.postMessage",
+        };
+
         let api_response = self
             .client
-            .post("https://slack.com/api/chat.postMessage")
+            .post(self.method_url(method))
             .header("Authorization", format!("Bearer {}", self.config.bot_token))
             .header("Content-Type", "application/json")
             .json(&payload)
@@ -453,7 +487,7 @@ impl SlackPlatform {
 
         let api_response = self
             .client
-            .post("https://slack.com/api/chat.postMessage")
+            .post(self.method_url("chat.postMessage"))
             .header("Authorization", format!("Bearer {}", self.config.bot_token))
             .header("Content-Type", "application/json")
             .json(&payload)
@@ -495,7 +529,7 @@ impl SlackPlatform {
 
         let api_response = self
             .client
-            .post("https://slack.com/api/reactions.add")
+            .post(self.method_url("reactions.add"))
             .header("Authorization", format!("Bearer {}", self.config.bot_token))
             .header("Content-Type", "application/json")
             .json(&payload)
@@ -525,7 +559,7 @@ impl SlackPlatform {
 
     /// Get user info from Slack API
     async fn get_user_info(&self, user_id: &str) -> Result<TriggerUser, PlatformError> {
-        let url = format!("https://slack.com/api/users.info?user={}", user_id);
+        let url = format!("{}?user={}", self.method_url("users.info"), user_id);
 
         let user_info = self
             .client
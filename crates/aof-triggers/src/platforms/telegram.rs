@@ -551,6 +551,17 @@ impl TriggerPlatform for TelegramPlatform {
         let text = Self::format_response_text(&response);
         let keyboard = Self::create_keyboard(&response);
 
+        // Private replies go to the user's DM chat (same ID as the user). The
+        // bot can only DM users who have started it, so fall back to the chat.
+        if let Some(user_chat_id) = response.ephemeral_user.as_ref().and_then(|u| u.parse::<i64>().ok()) {
+            if user_chat_id != chat_id {
+                match self.send_message(user_chat_id, &text, None, keyboard.clone()).await {
+                    Ok(_) => return Ok(()),
+                    Err(e) => warn!("Could not DM user {}, replying in chat: {}", user_chat_id, e),
+                }
+            }
+        }
+
         let reply_to = response
             .reply_to
             .as_ref()
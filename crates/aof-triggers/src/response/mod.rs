@@ -63,6 +63,11 @@ pub struct TriggerResponse {
     /// Message ID to reply to
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<String>,
+
+    /// Show only to this user (Slack ephemeral message, Telegram DM)
+    /// Platforms without private replies post normally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ephemeral_user: Option<String>,
 }
 
 impl TriggerResponse {
@@ -77,6 +82,7 @@ impl TriggerResponse {
             actions: Vec::new(),
             thread_id: None,
             reply_to: None,
+            ephemeral_user: None,
         }
     }
 
@@ -91,6 +97,7 @@ impl TriggerResponse {
             actions: Vec::new(),
             thread_id: None,
             reply_to: None,
+            ephemeral_user: None,
         }
     }
 
@@ -105,6 +112,7 @@ impl TriggerResponse {
             actions: Vec::new(),
             thread_id: None,
             reply_to: None,
+            ephemeral_user: None,
         }
     }
 
@@ -119,6 +127,7 @@ impl TriggerResponse {
             actions: Vec::new(),
             thread_id: None,
             reply_to: None,
+            ephemeral_user: None,
         }
     }
 
@@ -134,6 +143,17 @@ impl TriggerResponse {
         self
     }
 
+    /// Make the response visible only to one user
+    pub fn with_ephemeral_user(mut self, user_id: String) -> Self {
+        self.ephemeral_user = Some(user_id);
+        self
+    }
+
+    /// Whether the response is private to one user
+    pub fn is_ephemeral(&self) -> bool {
+        self.ephemeral_user.is_some()
+    }
+
     /// Add an attachment
     pub fn with_attachment(mut self, attachment: Attachment) -> Self {
         self.attachments.push(attachment);
@@ -282,6 +302,7 @@ impl TriggerResponseBuilder {
                 actions: Vec::new(),
                 thread_id: None,
                 reply_to: None,
+                ephemeral_user: None,
             },
         }
     }
@@ -340,6 +361,18 @@ impl TriggerResponseBuilder {
         self
     }
 
+    /// Show only to this user
+    pub fn ephemeral(mut self, user_id: impl Into<String>) -> Self {
+        self.response.ephemeral_user = Some(user_id.into());
+        self
+    }
+
+    /// Add a metadata entry
+    pub fn metadata(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.response.metadata.insert(key.into(), value);
+        self
+    }
+
     /// Build the response
     pub fn build(self) -> TriggerResponse {
         self.response
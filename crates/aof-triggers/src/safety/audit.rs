@@ -0,0 +1,337 @@
+//! Approval Audit - Structured records of approval decisions
+//!
+//! Every approve/deny decision produces an `ApprovalOutcome` alongside the
+//! chat message, so decisions can be reported on later ("what was approved
+//! this week") instead of being scraped from chat history.
+//!
+//! The most recent outcomes are kept in memory. When the context's (or the
+//! handler's) `audit` config subscribes to `approval_granted` /
+//! `approval_denied`, each outcome is also written to its `sink` as one JSON
+//! line tagged with the event.
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use aof_core::{AuditConfig, AuditEvent};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Decision taken on a pending approval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    Approved,
+    Denied,
+}
+
+impl ApprovalDecision {
+    /// Audit event emitted for this decision
+    pub fn audit_event(self) -> AuditEvent {
+        match self {
+            Self::Approved => AuditEvent::ApprovalGranted,
+            Self::Denied => AuditEvent::ApprovalDenied,
+        }
+    }
+}
+
+/// What happened to the command after the decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalResultStatus {
+    /// Approved and the command succeeded
+    Succeeded,
+    /// Approved but the command failed
+    Failed,
+    /// Denied, so the command never ran
+    NotExecuted,
+}
+
+/// Structured record of one approval decision
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalOutcome {
+    /// Command that was approved or denied
+    pub command: String,
+    /// Approve or deny
+    pub decision: ApprovalDecision,
+    /// User who made the decision
+    pub decided_by: String,
+    /// When the decision was made
+    pub decided_at: DateTime<Utc>,
+    /// User who requested the command
+    pub requested_by: String,
+    /// When approval was requested
+    pub requested_at: DateTime<Utc>,
+    /// Platform the decision came from
+    pub platform: String,
+    /// Channel the request was made in
+    pub channel_id: String,
+    /// Agent that proposed the command
+    pub agent_name: String,
+    /// Execution result
+    pub status: ApprovalResultStatus,
+}
+
+/// Filter for querying recorded outcomes; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct ApprovalQuery {
+    /// Only outcomes decided at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only outcomes with this decision
+    pub decision: Option<ApprovalDecision>,
+    /// Only outcomes decided by this user
+    pub decided_by: Option<String>,
+}
+
+impl ApprovalQuery {
+    /// Outcomes decided at or after `since`
+    pub fn since(since: DateTime<Utc>) -> Self {
+        Self {
+            since: Some(since),
+            ..Default::default()
+        }
+    }
+
+    /// Restrict to one decision
+    pub fn with_decision(mut self, decision: ApprovalDecision) -> Self {
+        self.decision = Some(decision);
+        self
+    }
+
+    /// Restrict to one approver
+    pub fn with_decided_by(mut self, user_id: impl Into<String>) -> Self {
+        self.decided_by = Some(user_id.into());
+        self
+    }
+
+    fn matches(&self, outcome: &ApprovalOutcome) -> bool {
+        if matches!(self.since, Some(since) if outcome.decided_at < since) {
+            return false;
+        }
+        if matches!(self.decision, Some(decision) if outcome.decision != decision) {
+            return false;
+        }
+        !matches!(&self.decided_by, Some(user) if &outcome.decided_by != user)
+    }
+}
+
+/// Outcomes kept in memory by default; older ones are dropped first
+pub const DEFAULT_APPROVAL_HISTORY: usize = 1000;
+
+/// Audit sink for approval outcomes
+#[derive(Debug)]
+pub struct ApprovalAuditLog {
+    outcomes: Mutex<VecDeque<ApprovalOutcome>>,
+    history: usize,
+}
+
+impl Default for ApprovalAuditLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApprovalAuditLog {
+    /// Audit log keeping the last `DEFAULT_APPROVAL_HISTORY` outcomes in memory
+    pub fn new() -> Self {
+        Self::with_history(DEFAULT_APPROVAL_HISTORY)
+    }
+
+    /// Audit log keeping the last `history` outcomes in memory
+    pub fn with_history(history: usize) -> Self {
+        Self {
+            outcomes: Mutex::new(VecDeque::new()),
+            history,
+        }
+    }
+
+    /// Record an outcome, emitting its audit event to `audit`'s sink
+    pub fn record(&self, outcome: ApprovalOutcome, audit: Option<&AuditConfig>) {
+        if let Some(audit) = audit {
+            let event = outcome.decision.audit_event();
+            if wants_event(audit, event) {
+                if let Err(e) = emit(audit, event, &outcome) {
+                    warn!("Failed to write {:?} audit event to {:?}: {}", event, audit.sink, e);
+                }
+            }
+        }
+
+        let mut outcomes = self.outcomes.lock().unwrap();
+        outcomes.push_back(outcome);
+        while outcomes.len() > self.history {
+            outcomes.pop_front();
+        }
+    }
+
+    /// Outcomes matching a query, oldest first
+    pub fn query(&self, query: &ApprovalQuery) -> Vec<ApprovalOutcome> {
+        self.outcomes
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|outcome| query.matches(outcome))
+            .cloned()
+            .collect()
+    }
+
+    /// All recorded outcomes, oldest first
+    pub fn all(&self) -> Vec<ApprovalOutcome> {
+        self.query(&ApprovalQuery::default())
+    }
+
+    /// Number of recorded outcomes
+    pub fn len(&self) -> usize {
+        self.outcomes.lock().unwrap().len()
+    }
+
+    /// Whether nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Audit line: the outcome tagged with its event
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+    event: AuditEvent,
+    #[serde(flatten)]
+    outcome: &'a ApprovalOutcome,
+}
+
+// Helper: Whether an audit config subscribes to `event` (no `events`: all)
+fn wants_event(audit: &AuditConfig, event: AuditEvent) -> bool {
+    audit.enabled
+        && (audit.events.is_empty()
+            || audit.events.iter().any(|e| *e == event || *e == AuditEvent::All))
+}
+
+// Helper: Write one audit line to a `file://` (or plain path) or `stdout://` sink
+fn emit(audit: &AuditConfig, event: AuditEvent, outcome: &ApprovalOutcome) -> std::io::Result<()> {
+    let Some(sink) = audit.sink.as_deref() else {
+        return Ok(());
+    };
+    let line = serde_json::to_string(&AuditRecord { event, outcome })?;
+
+    if sink == "stdout://" {
+        println!("{}", line);
+        return Ok(());
+    }
+    let path = match sink.strip_prefix("file://") {
+        Some(path) => path,
+        None if !sink.contains("://") => sink,
+        None => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "approval audit supports file:// and stdout:// sinks",
+            ))
+        }
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(Path::new(path))?;
+    writeln!(file, "{}", line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn outcome(decision: ApprovalDecision, by: &str, age_days: i64) -> ApprovalOutcome {
+        let decided_at = Utc::now() - Duration::days(age_days);
+        ApprovalOutcome {
+            command: "kubectl rollout restart deploy/api".to_string(),
+            decision,
+            decided_by: by.to_string(),
+            decided_at,
+            requested_by: "U_REQ".to_string(),
+            requested_at: decided_at - Duration::minutes(2),
+            platform: "slack".to_string(),
+            channel_id: "C123".to_string(),
+            agent_name: "k8s-ops".to_string(),
+            status: match decision {
+                ApprovalDecision::Approved => ApprovalResultStatus::Succeeded,
+                ApprovalDecision::Denied => ApprovalResultStatus::NotExecuted,
+            },
+        }
+    }
+
+    #[test]
+    fn test_query_approved_this_week() {
+        let log = ApprovalAuditLog::new();
+        log.record(outcome(ApprovalDecision::Approved, "U_ALICE", 1), None);
+        log.record(outcome(ApprovalDecision::Denied, "U_BOB", 2), None);
+        log.record(outcome(ApprovalDecision::Approved, "U_BOB", 10), None);
+
+        let week = ApprovalQuery::since(Utc::now() - Duration::days(7))
+            .with_decision(ApprovalDecision::Approved);
+        let approved = log.query(&week);
+
+        assert_eq!(approved.len(), 1);
+        assert_eq!(approved[0].decided_by, "U_ALICE");
+        assert_eq!(log.query(&ApprovalQuery::default().with_decided_by("U_BOB")).len(), 2);
+        assert_eq!(log.len(), 3);
+    }
+
+    #[test]
+    fn test_outcome_serialization() {
+        let json = serde_json::to_value(outcome(ApprovalDecision::Denied, "U_BOB", 0)).unwrap();
+
+        assert_eq!(json["decision"], "denied");
+        assert_eq!(json["status"], "not_executed");
+        assert_eq!(json["decided_by"], "U_BOB");
+        assert!(json["decided_at"].is_string());
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let log = ApprovalAuditLog::with_history(2);
+        log.record(outcome(ApprovalDecision::Approved, "U_ALICE", 0), None);
+        log.record(outcome(ApprovalDecision::Denied, "U_BOB", 0), None);
+        log.record(outcome(ApprovalDecision::Approved, "U_CAROL", 0), None);
+
+        let kept: Vec<_> = log.all().into_iter().map(|o| o.decided_by).collect();
+        assert_eq!(kept, vec!["U_BOB", "U_CAROL"]);
+    }
+
+    #[test]
+    fn test_sink_receives_subscribed_events() {
+        let path = std::env::temp_dir().join(format!("aof-approvals-{}.jsonl", uuid::Uuid::new_v4()));
+        let audit = AuditConfig {
+            enabled: true,
+            sink: Some(format!("file://{}", path.display())),
+            events: vec![AuditEvent::ApprovalDenied],
+            ..Default::default()
+        };
+        let log = ApprovalAuditLog::new();
+        log.record(outcome(ApprovalDecision::Approved, "U_ALICE", 0), Some(&audit));
+        log.record(outcome(ApprovalDecision::Denied, "U_BOB", 0), Some(&audit));
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["event"], "approval_denied");
+        assert_eq!(lines[0]["decided_by"], "U_BOB");
+        assert_eq!(log.len(), 2);
+    }
+
+    #[test]
+    fn test_disabled_audit_writes_nothing() {
+        let path = std::env::temp_dir().join(format!("aof-approvals-{}.jsonl", uuid::Uuid::new_v4()));
+        let audit = AuditConfig {
+            enabled: false,
+            sink: Some(path.display().to_string()),
+            ..Default::default()
+        };
+        ApprovalAuditLog::new().record(outcome(ApprovalDecision::Approved, "U_ALICE", 0), Some(&audit));
+
+        assert!(!path.exists());
+    }
+}
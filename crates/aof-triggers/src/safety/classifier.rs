@@ -183,6 +183,22 @@ impl Default for GenericPatterns {
     }
 }
 
+/// Builtin tools that only read, whatever their arguments
+const READ_ONLY_TOOLS: &[&str] = &[
+    "read_file", "list_directory", "search_files",
+    "pod_logs", "k8s_config_diff", "k8s_resource_quota",
+    "git_diff_summary", "git_changelog",
+    "prometheus_query", "loki_query", "elasticsearch_query", "victoriametrics_query",
+    "grafana_query", "datadog_metric_query", "datadog_log_query",
+    "newrelic_nrql_query", "newrelic_metrics_query", "splunk_search",
+    "net_diag", "cert_check", "data_transform", "helm_template", "kustomize_build",
+    "terraform_state", "k8s_api",
+    "list_tools", "fetch_more",
+];
+
+/// Tools whose `command` argument is a complete command line
+const SHELL_TOOLS: &[&str] = &["shell", "bash", "sh"];
+
 /// Tool classifier that determines action class for commands
 pub struct ToolClassifier {
     /// Tool-specific rules
@@ -286,6 +302,63 @@ impl ToolClassifier {
         }
     }
 
+    /// Classify a tool call made by an agent
+    ///
+    /// The call is turned into the command line `classify` expects:
+    /// - shell tools: their `command` argument
+    /// - CLI tools (`kubectl`, `git`, ...): the tool name plus `command`
+    /// - tools with an `action` argument: the tool name plus the action
+    /// - `http` / `http_request`: GET and HEAD read, other methods write
+    /// - per-operation tools: program and verb from the name
+    ///   (`kubectl_apply` -> `kubectl apply`, `argocd_app_sync` -> `argocd sync app`)
+    ///
+    /// Builtin read-only tools always classify as read.
+    pub fn classify_tool_call(&self, name: &str, args: &serde_json::Value) -> ClassificationResult {
+        let str_arg = |key: &str| args.get(key).and_then(|v| v.as_str()).map(str::trim);
+        let fixed = |class: ActionClass, verb: Option<&str>| ClassificationResult {
+            class,
+            tool: name.to_string(),
+            verb: verb.map(str::to_string),
+            confidence: 1.0,
+            source: ClassificationSource::ToolSpecific,
+        };
+
+        if READ_ONLY_TOOLS.contains(&name) {
+            return fixed(ActionClass::Read, None);
+        }
+        if matches!(name, "http" | "http_request") {
+            let method = str_arg("method").unwrap_or("GET").to_uppercase();
+            let class = if matches!(method.as_str(), "GET" | "HEAD") {
+                ActionClass::Read
+            } else {
+                ActionClass::Write
+            };
+            return fixed(class, Some(method.as_str()));
+        }
+        if let Some(command) = str_arg("command") {
+            if SHELL_TOOLS.contains(&name) || command.split_whitespace().next() == Some(name) {
+                return self.classify(command);
+            }
+            return self.classify(&format!("{} {}", name, command));
+        }
+        if let Some(action) = str_arg("action") {
+            return self.classify(&format!("{} {}", name, action));
+        }
+
+        let segments: Vec<&str> = name.split('_').collect();
+        match segments.as_slice() {
+            [program, nouns @ .., verb] => {
+                let mut command = format!("{} {}", program, verb);
+                for noun in nouns {
+                    command.push(' ');
+                    command.push_str(noun);
+                }
+                self.classify(&command)
+            }
+            _ => self.classify(name),
+        }
+    }
+
     /// Check if command matches tool-specific rules
     fn check_tool_rules(&self, tool: &str, rest: &str, rules: &ToolRules) -> Option<ClassificationResult> {
         // Check dangerous first
@@ -448,6 +521,45 @@ mod tests {
         assert_eq!(result.source, ClassificationSource::Default);
     }
 
+    #[test]
+    fn test_classify_tool_calls() {
+        let mut classifier = ToolClassifier::new();
+        classifier.add_tool_rules("kubectl", create_kubectl_rules());
+        let class = |name: &str, args: serde_json::Value| classifier.classify_tool_call(name, &args).class;
+
+        assert_eq!(class("kubectl", serde_json::json!({"command": "get pods"})), ActionClass::Read);
+        assert_eq!(class("kubectl", serde_json::json!({"command": "kubectl apply -f x.yaml"})), ActionClass::Write);
+        assert_eq!(class("shell", serde_json::json!({"command": "kubectl delete pod web-0"})), ActionClass::Delete);
+        assert_eq!(class("kubectl_apply", serde_json::json!({"manifest": "kind: Deployment"})), ActionClass::Write);
+        assert_eq!(class("terraform_destroy", serde_json::json!({})), ActionClass::Delete);
+        assert_eq!(class("github_run_list", serde_json::json!({})), ActionClass::Read);
+        assert_eq!(class("feature_flag", serde_json::json!({"action": "list"})), ActionClass::Read);
+        // Unknown verbs fail secure
+        assert_eq!(class("feature_flag", serde_json::json!({"action": "toggle"})), ActionClass::Write);
+        assert_eq!(class("http_request", serde_json::json!({"method": "get"})), ActionClass::Read);
+        assert_eq!(class("http_request", serde_json::json!({"method": "POST"})), ActionClass::Write);
+        assert_eq!(class("pod_logs", serde_json::json!({"pod": "web-0"})), ActionClass::Read);
+    }
+
+    #[test]
+    fn test_read_only_tools_pass_safe_mode() {
+        let classifier = ToolClassifier::new();
+        let safe_mode = crate::safety::SafeMode::new();
+        safe_mode.enable("U_OPS", Some("incident".to_string()));
+
+        let calls = [
+            ("terraform_state", serde_json::json!({"action": "current"})),
+            ("terraform_state", serde_json::json!({"action": "show", "address": "aws_instance.web"})),
+            ("k8s_api", serde_json::json!({"action": "list", "kind": "pods"})),
+            ("k8s_api", serde_json::json!({"action": "describe", "kind": "deployment", "name": "web"})),
+        ];
+        for (name, args) in calls {
+            let class = classifier.classify_tool_call(name, &args).class;
+            assert_eq!(class, ActionClass::Read, "{} {}", name, args);
+            assert!(safe_mode.blocking(class).is_none(), "{} {}", name, args);
+        }
+    }
+
     #[test]
     fn test_action_class_risk_levels() {
         assert_eq!(ActionClass::Read.risk_level(), 0);
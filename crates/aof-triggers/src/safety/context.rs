@@ -8,10 +8,15 @@
 
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 
+use chrono::{DateTime, Utc};
+
 use super::classifier::{ActionClass, ClassificationResult, ToolClassifier, ToolClassifications};
+use super::freeze::{active_freeze, FreezeWindow};
 use super::policy::{PlatformPolicy, PolicyDecision, PolicyEngine};
+use super::safe_mode::SafeMode;
 
 /// Complete safety configuration from a Context YAML
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,7 +61,8 @@ pub struct ContextSpec {
     pub safety: SafetySettings,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Safety settings of a context (the `safety` section of its YAML)
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SafetySettings {
     /// Namespaces requiring confirmation for operations
     #[serde(default)]
@@ -67,12 +73,26 @@ pub struct SafetySettings {
     /// Whether to audit all operations
     #[serde(default)]
     pub audit_all_operations: bool,
+    /// Change freeze windows - non-read operations are blocked while one is active
+    #[serde(default)]
+    pub freeze_windows: Vec<FreezeWindow>,
 }
 
 fn default_max_resources() -> u32 {
     10
 }
 
+impl Default for SafetySettings {
+    fn default() -> Self {
+        Self {
+            require_confirmation_for_namespace: Vec::new(),
+            max_resources_per_operation: default_max_resources(),
+            audit_all_operations: false,
+            freeze_windows: Vec::new(),
+        }
+    }
+}
+
 /// Result of safety evaluation
 #[derive(Debug, Clone)]
 pub struct SafetyEvaluation {
@@ -133,6 +153,8 @@ pub struct SafetyContext {
     settings: SafetySettings,
     /// Context name
     name: String,
+    /// Global kill switch that overrides every policy
+    safe_mode: Arc<SafeMode>,
 }
 
 impl SafetyContext {
@@ -143,6 +165,7 @@ impl SafetyContext {
             policy_engine: PolicyEngine::new(),
             settings: SafetySettings::default(),
             name: name.to_string(),
+            safe_mode: SafeMode::global(),
         }
     }
 
@@ -163,9 +186,22 @@ impl SafetyContext {
             policy_engine,
             settings: config.spec.safety,
             name: config.metadata.name,
+            safe_mode: SafeMode::global(),
         }
     }
 
+    /// Use a specific safe-mode switch instead of the process-wide one
+    pub fn with_safe_mode(mut self, safe_mode: Arc<SafeMode>) -> Self {
+        self.safe_mode = safe_mode;
+        self
+    }
+
+    /// Use the given safety settings (freeze windows, confirmations, ...)
+    pub fn with_settings(mut self, settings: SafetySettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
     /// Load from YAML file
     pub fn from_yaml_file(path: impl AsRef<Path>) -> Result<Self, String> {
         let content = std::fs::read_to_string(path.as_ref())
@@ -204,6 +240,20 @@ impl SafetyContext {
         &self.name
     }
 
+    /// Why an operation of `class` is blocked right now, regardless of platform or user
+    ///
+    /// Safe mode blocks risky operations everywhere; an active change freeze
+    /// blocks everything but reads in this context.
+    pub fn blocking(&self, class: ActionClass, now: DateTime<Utc>) -> Option<String> {
+        if let Some(safe_mode) = self.safe_mode.blocking(class) {
+            return Some(safe_mode.message());
+        }
+        match class {
+            ActionClass::Read => None,
+            _ => active_freeze(&self.settings.freeze_windows, now).map(|freeze| freeze.message()),
+        }
+    }
+
     /// Evaluate a command for a specific platform and user
     pub fn evaluate(
         &self,
@@ -211,17 +261,38 @@ impl SafetyContext {
         platform: &str,
         user_id: &str,
         namespace: Option<&str>,
+    ) -> SafetyEvaluation {
+        self.evaluate_at(command, platform, user_id, namespace, Utc::now())
+    }
+
+    /// Evaluate a command as of a given time (freeze windows depend on it)
+    pub fn evaluate_at(
+        &self,
+        command: &str,
+        platform: &str,
+        user_id: &str,
+        namespace: Option<&str>,
+        now: DateTime<Utc>,
     ) -> SafetyEvaluation {
         // Classify the command
         let classification = self.classifier.classify(command);
 
+        // Safe mode and change freezes block risky operations regardless of platform or user
+        let override_reason = self.blocking(classification.class, now);
+
         // Get policy decision
-        let decision = self.policy_engine.evaluate_with_context(
-            platform,
-            classification.class,
-            user_id,
-            namespace,
-        );
+        let decision = match override_reason {
+            Some(override_reason) => PolicyDecision::Block {
+                reason: format!("{} - {} blocked", override_reason, classification.class.description()),
+                suggestion: Some("Read-only operations are still available.".to_string()),
+            },
+            None => self.policy_engine.evaluate_with_context(
+                platform,
+                classification.class,
+                user_id,
+                namespace,
+            ),
+        };
 
         // Check namespace confirmation requirement
         let requires_namespace_confirmation = namespace
@@ -351,6 +422,7 @@ mod tests {
                     require_confirmation_for_namespace: vec!["production".to_string()],
                     max_resources_per_operation: 10,
                     audit_all_operations: true,
+                    freeze_windows: vec![],
                 },
             },
         };
@@ -378,4 +450,59 @@ mod tests {
         assert!(msg.contains("🚫"));
         assert!(msg.contains("*")); // Slack markdown
     }
+
+    #[test]
+    fn test_change_freeze_blocks_writes() {
+        let config: SafetyConfig = serde_yaml::from_str(r#"
+apiVersion: aof.dev/v1
+kind: Context
+metadata:
+  name: prod
+spec:
+  safety:
+    freeze_windows:
+      - start: 2026-12-20T00:00:00Z
+        end: 2027-01-05T00:00:00Z
+        reason: Holiday freeze
+"#).unwrap();
+        let ctx = SafetyContext::from_config(config);
+        let during = "2026-12-24T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let after = "2027-01-06T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        // Blocked during the freeze, even on the permissive CLI platform
+        let eval = ctx.evaluate_at("kubectl apply -f deployment.yaml", "cli", "admin", None, during);
+        assert!(eval.is_blocked());
+        assert!(eval.message.contains("Change freeze active until 2027-01-05 00:00 UTC"));
+        assert!(eval.message.contains("Holiday freeze"));
+
+        // Reads are still allowed
+        let eval = ctx.evaluate_at("kubectl get pods", "cli", "admin", None, during);
+        assert!(eval.is_allowed());
+
+        // Allowed outside the window
+        let eval = ctx.evaluate_at("kubectl apply -f deployment.yaml", "cli", "admin", None, after);
+        assert!(eval.is_allowed());
+    }
+
+    #[test]
+    fn test_safe_mode_blocks_allowed_writes() {
+        let safe_mode = Arc::new(SafeMode::new());
+        let ctx = SafetyContext::new("test").with_safe_mode(safe_mode.clone());
+
+        // The CLI policy allows writes
+        let eval = ctx.evaluate("kubectl delete pod foo", "cli", "admin", None);
+        assert!(eval.is_allowed());
+
+        safe_mode.enable("U_OPS", Some("runaway agent".to_string()));
+        let eval = ctx.evaluate("kubectl delete pod foo", "cli", "admin", None);
+        assert!(eval.is_blocked());
+        assert!(eval.message.contains("Safe mode active"));
+        assert!(eval.message.contains("runaway agent"));
+
+        // Reads are still allowed
+        assert!(ctx.evaluate("kubectl get pods", "cli", "admin", None).is_allowed());
+
+        safe_mode.disable();
+        assert!(ctx.evaluate("kubectl delete pod foo", "cli", "admin", None).is_allowed());
+    }
 }
@@ -0,0 +1,223 @@
+//! Change Freeze - Time windows during which risky operations are blocked
+//!
+//! A context can declare freeze windows, either as explicit ranges
+//! (holiday freeze) or as weekly recurring windows (no deploys Friday
+//! evening to Monday morning, via `until_day`). While a window is active, write, delete and
+//! dangerous operations are blocked for everyone; reads stay available.
+//!
+//! All times are UTC.
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// A single freeze window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FreezeWindow {
+    /// Explicit range, e.g. a holiday freeze
+    Range {
+        /// Freeze start (inclusive)
+        start: DateTime<Utc>,
+        /// Freeze end (exclusive)
+        end: DateTime<Utc>,
+        /// Why the freeze is in place
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+    /// Weekly recurring window starting on each listed day
+    ///
+    /// Without `until_day` the window ends the same day, or the next day when
+    /// `until` is not after `from` (e.g. `from: "18:00"`, `until: "08:00"`).
+    /// With `until_day` it ends on the next such day, so a window can span
+    /// the weekend (`days: [fri]`, `until_day: mon`).
+    Weekly {
+        /// Days the window starts on (`mon`, `tue`, ...)
+        days: Vec<Weekday>,
+        /// Start time of day (`HH:MM`)
+        #[serde(with = "time_of_day")]
+        from: NaiveTime,
+        /// End time of day (`HH:MM`)
+        #[serde(with = "time_of_day")]
+        until: NaiveTime,
+        /// Day the window ends on (None: the start day, or the next day if it wraps midnight)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until_day: Option<Weekday>,
+        /// Why the freeze is in place
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+}
+
+/// An active freeze and when it lifts
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveFreeze {
+    /// When the active window ends
+    pub until: DateTime<Utc>,
+    /// Why the freeze is in place
+    pub reason: Option<String>,
+}
+
+impl ActiveFreeze {
+    /// User-facing description of the freeze
+    pub fn message(&self) -> String {
+        let mut msg = format!(
+            "Change freeze active until {}",
+            self.until.format("%Y-%m-%d %H:%M UTC")
+        );
+        if let Some(reason) = &self.reason {
+            msg.push_str(&format!(" ({})", reason));
+        }
+        msg
+    }
+}
+
+impl FreezeWindow {
+    /// If the window covers `now`, return when it ends
+    pub fn active_at(&self, now: DateTime<Utc>) -> Option<ActiveFreeze> {
+        match self {
+            Self::Range { start, end, reason } => (*start <= now && now < *end).then(|| ActiveFreeze {
+                until: *end,
+                reason: reason.clone(),
+            }),
+            Self::Weekly { days, from, until, until_day, reason } => {
+                // A window lasts at most a week, so it started within the last seven days
+                let today = now.date_naive();
+                (0..=7)
+                    .map(|back| today - Duration::days(back))
+                    .filter(|day| days.contains(&day.weekday()))
+                    .find_map(|day| {
+                        let start = day.and_time(*from).and_utc();
+                        let length = match until_day {
+                            Some(end) => {
+                                let ahead = (7 + end.num_days_from_monday() - day.weekday().num_days_from_monday()) % 7;
+                                if ahead == 0 && until <= from { 7 } else { ahead }
+                            }
+                            None if until > from => 0,
+                            None => 1,
+                        };
+                        let end = (day + Duration::days(i64::from(length))).and_time(*until).and_utc();
+                        (start <= now && now < end).then(|| ActiveFreeze {
+                            until: end,
+                            reason: reason.clone(),
+                        })
+                    })
+            }
+        }
+    }
+}
+
+/// `HH:MM` (or `HH:MM:SS`) times of day
+mod time_of_day {
+    use chrono::NaiveTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&time.format("%H:%M").to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveTime, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        NaiveTime::parse_from_str(&s, "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(&s, "%H:%M:%S"))
+            .map_err(|_| serde::de::Error::custom(format!("invalid time of day '{}', expected HH:MM", s)))
+    }
+}
+
+/// Find the active freeze (the one lifting last if several overlap)
+pub fn active_freeze(windows: &[FreezeWindow], now: DateTime<Utc>) -> Option<ActiveFreeze> {
+    windows
+        .iter()
+        .filter_map(|window| window.active_at(now))
+        .max_by_key(|freeze| freeze.until)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_range_window() {
+        let window = FreezeWindow::Range {
+            start: at(2026, 12, 20, 0, 0),
+            end: at(2027, 1, 5, 0, 0),
+            reason: Some("Holiday freeze".to_string()),
+        };
+
+        let freeze = window.active_at(at(2026, 12, 24, 12, 0)).unwrap();
+        assert_eq!(freeze.until, at(2027, 1, 5, 0, 0));
+        assert_eq!(
+            freeze.message(),
+            "Change freeze active until 2027-01-05 00:00 UTC (Holiday freeze)"
+        );
+        assert!(window.active_at(at(2027, 1, 5, 0, 0)).is_none());
+        assert!(window.active_at(at(2026, 12, 19, 23, 59)).is_none());
+    }
+
+    #[test]
+    fn test_weekly_window_wraps_midnight() {
+        let yaml = r#"
+days: [fri]
+from: "18:00"
+until: "08:00"
+reason: Weekend deploy freeze
+"#;
+        let window: FreezeWindow = serde_yaml::from_str(yaml).unwrap();
+
+        // 2026-10-16 is a Friday
+        assert!(window.active_at(at(2026, 10, 16, 17, 59)).is_none());
+        let freeze = window.active_at(at(2026, 10, 16, 22, 0)).unwrap();
+        assert_eq!(freeze.until, at(2026, 10, 17, 8, 0));
+        assert!(window.active_at(at(2026, 10, 17, 7, 30)).is_some());
+        assert!(window.active_at(at(2026, 10, 17, 8, 0)).is_none());
+        // Thursday night is not frozen
+        assert!(window.active_at(at(2026, 10, 15, 22, 0)).is_none());
+    }
+
+    #[test]
+    fn test_weekly_window_spans_weekend() {
+        let yaml = r#"
+days: [fri]
+from: "18:00"
+until_day: mon
+until: "08:00"
+reason: Weekend deploy freeze
+"#;
+        let window: FreezeWindow = serde_yaml::from_str(yaml).unwrap();
+
+        // 2026-10-16 is a Friday
+        assert!(window.active_at(at(2026, 10, 16, 17, 59)).is_none());
+        let freeze = window.active_at(at(2026, 10, 17, 12, 0)).unwrap();
+        assert_eq!(freeze.until, at(2026, 10, 19, 8, 0));
+        assert!(window.active_at(at(2026, 10, 18, 23, 0)).is_some());
+        assert!(window.active_at(at(2026, 10, 19, 7, 59)).is_some());
+        assert!(window.active_at(at(2026, 10, 19, 8, 0)).is_none());
+        assert!(window.active_at(at(2026, 10, 20, 12, 0)).is_none());
+    }
+
+    #[test]
+    fn test_active_freeze_picks_latest_end() {
+        let windows = vec![
+            FreezeWindow::Weekly {
+                days: vec![Weekday::Thu],
+                from: NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+                until: NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+                until_day: None,
+                reason: None,
+            },
+            FreezeWindow::Range {
+                start: at(2026, 10, 14, 0, 0),
+                end: at(2026, 10, 16, 0, 0),
+                reason: Some("Release".to_string()),
+            },
+        ];
+
+        let freeze = active_freeze(&windows, at(2026, 10, 15, 9, 0)).unwrap();
+        assert_eq!(freeze.until, at(2026, 10, 16, 0, 0));
+        assert!(active_freeze(&windows, at(2026, 10, 16, 9, 0)).is_none());
+    }
+}
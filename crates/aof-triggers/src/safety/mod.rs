@@ -4,6 +4,10 @@
 //! - Tool classification by action class (read/write/delete/dangerous)
 //! - Platform-aware policy enforcement (stricter on mobile platforms)
 //! - Approval workflow integration for sensitive operations
+//! - Structured audit records of approval decisions
+//! - Change freeze windows that block risky operations
+//! - A global safe-mode kill switch that blocks risky operations everywhere
+//! - A tool executor wrapper that enforces safe mode and freezes on agent tool calls
 //!
 //! Design Philosophy:
 //! - Safety-first: Default to most restrictive classification when unknown
@@ -14,6 +18,10 @@
 mod classifier;
 mod policy;
 mod context;
+mod audit;
+mod freeze;
+mod safe_mode;
+mod tool_guard;
 
 pub use classifier::{
     ToolClassifier, ActionClass, ClassificationResult, ToolClassifications,
@@ -22,5 +30,18 @@ pub use policy::{
     PlatformPolicy, PolicyDecision, PolicyEngine,
 };
 pub use context::{
-    SafetyContext, SafetyConfig,
+    SafetyContext, SafetyConfig, SafetySettings,
 };
+pub use audit::{
+    ApprovalAuditLog, ApprovalDecision, ApprovalOutcome, ApprovalQuery, ApprovalResultStatus,
+    DEFAULT_APPROVAL_HISTORY,
+};
+pub use freeze::{
+    active_freeze, ActiveFreeze, FreezeWindow,
+};
+pub use safe_mode::{
+    SafeMode, SafeModeState, SAFE_MODE_ENV,
+};
+pub use tool_guard::GuardedToolExecutor;
+#[cfg(unix)]
+pub use safe_mode::toggle_on_sigusr1;
@@ -0,0 +1,203 @@
+//! Safe Mode - Global kill switch for risky operations
+//!
+//! While safe mode is on, write, delete and dangerous operations are blocked
+//! on every platform and in every context, regardless of platform policies,
+//! approvers or freeze windows. Reads stay available so ops can keep
+//! investigating.
+//!
+//! Safe mode can be switched:
+//! - at startup, with `AOF_SAFE_MODE=1`
+//! - from chat, with `/safemode on [reason]` and `/safemode off`
+//! - by sending the server process `SIGUSR1` (toggles)
+
+use std::sync::{Arc, Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::classifier::ActionClass;
+
+/// Environment variable that turns safe mode on at startup
+pub const SAFE_MODE_ENV: &str = "AOF_SAFE_MODE";
+
+/// Who turned safe mode on, when and why
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SafeModeState {
+    /// User (or `env` / `SIGUSR1`) that enabled safe mode
+    pub enabled_by: String,
+    /// Why safe mode was enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// When safe mode was enabled
+    pub since: DateTime<Utc>,
+}
+
+impl SafeModeState {
+    /// User-facing description of the active safe mode
+    pub fn message(&self) -> String {
+        let mut msg = format!(
+            "Safe mode active since {} (enabled by {})",
+            self.since.format("%Y-%m-%d %H:%M UTC"),
+            self.enabled_by
+        );
+        if let Some(reason) = &self.reason {
+            msg.push_str(&format!(": {}", reason));
+        }
+        msg
+    }
+}
+
+/// Global safe-mode switch, shared by every handler and safety context
+#[derive(Debug, Default)]
+pub struct SafeMode {
+    state: Mutex<Option<SafeModeState>>,
+}
+
+impl SafeMode {
+    /// Switch that starts off
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Switch that starts on when `AOF_SAFE_MODE` is set to a truthy value
+    pub fn from_env() -> Self {
+        let mode = Self::new();
+        if env_enabled(std::env::var(SAFE_MODE_ENV).ok().as_deref()) {
+            mode.enable("env", Some(format!("{} is set", SAFE_MODE_ENV)));
+        }
+        mode
+    }
+
+    /// Process-wide switch, initialized from the environment on first use
+    pub fn global() -> Arc<SafeMode> {
+        static GLOBAL: OnceLock<Arc<SafeMode>> = OnceLock::new();
+        GLOBAL.get_or_init(|| Arc::new(Self::from_env())).clone()
+    }
+
+    /// Turn safe mode on; returns false if it was already on
+    ///
+    /// An already active safe mode keeps its original state.
+    pub fn enable(&self, enabled_by: &str, reason: Option<String>) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.is_some() {
+            return false;
+        }
+        match &reason {
+            Some(reason) => warn!("Safe mode enabled by {}: {}", enabled_by, reason),
+            None => warn!("Safe mode enabled by {}", enabled_by),
+        }
+        *state = Some(SafeModeState {
+            enabled_by: enabled_by.to_string(),
+            reason,
+            since: Utc::now(),
+        });
+        true
+    }
+
+    /// Turn safe mode off; returns the state it was in, if it was on
+    pub fn disable(&self) -> Option<SafeModeState> {
+        let previous = self.state.lock().unwrap().take();
+        if previous.is_some() {
+            warn!("Safe mode disabled");
+        }
+        previous
+    }
+
+    /// Flip safe mode; returns whether it is now on
+    pub fn toggle(&self, by: &str) -> bool {
+        if self.disable().is_some() {
+            false
+        } else {
+            self.enable(by, None)
+        }
+    }
+
+    /// Whether safe mode is on
+    pub fn is_active(&self) -> bool {
+        self.state.lock().unwrap().is_some()
+    }
+
+    /// Current state (None: safe mode is off)
+    pub fn status(&self) -> Option<SafeModeState> {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// The active safe mode, if it blocks operations of this class
+    pub fn blocking(&self, class: ActionClass) -> Option<SafeModeState> {
+        match class {
+            ActionClass::Read => None,
+            _ => self.status(),
+        }
+    }
+}
+
+/// Whether an `AOF_SAFE_MODE` value turns safe mode on
+fn env_enabled(value: Option<&str>) -> bool {
+    matches!(
+        value.map(|v| v.trim().to_lowercase()).as_deref(),
+        Some("1" | "true" | "on" | "yes")
+    )
+}
+
+/// Toggle safe mode whenever the process receives `SIGUSR1`
+///
+/// Must be called from within a Tokio runtime.
+#[cfg(unix)]
+pub fn toggle_on_sigusr1(safe_mode: Arc<SafeMode>) -> std::io::Result<tokio::task::JoinHandle<()>> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut signals = signal(SignalKind::user_defined1())?;
+    Ok(tokio::spawn(async move {
+        while signals.recv().await.is_some() {
+            safe_mode.toggle("SIGUSR1");
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enable_disable() {
+        let mode = SafeMode::new();
+        assert!(!mode.is_active());
+        assert!(mode.blocking(ActionClass::Write).is_none());
+
+        assert!(mode.enable("U_OPS", Some("runaway agent".to_string())));
+        assert!(!mode.enable("U_OTHER", None));
+
+        let state = mode.status().unwrap();
+        assert_eq!(state.enabled_by, "U_OPS");
+        assert!(state.message().ends_with("(enabled by U_OPS): runaway agent"));
+
+        assert!(mode.blocking(ActionClass::Read).is_none());
+        for class in [ActionClass::Write, ActionClass::Delete, ActionClass::Dangerous] {
+            assert!(mode.blocking(class).is_some());
+        }
+
+        assert_eq!(mode.disable().unwrap().enabled_by, "U_OPS");
+        assert!(mode.disable().is_none());
+        assert!(!mode.is_active());
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mode = SafeMode::new();
+        assert!(mode.toggle("SIGUSR1"));
+        assert_eq!(mode.status().unwrap().enabled_by, "SIGUSR1");
+        assert!(!mode.toggle("SIGUSR1"));
+        assert!(!mode.is_active());
+    }
+
+    #[test]
+    fn test_env_values() {
+        for value in ["1", "true", "ON", " yes "] {
+            assert!(env_enabled(Some(value)), "{}", value);
+        }
+        for value in [None, Some(""), Some("0"), Some("false"), Some("off")] {
+            assert!(!env_enabled(value));
+        }
+    }
+}
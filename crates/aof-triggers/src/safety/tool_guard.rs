@@ -0,0 +1,178 @@
+//! Tool Guard - Enforce the safety layer on the tool calls agents make
+//!
+//! Checking the user's message is not enough: "please fix the crashlooping
+//! deployment" reads harmlessly, but the agent may answer it with
+//! `kubectl_apply`. `GuardedToolExecutor` wraps an agent's tools and
+//! classifies every call before it runs. While safe mode is on, or a change
+//! freeze is active in a context the agent serves, write, delete and
+//! dangerous calls are refused; the model receives the refusal as the tool
+//! result and can explain it to the user.
+
+use std::sync::Arc;
+
+use aof_core::{AofResult, Tool, ToolDefinition, ToolExecutor, ToolInput, ToolResult};
+use aof_runtime::ToolExecutorLayer;
+use async_trait::async_trait;
+use tracing::warn;
+
+use super::classifier::ToolClassifier;
+use super::context::SafetyContext;
+use super::safe_mode::SafeMode;
+
+/// Tool executor that refuses risky calls while safe mode or a change freeze is on
+pub struct GuardedToolExecutor {
+    inner: Arc<dyn ToolExecutor>,
+    /// Safety contexts the calls run in; each carries safe mode and its freeze windows
+    contexts: Vec<SafetyContext>,
+    classifier: Arc<ToolClassifier>,
+}
+
+impl GuardedToolExecutor {
+    /// Guard `inner` against safe mode with the default classifier
+    pub fn new(inner: Arc<dyn ToolExecutor>, safe_mode: Arc<SafeMode>) -> Self {
+        Self {
+            inner,
+            contexts: vec![SafetyContext::default().with_safe_mode(safe_mode)],
+            classifier: Arc::new(ToolClassifier::new()),
+        }
+    }
+
+    /// Also refuse risky calls during the change freezes of these contexts
+    ///
+    /// The contexts replace the default one, so each should use the same
+    /// safe-mode switch. An empty list keeps the default.
+    pub fn with_contexts(mut self, contexts: Vec<SafetyContext>) -> Self {
+        if !contexts.is_empty() {
+            self.contexts = contexts;
+        }
+        self
+    }
+
+    /// Classify calls with custom rules
+    pub fn with_classifier(mut self, classifier: Arc<ToolClassifier>) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Runtime layer guarding every agent's tools
+    ///
+    /// `contexts_for` returns the safety contexts an agent's calls run in
+    /// (by agent name); it is consulted whenever the layer is applied.
+    pub fn layer<F>(safe_mode: Arc<SafeMode>, contexts_for: F) -> ToolExecutorLayer
+    where
+        F: Fn(&str) -> Vec<SafetyContext> + Send + Sync + 'static,
+    {
+        let classifier = Arc::new(ToolClassifier::new());
+        Arc::new(move |agent, inner| {
+            let guard = Self::new(inner, safe_mode.clone())
+                .with_contexts(contexts_for(agent))
+                .with_classifier(classifier.clone());
+            Arc::new(guard) as Arc<dyn ToolExecutor>
+        })
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for GuardedToolExecutor {
+    async fn execute_tool(&self, name: &str, input: ToolInput) -> AofResult<ToolResult> {
+        let classification = self.classifier.classify_tool_call(name, &input.arguments);
+        let now = chrono::Utc::now();
+        if let Some(reason) = self.contexts.iter().find_map(|ctx| ctx.blocking(classification.class, now)) {
+            warn!("Refused {} call to '{}': {}", classification.class, name, reason);
+            return Ok(ToolResult::error(format!(
+                "Refused: {}. This {} is blocked; only read-only tools and commands can run.",
+                reason,
+                classification.class.description()
+            )));
+        }
+        self.inner.execute_tool(name, input).await
+    }
+
+    fn list_tools(&self) -> Vec<ToolDefinition> {
+        self.inner.list_tools()
+    }
+
+    fn get_tool(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        self.inner.get_tool(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Executor that records the calls that reach it
+    #[derive(Default)]
+    struct Recording {
+        calls: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl ToolExecutor for Recording {
+        async fn execute_tool(&self, name: &str, _input: ToolInput) -> AofResult<ToolResult> {
+            self.calls.lock().unwrap().push(name.to_string());
+            Ok(ToolResult::success(serde_json::json!("ok")))
+        }
+
+        fn list_tools(&self) -> Vec<ToolDefinition> {
+            Vec::new()
+        }
+
+        fn get_tool(&self, _name: &str) -> Option<Arc<dyn Tool>> {
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refuses_writes_only_in_safe_mode() {
+        let inner = Arc::new(Recording::default());
+        let safe_mode = Arc::new(SafeMode::new());
+        let layer = GuardedToolExecutor::layer(safe_mode.clone(), |_| Vec::new());
+        let guarded = layer("k8s-ops", inner.clone() as Arc<dyn ToolExecutor>);
+        let apply = || ToolInput::new(serde_json::json!({"command": "apply -f deploy.yaml"}));
+        let get = || ToolInput::new(serde_json::json!({"command": "get pods"}));
+
+        assert!(guarded.execute_tool("kubectl", apply()).await.unwrap().success);
+
+        safe_mode.enable("U_OPS", Some("incident".to_string()));
+        let refused = guarded.execute_tool("kubectl", apply()).await.unwrap();
+        assert!(!refused.success);
+        assert!(refused.error.unwrap().contains("incident"));
+        assert!(guarded.execute_tool("kubectl", get()).await.unwrap().success);
+
+        // The refused call never reached the real executor
+        assert_eq!(*inner.calls.lock().unwrap(), ["kubectl", "kubectl"]);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_writes_during_context_freeze() {
+        use crate::safety::{FreezeWindow, SafetySettings};
+
+        let inner = Arc::new(Recording::default());
+        let safe_mode = Arc::new(SafeMode::new());
+        let now = chrono::Utc::now();
+        let prod = SafetyContext::new("prod")
+            .with_safe_mode(safe_mode.clone())
+            .with_settings(SafetySettings {
+                freeze_windows: vec![FreezeWindow::Range {
+                    start: now - chrono::Duration::hours(1),
+                    end: now + chrono::Duration::hours(1),
+                    reason: Some("release freeze".to_string()),
+                }],
+                ..Default::default()
+            });
+        let guarded = GuardedToolExecutor::new(inner.clone() as Arc<dyn ToolExecutor>, safe_mode)
+            .with_contexts(vec![prod]);
+
+        // The user's message never mentioned a write; the agent chose to apply
+        let apply = ToolInput::new(serde_json::json!({"manifest": "kind: Deployment"}));
+        let refused = guarded.execute_tool("kubectl_apply", apply).await.unwrap();
+        assert!(!refused.success);
+        assert!(refused.error.unwrap().contains("release freeze"));
+
+        let get = ToolInput::new(serde_json::json!({"command": "get pods"}));
+        assert!(guarded.execute_tool("kubectl", get).await.unwrap().success);
+        assert_eq!(*inner.calls.lock().unwrap(), ["kubectl"]);
+    }
+}
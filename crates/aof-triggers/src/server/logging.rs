@@ -0,0 +1,372 @@
+//! Logging for trigger servers
+//!
+//! Trigger servers run unattended, so their logs usually feed a log pipeline
+//! rather than a terminal. `LogConfig` installs a `tracing` subscriber with:
+//!
+//! - `text` or `json` records (one JSON object per line)
+//! - a base level plus per-module levels
+//! - stderr and/or a rolling file (rotated every minute, hour or day, or never)
+//!
+//! `RUST_LOG`, when set, replaces the configured levels.
+//!
+//! ```yaml
+//! logging:
+//!   format: json
+//!   level: info
+//!   modules:
+//!     aof_triggers::handler: debug
+//!     hyper: warn
+//!   file:
+//!     directory: /var/log/aof
+//!     rotation: daily
+//!     max_files: 7
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use aof_core::{AofError, AofResult};
+use serde::{Deserialize, Serialize};
+use tracing::Subscriber;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Record format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Text,
+    /// One JSON object per line: timestamp, level, target, message, fields and span
+    Json,
+}
+
+/// How often the log file is rotated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogRotation {
+    Minutely,
+    Hourly,
+    #[default]
+    Daily,
+    /// Always write to the same file
+    Never,
+}
+
+impl LogRotation {
+    fn rotation(&self) -> Rotation {
+        match self {
+            Self::Minutely => Rotation::MINUTELY,
+            Self::Hourly => Rotation::HOURLY,
+            Self::Daily => Rotation::DAILY,
+            Self::Never => Rotation::NEVER,
+        }
+    }
+}
+
+/// Rolling log file sink
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogFileConfig {
+    /// Directory for log files (created if missing)
+    pub directory: PathBuf,
+
+    /// File name prefix; files are named `<prefix>.<date>.log`
+    #[serde(default = "default_file_prefix")]
+    pub prefix: String,
+
+    /// Rotation interval
+    #[serde(default)]
+    pub rotation: LogRotation,
+
+    /// Rotated files to keep; older ones are deleted (None: keep all)
+    #[serde(default)]
+    pub max_files: Option<usize>,
+}
+
+impl LogFileConfig {
+    /// Rolling file appender for this sink
+    fn appender(&self) -> AofResult<RollingFileAppender> {
+        let mut builder = RollingFileAppender::builder()
+            .rotation(self.rotation.rotation())
+            .filename_prefix(self.prefix.as_str())
+            .filename_suffix("log");
+        if let Some(max_files) = self.max_files {
+            builder = builder.max_log_files(max_files);
+        }
+        builder.build(&self.directory).map_err(|e| {
+            AofError::config(format!(
+                "Failed to open log directory {}: {}",
+                self.directory.display(),
+                e
+            ))
+        })
+    }
+}
+
+fn default_file_prefix() -> String {
+    "aof-triggers".to_string()
+}
+
+fn default_level() -> String {
+    "info".to_string()
+}
+
+fn default_stderr() -> bool {
+    true
+}
+
+/// Logging configuration for a trigger server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogConfig {
+    /// Record format
+    #[serde(default)]
+    pub format: LogFormat,
+
+    /// Level for modules without their own (trace, debug, info, warn, error)
+    #[serde(default = "default_level")]
+    pub level: String,
+
+    /// Per-module levels (module path -> level), e.g. `aof_triggers::handler: debug`
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
+
+    /// Also write to stderr
+    #[serde(default = "default_stderr")]
+    pub stderr: bool,
+
+    /// Rolling file sink
+    #[serde(default)]
+    pub file: Option<LogFileConfig>,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::default(),
+            level: default_level(),
+            modules: BTreeMap::new(),
+            stderr: default_stderr(),
+            file: None,
+        }
+    }
+}
+
+/// Keeps the file sink running; logs are flushed when it is dropped
+#[must_use = "dropping the guard stops writing to the log file"]
+pub struct LogGuard {
+    _file: Option<WorkerGuard>,
+}
+
+impl LogConfig {
+    /// Filter directives: the base level, then per-module levels
+    pub fn directives(&self) -> String {
+        std::iter::once(self.level.clone())
+            .chain(self.modules.iter().map(|(module, level)| format!("{}={}", module, level)))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Filter for the configured levels
+    pub fn filter(&self) -> AofResult<EnvFilter> {
+        EnvFilter::try_new(self.directives()).map_err(|e| {
+            AofError::config(format!("Invalid log levels '{}': {}", self.directives(), e))
+        })
+    }
+
+    /// Formatting layer writing records to `writer`
+    fn layer<S, W>(&self, writer: W, ansi: bool) -> Box<dyn Layer<S> + Send + Sync>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a> + 'static,
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+    {
+        let layer = tracing_subscriber::fmt::layer().with_writer(writer).with_target(true);
+        match self.format {
+            LogFormat::Text => layer.with_ansi(ansi).boxed(),
+            LogFormat::Json => layer
+                .json()
+                .flatten_event(true)
+                .with_current_span(true)
+                .with_span_list(false)
+                .boxed(),
+        }
+    }
+
+    /// Install as the global subscriber
+    ///
+    /// Keep the returned guard alive while the server runs; dropping it
+    /// flushes the file sink.
+    pub fn init(&self) -> AofResult<LogGuard> {
+        let filter = match EnvFilter::try_from_default_env() {
+            Ok(filter) => filter,
+            Err(_) => self.filter()?,
+        };
+
+        let mut layers = Vec::new();
+        if self.stderr {
+            layers.push(self.layer(std::io::stderr, true));
+        }
+        let mut file_guard = None;
+        if let Some(file) = &self.file {
+            let (writer, guard) = tracing_appender::non_blocking(file.appender()?);
+            layers.push(self.layer(writer, false));
+            file_guard = Some(guard);
+        }
+
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(layers)
+            .try_init()
+            .map_err(|e| AofError::config(format!("Failed to install logger: {}", e)))?;
+
+        Ok(LogGuard { _file: file_guard })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing::{debug, info, info_span, warn};
+
+    /// Writer that collects records in memory
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Captured {
+        fn records(&self) -> Vec<serde_json::Value> {
+            String::from_utf8(self.0.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).expect("log line is not JSON"))
+                .collect()
+        }
+    }
+
+    fn capture(config: &LogConfig, emit: impl FnOnce()) -> Vec<serde_json::Value> {
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::registry()
+            .with(config.filter().unwrap())
+            .with(config.layer(move || writer.clone(), false));
+        tracing::subscriber::with_default(subscriber, emit);
+        captured.records()
+    }
+
+    #[test]
+    fn test_json_records_have_expected_fields() {
+        let config = LogConfig {
+            format: LogFormat::Json,
+            ..Default::default()
+        };
+
+        let records = capture(&config, || {
+            let span = info_span!("handle_message", platform = "slack");
+            let _entered = span.enter();
+            info!(user_id = "U123", command = "status", "Executing command");
+            debug!("filtered out at info");
+        });
+
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert!(record["timestamp"].as_str().is_some());
+        assert_eq!(record["level"], "INFO");
+        assert_eq!(record["target"], module_path!());
+        assert_eq!(record["message"], "Executing command");
+        assert_eq!(record["user_id"], "U123");
+        assert_eq!(record["command"], "status");
+        assert_eq!(record["span"]["name"], "handle_message");
+        assert_eq!(record["span"]["platform"], "slack");
+    }
+
+    #[test]
+    fn test_per_module_levels() {
+        let config = LogConfig {
+            format: LogFormat::Json,
+            level: "debug".to_string(),
+            modules: BTreeMap::from([("noisy".to_string(), "warn".to_string())]),
+            ..Default::default()
+        };
+        assert_eq!(config.directives(), "debug,noisy=warn");
+
+        let records = capture(&config, || {
+            debug!("kept");
+            info!(target: "noisy", "dropped");
+            warn!(target: "noisy", "kept too");
+        });
+
+        let messages: Vec<&str> = records.iter().map(|r| r["message"].as_str().unwrap()).collect();
+        assert_eq!(messages, ["kept", "kept too"]);
+    }
+
+    #[test]
+    fn test_invalid_level_is_rejected() {
+        let config = LogConfig {
+            modules: BTreeMap::from([("hyper".to_string(), "chatty".to_string())]),
+            ..Default::default()
+        };
+        assert!(config.filter().is_err());
+    }
+
+    #[test]
+    fn test_file_sink_writes_json() {
+        let dir = std::env::temp_dir().join(format!("aof-log-test-{}", uuid::Uuid::new_v4()));
+        let config = LogConfig {
+            format: LogFormat::Json,
+            stderr: false,
+            file: Some(LogFileConfig {
+                directory: dir.clone(),
+                prefix: "bot".to_string(),
+                rotation: LogRotation::Never,
+                max_files: None,
+            }),
+            ..Default::default()
+        };
+
+        let (writer, guard) = tracing_appender::non_blocking(config.file.as_ref().unwrap().appender().unwrap());
+        let subscriber = tracing_subscriber::registry()
+            .with(config.filter().unwrap())
+            .with(config.layer(writer, false));
+        tracing::subscriber::with_default(subscriber, || info!(platform = "telegram", "Server started"));
+        drop(guard);
+
+        let content = std::fs::read_to_string(dir.join("bot.log")).unwrap();
+        let record: serde_json::Value = serde_json::from_str(content.lines().next().unwrap()).unwrap();
+        assert_eq!(record["message"], "Server started");
+        assert_eq!(record["platform"], "telegram");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_config_from_yaml() {
+        let config: LogConfig = serde_yaml::from_str(
+            "format: json\nmodules:\n  hyper: warn\nfile:\n  directory: /var/log/aof\n  rotation: hourly\n  max_files: 24\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.format, LogFormat::Json);
+        assert_eq!(config.level, "info");
+        assert!(config.stderr);
+        assert_eq!(config.directives(), "info,hyper=warn");
+        let file = config.file.unwrap();
+        assert_eq!(file.prefix, "aof-triggers");
+        assert_eq!(file.rotation, LogRotation::Hourly);
+        assert_eq!(file.max_files, Some(24));
+    }
+}
@@ -18,6 +18,10 @@ use tracing::{debug, error, info};
 
 use crate::handler::TriggerHandler;
 
+pub mod logging;
+
+pub use logging::{LogConfig, LogFileConfig, LogFormat, LogGuard, LogRotation};
+
 /// Server configuration
 #[derive(Debug, Clone)]
 pub struct TriggerServerConfig {
@@ -85,6 +89,7 @@ impl TriggerServer {
         let app = Router::new()
             .route("/", get(root_handler))
             .route("/health", get(health_handler))
+            .route("/metrics", get(metrics_handler))
             .route("/webhook/:platform", post(webhook_handler))
             .route("/platforms", get(platforms_handler))
             .layer(TraceLayer::new_for_http())
@@ -196,6 +201,14 @@ async fn health_handler() -> impl IntoResponse {
     }))
 }
 
+/// Prometheus metrics handler
+async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [("content-type", "text/plain; version=0.0.4")],
+        state.handler.metrics().render_prometheus(),
+    )
+}
+
 /// Webhook handler
 async fn webhook_handler(
     State(state): State<AppState>,
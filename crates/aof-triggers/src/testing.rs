@@ -0,0 +1,245 @@
+//! Test harness for the full trigger handler pipeline
+//!
+//! `TriggerHandlerTestHarness` wires a `TriggerHandler` to an in-memory
+//! `MockPlatform`, so tests can feed messages through parsing, safety checks,
+//! context switching and execution, then assert on what the bot replied.
+//!
+//! ```rust,no_run
+//! # async fn example() {
+//! use aof_triggers::testing::TriggerHandlerTestHarness;
+//!
+//! let harness = TriggerHandlerTestHarness::new();
+//! let responses = harness.send("/help").await;
+//! assert!(responses[0].text.contains("/agent"));
+//! # }
+//! ```
+//!
+//! Available with the `test-util` feature. Each harness gets its own
+//! `SafeMode` switch, so `AOF_SAFE_MODE` in the environment doesn't leak in.
+//!
+//! Agent execution uses whatever `Runtime` is installed; pass one built with
+//! `Runtime::with_canned_responses` to avoid calling a real model.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::RwLock;
+
+use crate::handler::{TriggerHandler, TriggerHandlerConfig};
+use crate::platforms::{PlatformError, TriggerMessage, TriggerPlatform, TriggerUser};
+use crate::response::TriggerResponse;
+use crate::safety::SafeMode;
+use aof_runtime::{Runtime, RuntimeOrchestrator};
+
+/// Platform that records every response instead of sending it
+pub struct MockPlatform {
+    name: &'static str,
+    sent: Mutex<Vec<(String, TriggerResponse)>>,
+}
+
+impl MockPlatform {
+    /// Mock for the named platform (`"slack"`, `"telegram"`, ...)
+    ///
+    /// The name matters: the handler applies platform-specific policies,
+    /// such as blocking writes on mobile platforms.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            sent: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Responses sent so far, with the channel they were sent to
+    pub fn sent(&self) -> Vec<(String, TriggerResponse)> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    /// Number of responses sent so far
+    pub fn sent_count(&self) -> usize {
+        self.sent.lock().unwrap().len()
+    }
+
+    /// Forget recorded responses
+    pub fn clear(&self) {
+        self.sent.lock().unwrap().clear();
+    }
+}
+
+#[async_trait::async_trait]
+impl TriggerPlatform for MockPlatform {
+    async fn parse_message(
+        &self,
+        _raw: &[u8],
+        _headers: &HashMap<String, String>,
+    ) -> Result<TriggerMessage, PlatformError> {
+        Err(PlatformError::UnsupportedMessageType)
+    }
+
+    async fn send_response(
+        &self,
+        channel: &str,
+        response: TriggerResponse,
+    ) -> Result<(), PlatformError> {
+        self.sent.lock().unwrap().push((channel.to_string(), response));
+        Ok(())
+    }
+
+    fn platform_name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn verify_signature(&self, _payload: &[u8], _signature: &str) -> bool {
+        true
+    }
+
+    fn bot_name(&self) -> &str {
+        "aofbot"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Drives a `TriggerHandler` end to end against a `MockPlatform`
+pub struct TriggerHandlerTestHarness {
+    handler: TriggerHandler,
+    orchestrator: Arc<RuntimeOrchestrator>,
+    platform: Arc<MockPlatform>,
+    user_id: String,
+    channel_id: String,
+    next_id: AtomicU64,
+}
+
+impl TriggerHandlerTestHarness {
+    /// Harness on a mock Slack platform with the default handler config
+    pub fn new() -> Self {
+        Self::for_platform("slack")
+    }
+
+    /// Harness on the named mock platform
+    pub fn for_platform(platform: &'static str) -> Self {
+        Self::with_config(platform, TriggerHandlerConfig::default())
+    }
+
+    /// Harness on the named mock platform with a custom handler config
+    pub fn with_config(platform: &'static str, config: TriggerHandlerConfig) -> Self {
+        let orchestrator = Arc::new(RuntimeOrchestrator::new());
+        let mut handler = TriggerHandler::with_config(orchestrator.clone(), config)
+            .with_safe_mode(Arc::new(SafeMode::new()));
+        let platform = Arc::new(MockPlatform::new(platform));
+        handler.register_platform(platform.clone());
+
+        Self {
+            handler,
+            orchestrator,
+            platform,
+            user_id: "U_TEST".to_string(),
+            channel_id: "C_TEST".to_string(),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Install the runtime used for agent execution
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.handler.set_runtime(Arc::new(RwLock::new(runtime)));
+        self
+    }
+
+    /// Default sender for `send`
+    pub fn with_user(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    /// Default channel for messages
+    pub fn with_channel(mut self, channel_id: impl Into<String>) -> Self {
+        self.channel_id = channel_id.into();
+        self
+    }
+
+    /// The handler under test
+    pub fn handler(&self) -> &TriggerHandler {
+        &self.handler
+    }
+
+    /// Mutable access for registering bindings, contexts, flows, ...
+    pub fn handler_mut(&mut self) -> &mut TriggerHandler {
+        &mut self.handler
+    }
+
+    /// Orchestrator the handler submits tasks to
+    pub fn orchestrator(&self) -> &Arc<RuntimeOrchestrator> {
+        &self.orchestrator
+    }
+
+    /// The mock platform
+    pub fn platform(&self) -> &Arc<MockPlatform> {
+        &self.platform
+    }
+
+    /// Build a message from `user_id` on the harness platform and channel
+    pub fn message(&self, user_id: &str, text: &str) -> TriggerMessage {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let user = TriggerUser {
+            id: user_id.to_string(),
+            username: None,
+            display_name: None,
+            is_bot: false,
+        };
+        TriggerMessage::new(
+            format!("msg-{}", id),
+            self.platform.platform_name().to_string(),
+            self.channel_id.clone(),
+            user,
+            text.to_string(),
+        )
+    }
+
+    /// Send text as the default user; returns the responses it produced
+    pub async fn send(&self, text: &str) -> Vec<TriggerResponse> {
+        self.send_as(&self.user_id, text).await
+    }
+
+    /// Send text as a specific user; returns the responses it produced
+    pub async fn send_as(&self, user_id: &str, text: &str) -> Vec<TriggerResponse> {
+        self.send_message(self.message(user_id, text)).await
+    }
+
+    /// Feed a prepared message; returns the responses it produced
+    ///
+    /// Panics if the handler returns an error, so failures surface in the test.
+    pub async fn send_message(&self, message: TriggerMessage) -> Vec<TriggerResponse> {
+        let before = self.platform.sent_count();
+        self.handler
+            .handle_message(self.platform.platform_name(), message)
+            .await
+            .expect("handler failed");
+        self.platform.sent()[before..]
+            .iter()
+            .map(|(_, response)| response.clone())
+            .collect()
+    }
+
+    /// Every response sent so far
+    pub fn responses(&self) -> Vec<TriggerResponse> {
+        self.platform.sent().into_iter().map(|(_, response)| response).collect()
+    }
+
+    /// Most recent response
+    pub fn last_response(&self) -> Option<TriggerResponse> {
+        self.platform.sent().pop().map(|(_, response)| response)
+    }
+
+    /// Forget recorded responses
+    pub fn clear(&self) {
+        self.platform.clear();
+    }
+}
+
+impl Default for TriggerHandlerTestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,453 @@
+//! End-to-end handler tests driven through the test harness
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use aof_core::{
+    AgentConfig, AofResult, Model, ModelConfig, ModelProvider, ModelRequest, ModelResponse, RequestMessage,
+    StopReason, StreamChunk, Tool, ToolCall, ToolDefinition, ToolExecutor, ToolInput, ToolResult, Usage,
+};
+use aof_runtime::{AgentExecutor, Runtime};
+use aof_triggers::response::ResponseStatus;
+use aof_triggers::safety::FreezeWindow;
+use aof_triggers::testing::TriggerHandlerTestHarness;
+use aof_triggers::{MessageCatalog, TriggerHandlerConfig};
+
+fn canned_runtime(agent: &str, output: &str) -> Runtime {
+    Runtime::new().with_canned_responses(HashMap::from([(agent.to_string(), output.to_string())]))
+}
+
+#[tokio::test]
+async fn test_help_lists_commands() {
+    let harness = TriggerHandlerTestHarness::new();
+
+    let responses = harness.send("/help").await;
+
+    // Auto-ack first, then the help text
+    assert_eq!(responses.len(), 2);
+    let help = responses.last().unwrap();
+    assert!(help.text.contains("/agent"));
+    assert!(help.text.contains("/fleet"));
+}
+
+#[tokio::test]
+async fn test_context_switch() {
+    let harness = TriggerHandlerTestHarness::new().with_user("U_ALICE");
+
+    harness.send("/context aws").await;
+
+    let reply = harness.last_response().unwrap();
+    assert_eq!(reply.status, ResponseStatus::Success);
+    assert!(reply.text.contains("Switched to"));
+    assert_eq!(harness.handler().get_user_context("U_ALICE"), "aws");
+    // Other users keep their own context
+    assert_ne!(harness.handler().get_user_context("U_BOB"), "aws");
+}
+
+#[tokio::test]
+async fn test_write_blocked_on_mobile() {
+    let harness = TriggerHandlerTestHarness::for_platform("telegram")
+        .with_runtime(canned_runtime("k8s-ops", "deployment.apps \"nginx\" deleted"));
+    harness.send("/context k8s").await;
+
+    let responses = harness.send("delete the nginx deployment").await;
+
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].status, ResponseStatus::Error);
+    assert!(responses[0].text.contains("Write operation blocked"));
+    // Only the requester sees the block message
+    assert_eq!(responses[0].ephemeral_user.as_deref(), Some("U_TEST"));
+}
+
+#[tokio::test]
+async fn test_read_runs_agent() {
+    let harness = TriggerHandlerTestHarness::for_platform("telegram")
+        .with_runtime(canned_runtime("k8s-ops", "3 pods running in default"));
+    harness.send("/context k8s").await;
+
+    harness.send("how many pods are running?").await;
+
+    let reply = harness.last_response().unwrap();
+    assert!(reply.text.contains("3 pods running"));
+}
+
+#[tokio::test]
+async fn test_block_message_uses_configured_locale() {
+    let config = TriggerHandlerConfig {
+        default_locale: "es".to_string(),
+        messages: MessageCatalog::new().with_message(
+            "es",
+            "write_blocked",
+            "Operación de escritura bloqueada: {platform} es de solo lectura.",
+        ),
+        ..Default::default()
+    };
+    let harness = TriggerHandlerTestHarness::with_config("telegram", config)
+        .with_runtime(canned_runtime("k8s-ops", "done"));
+    harness.send("/context k8s").await;
+
+    let responses = harness.send("delete the nginx deployment").await;
+    assert_eq!(
+        responses[0].text,
+        "Operación de escritura bloqueada: telegram es de solo lectura."
+    );
+
+    // A user preference wins; untranslated locales fall back to English
+    harness.handler().set_user_locale("U_TEST", "fr");
+    let responses = harness.send("delete the nginx deployment").await;
+    assert!(responses[0].text.starts_with("Write operation blocked"));
+}
+
+#[tokio::test]
+async fn test_custom_command_prefix() {
+    let config = TriggerHandlerConfig {
+        command_prefixes: vec!["!aof".to_string(), "/".to_string()],
+        ..Default::default()
+    };
+    let harness = TriggerHandlerTestHarness::with_config("slack", config);
+
+    harness.send("!aof context aws").await;
+    assert_eq!(harness.handler().get_user_context("U_TEST"), "aws");
+
+    // The default prefix is still accepted alongside the custom one
+    harness.send("/context docker").await;
+    assert_eq!(harness.handler().get_user_context("U_TEST"), "docker");
+}
+
+#[tokio::test]
+async fn test_safe_mode_blocks_allowed_write() {
+    let config = TriggerHandlerConfig {
+        admin_users: vec!["U_TEST".to_string()],
+        ..Default::default()
+    };
+    let harness = TriggerHandlerTestHarness::with_config("slack", config)
+        .with_runtime(canned_runtime("k8s-ops", "deployment.apps \"nginx\" deleted"));
+    harness.send("/context k8s").await;
+
+    // Slack lets the write through while safe mode is off
+    harness.send("delete the nginx deployment").await;
+    let reply = harness.last_response().unwrap();
+    assert_ne!(reply.status, ResponseStatus::Error);
+    assert!(reply.text.contains("deleted"));
+
+    harness.send("/safemode on runaway agent").await;
+    assert!(harness.handler().safe_mode().is_active());
+
+    let responses = harness.send("delete the nginx deployment").await;
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].status, ResponseStatus::Error);
+    assert!(responses[0].text.contains("Safe mode is on"));
+    assert!(responses[0].text.contains("runaway agent"));
+
+    // Reads still reach the agent
+    harness.send("how many pods are running?").await;
+    assert_ne!(harness.last_response().unwrap().status, ResponseStatus::Error);
+
+    harness.send("/safemode off").await;
+    assert!(!harness.handler().safe_mode().is_active());
+    harness.send("delete the nginx deployment").await;
+    assert!(harness.last_response().unwrap().text.contains("deleted"));
+}
+
+/// Model that answers the first turn with a `kubectl apply` tool call, then
+/// replies with whatever the tool returned
+struct ApplyingModel {
+    config: ModelConfig,
+    tool_messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl ApplyingModel {
+    fn new(tool_messages: Arc<Mutex<Vec<String>>>) -> Self {
+        Self {
+            config: ModelConfig {
+                model: "scripted".to_string(),
+                provider: ModelProvider::Custom,
+                api_key: None,
+                endpoint: None,
+                temperature: 0.0,
+                max_tokens: None,
+                timeout_secs: 60,
+                stop: vec![],
+                seed: None,
+                headers: HashMap::new(),
+                extra: HashMap::new(),
+            },
+            tool_messages,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Model for ApplyingModel {
+    async fn generate(&self, request: &ModelRequest) -> AofResult<ModelResponse> {
+        let tool_messages: Vec<&RequestMessage> =
+            request.messages.iter().filter(|m| m.tool_call_id.is_some()).collect();
+        let usage = Usage {
+            input_tokens: 10,
+            output_tokens: 10,
+        };
+        if tool_messages.is_empty() {
+            return Ok(ModelResponse {
+                content: String::new(),
+                tool_calls: vec![ToolCall {
+                    id: "call-1".to_string(),
+                    name: "kubectl".to_string(),
+                    arguments: serde_json::json!({"command": "apply -f fix.yaml"}),
+                }],
+                stop_reason: StopReason::ToolUse,
+                usage,
+                metadata: HashMap::new(),
+            });
+        }
+        let seen: Vec<String> = tool_messages.iter().map(|m| m.content.clone()).collect();
+        self.tool_messages.lock().unwrap().extend(seen.iter().cloned());
+        Ok(ModelResponse {
+            content: format!("Could not apply the fix: {}", seen.join("; ")),
+            tool_calls: vec![],
+            stop_reason: StopReason::EndTurn,
+            usage,
+            metadata: HashMap::new(),
+        })
+    }
+
+    async fn generate_stream(
+        &self,
+        _request: &ModelRequest,
+    ) -> AofResult<std::pin::Pin<Box<dyn futures::Stream<Item = AofResult<StreamChunk>> + Send>>> {
+        Err(aof_core::AofError::model("streaming is not scripted"))
+    }
+
+    fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::Custom
+    }
+}
+
+/// Tool executor that records the calls that reach it
+#[derive(Default)]
+struct RecordingTools {
+    calls: Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl ToolExecutor for RecordingTools {
+    async fn execute_tool(&self, name: &str, _input: ToolInput) -> AofResult<ToolResult> {
+        self.calls.lock().unwrap().push(name.to_string());
+        Ok(ToolResult::success(serde_json::json!("deployment.apps/api configured")))
+    }
+
+    fn list_tools(&self) -> Vec<ToolDefinition> {
+        Vec::new()
+    }
+
+    fn get_tool(&self, _name: &str) -> Option<Arc<dyn Tool>> {
+        None
+    }
+}
+
+#[tokio::test]
+async fn test_safe_mode_refuses_write_tool_calls() {
+    let seen_by_model = Arc::new(Mutex::new(Vec::new()));
+    let model = Box::new(ApplyingModel::new(seen_by_model.clone()));
+    let tools = Arc::new(RecordingTools::default());
+    let config: AgentConfig = serde_yaml::from_str("name: k8s-ops\nmodel: scripted\n").unwrap();
+    let mut runtime = Runtime::new();
+    runtime.register_agent(AgentExecutor::new(config, model, Some(tools.clone() as Arc<dyn ToolExecutor>), None).unwrap());
+
+    let harness = TriggerHandlerTestHarness::new().with_runtime(runtime);
+    harness.send("/context k8s").await;
+    harness.send("/safemode on incident").await;
+
+    // The message reads harmlessly, so it reaches the agent...
+    harness.send("please fix the crashlooping deployment").await;
+    let reply = harness.last_response().unwrap();
+
+    // ...but the write the agent attempts is refused at execution
+    assert!(tools.calls.lock().unwrap().is_empty());
+    let tool_messages = seen_by_model.lock().unwrap().clone();
+    assert_eq!(tool_messages.len(), 1);
+    assert!(tool_messages[0].contains("Refused"));
+    assert!(tool_messages[0].contains("incident"));
+    assert!(reply.text.contains("Refused"));
+}
+
+#[tokio::test]
+async fn test_change_freeze_refuses_write_tool_calls() {
+    let seen_by_model = Arc::new(Mutex::new(Vec::new()));
+    let model = Box::new(ApplyingModel::new(seen_by_model.clone()));
+    let tools = Arc::new(RecordingTools::default());
+    let config: AgentConfig = serde_yaml::from_str("name: k8s-ops\nmodel: scripted\n").unwrap();
+    let mut runtime = Runtime::new();
+    runtime.register_agent(AgentExecutor::new(config, model, Some(tools.clone() as Arc<dyn ToolExecutor>), None).unwrap());
+
+    let harness = TriggerHandlerTestHarness::new().with_runtime(runtime);
+    let mut k8s = harness.handler().get_context("k8s").unwrap();
+    k8s.safety.freeze_windows = vec![FreezeWindow::Range {
+        start: chrono::Utc::now() - chrono::Duration::hours(1),
+        end: chrono::Utc::now() + chrono::Duration::hours(1),
+        reason: Some("Release freeze".to_string()),
+    }];
+    harness.handler().register_context("k8s", k8s);
+    harness.send("/context k8s").await;
+
+    // Nothing in the message looks like a write; the agent's apply is refused
+    harness.send("please fix the crashlooping deployment").await;
+
+    assert!(tools.calls.lock().unwrap().is_empty());
+    let tool_messages = seen_by_model.lock().unwrap().clone();
+    assert_eq!(tool_messages.len(), 1);
+    assert!(tool_messages[0].contains("Refused"));
+    assert!(tool_messages[0].contains("Release freeze"));
+}
+
+#[tokio::test]
+async fn test_safe_mode_off_requires_admin() {
+    let config = TriggerHandlerConfig {
+        admin_users: vec!["U_ADMIN".to_string()],
+        ..Default::default()
+    };
+    let harness = TriggerHandlerTestHarness::with_config("slack", config);
+
+    // Anyone can pull the brake
+    harness.send("/safemode on").await;
+    assert!(harness.handler().safe_mode().is_active());
+
+    harness.send("/safemode off").await;
+    assert!(harness.handler().safe_mode().is_active());
+    assert!(harness.last_response().unwrap().text.contains("not allowed to turn safe mode off"));
+
+    harness.send_as("U_ADMIN", "/safemode off").await;
+    assert!(!harness.handler().safe_mode().is_active());
+}
+
+#[tokio::test]
+async fn test_safe_mode_off_refused_without_admins() {
+    let harness = TriggerHandlerTestHarness::new();
+
+    harness.send("/safemode on").await;
+    harness.send("/safemode off").await;
+    assert!(harness.handler().safe_mode().is_active());
+    let reply = harness.last_response().unwrap();
+    assert_eq!(reply.status, ResponseStatus::Error);
+    assert!(reply.text.contains("no admin users are configured"));
+}
+
+#[tokio::test]
+async fn test_change_freeze_blocks_writes_in_context() {
+    let harness = TriggerHandlerTestHarness::new()
+        .with_runtime(canned_runtime("k8s-ops", "deployment.apps \"nginx\" deleted"));
+    let mut k8s = harness.handler().get_context("k8s").unwrap();
+    k8s.safety.freeze_windows = vec![FreezeWindow::Range {
+        start: chrono::Utc::now() - chrono::Duration::hours(1),
+        end: chrono::Utc::now() + chrono::Duration::hours(1),
+        reason: Some("Release freeze".to_string()),
+    }];
+    harness.handler().register_context("k8s", k8s);
+    harness.send("/context k8s").await;
+
+    let responses = harness.send("delete the nginx deployment").await;
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].status, ResponseStatus::Error);
+    assert!(responses[0].text.contains("blocked in the *k8s* context"));
+    assert!(responses[0].text.contains("Release freeze"));
+
+    // Background runs are held to the same freeze
+    let reply = harness.send("/run agent k8s-ops --background delete the nginx deployment").await;
+    assert!(reply.last().unwrap().text.contains("Release freeze"));
+
+    // Reads still reach the agent
+    harness.send("how many pods are running?").await;
+    assert_ne!(harness.last_response().unwrap().status, ResponseStatus::Error);
+
+    // Other contexts are not frozen
+    harness.send("/context devops").await;
+    let responses = harness.send("delete the nginx deployment").await;
+    assert!(responses.iter().all(|r| !r.text.contains("Release freeze")));
+}
+
+#[tokio::test]
+async fn test_background_run_is_queryable_and_notifies() {
+    let harness = TriggerHandlerTestHarness::new()
+        .with_runtime(canned_runtime("k8s-ops", "3 pods running in default"));
+
+    let responses = harness.send("/run agent k8s-ops --background check the pods").await;
+
+    let started = responses.last().unwrap();
+    assert!(started.text.contains("Started task"));
+    let task_id = started.metadata["task_id"].as_str().unwrap().to_string();
+
+    // The completion notice arrives on its own once the task finishes
+    let notice = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let notice = harness.responses().into_iter().find(|r| {
+                r.text.contains("completed") && r.metadata.get("task_id") == Some(&serde_json::json!(task_id))
+            });
+            if let Some(notice) = notice {
+                return notice;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("no completion notice");
+    assert_eq!(notice.status, ResponseStatus::Success);
+    assert!(notice.text.contains("3 pods running in default"));
+
+    harness.send(&format!("/status task {}", task_id)).await;
+    let status = harness.last_response().unwrap();
+    assert!(status.text.contains("**Status:** Completed"));
+    assert!(status.text.contains("3 pods running in default"));
+}
+
+/// Wait for the completion notice of a background task
+async fn task_notice(harness: &TriggerHandlerTestHarness, task_id: &str) -> aof_triggers::TriggerResponse {
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let notice = harness.responses().into_iter().find(|r| {
+                r.metadata.get("task_id") == Some(&serde_json::json!(task_id)) && !r.text.contains("Started task")
+            });
+            if let Some(notice) = notice {
+                return notice;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("no completion notice")
+}
+
+#[tokio::test]
+async fn test_background_run_requests_approval() {
+    let harness = TriggerHandlerTestHarness::new().with_runtime(canned_runtime(
+        "k8s-ops",
+        "I'll scale the deployment.\nrequires_approval: true\ncommand: kubectl scale deploy/web --replicas=3",
+    ));
+
+    let responses = harness.send("/run agent k8s-ops --background check the web deployment").await;
+    let task_id = responses.last().unwrap().metadata["task_id"].as_str().unwrap().to_string();
+
+    let notice = task_notice(&harness, &task_id).await;
+    assert!(notice.text.contains("completed"));
+    assert!(notice.text.contains("This action requires approval"));
+    assert!(notice.text.contains("`kubectl scale deploy/web --replicas=3`"));
+    assert!(!notice.text.contains("requires_approval: true"));
+}
+
+#[tokio::test]
+async fn test_background_run_respects_open_circuit() {
+    let harness = TriggerHandlerTestHarness::new().with_runtime(canned_runtime("k8s-ops", "3 pods running"));
+    let key = aof_triggers::handler::CircuitBreaker::agent_key("k8s-ops");
+    for _ in 0..5 {
+        harness.handler().circuit_breaker().record_failure(&key);
+    }
+
+    let responses = harness.send("/run agent k8s-ops --background check the pods").await;
+
+    let reply = responses.last().unwrap();
+    assert_eq!(reply.status, ResponseStatus::Error);
+    assert!(reply.text.contains("temporarily unavailable"));
+    assert!(harness.responses().iter().all(|r| !r.text.contains("Started task")));
+}
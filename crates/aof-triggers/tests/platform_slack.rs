@@ -2,6 +2,7 @@
 
 use aof_triggers::platforms::slack::{SlackPlatform, SlackConfig};
 use aof_triggers::platforms::TriggerPlatform;
+use aof_tools::{MockHttpServer, MockResponse};
 use std::collections::HashMap;
 
 fn test_config() -> SlackConfig {
@@ -100,3 +101,50 @@ async fn test_slack_supports_interactive() {
     let platform = SlackPlatform::new(test_config()).unwrap();
     assert!(platform.supports_interactive());
 }
+
+/// Answer one Web API call with `{"ok": true}`
+async fn mock_slack_api() -> MockHttpServer {
+    MockHttpServer::start(vec![MockResponse::json(serde_json::json!({ "ok": true }))]).await
+}
+
+#[tokio::test]
+async fn test_slack_ephemeral_response_request() {
+    use aof_triggers::response::TriggerResponseBuilder;
+
+    let server = mock_slack_api().await;
+    let platform = SlackPlatform::new(test_config())
+        .unwrap()
+        .with_api_url(format!("{}/api", server.url()));
+
+    let response = TriggerResponseBuilder::new()
+        .text("You are not allowed to approve this")
+        .error()
+        .thread_id("1700000000.000100".to_string())
+        .ephemeral("U_DEV")
+        .build();
+    platform.send_response("C123", response).await.unwrap();
+
+    let sent = &server.requests()[0];
+    assert_eq!(sent.path, "/api/chat.postEphemeral");
+    let body = sent.json();
+    assert_eq!(body["channel"], "C123");
+    assert_eq!(body["user"], "U_DEV");
+    assert_eq!(body["thread_ts"], "1700000000.000100");
+    assert_eq!(body["text"], "You are not allowed to approve this");
+}
+
+#[tokio::test]
+async fn test_slack_regular_response_request() {
+    use aof_triggers::response::TriggerResponse;
+
+    let server = mock_slack_api().await;
+    let platform = SlackPlatform::new(test_config())
+        .unwrap()
+        .with_api_url(format!("{}/api", server.url()));
+
+    platform.send_response("C123", TriggerResponse::text("Deployed")).await.unwrap();
+
+    let sent = &server.requests()[0];
+    assert_eq!(sent.path, "/api/chat.postMessage");
+    assert!(sent.json().get("user").is_none());
+}
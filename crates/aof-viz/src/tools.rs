@@ -17,6 +17,14 @@ pub struct ToolResult {
     pub duration_ms: Option<u64>,
 }
 
+/// Row, column and cell-width caps applied when rendering a table
+#[derive(Debug, Clone, Copy)]
+struct TableLimits {
+    columns: Option<usize>,
+    column_width: Option<usize>,
+    rows: Option<usize>,
+}
+
 /// Renders tool call results
 pub struct ToolRenderer {
     config: RenderConfig,
@@ -69,6 +77,21 @@ impl ToolRenderer {
 
     /// Render kubectl-style table output
     pub fn render_table(&self, headers: &[&str], rows: &[Vec<&str>]) -> String {
+        let limits = if self.config.compact {
+            TableLimits { columns: Some(3), column_width: Some(12), rows: Some(5) }
+        } else {
+            TableLimits { columns: None, column_width: Some(20), rows: Some(15) }
+        };
+        Self::table(headers, rows, limits)
+    }
+
+    /// Render a table with every row, column and character of each cell
+    pub fn render_full_table(&self, headers: &[&str], rows: &[Vec<&str>]) -> String {
+        let limits = TableLimits { columns: None, column_width: None, rows: None };
+        Self::table(headers, rows, limits)
+    }
+
+    fn table(headers: &[&str], rows: &[Vec<&str>], limits: TableLimits) -> String {
         if rows.is_empty() {
             return "No results".to_string();
         }
@@ -83,14 +106,13 @@ impl ToolRenderer {
             }
         }
 
-        // Compact mode: limit to 3 columns
-        if self.config.compact && widths.len() > 3 {
-            widths.truncate(3);
+        if let Some(columns) = limits.columns {
+            widths.truncate(columns);
         }
 
-        // Limit column width
-        let max_col = if self.config.compact { 12 } else { 20 };
-        widths = widths.iter().map(|w| (*w).min(max_col)).collect();
+        if let Some(max_col) = limits.column_width {
+            widths = widths.iter().map(|w| (*w).min(max_col)).collect();
+        }
 
         let mut lines = Vec::new();
 
@@ -108,7 +130,8 @@ impl ToolRenderer {
         lines.push(sep.join("──"));
 
         // Rows
-        for row in rows.iter().take(if self.config.compact { 5 } else { 15 }) {
+        let max_rows = limits.rows.unwrap_or(rows.len());
+        for row in rows.iter().take(max_rows) {
             let cells: Vec<String> = row
                 .iter()
                 .take(widths.len())
@@ -121,8 +144,8 @@ impl ToolRenderer {
             lines.push(cells.join("  "));
         }
 
-        if rows.len() > (if self.config.compact { 5 } else { 15 }) {
-            lines.push(format!("... {} more rows", rows.len() - 5));
+        if rows.len() > max_rows {
+            lines.push(format!("... {} more rows", rows.len() - max_rows));
         }
 
         lines.join("\n")
@@ -140,12 +163,17 @@ impl ToolRenderer {
 
     /// Truncate string to max length
     fn truncate(s: &str, max_len: usize) -> String {
+        // Cut on a char boundary so multi-byte text (emoji, accents) can't panic
+        let prefix = |len: usize| {
+            let end = (0..=len).rev().find(|&i| s.is_char_boundary(i)).unwrap_or(0);
+            &s[..end]
+        };
         if s.len() <= max_len {
             s.to_string()
         } else if max_len > 3 {
-            format!("{}...", &s[..max_len - 3])
+            format!("{}...", prefix(max_len - 3))
         } else {
-            s[..max_len].to_string()
+            prefix(max_len).to_string()
         }
     }
 }
@@ -215,9 +243,23 @@ mod tests {
         assert!(output.contains("Running"));
     }
 
+    #[test]
+    fn test_render_full_table_keeps_every_row_and_cell() {
+        let renderer = ToolRenderer::new(RenderConfig::default());
+        let long = "a message that is well past twenty characters";
+        let names: Vec<String> = (0..20).map(|i| format!("pod-{}", i)).collect();
+        let rows: Vec<Vec<&str>> = names.iter().map(|n| vec![n.as_str(), long]).collect();
+
+        let output = renderer.render_full_table(&["NAME", "MESSAGE"], &rows);
+        assert!(names.iter().all(|n| output.contains(n.as_str())));
+        assert!(output.contains(long));
+        assert!(!output.contains("more rows"));
+    }
+
     #[test]
     fn test_truncate() {
         assert_eq!(ToolRenderer::truncate("hello world", 8), "hello...");
         assert_eq!(ToolRenderer::truncate("short", 10), "short");
+        assert_eq!(ToolRenderer::truncate("ünïcödé text", 6), "ün...");
     }
 }
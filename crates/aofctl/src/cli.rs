@@ -167,6 +167,12 @@ pub enum Commands {
         args: Vec<String>,
     },
 
+    /// Test built-in tools in isolation (tool run <name> --input '{...}')
+    Tool {
+        #[command(subcommand)]
+        command: commands::tool::ToolCommands,
+    },
+
     /// Validate agent configuration (legacy command, use 'apply --dry-run' instead)
     #[command(hide = true)]
     Validate {
@@ -217,12 +223,19 @@ pub enum Commands {
     /// Manage agent flows (workflow orchestration)
     ///
     /// NOTE: Prefer kubectl-style commands: aofctl get flows, aofctl run flow <name>
-    #[command(hide = true)]
+    #[command(hide = true, alias = "flows")]
     Flow {
         #[command(subcommand)]
         command: commands::flow::FlowCommands,
     },
 
+    /// Inspect configured contexts (contexts health)
+    #[command(alias = "context")]
+    Contexts {
+        #[command(subcommand)]
+        command: commands::contexts::ContextsCommands,
+    },
+
     /// Generate shell completion scripts
     Completion {
         /// Shell to generate completion for
@@ -303,6 +316,7 @@ impl Cli {
             } => commands::exec::execute(&resource_type, &name, command).await,
             Commands::ApiResources => commands::api_resources::execute().await,
             Commands::Tools { server, args } => commands::tools::execute(&server, &args).await,
+            Commands::Tool { command } => commands::tool::execute(command).await,
             Commands::Validate { file } => commands::validate::execute(&file).await,
             Commands::Version => commands::version::execute().await,
             Commands::Serve {
@@ -325,6 +339,9 @@ impl Cli {
             }
             Commands::Fleet { command } => commands::fleet::execute(command).await,
             Commands::Flow { command } => commands::flow::execute(command).await,
+            Commands::Contexts { command } => {
+                commands::contexts::execute(command, &self.contexts_dir).await
+            }
             Commands::Completion { shell } => commands::completion::execute(shell),
         }
     }
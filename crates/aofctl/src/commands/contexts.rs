@@ -0,0 +1,154 @@
+//! Context CLI commands
+//!
+//! Commands:
+//! - aofctl contexts health    - Probe every context's connectivity
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context as _, Result};
+use aof_core::Context;
+use aof_tools::{ContextProbe, HealthReport, ProbeTarget, ProcessRunner};
+use clap::Subcommand;
+
+/// Context subcommands
+#[derive(Subcommand, Debug)]
+pub enum ContextsCommands {
+    /// Check connectivity of every context (kubectl version, aws sts get-caller-identity)
+    Health {
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        output: String,
+
+        /// Timeout for each probe in seconds
+        #[arg(long, default_value_t = aof_tools::DEFAULT_PROBE_TIMEOUT_SECS)]
+        timeout: u64,
+    },
+}
+
+/// Execute context commands
+pub async fn execute(command: ContextsCommands, contexts_dir: &str) -> Result<()> {
+    match command {
+        ContextsCommands::Health { output, timeout } => {
+            let contexts = load_contexts(Path::new(contexts_dir))?;
+            let probes = contexts.iter().map(health_probe).collect();
+            let report = HealthReport::probe(Arc::new(ProcessRunner), probes, timeout).await;
+
+            match output.as_str() {
+                "json" => println!("{}", serde_json::to_string_pretty(&report)?),
+                _ => println!("{}", report.render(aof_viz::RenderConfig::terminal())),
+            }
+
+            if report.has_failures() {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Load every context in a directory, sorted by name
+fn load_contexts(dir: &Path) -> Result<Vec<Context>> {
+    if !dir.exists() {
+        anyhow::bail!(
+            "Contexts directory '{}' not found. Create it with context YAML files.",
+            dir.display()
+        );
+    }
+
+    let mut contexts = Vec::new();
+    for entry in fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        let is_yaml = path
+            .extension()
+            .map(|e| e == "yaml" || e == "yml")
+            .unwrap_or(false);
+        if !is_yaml {
+            continue;
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read context file {:?}", path))?;
+        let mut context: Context = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse context file {:?}", path))?;
+        context.expand_env_vars();
+        contexts.push(context);
+    }
+
+    contexts.sort_by(|a, b| a.name().cmp(b.name()));
+    Ok(contexts)
+}
+
+/// Probes for the connections a context configures
+///
+/// Kubernetes is probed when the context sets a kubeconfig or cluster; AWS when
+/// its env sets `AWS_PROFILE` or a region.
+fn health_probe(context: &Context) -> ContextProbe {
+    let spec = &context.spec;
+    let mut targets = Vec::new();
+
+    if spec.kubeconfig.is_some() || spec.cluster.is_some() {
+        targets.push(ProbeTarget::Kubernetes {
+            kubeconfig: spec.kubeconfig.clone(),
+            context: None,
+        });
+    }
+
+    let profile = spec.env.get("AWS_PROFILE").cloned();
+    let region = spec
+        .env
+        .get("AWS_REGION")
+        .or_else(|| spec.env.get("AWS_DEFAULT_REGION"))
+        .cloned();
+    if profile.is_some() || region.is_some() {
+        targets.push(ProbeTarget::Aws { profile, region });
+    }
+
+    ContextProbe {
+        context: context.name().to_string(),
+        targets,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_contexts_and_build_probes() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("prod.yaml"),
+            "apiVersion: aof.dev/v1\nkind: Context\nmetadata:\n  name: prod\nspec:\n  kubeconfig: /kube/prod\n  env:\n    AWS_PROFILE: prod\n    AWS_REGION: us-east-1\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("local.yml"),
+            "apiVersion: aof.dev/v1\nkind: Context\nmetadata:\n  name: local\nspec: {}\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("README.md"), "not a context").unwrap();
+
+        let contexts = load_contexts(dir.path()).unwrap();
+        let probes: Vec<ContextProbe> = contexts.iter().map(health_probe).collect();
+
+        assert_eq!(probes.len(), 2);
+        assert_eq!(probes[0].context, "local");
+        assert!(probes[0].targets.is_empty());
+        assert_eq!(probes[1].context, "prod");
+        assert_eq!(
+            probes[1].targets,
+            vec![
+                ProbeTarget::Kubernetes {
+                    kubeconfig: Some("/kube/prod".to_string()),
+                    context: None
+                },
+                ProbeTarget::Aws {
+                    profile: Some("prod".to_string()),
+                    region: Some("us-east-1".to_string())
+                },
+            ]
+        );
+    }
+}
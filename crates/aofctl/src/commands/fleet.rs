@@ -10,9 +10,10 @@
 //! - aofctl fleet delete <name>          - Remove fleet
 
 use anyhow::{Context, Result};
-use aof_core::AgentFleet;
-use aof_runtime::fleet::FleetCoordinator;
+use aof_core::{AgentFleet, FleetTask};
+use aof_runtime::fleet::{FleetCoordinator, FleetEvent};
 use clap::Subcommand;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
@@ -410,10 +411,237 @@ fn print_fleet_wide(fleet: &AgentFleet) {
     );
 }
 
+/// Machine-readable report of a fleet run (`aofctl run fleet -o json|yaml`)
+#[derive(Debug, Serialize)]
+pub struct FleetRunReport {
+    pub fleet: String,
+    pub mode: String,
+    pub task_id: String,
+    pub status: serde_json::Value,
+    pub duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Per-agent answers, with their tier in tiered mode
+    pub agents: Vec<serde_json::Value>,
+    /// Per-tier outcome (tiered mode only)
+    pub tiers: Vec<TierReport>,
+    /// Consensus outcome (peer and tiered consensus modes)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consensus: Option<ConsensusReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<serde_json::Value>,
+    /// Final aggregated answer
+    pub result: serde_json::Value,
+}
+
+/// Outcome of one tier in a tiered fleet run
+#[derive(Debug, Serialize)]
+pub struct TierReport {
+    pub tier: u32,
+    pub agents: Vec<String>,
+    pub consensus: String,
+    pub results: usize,
+    pub confidence: f64,
+    pub duration_ms: u64,
+}
+
+/// Consensus outcome of a fleet run
+#[derive(Debug, Serialize)]
+pub struct ConsensusReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub votes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation: Option<String>,
+}
+
+impl FleetRunReport {
+    /// Build the report from the finished task and the events collected during the run
+    pub fn new(fleet: &str, mode: &str, task: &FleetTask, events: &[FleetEvent], duration_ms: u64) -> Self {
+        let raw = task.result.clone().unwrap_or(serde_json::Value::Null);
+
+        // Peer merge mode reports answers under "results", other modes under "agent_results"
+        let agents = raw
+            .get("agent_results")
+            .or_else(|| raw.get("results"))
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        let mut tiers: Vec<TierReport> = Vec::new();
+        for event in events {
+            match event {
+                FleetEvent::TierStarted { tier, agents, consensus } => tiers.push(TierReport {
+                    tier: *tier,
+                    agents: agents.iter().map(|a| a.name.clone()).collect(),
+                    consensus: consensus.clone(),
+                    results: 0,
+                    confidence: 0.0,
+                    duration_ms: 0,
+                }),
+                FleetEvent::TierCompleted { tier, results, confidence, duration_ms } => {
+                    if let Some(report) = tiers.iter_mut().rev().find(|t| t.tier == *tier) {
+                        report.results = *results;
+                        report.confidence = *confidence;
+                        report.duration_ms = *duration_ms;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let consensus = raw.get("confidence").map(|confidence| ConsensusReport {
+            confidence: confidence.as_f64(),
+            votes: raw.get("votes").and_then(|v| v.as_u64()),
+            requires_review: raw.get("requires_review").and_then(|v| v.as_bool()),
+            aggregation: raw.get("aggregation").and_then(|v| v.as_str()).map(String::from),
+        });
+
+        Self {
+            fleet: fleet.to_string(),
+            mode: mode.to_string(),
+            task_id: task.task_id.clone(),
+            status: serde_json::to_value(task.status).unwrap_or_default(),
+            duration_ms,
+            error: task.error.clone(),
+            agents,
+            tiers,
+            consensus,
+            usage: raw.get("usage").cloned(),
+            result: final_answer(&raw),
+        }
+    }
+}
+
+/// Pick the final aggregated answer out of a fleet task result
+fn final_answer(raw: &serde_json::Value) -> serde_json::Value {
+    // Peer consensus: "response"; tiered consensus/synthesis: "result"; deep: "conclusion"
+    for key in ["response", "result", "conclusion"] {
+        if let Some(answer) = raw.get(key).filter(|v| !v.is_null()) {
+            return answer.clone();
+        }
+    }
+    // Merge modes have no single answer; the merged list is the result
+    for key in ["tier_results", "results"] {
+        if let Some(merged) = raw.get(key) {
+            return merged.clone();
+        }
+    }
+    if raw.is_object() {
+        serde_json::Value::Null
+    } else {
+        raw.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn completed_task(result: serde_json::Value) -> FleetTask {
+        FleetTask {
+            task_id: "9f1c2d3e-task".to_string(),
+            input: serde_json::json!({"input": "why is checkout slow?"}),
+            assigned_to: None,
+            status: aof_core::FleetTaskStatus::Completed,
+            result: Some(result),
+            error: None,
+            created_at: chrono::Utc::now(),
+            started_at: None,
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_report_tiered_run() {
+        let task = completed_task(serde_json::json!({
+            "aggregation": "consensus",
+            "result": "Connection pool exhausted on payments-db",
+            "confidence": 0.8,
+            "tier_count": 2,
+            "agent_results": [
+                {"agent": "log-reader", "tier": 1, "response": "timeouts to payments-db"},
+                {"agent": "metrics-reader", "tier": 1, "response": "db connections at max"},
+                {"agent": "rca", "tier": 2, "response": "Connection pool exhausted on payments-db"},
+            ],
+        }));
+        let events = vec![
+            FleetEvent::TierStarted {
+                tier: 1,
+                agents: vec![
+                    aof_runtime::fleet::TierAgentInfo { name: "log-reader".into(), model: "m".into() },
+                    aof_runtime::fleet::TierAgentInfo { name: "metrics-reader".into(), model: "m".into() },
+                ],
+                consensus: "Majority".into(),
+            },
+            FleetEvent::TierCompleted { tier: 1, results: 2, confidence: 1.0, duration_ms: 1200 },
+            FleetEvent::TierStarted {
+                tier: 2,
+                agents: vec![aof_runtime::fleet::TierAgentInfo { name: "rca".into(), model: "m".into() }],
+                consensus: "Weighted".into(),
+            },
+            FleetEvent::TierCompleted { tier: 2, results: 1, confidence: 0.8, duration_ms: 900 },
+        ];
+
+        let report = FleetRunReport::new("rca-fleet", "Tiered", &task, &events, 2300);
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["fleet"], "rca-fleet");
+        assert_eq!(json["mode"], "Tiered");
+        assert_eq!(json["task_id"], task.task_id.as_str());
+        assert_eq!(json["duration_ms"], 2300);
+        assert_eq!(json["result"], "Connection pool exhausted on payments-db");
+        assert_eq!(json["agents"].as_array().unwrap().len(), 3);
+        assert_eq!(json["agents"][2]["tier"], 2);
+        assert_eq!(json["tiers"][0]["agents"], serde_json::json!(["log-reader", "metrics-reader"]));
+        assert_eq!(json["tiers"][1]["duration_ms"], 900);
+        assert_eq!(json["consensus"]["confidence"], 0.8);
+        assert_eq!(json["consensus"]["aggregation"], "consensus");
+        assert!(json.get("error").is_none());
+    }
+
+    #[test]
+    fn test_report_peer_consensus_run() {
+        let task = completed_task(serde_json::json!({
+            "response": "Roll back deploy 42",
+            "confidence": 0.67,
+            "votes": 2,
+            "requires_review": false,
+            "agent_results": [
+                {"agent": "a", "tier": null, "response": "Roll back deploy 42"},
+                {"agent": "b", "tier": null, "response": "Roll back deploy 42"},
+                {"agent": "c", "tier": null, "response": "Scale up"},
+            ],
+            "usage": {"input_tokens": 300, "output_tokens": 90, "total_tokens": 390},
+        }));
+
+        let json = serde_json::to_value(FleetRunReport::new("peer", "Peer", &task, &[], 10)).unwrap();
+
+        assert_eq!(json["result"], "Roll back deploy 42");
+        assert_eq!(json["consensus"]["votes"], 2);
+        assert_eq!(json["consensus"]["requires_review"], false);
+        assert_eq!(json["usage"]["total_tokens"], 390);
+        assert_eq!(json["agents"].as_array().unwrap().len(), 3);
+        assert!(json["tiers"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_report_failed_run() {
+        let mut task = completed_task(serde_json::json!({"agent_results": []}));
+        task.status = aof_core::FleetTaskStatus::Failed;
+        task.error = Some("Failed to reach consensus".to_string());
+
+        let json = serde_json::to_value(FleetRunReport::new("peer", "Peer", &task, &[], 10)).unwrap();
+
+        assert_eq!(json["status"], "failed");
+        assert_eq!(json["error"], "Failed to reach consensus");
+        assert!(json["result"].is_null());
+        assert!(json.get("consensus").is_none());
+    }
+
     #[test]
     fn test_fleet_registry() {
         let mut registry = FLEET_REGISTRY.lock().unwrap();
@@ -9,10 +9,12 @@
 //! - aofctl flow logs <run-id>            - View execution logs
 //! - aofctl flow visualize <name>         - ASCII graph output
 //! - aofctl flow delete <name>            - Remove flow
+//! - aofctl flow explain "<message>"      - Show where a message's command binding routes it
 
 use anyhow::{Context, Result};
-use aof_core::{NextStep, Workflow};
+use aof_core::{NextStep, Registry, TriggerRegistry, Workflow};
 use aof_runtime::executor::{Runtime, WorkflowEvent, WorkflowExecutor};
+use aof_triggers::{explain_route, BindingTarget, CommandBinding, FlowRegistry, RouteExplanation};
 use clap::Subcommand;
 use std::collections::HashMap;
 use std::fs;
@@ -100,6 +102,28 @@ pub enum FlowCommands {
         /// Flow name
         name: String,
     },
+
+    /// Explain where a message's command binding routes it (to debug routing)
+    Explain {
+        /// Message text as a user would send it
+        message: String,
+
+        /// Directory containing Trigger YAML files (command bindings)
+        #[arg(long, default_value = "./triggers")]
+        triggers_dir: String,
+
+        /// Directory containing AgentFlow YAML files, to check that a target flow exists
+        #[arg(long)]
+        flows_dir: Option<String>,
+
+        /// Accepted command prefixes
+        #[arg(long = "prefix", default_value = "/")]
+        prefixes: Vec<String>,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        output: String,
+    },
 }
 
 /// Registry of loaded flows (in-memory for now)
@@ -134,6 +158,17 @@ pub async fn execute(cmd: FlowCommands) -> Result<()> {
         FlowCommands::Logs { run_id, follow } => logs_flow(&run_id, follow).await,
         FlowCommands::Visualize { name, format } => visualize_flow(&name, &format).await,
         FlowCommands::Delete { name } => delete_flow(&name).await,
+        FlowCommands::Explain {
+            message,
+            triggers_dir,
+            flows_dir,
+            prefixes,
+            output,
+        } => {
+            let explanation = explain_message(&message, &triggers_dir, flows_dir.as_deref(), &prefixes).await?;
+            println!("{}", render_explanation(&explanation, &output)?);
+            Ok(())
+        }
     }
 }
 
@@ -829,6 +864,61 @@ async fn delete_flow(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Explain a message against the command bindings of every Trigger, as `aofctl serve` loads them
+async fn explain_message(
+    message: &str,
+    triggers_dir: &str,
+    flows_dir: Option<&str>,
+    prefixes: &[String],
+) -> Result<RouteExplanation> {
+    let mut triggers = TriggerRegistry::new();
+    triggers
+        .load_directory(Path::new(triggers_dir))
+        .with_context(|| format!("Failed to load triggers from {}", triggers_dir))?;
+
+    let mut bindings = HashMap::new();
+    for trigger in triggers.get_all() {
+        for (command, binding) in &trigger.spec.commands {
+            bindings.insert(command.trim_start_matches('/').to_string(), CommandBinding::from(binding));
+        }
+    }
+
+    let mut explanation = explain_route(&bindings, message, prefixes);
+    if let (Some(dir), Some(route)) = (flows_dir, &explanation.route) {
+        if let BindingTarget::Flow(ref flow) = route.target {
+            let registry = FlowRegistry::from_directory(dir)
+                .await
+                .with_context(|| format!("Failed to load flows from {}", dir))?;
+            if registry.get(flow).is_none() {
+                explanation.detail.push_str(&format!(" (flow '{}' is not defined in {})", flow, dir));
+            }
+        }
+    }
+
+    Ok(explanation)
+}
+
+/// Render a route explanation
+fn render_explanation(explanation: &RouteExplanation, output: &str) -> Result<String> {
+    match output {
+        "json" => Ok(serde_json::to_string_pretty(explanation)?),
+        "text" => {
+            let mut lines = Vec::new();
+            if let Some(ref route) = explanation.route {
+                lines.push(format!("{:<10} {}", "COMMAND", route.command));
+                lines.push(format!("{:<10} {}", "BINDING", route.binding));
+                lines.push(format!("{:<10} {}", "TARGET", route.target));
+            }
+            if let Some(ref text) = explanation.text {
+                lines.push(format!("{:<10} {}", "TEXT", text));
+            }
+            lines.push(format!("{:<10} {}", "DETAIL", explanation.detail));
+            Ok(lines.join("\n"))
+        }
+        other => anyhow::bail!("Unsupported output format: {} (use text or json)", other),
+    }
+}
+
 /// Print flow in wide format
 fn print_flow_wide(workflow: &Workflow) {
     let step_names: Vec<&str> = workflow.spec.steps.iter().map(|s| s.name.as_str()).collect();
@@ -859,4 +949,59 @@ mod tests {
         assert!(registry.contains_key("test-flow"));
         registry.remove("test-flow");
     }
+
+    const FLOW: &str = "apiVersion: aof.dev/v1\nkind: AgentFlow\nmetadata:\n  name: NAME\nspec:\n  nodes:\n    - id: process\n      type: End\n  connections:\n    - from: start\n      to: process\n";
+
+    const TRIGGER: &str = r#"
+apiVersion: aof.dev/v1
+kind: Trigger
+metadata:
+  name: slack-ops
+spec:
+  type: Slack
+  config:
+    bot_token: xoxb-test
+  commands:
+    /deploy:
+      flow: deploy
+      description: Deploy a service
+    /rollback:
+      flow: rollback
+      description: Roll back a service
+    /help:
+      agent: builtin
+      description: Show help
+"#;
+
+    #[tokio::test]
+    async fn test_explain_uses_command_bindings() {
+        let flows = tempfile::tempdir().unwrap();
+        std::fs::write(flows.path().join("deploy.yaml"), FLOW.replace("NAME", "deploy")).unwrap();
+        let triggers = tempfile::tempdir().unwrap();
+        std::fs::write(triggers.path().join("slack.yaml"), TRIGGER).unwrap();
+        let triggers_dir = triggers.path().to_str().unwrap();
+        let prefixes = vec!["/".to_string()];
+
+        let explanation = explain_message("/deploy api to prod", triggers_dir, None, &prefixes).await.unwrap();
+        let text = render_explanation(&explanation, "text").unwrap();
+        assert!(text.contains("TARGET     flow 'deploy'"));
+        assert!(text.contains("TEXT       api to prod"));
+
+        // A flow named in free text is not a route
+        let explanation = explain_message("please deploy api", triggers_dir, None, &prefixes).await.unwrap();
+        assert!(explanation.route.is_none());
+
+        // Builtin bindings stay with the command parser when there is no default binding
+        let json: serde_json::Value = serde_json::from_str(
+            &render_explanation(&explain_message("/help", triggers_dir, None, &prefixes).await.unwrap(), "json").unwrap(),
+        )
+        .unwrap();
+        assert!(json["route"].is_null());
+
+        // Missing target flows are called out
+        let explanation = explain_message("/rollback api", triggers_dir, flows.path().to_str(), &prefixes)
+            .await
+            .unwrap();
+        assert!(explanation.detail.contains("flow 'rollback' is not defined"));
+    }
 }
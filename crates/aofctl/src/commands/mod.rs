@@ -6,10 +6,12 @@ pub mod describe;
 pub mod logs;
 pub mod exec;
 pub mod api_resources;
+pub mod tool;
 pub mod tools;
 pub mod validate;
 pub mod version;
 pub mod serve;
 pub mod fleet;
 pub mod flow;
+pub mod contexts;
 pub mod completion;
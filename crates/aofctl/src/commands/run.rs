@@ -460,6 +460,16 @@ async fn run_agent(
             None
         };
 
+        // Seed the agent's memory so the model continues the saved conversation
+        if let (Some(session), Some(agent)) = (&resume_session, runtime.get_agent(&agent_name)) {
+            if !session.conversation.is_empty() {
+                agent
+                    .restore_history(session.conversation.clone())
+                    .await
+                    .with_context(|| "Failed to restore session history")?;
+            }
+        }
+
         // Launch interactive REPL mode with TUI log capture
         run_agent_interactive_with_resume(&runtime, &agent_name, output, resume_session).await?;
         return Ok(());
@@ -566,14 +576,8 @@ impl AppState {
         let (tx, rx) = tokio_mpsc::channel(1);
         let _ = tx; // Drop sender since we only use the receiver
 
-        // Set context window based on model
-        let context_window = match model_name.as_str() {
-            "google:gemini-2.5-flash" => 1000000, // 1M tokens
-            "google:gemini-2.0-flash" => 1000000,
-            "openai:gpt-4-turbo" => 128000,
-            "openai:gpt-4" => 8192,
-            _ => 128000, // default
-        };
+        // Resolve context window from the model capability registry
+        let context_window = aof_llm::context_window(&model_name);
 
         // Create greeting message with ASCII art
         let greeting = r#"
@@ -633,13 +637,7 @@ Press ? for help │ ESC to cancel │ Ctrl+C to quit"#;
         let (tx, rx) = tokio_mpsc::channel(1);
         let _ = tx;
 
-        let context_window = match model_name.as_str() {
-            "google:gemini-2.5-flash" => 1000000,
-            "google:gemini-2.0-flash" => 1000000,
-            "openai:gpt-4-turbo" => 128000,
-            "openai:gpt-4" => 8192,
-            _ => 128000,
-        };
+        let context_window = aof_llm::context_window(&model_name);
 
         // Convert session messages to chat history
         let mut chat_history: Vec<(String, String)> = session.to_chat_history();
@@ -742,10 +740,10 @@ Press ? for help │ ESC to cancel │ Ctrl+C to quit"#;
         }
     }
 
-    fn update_token_count(&mut self, text: &str) {
-        // Rough estimate: ~4 characters per token
-        let estimated_tokens = (text.len() / 4) as u32;
-        self.output_tokens = self.output_tokens.saturating_add(estimated_tokens);
+    /// Add the token usage reported by the model for one run
+    fn record_usage(&mut self, input_tokens: u32, output_tokens: u32) {
+        self.input_tokens = self.input_tokens.saturating_add(input_tokens);
+        self.output_tokens = self.output_tokens.saturating_add(output_tokens);
     }
 
     fn scroll_up(&mut self, amount: u16) {
@@ -900,8 +898,11 @@ async fn run_agent_interactive_with_resume(
                             if !app_state.agent_busy {
                                 // Save current session first
                                 let _ = app_state.save_session();
-                                // Create new session
+                                // Create new session, dropping the model's history as well
                                 app_state.session = Session::new(&app_state.agent_name, &app_state.model_name);
+                                if let Some(agent) = runtime.get_agent(agent_name) {
+                                    let _ = agent.restore_history(Vec::new()).await;
+                                }
                                 app_state.chat_history.clear();
                                 app_state.activities.clear();
                                 app_state.input_tokens = 0;
@@ -940,15 +941,9 @@ async fn run_agent_interactive_with_resume(
                             // Execute agent with timer updates during execution
                             app_state.chat_history.push(("user".to_string(), input_str.clone()));
 
-                            // Add to session
-                            let input_tokens_estimate = (input_str.len() / 4) as u32;
-                            app_state.session.add_message(
-                                "user",
-                                &input_str,
-                                Some(MessageTokens { input: input_tokens_estimate, output: 0 }),
-                            );
+                            // Add to session (token usage is recorded with the response)
+                            app_state.session.add_message("user", &input_str, None);
 
-                            app_state.input_tokens = app_state.input_tokens.saturating_add(input_tokens_estimate);
                             app_state.agent_busy = true;
                             app_state.last_error = None;
                             app_state.execution_start = Some(Instant::now());
@@ -963,7 +958,7 @@ async fn run_agent_interactive_with_resume(
 
                             // Draw busy state before execution
                             terminal.draw(|f| ui(f, agent_name, &app_state))?;
-                            let mut exec_future = Box::pin(runtime.execute(agent_name, &input_str));
+                            let mut exec_future = Box::pin(runtime.execute_with_usage(agent_name, &input_str));
                             let mut timer_handle = tokio::time::interval(std::time::Duration::from_millis(100));
                             let cancel_token = app_state.cancellation_token.clone();
 
@@ -988,7 +983,12 @@ async fn run_agent_interactive_with_resume(
                                     result = &mut exec_future => {
                                         let duration_ms = app_state.execution_time_ms as u64;
                                         match result {
-                                            Ok(response) => {
+                                            Ok((response, input_tokens, output_tokens)) => {
+                                                // Usage reported by the provider for the whole run
+                                                let input_tokens = input_tokens as u32;
+                                                let output_tokens = output_tokens as u32;
+                                                app_state.record_usage(input_tokens, output_tokens);
+
                                                 if response.is_empty() {
                                                     let error_msg = "Error: Empty response from agent".to_string();
                                                     app_state.chat_history.push(("error".to_string(), error_msg.clone()));
@@ -996,23 +996,20 @@ async fn run_agent_interactive_with_resume(
                                                     app_state.last_error = Some(error_msg);
                                                     app_state.add_activity(ActivityEvent::error("Empty response received"));
                                                 } else {
-                                                    // Update output tokens based on response length
-                                                    let output_tokens = (response.len() / 4) as u32;
-                                                    app_state.update_token_count(&response);
                                                     app_state.chat_history.push(("assistant".to_string(), response.clone()));
 
                                                     // Add to session
                                                     app_state.session.add_message(
                                                         "assistant",
                                                         &response,
-                                                        Some(MessageTokens { input: 0, output: output_tokens }),
+                                                        Some(MessageTokens { input: input_tokens, output: output_tokens }),
                                                     );
 
                                                     // Auto-scroll to latest message
                                                     app_state.auto_scroll_to_bottom();
 
                                                     // Emit completion activity
-                                                    app_state.add_activity(ActivityEvent::llm_response(input_tokens_estimate, output_tokens));
+                                                    app_state.add_activity(ActivityEvent::llm_response(input_tokens, output_tokens));
                                                     app_state.add_activity(ActivityEvent::completed(duration_ms));
                                                 }
                                             }
@@ -1051,6 +1048,15 @@ async fn run_agent_interactive_with_resume(
                                     }
                                 }
                             }
+
+                            // Keep the model-facing history in the session for --resume
+                            if !cancelled {
+                                if let Some(agent) = runtime.get_agent(agent_name) {
+                                    if let Ok(history) = agent.conversation_history().await {
+                                        app_state.session.conversation = history;
+                                    }
+                                }
+                            }
                         }
 
                         app_state.current_input.clear();
@@ -1374,7 +1380,7 @@ fn ui(f: &mut Frame, agent_name: &str, app: &AppState) {
                 .border_style(Style::default().fg(primary_white))
         )
         .gauge_style(Style::default().fg(Color::Green))
-        .ratio(context_percentage / 100.0)
+        .ratio((context_percentage / 100.0).min(1.0))
         .label(Span::raw(format!(
             "  IN: {} │ OUT: {} │ TOTAL: {} / {} ({:.1}%)",
             app.input_tokens, app.output_tokens, context_used, app.context_window, context_percentage
@@ -2284,6 +2290,7 @@ async fn run_job(name_or_config: &str, input: Option<&str>, output: &str) -> Res
 
 /// Run a fleet with configuration
 async fn run_fleet(config_path: &str, input: Option<&str>, output_format: &str) -> Result<()> {
+    use super::fleet::FleetRunReport;
     use aof_runtime::fleet::{FleetCoordinator, FleetEvent};
     use tokio::sync::mpsc;
     use std::time::Instant;
@@ -2385,20 +2392,19 @@ async fn run_fleet(config_path: &str, input: Option<&str>, output_format: &str)
     });
 
     // Submit task
-    let task_id = coordinator
+    coordinator
         .submit_task(task_input.clone())
         .await
         .context("Failed to submit task")?;
 
     // Execute the task (execute_next processes the queued task)
-    let task_result = coordinator
+    let task = coordinator
         .execute_next()
         .await
-        .context("Failed to execute task")?;
+        .context("Failed to execute task")?
+        .context("Fleet did not run the submitted task")?;
 
-    let result = task_result
-        .map(|t| t.result.unwrap_or_default())
-        .unwrap_or_default();
+    let result = task.result.clone().unwrap_or_default();
 
     // Stop fleet
     coordinator.stop().await.context("Failed to stop fleet")?;
@@ -2411,25 +2417,14 @@ async fn run_fleet(config_path: &str, input: Option<&str>, output_format: &str)
 
     // Output result
     match output_format {
-        "json" => {
-            let output_json = serde_json::json!({
-                "task_id": task_id,
-                "status": "Completed",
-                "result": result,
-                "duration_ms": duration_ms,
-                "fleet": fleet_name,
-            });
-            println!("{}", serde_json::to_string_pretty(&output_json)?);
-        }
-        "yaml" => {
-            let output_yaml = serde_json::json!({
-                "task_id": task_id,
-                "status": "Completed",
-                "result": result,
-                "duration_ms": duration_ms,
-                "fleet": fleet_name,
-            });
-            println!("{}", serde_yaml::to_string(&output_yaml)?);
+        "json" | "yaml" => {
+            let events = events.lock().unwrap().clone();
+            let report = FleetRunReport::new(&fleet_name, &mode, &task, &events, duration_ms);
+            if output_format == "json" {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("{}", serde_yaml::to_string(&report)?);
+            }
         }
         "text" | _ => {
             // Print beautiful fleet result
@@ -2446,5 +2441,13 @@ async fn run_fleet(config_path: &str, input: Option<&str>, output_format: &str)
         }
     }
 
+    // Non-zero exit for failed runs so CI can gate on the result
+    if task.status == aof_core::FleetTaskStatus::Failed {
+        anyhow::bail!(
+            "Fleet task failed: {}",
+            task.error.as_deref().unwrap_or("unknown error")
+        );
+    }
+
     Ok(())
 }
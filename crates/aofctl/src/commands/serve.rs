@@ -9,10 +9,11 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use aof_core::{TriggerRegistry, Registry, StandaloneTriggerType};
+use aof_core::{ApprovalConfig, AuditConfig, TriggerRegistry, Registry, StandaloneTriggerType};
 use aof_runtime::{Runtime, RuntimeOrchestrator};
 use aof_triggers::{
     TriggerHandler, TriggerHandlerConfig, TriggerServer, TriggerServerConfig,
+    CircuitBreakerConfig, MessageCatalog, Verbosity, DEFAULT_LOCALE, LogConfig,
     SlackPlatform, SlackConfig,
     DiscordPlatform, PlatformConfig,
     TelegramPlatform, TelegramConfig,
@@ -75,6 +76,10 @@ pub struct ServeSpec {
     /// Runtime settings
     #[serde(default)]
     pub runtime: RuntimeConfig,
+
+    /// Logging (format, levels, file sink); errors only to stderr when omitted
+    #[serde(default)]
+    pub logging: Option<LogConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -326,6 +331,51 @@ pub struct RuntimeConfig {
 
     /// Default agent for natural language messages (non-slash-command)
     pub default_agent: Option<String>,
+
+    /// Locale for bot messages (default: "en")
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    /// Localized bot message overrides (locale -> message id -> template)
+    #[serde(default)]
+    pub messages: MessageCatalog,
+
+    /// Reply verbosity for users who haven't picked one: terse, normal or verbose
+    #[serde(default)]
+    pub verbosity: Verbosity,
+
+    /// Accepted command prefixes (e.g. ["!aof"] to avoid clashing with Slack slash commands)
+    #[serde(default = "default_command_prefixes")]
+    pub command_prefixes: Vec<String>,
+
+    /// Consecutive failures before an agent or flow is paused (0 disables)
+    #[serde(default = "default_circuit_breaker_threshold")]
+    pub circuit_breaker_threshold: u32,
+
+    /// Seconds a paused agent or flow waits before a trial invocation
+    #[serde(default = "default_circuit_breaker_cooldown")]
+    pub circuit_breaker_cooldown_secs: u64,
+
+    /// Seconds an agent's clarification question waits for the user's answer
+    #[serde(default = "default_clarification_timeout")]
+    pub clarification_timeout_secs: u64,
+
+    /// Users who may turn safe mode off from chat (empty: nobody; use
+    /// SIGUSR1 or restart without AOF_SAFE_MODE)
+    #[serde(default)]
+    pub admin_users: Vec<String>,
+
+    /// Check a context's connectivity before every `/context` switch
+    #[serde(default)]
+    pub probe_context_switch: bool,
+
+    /// Who may approve writes (`allowed_users`, `allow_self_approval`)
+    #[serde(default)]
+    pub approval: ApprovalConfig,
+
+    /// Where approve/deny decisions are audited (`enabled`, `sink`, `events`)
+    #[serde(default)]
+    pub audit: Option<AuditConfig>,
 }
 
 impl Default for RuntimeConfig {
@@ -335,6 +385,17 @@ impl Default for RuntimeConfig {
             task_timeout_secs: default_task_timeout(),
             max_tasks_per_user: default_max_per_user(),
             default_agent: None,
+            locale: None,
+            messages: MessageCatalog::default(),
+            verbosity: Verbosity::default(),
+            command_prefixes: default_command_prefixes(),
+            circuit_breaker_threshold: default_circuit_breaker_threshold(),
+            circuit_breaker_cooldown_secs: default_circuit_breaker_cooldown(),
+            clarification_timeout_secs: default_clarification_timeout(),
+            admin_users: Vec::new(),
+            probe_context_switch: false,
+            approval: ApprovalConfig::default(),
+            audit: None,
         }
     }
 }
@@ -351,6 +412,22 @@ fn default_max_per_user() -> usize {
     3
 }
 
+fn default_command_prefixes() -> Vec<String> {
+    vec!["/".to_string()]
+}
+
+fn default_circuit_breaker_threshold() -> u32 {
+    5
+}
+
+fn default_circuit_breaker_cooldown() -> u64 {
+    60
+}
+
+fn default_clarification_timeout() -> u64 {
+    300
+}
+
 /// Resolve a value that can come from config or environment variable
 fn resolve_env_value(direct: Option<&str>, env_name: Option<&str>) -> Option<String> {
     // First try direct value
@@ -409,10 +486,22 @@ pub async fn execute(
                     watch: false,
                 },
                 runtime: RuntimeConfig::default(),
+                logging: None,
             },
         }
     };
 
+    // Install logging; the guard flushes the log file when the server exits
+    let _log_guard = config
+        .spec
+        .logging
+        .clone()
+        .unwrap_or_else(|| LogConfig {
+            level: "error".to_string(),
+            ..Default::default()
+        })
+        .init()?;
+
     // Apply CLI overrides
     let server_port = port.unwrap_or(config.spec.server.port);
     let server_host = host.unwrap_or(&config.spec.server.host);
@@ -438,6 +527,19 @@ pub async fn execute(
         default_agent: config.spec.runtime.default_agent.clone(),
         command_bindings: std::collections::HashMap::new(), // Loaded from Trigger CRDs
         max_message_age_secs: 60, // Drop messages older than 1 minute (handles queued messages)
+        default_locale: config.spec.runtime.locale.clone().unwrap_or_else(|| DEFAULT_LOCALE.to_string()),
+        messages: config.spec.runtime.messages.clone(),
+        default_verbosity: config.spec.runtime.verbosity,
+        command_prefixes: config.spec.runtime.command_prefixes.clone(),
+        circuit_breaker: CircuitBreakerConfig {
+            failure_threshold: config.spec.runtime.circuit_breaker_threshold,
+            cooldown: std::time::Duration::from_secs(config.spec.runtime.circuit_breaker_cooldown_secs),
+        },
+        clarification_timeout_secs: config.spec.runtime.clarification_timeout_secs,
+        admin_users: config.spec.runtime.admin_users.clone(),
+        probe_context_switch: config.spec.runtime.probe_context_switch,
+        approval: config.spec.runtime.approval.clone(),
+        audit: config.spec.runtime.audit.clone(),
     };
 
     if let Some(ref agent) = config.spec.runtime.default_agent {
@@ -447,6 +549,17 @@ pub async fn execute(
     // Create trigger handler
     let mut handler = TriggerHandler::with_config(orchestrator, handler_config);
 
+    // Safe mode: on at startup with AOF_SAFE_MODE=1, toggled with SIGUSR1
+    if let Some(state) = handler.safe_mode().status() {
+        println!("  {}", state.message());
+    }
+    #[cfg(unix)]
+    {
+        if let Err(e) = aof_triggers::safety::toggle_on_sigusr1(handler.safe_mode()) {
+            eprintln!("  Failed to install SIGUSR1 safe-mode handler: {}", e);
+        }
+    }
+
     // Register platforms
     let mut platforms_registered = 0;
 
@@ -752,13 +865,7 @@ pub async fn execute(
 
                         // Add command bindings from trigger
                         for (cmd, binding) in &trigger.spec.commands {
-                            // Convert core CommandBinding to handler CommandBinding
-                            let handler_binding = HandlerCommandBinding {
-                                agent: binding.agent.clone(),
-                                fleet: binding.fleet.clone(),
-                                flow: binding.flow.clone(),
-                                description: binding.description.clone(),
-                            };
+                            let handler_binding = HandlerCommandBinding::from(binding);
 
                             // Strip leading slash if present for consistent lookup
                             let cmd_name = cmd.trim_start_matches('/').to_string();
@@ -0,0 +1,138 @@
+//! Tool CLI commands for testing built-in tools in isolation
+//!
+//! Commands:
+//! - aofctl tool run <name> --input '{"command": "get pods"}' - Execute one tool
+//!
+//! This runs a tool exactly as an agent would (same registry, same input
+//! handling) without needing a model or an agent config, so a tool can be
+//! verified before it is wired into an agent.
+
+use anyhow::{bail, Context, Result};
+use aof_core::{ToolExecutor, ToolInput, ToolResult};
+use aof_tools::ToolRegistry;
+use clap::Subcommand;
+
+/// Tool subcommands
+#[derive(Subcommand, Debug)]
+pub enum ToolCommands {
+    /// Execute a built-in tool with the given input and print its result
+    Run {
+        /// Tool name (e.g. kubectl, git, pod_logs)
+        name: String,
+
+        /// Tool arguments as a JSON object
+        #[arg(short, long, default_value = "{}")]
+        input: String,
+
+        /// Output format (json, yaml)
+        #[arg(short, long, default_value = "json")]
+        output: String,
+    },
+}
+
+/// Execute tool subcommand
+pub async fn execute(cmd: ToolCommands) -> Result<()> {
+    match cmd {
+        ToolCommands::Run {
+            name,
+            input,
+            output,
+        } => {
+            let registry = aof_runtime::builtin_tool_registry(std::slice::from_ref(&name));
+            let result = run_tool(&registry, &name, &input).await?;
+            print_result(&result, &output)?;
+
+            if !result.success {
+                bail!(
+                    "Tool {} failed: {}",
+                    name,
+                    result.error.as_deref().unwrap_or("unknown error")
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Execute `name` from `registry` with a JSON object `input`
+pub async fn run_tool(registry: &ToolRegistry, name: &str, input: &str) -> Result<ToolResult> {
+    if registry.get(name).is_none() {
+        bail!("Unknown built-in tool: {}", name);
+    }
+
+    let arguments: serde_json::Value = serde_json::from_str(input)
+        .with_context(|| format!("Invalid --input for tool {}: expected a JSON object", name))?;
+    if !arguments.is_object() {
+        bail!("Invalid --input for tool {}: expected a JSON object", name);
+    }
+
+    registry
+        .as_executor()
+        .execute_tool(name, ToolInput::new(arguments))
+        .await
+        .with_context(|| format!("Failed to execute tool {}", name))
+}
+
+/// Print a tool result in the requested format
+fn print_result(result: &ToolResult, output: &str) -> Result<()> {
+    match output {
+        "json" => println!("{}", serde_json::to_string_pretty(result)?),
+        "yaml" => print!("{}", serde_yaml::to_string(result)?),
+        other => bail!("Unsupported output format: {} (use json or yaml)", other),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aof_tools::{CommandOutput, FakeRunner, KubectlTool};
+    use std::sync::Arc;
+
+    /// Answers every command with canned kubectl output
+    fn kubectl_runner() -> Arc<FakeRunner> {
+        Arc::new(FakeRunner::new(|_, _| {
+            CommandOutput::ok("NAME    READY   STATUS\napi-0   1/1     Running")
+        }))
+    }
+
+    fn kubectl_registry(runner: Arc<FakeRunner>) -> ToolRegistry {
+        let mut registry = ToolRegistry::new();
+        registry.register(KubectlTool::new().with_runner(runner)).unwrap();
+        registry
+    }
+
+    #[tokio::test]
+    async fn test_run_kubectl_with_command_input() {
+        let runner = kubectl_runner();
+        let registry = kubectl_registry(runner.clone());
+
+        let result = run_tool(&registry, "kubectl", r#"{"command": "get pods -n default"}"#)
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.data["stdout"].as_str().unwrap().contains("api-0"));
+        assert_eq!(result.data["command"], "kubectl get pods -n default");
+        assert_eq!(runner.calls(), vec![vec!["kubectl", "get", "pods", "-n", "default"]]);
+    }
+
+    #[tokio::test]
+    async fn test_run_rejects_unknown_tool_and_bad_input() {
+        let runner = kubectl_runner();
+        let registry = kubectl_registry(runner.clone());
+
+        let err = run_tool(&registry, "kubectl_nope", "{}").await.unwrap_err();
+        assert!(err.to_string().contains("Unknown built-in tool"));
+
+        assert!(run_tool(&registry, "kubectl", "get pods").await.is_err());
+        assert!(run_tool(&registry, "kubectl", r#"["get", "pods"]"#).await.is_err());
+        assert!(runner.calls().is_empty());
+    }
+
+    #[test]
+    fn test_builtin_registry_resolves_tool() {
+        let registry = aof_runtime::builtin_tool_registry(&["kubectl".to_string()]);
+        assert!(registry.get("kubectl").is_some());
+    }
+}
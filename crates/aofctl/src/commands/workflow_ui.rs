@@ -255,7 +255,10 @@ pub async fn run_visualization(
         // Check if workflow completed
         if matches!(
             viz.status,
-            WorkflowStatus::Completed | WorkflowStatus::Failed | WorkflowStatus::Cancelled
+            WorkflowStatus::Completed
+                | WorkflowStatus::Failed
+                | WorkflowStatus::Cancelled
+                | WorkflowStatus::TimedOut
         ) {
             // Wait for user to dismiss
             loop {
@@ -331,7 +334,7 @@ fn render_header(f: &mut Frame, viz: &WorkflowVisualization, area: Rect) {
         WorkflowStatus::Running => Color::Yellow,
         WorkflowStatus::WaitingApproval | WorkflowStatus::WaitingInput => Color::Cyan,
         WorkflowStatus::Completed => Color::Green,
-        WorkflowStatus::Failed => Color::Red,
+        WorkflowStatus::Failed | WorkflowStatus::TimedOut => Color::Red,
         WorkflowStatus::Cancelled => Color::DarkGray,
     };
 
@@ -543,7 +546,10 @@ fn render_logs(f: &mut Frame, viz: &WorkflowVisualization, area: Rect) {
 fn render_footer(f: &mut Frame, viz: &WorkflowVisualization, area: Rect) {
     let footer_text = if matches!(
         viz.status,
-        WorkflowStatus::Completed | WorkflowStatus::Failed | WorkflowStatus::Cancelled
+        WorkflowStatus::Completed
+            | WorkflowStatus::Failed
+            | WorkflowStatus::Cancelled
+            | WorkflowStatus::TimedOut
     ) {
         " Press Q or Enter to exit "
     } else {
@@ -618,6 +624,7 @@ mod tests {
                 checkpointing: None,
                 recovery: None,
                 fleet: None,
+                timeout: None,
             },
         }
     }
@@ -18,9 +18,12 @@ async fn main() -> anyhow::Result<()> {
     let is_interactive = matches!(&cli.command, cli::Commands::Run { input, .. }
         if input.is_none() && std::io::stdin().is_terminal());
 
+    // `serve` installs its own subscriber from the daemon config's `logging` section
+    let is_serve = matches!(&cli.command, cli::Commands::Serve { .. });
+
     // Initialize tracing only if NOT in interactive mode
     // Interactive mode will set up its own LogWriter-based layer in run_agent_interactive()
-    if !is_interactive {
+    if !is_interactive && !is_serve {
         tracing_subscriber::registry()
             .with(
                 tracing_subscriber::EnvFilter::try_from_default_env()
@@ -29,6 +29,10 @@ pub struct Session {
     pub token_usage: TokenUsage,
     /// Activity log entries
     pub activity_log: Vec<ActivityLogEntry>,
+    /// Model-facing conversation (including tool calls), restored into the
+    /// agent's memory on resume
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conversation: Vec<aof_core::Message>,
 }
 
 /// A message in the session
@@ -79,6 +83,7 @@ impl Session {
             messages: Vec::new(),
             token_usage: TokenUsage::default(),
             activity_log: Vec::new(),
+            conversation: Vec::new(),
         }
     }
 
@@ -435,6 +440,33 @@ mod tests {
         assert_eq!(loaded.messages.len(), 2);
     }
 
+    #[test]
+    fn test_conversation_round_trip() {
+        let (manager, _temp) = test_manager();
+
+        let mut session = Session::new("test-agent", "google:gemini-2.5-flash");
+        session.conversation = vec![
+            aof_core::Message {
+                role: aof_core::MessageRole::User,
+                content: "Which pods are crashing?".to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            },
+            aof_core::Message {
+                role: aof_core::MessageRole::Assistant,
+                content: "api-7f9c is in CrashLoopBackOff.".to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            },
+        ];
+        manager.save(&session).unwrap();
+
+        let latest = manager.load_latest("test-agent").unwrap();
+        assert_eq!(latest.conversation.len(), 2);
+        assert_eq!(latest.conversation[0].role, aof_core::MessageRole::User);
+        assert_eq!(latest.conversation[1].content, "api-7f9c is in CrashLoopBackOff.");
+    }
+
     #[test]
     fn test_load_latest() {
         let (manager, _temp) = test_manager();